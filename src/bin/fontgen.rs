@@ -0,0 +1,43 @@
+// Writes a glyph module for a TrueType/OpenType font, in the shape of the modules in src/render/fonts.
+//
+// cargo run --bin fontgen -- <font file> <module name> [output file]
+//
+// The module name is used for the constant names, e.g. merriweather_bold gives MERRIWEATHER_BOLD_65.
+// Without an output file the module is written to src/render/fonts/<module name>.rs
+
+use render_notation::render::fonts::codegen::{generate_module, DEFAULT_CHAR_RANGES};
+use render_notation::render::fonts::truetype::LoadedFont;
+
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    if args.len() < 3 {
+        eprintln!("Usage: fontgen <font file> <module name> [output file]");
+        std::process::exit(1);
+    }
+    let (font_path, module_name) = (&args[1], &args[2]);
+    let output_path = match args.get(3) {
+        Some(output_path) => output_path.clone(),
+        None => format!("src/render/fonts/{}.rs", module_name),
+    };
+
+    let font = match LoadedFont::load(font_path) {
+        Ok(font) => font,
+        Err(err) => {
+            eprintln!("{}: {}", font_path, err);
+            std::process::exit(1);
+        }
+    };
+
+    let module = match generate_module(&font, module_name, &DEFAULT_CHAR_RANGES) {
+        Ok(module) => module,
+        Err(err) => {
+            eprintln!("{}: {}", font_path, err);
+            std::process::exit(1);
+        }
+    };
+    if let Err(err) = std::fs::write(&output_path, module) {
+        eprintln!("{}: {}", output_path, err);
+        std::process::exit(1);
+    }
+    println!("Wrote {} - add `pub mod {};` to src/render/fonts/mod.rs", output_path, module_name);
+}
@@ -1,5 +1,24 @@
+pub use crate::render::output::elements::ackolades::*;
+pub use crate::render::output::elements::analysis::*;
 pub use crate::render::output::elements::beams::*;
+pub use crate::render::output::elements::chorddiagrams::*;
+pub use crate::render::output::elements::chords::*;
 pub use crate::render::output::elements::elements::*;
+pub use crate::render::output::elements::figuredbass::*;
+pub use crate::render::output::elements::functions::*;
+pub use crate::render::output::elements::intervals::*;
+pub use crate::render::output::elements::keyboards::*;
+pub use crate::render::output::elements::lyrics::*;
+pub use crate::render::output::elements::ornaments::*;
+pub use crate::render::output::elements::romannumerals::*;
+pub use crate::render::output::elements::scaledegrees::*;
+pub use crate::render::output::elements::smallnotes::*;
+pub use crate::render::output::elements::spanners::*;
+pub use crate::render::output::elements::staves::*;
+pub use crate::render::output::elements::tablature::*;
+pub use crate::render::output::elements::tremolos::*;
+pub use crate::render::output::elements::tuplets::*;
+pub use crate::render::output::options::*;
 pub use crate::render::output::output;
 pub use crate::render::output::output::*;
 pub use crate::render::output::*;
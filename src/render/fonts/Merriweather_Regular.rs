@@ -1,8 +1,13 @@
 use graphics::path::PathSegment;
 use graphics::path::PathSegment::*;
 
+// Glyph outline, falling back to the notdef box for characters the font does not cover
 pub fn get_path(c: char) -> &'static [PathSegment] {
-    match c as u32 {
+    get_glyph(c).unwrap_or(MERRIWEATHER_REGULAR_NOTDEF)
+}
+
+pub fn get_glyph(c: char) -> Option<&'static [PathSegment]> {
+    let path = match c as u32 {
         // 0 => MERRIWEATHER_REGULAR_0,
         // 1 => MERRIWEATHER_REGULAR_1,
         // 2 => MERRIWEATHER_REGULAR_2,
@@ -129,7 +134,7 @@ pub fn get_path(c: char) -> &'static [PathSegment] {
         123 => MERRIWEATHER_REGULAR_123,
         124 => MERRIWEATHER_REGULAR_124,
         125 => MERRIWEATHER_REGULAR_125,
-        // 126 => MERRIWEATHER_REGULAR_126,
+        126 => MERRIWEATHER_REGULAR_126,
         // 127 => MERRIWEATHER_REGULAR_127,
         // 128 => MERRIWEATHER_REGULAR_128,
         // 129 => MERRIWEATHER_REGULAR_129,
@@ -163,110 +168,124 @@ pub fn get_path(c: char) -> &'static [PathSegment] {
         // 157 => MERRIWEATHER_REGULAR_157,
         // 158 => MERRIWEATHER_REGULAR_158,
         // 159 => MERRIWEATHER_REGULAR_159,
-        // 160 => MERRIWEATHER_REGULAR_160,
-        // 161 => MERRIWEATHER_REGULAR_161,
-        // 162 => MERRIWEATHER_REGULAR_162,
-        // 163 => MERRIWEATHER_REGULAR_163,
-        // 164 => MERRIWEATHER_REGULAR_164,
-        // 165 => MERRIWEATHER_REGULAR_165,
-        // 166 => MERRIWEATHER_REGULAR_166,
-        // 167 => MERRIWEATHER_REGULAR_167,
-        // 168 => MERRIWEATHER_REGULAR_168,
-        // 169 => MERRIWEATHER_REGULAR_169,
-        // 170 => MERRIWEATHER_REGULAR_170,
-        // 171 => MERRIWEATHER_REGULAR_171,
-        // 172 => MERRIWEATHER_REGULAR_172,
-        // 173 => MERRIWEATHER_REGULAR_173,
-        // 174 => MERRIWEATHER_REGULAR_174,
-        // 175 => MERRIWEATHER_REGULAR_175,
-        // 176 => MERRIWEATHER_REGULAR_176,
-        // 177 => MERRIWEATHER_REGULAR_177,
+        160 => MERRIWEATHER_REGULAR_NBSP,
+        161 => MERRIWEATHER_REGULAR_161,
+        162 => MERRIWEATHER_REGULAR_162,
+        163 => MERRIWEATHER_REGULAR_163,
+        164 => MERRIWEATHER_REGULAR_164,
+        165 => MERRIWEATHER_REGULAR_165,
+        166 => MERRIWEATHER_REGULAR_166,
+        167 => MERRIWEATHER_REGULAR_167,
+        168 => MERRIWEATHER_REGULAR_168,
+        169 => MERRIWEATHER_REGULAR_169,
+        170 => MERRIWEATHER_REGULAR_170,
+        171 => MERRIWEATHER_REGULAR_171,
+        172 => MERRIWEATHER_REGULAR_172,
+        173 => MERRIWEATHER_REGULAR_173,
+        174 => MERRIWEATHER_REGULAR_174,
+        175 => MERRIWEATHER_REGULAR_175,
+        176 => MERRIWEATHER_REGULAR_176,
+        177 => MERRIWEATHER_REGULAR_177,
         178 => MERRIWEATHER_REGULAR_178,
         179 => MERRIWEATHER_REGULAR_179,
-        // 180 => MERRIWEATHER_REGULAR_180,
+        180 => MERRIWEATHER_REGULAR_180,
         181 => MERRIWEATHER_REGULAR_181,
         182 => MERRIWEATHER_REGULAR_182,
-        // 183 => MERRIWEATHER_REGULAR_183,
-        // 184 => MERRIWEATHER_REGULAR_184,
+        183 => MERRIWEATHER_REGULAR_183,
+        184 => MERRIWEATHER_REGULAR_184,
         185 => MERRIWEATHER_REGULAR_185,
         186 => MERRIWEATHER_REGULAR_186,
-        // 187 => MERRIWEATHER_REGULAR_187,
-        // 188 => MERRIWEATHER_REGULAR_188,
-        // 189 => MERRIWEATHER_REGULAR_189,
-        // 190 => MERRIWEATHER_REGULAR_190,
-        // 191 => MERRIWEATHER_REGULAR_191,
-        // 192 => MERRIWEATHER_REGULAR_192,
-        // 193 => MERRIWEATHER_REGULAR_193,
-        // 194 => MERRIWEATHER_REGULAR_194,
-        // 195 => MERRIWEATHER_REGULAR_195,
-        // 196 => MERRIWEATHER_REGULAR_196,
-        // 197 => MERRIWEATHER_REGULAR_197,
-        // 198 => MERRIWEATHER_REGULAR_198,
-        // 199 => MERRIWEATHER_REGULAR_199,
-        // 200 => MERRIWEATHER_REGULAR_200,
-        // 201 => MERRIWEATHER_REGULAR_201,
-        // 202 => MERRIWEATHER_REGULAR_202,
-        // 203 => MERRIWEATHER_REGULAR_203,
-        // 204 => MERRIWEATHER_REGULAR_204,
-        // 205 => MERRIWEATHER_REGULAR_205,
-        // 206 => MERRIWEATHER_REGULAR_206,
-        // 207 => MERRIWEATHER_REGULAR_207,
-        // 208 => MERRIWEATHER_REGULAR_208,
-        // 209 => MERRIWEATHER_REGULAR_209,
-        // 210 => MERRIWEATHER_REGULAR_210,
-        // 211 => MERRIWEATHER_REGULAR_211,
-        // 212 => MERRIWEATHER_REGULAR_212,
-        // 213 => MERRIWEATHER_REGULAR_213,
-        // 214 => MERRIWEATHER_REGULAR_214,
-        // 215 => MERRIWEATHER_REGULAR_215,
-        // 216 => MERRIWEATHER_REGULAR_216,
-        // 217 => MERRIWEATHER_REGULAR_217,
-        // 218 => MERRIWEATHER_REGULAR_218,
-        // 219 => MERRIWEATHER_REGULAR_219,
-        // 220 => MERRIWEATHER_REGULAR_220,
-        // 221 => MERRIWEATHER_REGULAR_221,
-        // 222 => MERRIWEATHER_REGULAR_222,
-        // 223 => MERRIWEATHER_REGULAR_223,
-        // 224 => MERRIWEATHER_REGULAR_224,
-        // 225 => MERRIWEATHER_REGULAR_225,
-        // 226 => MERRIWEATHER_REGULAR_226,
-        // 227 => MERRIWEATHER_REGULAR_227,
-        // 228 => MERRIWEATHER_REGULAR_228,
-        // 229 => MERRIWEATHER_REGULAR_229,
-        // 230 => MERRIWEATHER_REGULAR_230,
-        // 231 => MERRIWEATHER_REGULAR_231,
-        // 232 => MERRIWEATHER_REGULAR_232,
-        // 233 => MERRIWEATHER_REGULAR_233,
-        // 234 => MERRIWEATHER_REGULAR_234,
-        // 235 => MERRIWEATHER_REGULAR_235,
-        // 236 => MERRIWEATHER_REGULAR_236,
-        // 237 => MERRIWEATHER_REGULAR_237,
-        // 238 => MERRIWEATHER_REGULAR_238,
-        // 239 => MERRIWEATHER_REGULAR_239,
-        // 240 => MERRIWEATHER_REGULAR_240,
-        // 241 => MERRIWEATHER_REGULAR_241,
-        // 242 => MERRIWEATHER_REGULAR_242,
-        // 243 => MERRIWEATHER_REGULAR_243,
-        // 244 => MERRIWEATHER_REGULAR_244,
-        // 245 => MERRIWEATHER_REGULAR_245,
-        // 246 => MERRIWEATHER_REGULAR_246,
-        // 247 => MERRIWEATHER_REGULAR_247,
-        // 248 => MERRIWEATHER_REGULAR_248,
-        // 249 => MERRIWEATHER_REGULAR_249,
-        // 250 => MERRIWEATHER_REGULAR_250,
-        // 251 => MERRIWEATHER_REGULAR_251,
-        // 252 => MERRIWEATHER_REGULAR_252,
-        // 253 => MERRIWEATHER_REGULAR_253,
-        // 254 => MERRIWEATHER_REGULAR_254,
-        _ => {
-            println!("Not yet implemented: char code {}", c as u32);
-            todo!()
-        }
-    }
+        187 => MERRIWEATHER_REGULAR_187,
+        188 => MERRIWEATHER_REGULAR_188,
+        189 => MERRIWEATHER_REGULAR_189,
+        190 => MERRIWEATHER_REGULAR_190,
+        191 => MERRIWEATHER_REGULAR_191,
+        192 => MERRIWEATHER_REGULAR_192,
+        193 => MERRIWEATHER_REGULAR_193,
+        194 => MERRIWEATHER_REGULAR_194,
+        195 => MERRIWEATHER_REGULAR_195,
+        196 => MERRIWEATHER_REGULAR_196,
+        197 => MERRIWEATHER_REGULAR_197,
+        198 => MERRIWEATHER_REGULAR_198,
+        199 => MERRIWEATHER_REGULAR_199,
+        200 => MERRIWEATHER_REGULAR_200,
+        201 => MERRIWEATHER_REGULAR_201,
+        202 => MERRIWEATHER_REGULAR_202,
+        203 => MERRIWEATHER_REGULAR_203,
+        204 => MERRIWEATHER_REGULAR_204,
+        205 => MERRIWEATHER_REGULAR_205,
+        206 => MERRIWEATHER_REGULAR_206,
+        207 => MERRIWEATHER_REGULAR_207,
+        208 => MERRIWEATHER_REGULAR_208,
+        209 => MERRIWEATHER_REGULAR_209,
+        210 => MERRIWEATHER_REGULAR_210,
+        211 => MERRIWEATHER_REGULAR_211,
+        212 => MERRIWEATHER_REGULAR_212,
+        213 => MERRIWEATHER_REGULAR_213,
+        214 => MERRIWEATHER_REGULAR_214,
+        215 => MERRIWEATHER_REGULAR_215,
+        216 => MERRIWEATHER_REGULAR_216,
+        217 => MERRIWEATHER_REGULAR_217,
+        218 => MERRIWEATHER_REGULAR_218,
+        219 => MERRIWEATHER_REGULAR_219,
+        220 => MERRIWEATHER_REGULAR_220,
+        221 => MERRIWEATHER_REGULAR_221,
+        222 => MERRIWEATHER_REGULAR_222,
+        223 => MERRIWEATHER_REGULAR_223,
+        224 => MERRIWEATHER_REGULAR_224,
+        225 => MERRIWEATHER_REGULAR_225,
+        226 => MERRIWEATHER_REGULAR_226,
+        227 => MERRIWEATHER_REGULAR_227,
+        228 => MERRIWEATHER_REGULAR_228,
+        229 => MERRIWEATHER_REGULAR_229,
+        230 => MERRIWEATHER_REGULAR_230,
+        231 => MERRIWEATHER_REGULAR_231,
+        232 => MERRIWEATHER_REGULAR_232,
+        233 => MERRIWEATHER_REGULAR_233,
+        234 => MERRIWEATHER_REGULAR_234,
+        235 => MERRIWEATHER_REGULAR_235,
+        236 => MERRIWEATHER_REGULAR_236,
+        237 => MERRIWEATHER_REGULAR_237,
+        238 => MERRIWEATHER_REGULAR_238,
+        239 => MERRIWEATHER_REGULAR_239,
+        240 => MERRIWEATHER_REGULAR_240,
+        241 => MERRIWEATHER_REGULAR_241,
+        242 => MERRIWEATHER_REGULAR_242,
+        243 => MERRIWEATHER_REGULAR_243,
+        244 => MERRIWEATHER_REGULAR_244,
+        245 => MERRIWEATHER_REGULAR_245,
+        246 => MERRIWEATHER_REGULAR_246,
+        247 => MERRIWEATHER_REGULAR_247,
+        248 => MERRIWEATHER_REGULAR_248,
+        249 => MERRIWEATHER_REGULAR_249,
+        250 => MERRIWEATHER_REGULAR_250,
+        251 => MERRIWEATHER_REGULAR_251,
+        252 => MERRIWEATHER_REGULAR_252,
+        253 => MERRIWEATHER_REGULAR_253,
+        254 => MERRIWEATHER_REGULAR_254,
+        _ => return None,
+    };
+    Some(path)
+}
+
+// Advance width from the hmtx table, in the units of the glyph tables.
+// These tables predate fontgen and carry no metrics yet, regenerate the module with fontgen from the font file to fill them.
+pub fn get_advance(_c: char) -> Option<f32> {
+    None
 }
 
+// Kerning of a pair from the kern/GPOS tables, 0.0 when the pair is not kerned
+pub fn get_kerning(_left: char, _right: char) -> f32 {
+    0.0
+}
+
+// no-break space, no outline and the advance of a space
+pub const MERRIWEATHER_REGULAR_NBSP: &'static [PathSegment] = &[];
+
+pub const MERRIWEATHER_REGULAR_NOTDEF: &'static [PathSegment] = MERRIWEATHER_REGULAR_1;
+
 // //  ------------------------------
-// pub const MERRIWEATHER_REGULAR_1: &'static [PathSegment] = &[M(618.93396, -0.0), L(68.41686, -0.0), L(68.41686, -549.72156), L(618.93396, -549.72156), L(618.93396, -0.0), Z, M(474.14478, -97.85203), L(517.8997, -141.607), L(382.6571, -275.25854), L(517.8997, -404.93237), L(471.75815, -449.48288), L(344.47095, -313.4447), L(214.79713, -450.2784), L(170.24661, -404.93237), L(303.89816, -273.66745), L(171.04216, -150.35799), L(217.18376, -97.85203), L(342.87985, -234.68575), L(474.14478, -97.85203), Z];
+pub const MERRIWEATHER_REGULAR_1: &'static [PathSegment] = &[M(618.93396, -0.0), L(68.41686, -0.0), L(68.41686, -549.72156), L(618.93396, -549.72156), L(618.93396, -0.0), Z, M(474.14478, -97.85203), L(517.8997, -141.607), L(382.6571, -275.25854), L(517.8997, -404.93237), L(471.75815, -449.48288), L(344.47095, -313.4447), L(214.79713, -450.2784), L(170.24661, -404.93237), L(303.89816, -273.66745), L(171.04216, -150.35799), L(217.18376, -97.85203), L(342.87985, -234.68575), L(474.14478, -97.85203), Z];
 
 // //  ------------------------------
 // pub const MERRIWEATHER_REGULAR_2: &'static [PathSegment] = &[M(618.93396, -0.0), L(68.41686, -0.0), L(68.41686, -549.72156), L(618.93396, -549.72156), L(618.93396, -0.0), Z, M(474.14478, -97.85203), L(517.8997, -141.607), L(382.6571, -275.25854), L(517.8997, -404.93237), L(471.75815, -449.48288), L(344.47095, -313.4447), L(214.79713, -450.2784), L(170.24661, -404.93237), L(303.89816, -273.66745), L(171.04216, -150.35799), L(217.18376, -97.85203), L(342.87985, -234.68575), L(474.14478, -97.85203), Z];
@@ -3173,7 +3192,7 @@ pub const MERRIWEATHER_REGULAR_125: &'static [PathSegment] = &[
 ];
 
 // // ~ ------------------------------
-// pub const MERRIWEATHER_REGULAR_126: &'static [PathSegment] = &[M(132.856, -279.23627), Q(166.26889, -301.51154, 206.04613, -301.51154), Q(231.50357, -301.51154, 250.59665, -291.965), Q(269.68973, -282.41846, 293.5561, -264.1209), Q(311.8536, -249.8011, 324.58234, -242.6412), Q(337.31104, -235.4813, 352.4264, -235.4813), Q(399.36356, -235.4813, 433.572, -280.82736), L(464.59824, -247.41447), Q(447.89178, -217.18376, 415.27444, -195.30627), Q(382.6571, -173.42879, 343.6754, -173.42879), Q(319.01352, -173.42879, 300.71597, -182.97533), Q(282.41846, -192.52187, 260.1432, -210.02386), Q(241.84566, -225.1392, 228.3214, -232.69688), Q(214.79713, -240.25456, 198.09068, -240.25456), Q(151.94908, -240.25456, 115.35401, -194.90851), L(85.12331, -229.11694), Q(99.443115, -256.961, 132.856, -279.23627), Z];
+pub const MERRIWEATHER_REGULAR_126: &'static [PathSegment] = &[M(132.856, -279.23627), Q(166.26889, -301.51154, 206.04613, -301.51154), Q(231.50357, -301.51154, 250.59665, -291.965), Q(269.68973, -282.41846, 293.5561, -264.1209), Q(311.8536, -249.8011, 324.58234, -242.6412), Q(337.31104, -235.4813, 352.4264, -235.4813), Q(399.36356, -235.4813, 433.572, -280.82736), L(464.59824, -247.41447), Q(447.89178, -217.18376, 415.27444, -195.30627), Q(382.6571, -173.42879, 343.6754, -173.42879), Q(319.01352, -173.42879, 300.71597, -182.97533), Q(282.41846, -192.52187, 260.1432, -210.02386), Q(241.84566, -225.1392, 228.3214, -232.69688), Q(214.79713, -240.25456, 198.09068, -240.25456), Q(151.94908, -240.25456, 115.35401, -194.90851), L(85.12331, -229.11694), Q(99.443115, -256.961, 132.856, -279.23627), Z];
 
 // //  ------------------------------
 // pub const MERRIWEATHER_REGULAR_127: &'static [PathSegment] = &[M(618.93396, -0.0), L(68.41686, -0.0), L(68.41686, -549.72156), L(618.93396, -549.72156), L(618.93396, -0.0), Z, M(474.14478, -97.85203), L(517.8997, -141.607), L(382.6571, -275.25854), L(517.8997, -404.93237), L(471.75815, -449.48288), L(344.47095, -313.4447), L(214.79713, -450.2784), L(170.24661, -404.93237), L(303.89816, -273.66745), L(171.04216, -150.35799), L(217.18376, -97.85203), L(342.87985, -234.68575), L(474.14478, -97.85203), Z];
@@ -3275,55 +3294,55 @@ pub const MERRIWEATHER_REGULAR_125: &'static [PathSegment] = &[
 // pub const MERRIWEATHER_REGULAR_159: &'static [PathSegment] = &[M(618.93396, -0.0), L(68.41686, -0.0), L(68.41686, -549.72156), L(618.93396, -549.72156), L(618.93396, -0.0), Z, M(474.14478, -97.85203), L(517.8997, -141.607), L(382.6571, -275.25854), L(517.8997, -404.93237), L(471.75815, -449.48288), L(344.47095, -313.4447), L(214.79713, -450.2784), L(170.24661, -404.93237), L(303.89816, -273.66745), L(171.04216, -150.35799), L(217.18376, -97.85203), L(342.87985, -234.68575), L(474.14478, -97.85203), Z];
 
 // // ¡ ------------------------------
-// pub const MERRIWEATHER_REGULAR_161: &'static [PathSegment] = &[M(178.20206, -466.98486), Q(159.90453, -450.2784, 136.83372, -450.2784), Q(111.37629, -450.2784, 97.85203, -464.59824), Q(84.32776, -478.91803, 84.32776, -500.39777), Q(84.32776, -525.85516, 102.22752, -541.7661), Q(120.12728, -557.677, 145.58472, -557.677), Q(166.26889, -557.677, 181.38425, -543.75494), Q(196.4996, -529.83295, 196.4996, -507.55765), Q(196.4996, -483.6913, 178.20206, -466.98486), Z, M(117.74065, -360.38184), L(170.24661, -360.38184), L(193.31741, 143.99364), L(96.26093, 143.99364), L(117.74065, -360.38184), Z];
+pub const MERRIWEATHER_REGULAR_161: &'static [PathSegment] = &[M(178.20206, -466.98486), Q(159.90453, -450.2784, 136.83372, -450.2784), Q(111.37629, -450.2784, 97.85203, -464.59824), Q(84.32776, -478.91803, 84.32776, -500.39777), Q(84.32776, -525.85516, 102.22752, -541.7661), Q(120.12728, -557.677, 145.58472, -557.677), Q(166.26889, -557.677, 181.38425, -543.75494), Q(196.4996, -529.83295, 196.4996, -507.55765), Q(196.4996, -483.6913, 178.20206, -466.98486), Z, M(117.74065, -360.38184), L(170.24661, -360.38184), L(193.31741, 143.99364), L(96.26093, 143.99364), L(117.74065, -360.38184), Z];
 
 // // ¢ ------------------------------
-// pub const MERRIWEATHER_REGULAR_162: &'static [PathSegment] = &[M(78.361176, -330.9467), Q(101.0342, -381.86157, 145.58472, -414.08115), Q(190.13524, -446.3007, 252.98329, -451.8695), L(259.34766, -543.3572), L(311.05807, -543.3572), L(303.89816, -452.66507), Q(338.1066, -449.48288, 365.15512, -440.3341), Q(392.20364, -431.18533, 397.77246, -429.59427), L(392.9992, -307.08035), L(342.08432, -307.08035), L(324.58234, -388.22592), Q(319.80905, -410.5012, 264.1209, -410.5012), Q(209.22832, -410.5012, 177.00874, -365.5529), Q(144.78917, -320.6046, 144.78917, -237.86794), Q(144.78917, -143.99364, 180.5887, -95.06762), Q(216.38821, -46.141605, 274.463, -46.141605), Q(307.8759, -46.141605, 338.1066, -56.48369), Q(368.3373, -66.825775, 389.02145, -81.145584), L(404.1368, -49.323784), Q(385.04373, -28.639618, 347.65314, -11.535401), Q(310.2625, 5.5688143, 269.68973, 8.750994), L(264.91647, 94.669846), L(214.00159, 94.669846), L(218.77486, 5.5688143), Q(140.0159, -6.3643594, 97.85203, -67.621315), Q(55.688145, -128.87828, 55.688145, -218.77486), Q(55.688145, -280.0318, 78.361176, -330.9467), Z];
+pub const MERRIWEATHER_REGULAR_162: &'static [PathSegment] = &[M(78.361176, -330.9467), Q(101.0342, -381.86157, 145.58472, -414.08115), Q(190.13524, -446.3007, 252.98329, -451.8695), L(259.34766, -543.3572), L(311.05807, -543.3572), L(303.89816, -452.66507), Q(338.1066, -449.48288, 365.15512, -440.3341), Q(392.20364, -431.18533, 397.77246, -429.59427), L(392.9992, -307.08035), L(342.08432, -307.08035), L(324.58234, -388.22592), Q(319.80905, -410.5012, 264.1209, -410.5012), Q(209.22832, -410.5012, 177.00874, -365.5529), Q(144.78917, -320.6046, 144.78917, -237.86794), Q(144.78917, -143.99364, 180.5887, -95.06762), Q(216.38821, -46.141605, 274.463, -46.141605), Q(307.8759, -46.141605, 338.1066, -56.48369), Q(368.3373, -66.825775, 389.02145, -81.145584), L(404.1368, -49.323784), Q(385.04373, -28.639618, 347.65314, -11.535401), Q(310.2625, 5.5688143, 269.68973, 8.750994), L(264.91647, 94.669846), L(214.00159, 94.669846), L(218.77486, 5.5688143), Q(140.0159, -6.3643594, 97.85203, -67.621315), Q(55.688145, -128.87828, 55.688145, -218.77486), Q(55.688145, -280.0318, 78.361176, -330.9467), Z];
 
 // // £ ------------------------------
-// pub const MERRIWEATHER_REGULAR_163: &'static [PathSegment] = &[M(153.54018, -396.97693), Q(153.54018, -455.84723, 179.79315, -501.59106), Q(206.04613, -547.3349, 250.99443, -572.3946), Q(295.94272, -597.4542, 350.03976, -597.4542), Q(395.38583, -597.4542, 432.77643, -584.32776), Q(470.16705, -571.20123, 493.23785, -551.3126), L(451.8695, -490.85123), Q(447.09625, -499.6022, 429.19647, -513.52423), Q(411.29672, -527.4463, 385.44153, -538.18616), Q(359.5863, -548.926, 330.15115, -548.926), Q(240.25456, -548.926, 240.25456, -416.07), L(240.25456, -342.08432), L(361.97293, -342.08432), L(361.97293, -298.32935), L(240.25456, -298.32935), L(240.25456, -213.20604), Q(240.25456, -167.06444, 227.52585, -135.24263), Q(214.79713, -103.42084, 192.52187, -79.55449), Q(221.16148, -77.167854, 272.87192, -66.03023), Q(305.48926, -58.870323, 329.75336, -54.8926), Q(354.0175, -50.914875, 377.88385, -50.914875), Q(426.41208, -50.914875, 445.1074, -69.21241), Q(463.8027, -87.50994, 463.8027, -128.87828), Q(463.8027, -151.15353, 458.2339, -172.23547), Q(452.66507, -193.31741, 443.91406, -201.27287), L(531.424, -201.27287), Q(537.7884, -193.31741, 542.56165, -176.61098), Q(547.3349, -159.90453, 547.3349, -137.62927), Q(547.3349, -100.23866, 526.65076, -67.22355), Q(505.96658, -34.20843, 469.3715, -14.319809), Q(432.77643, 5.5688143, 386.63483, 5.5688143), Q(351.63086, 5.5688143, 322.59348, -1.5910898), Q(293.5561, -8.750994, 253.77882, -22.275257), Q(221.16148, -33.412888, 199.68178, -38.583927), Q(178.20206, -43.75497, 155.9268, -44.550514), Q(116.94511, -0.0, 79.55449, -0.0), Q(63.643593, -0.0, 51.31265, -7.955449), Q(38.9817, -15.910898, 38.9817, -32.61734), Q(38.9817, -47.732697, 52.108192, -61.256958), Q(65.23468, -74.78122, 86.71439, -82.3389), Q(108.19411, -89.896576, 132.06046, -89.10103), Q(140.81145, -102.6253, 147.17581, -136.43596), Q(153.54018, -170.24661, 153.54018, -202.0684), L(153.54018, -298.32935), L(74.78122, -298.32935), L(74.78122, -342.08432), L(153.54018, -342.08432), L(153.54018, -396.97693), Z];
+pub const MERRIWEATHER_REGULAR_163: &'static [PathSegment] = &[M(153.54018, -396.97693), Q(153.54018, -455.84723, 179.79315, -501.59106), Q(206.04613, -547.3349, 250.99443, -572.3946), Q(295.94272, -597.4542, 350.03976, -597.4542), Q(395.38583, -597.4542, 432.77643, -584.32776), Q(470.16705, -571.20123, 493.23785, -551.3126), L(451.8695, -490.85123), Q(447.09625, -499.6022, 429.19647, -513.52423), Q(411.29672, -527.4463, 385.44153, -538.18616), Q(359.5863, -548.926, 330.15115, -548.926), Q(240.25456, -548.926, 240.25456, -416.07), L(240.25456, -342.08432), L(361.97293, -342.08432), L(361.97293, -298.32935), L(240.25456, -298.32935), L(240.25456, -213.20604), Q(240.25456, -167.06444, 227.52585, -135.24263), Q(214.79713, -103.42084, 192.52187, -79.55449), Q(221.16148, -77.167854, 272.87192, -66.03023), Q(305.48926, -58.870323, 329.75336, -54.8926), Q(354.0175, -50.914875, 377.88385, -50.914875), Q(426.41208, -50.914875, 445.1074, -69.21241), Q(463.8027, -87.50994, 463.8027, -128.87828), Q(463.8027, -151.15353, 458.2339, -172.23547), Q(452.66507, -193.31741, 443.91406, -201.27287), L(531.424, -201.27287), Q(537.7884, -193.31741, 542.56165, -176.61098), Q(547.3349, -159.90453, 547.3349, -137.62927), Q(547.3349, -100.23866, 526.65076, -67.22355), Q(505.96658, -34.20843, 469.3715, -14.319809), Q(432.77643, 5.5688143, 386.63483, 5.5688143), Q(351.63086, 5.5688143, 322.59348, -1.5910898), Q(293.5561, -8.750994, 253.77882, -22.275257), Q(221.16148, -33.412888, 199.68178, -38.583927), Q(178.20206, -43.75497, 155.9268, -44.550514), Q(116.94511, -0.0, 79.55449, -0.0), Q(63.643593, -0.0, 51.31265, -7.955449), Q(38.9817, -15.910898, 38.9817, -32.61734), Q(38.9817, -47.732697, 52.108192, -61.256958), Q(65.23468, -74.78122, 86.71439, -82.3389), Q(108.19411, -89.896576, 132.06046, -89.10103), Q(140.81145, -102.6253, 147.17581, -136.43596), Q(153.54018, -170.24661, 153.54018, -202.0684), L(153.54018, -298.32935), L(74.78122, -298.32935), L(74.78122, -342.08432), L(153.54018, -342.08432), L(153.54018, -396.97693), Z];
 
 // // ¤ ------------------------------
-// pub const MERRIWEATHER_REGULAR_164: &'static [PathSegment] = &[M(130.46936, -296.73825), Q(130.46936, -356.4041, 163.08672, -402.54575), L(78.75895, -486.8735), L(121.718376, -530.6285), L(206.04613, -445.50516), Q(249.00555, -474.14478, 299.12488, -474.14478), Q(349.24423, -474.14478, 394.59027, -447.09625), L(478.91803, -530.6285), L(521.87744, -487.66904), L(439.1408, -405.7279), Q(471.75815, -361.97293, 471.75815, -303.89816), Q(471.75815, -243.43675, 436.75415, -197.29514), L(520.2864, -114.55847), L(477.32697, -70.8035), L(393.79474, -155.13126), Q(348.44867, -126.491646, 299.12488, -126.491646), Q(249.8011, -126.491646, 205.2506, -152.74463), L(121.718376, -69.21241), L(78.75895, -112.96738), L(161.49562, -194.90851), Q(130.46936, -238.66348, 130.46936, -296.73825), Z, M(378.28162, -218.77486), Q(405.7279, -250.59665, 405.7279, -299.12488), Q(405.7279, -330.15115, 391.0103, -356.4041), Q(376.29276, -382.6571, 351.2331, -398.17023), Q(326.17343, -413.68335, 297.5338, -413.68335), Q(248.21002, -413.68335, 220.76372, -379.8727), Q(193.31741, -346.06204, 193.31741, -297.5338), Q(193.31741, -267.3031, 207.63722, -241.84566), Q(221.95703, -216.38821, 246.22116, -201.67064), Q(270.48526, -186.95306, 299.12488, -186.95306), Q(350.8353, -186.95306, 378.28162, -218.77486), Z];
+pub const MERRIWEATHER_REGULAR_164: &'static [PathSegment] = &[M(130.46936, -296.73825), Q(130.46936, -356.4041, 163.08672, -402.54575), L(78.75895, -486.8735), L(121.718376, -530.6285), L(206.04613, -445.50516), Q(249.00555, -474.14478, 299.12488, -474.14478), Q(349.24423, -474.14478, 394.59027, -447.09625), L(478.91803, -530.6285), L(521.87744, -487.66904), L(439.1408, -405.7279), Q(471.75815, -361.97293, 471.75815, -303.89816), Q(471.75815, -243.43675, 436.75415, -197.29514), L(520.2864, -114.55847), L(477.32697, -70.8035), L(393.79474, -155.13126), Q(348.44867, -126.491646, 299.12488, -126.491646), Q(249.8011, -126.491646, 205.2506, -152.74463), L(121.718376, -69.21241), L(78.75895, -112.96738), L(161.49562, -194.90851), Q(130.46936, -238.66348, 130.46936, -296.73825), Z, M(378.28162, -218.77486), Q(405.7279, -250.59665, 405.7279, -299.12488), Q(405.7279, -330.15115, 391.0103, -356.4041), Q(376.29276, -382.6571, 351.2331, -398.17023), Q(326.17343, -413.68335, 297.5338, -413.68335), Q(248.21002, -413.68335, 220.76372, -379.8727), Q(193.31741, -346.06204, 193.31741, -297.5338), Q(193.31741, -267.3031, 207.63722, -241.84566), Q(221.95703, -216.38821, 246.22116, -201.67064), Q(270.48526, -186.95306, 299.12488, -186.95306), Q(350.8353, -186.95306, 378.28162, -218.77486), Z];
 
 // // ¥ ------------------------------
-// pub const MERRIWEATHER_REGULAR_165: &'static [PathSegment] = &[M(96.26093, -544.94824), L(45.34606, -555.29034), L(45.34606, -591.0899), L(256.16547, -591.0899), L(256.16547, -555.29034), L(198.09068, -546.53937), L(299.92044, -376.29276), L(323.78677, -326.96896), L(351.63086, -377.0883), L(443.91406, -546.53937), L(383.45267, -555.29034), L(383.45267, -591.0899), L(566.428, -591.0899), L(566.428, -555.29034), L(509.9443, -544.94824), L(371.51947, -326.17343), L(529.03735, -326.17343), L(529.03735, -284.00955), L(350.03976, -284.00955), L(350.03976, -182.1798), L(529.03735, -182.1798), L(529.03735, -139.22037), L(350.03976, -139.22037), L(350.03976, -44.550514), L(440.73187, -35.799522), L(440.73187, -0.0), L(182.97533, -0.0), L(182.97533, -35.799522), L(271.28082, -44.550514), L(271.28082, -139.22037), L(93.07876, -139.22037), L(93.07876, -182.1798), L(271.28082, -182.1798), L(271.28082, -284.00955), L(93.07876, -284.00955), L(93.07876, -326.17343), L(243.43675, -326.17343), L(96.26093, -544.94824), Z];
+pub const MERRIWEATHER_REGULAR_165: &'static [PathSegment] = &[M(96.26093, -544.94824), L(45.34606, -555.29034), L(45.34606, -591.0899), L(256.16547, -591.0899), L(256.16547, -555.29034), L(198.09068, -546.53937), L(299.92044, -376.29276), L(323.78677, -326.96896), L(351.63086, -377.0883), L(443.91406, -546.53937), L(383.45267, -555.29034), L(383.45267, -591.0899), L(566.428, -591.0899), L(566.428, -555.29034), L(509.9443, -544.94824), L(371.51947, -326.17343), L(529.03735, -326.17343), L(529.03735, -284.00955), L(350.03976, -284.00955), L(350.03976, -182.1798), L(529.03735, -182.1798), L(529.03735, -139.22037), L(350.03976, -139.22037), L(350.03976, -44.550514), L(440.73187, -35.799522), L(440.73187, -0.0), L(182.97533, -0.0), L(182.97533, -35.799522), L(271.28082, -44.550514), L(271.28082, -139.22037), L(93.07876, -139.22037), L(93.07876, -182.1798), L(271.28082, -182.1798), L(271.28082, -284.00955), L(93.07876, -284.00955), L(93.07876, -326.17343), L(243.43675, -326.17343), L(96.26093, -544.94824), Z];
 
 // // ¦ ------------------------------
-// pub const MERRIWEATHER_REGULAR_166: &'static [PathSegment] = &[M(200.47733, -369.13284), L(135.24263, -369.13284), L(135.24263, -691.32855), L(200.47733, -691.32855), L(200.47733, -369.13284), Z, M(200.47733, 72.394585), L(135.24263, 72.394585), L(135.24263, -249.00555), L(200.47733, -249.00555), L(200.47733, 72.394585), Z];
+pub const MERRIWEATHER_REGULAR_166: &'static [PathSegment] = &[M(200.47733, -369.13284), L(135.24263, -369.13284), L(135.24263, -691.32855), L(200.47733, -691.32855), L(200.47733, -369.13284), Z, M(200.47733, 72.394585), L(135.24263, 72.394585), L(135.24263, -249.00555), L(200.47733, -249.00555), L(200.47733, 72.394585), Z];
 
 // // § ------------------------------
-// pub const MERRIWEATHER_REGULAR_167: &'static [PathSegment] = &[M(147.97136, 1.5910898), Q(160.70007, 14.319809, 192.91965, 24.661892), Q(225.1392, 35.003975, 260.93872, 35.003975), Q(320.6046, 35.003975, 350.43753, 15.115354), Q(380.27048, -4.7732697, 380.27048, -36.595066), Q(380.27048, -63.643593, 361.1774, -82.73667), Q(342.08432, -101.82975, 314.24023, -114.95624), Q(286.39618, -128.08273, 233.89021, -147.97136), Q(159.10898, -177.40652, 115.751785, -217.18376), Q(72.394585, -256.961, 72.394585, -323.78677), Q(72.394585, -389.81702, 130.46936, -431.18533), Q(87.50994, -472.55368, 87.50994, -525.05963), Q(87.50994, -563.2458, 110.978516, -592.68097), Q(134.4471, -622.11615, 177.40652, -638.02704), Q(220.36594, -653.9379, 276.84964, -653.9379), Q(311.8536, -653.9379, 341.68655, -647.9713), Q(371.51947, -642.00476, 404.93237, -632.4582), Q(428.7987, -625.2983, 431.18533, -625.2983), L(426.41208, -500.39777), L(378.67938, -500.39777), L(358.79077, -587.9077), Q(353.22195, -594.27203, 328.95782, -601.43195), Q(304.6937, -608.59186, 273.66745, -608.59186), Q(219.5704, -608.59186, 190.93079, -591.0899), Q(162.29117, -573.5879, 162.29117, -540.9705), Q(162.29117, -511.53537, 178.9976, -492.04453), Q(195.70406, -472.55368, 218.37708, -461.41605), Q(241.05011, -450.2784, 293.5561, -428.7987), Q(347.65314, -404.93237, 379.4749, -387.82816), Q(411.29672, -370.72394, 438.743, -338.50436), Q(466.18933, -306.2848, 466.18933, -261.73428), Q(466.18933, -193.31741, 408.11453, -144.78917), Q(455.84723, -105.01193, 455.84723, -56.48369), Q(455.84723, -21.479713, 434.7653, 9.1487665), Q(413.68335, 39.777245, 368.73508, 59.268097), Q(323.78677, 78.75895, 256.961, 78.75895), Q(216.38821, 78.75895, 183.77087, 71.599045), Q(151.15353, 64.43914, 108.989655, 51.71042), Q(81.145584, 42.959427, 73.98568, 42.16388), L(82.73667, -93.07876), L(134.4471, -93.07876), L(147.97136, 1.5910898), Z, M(294.35162, -207.63722), Q(298.32935, -206.04613, 322.99124, -195.30627), Q(347.65314, -184.56642, 369.92838, -171.04216), Q(396.18137, -198.88623, 396.18137, -233.89021), Q(396.18137, -276.84964, 361.97293, -303.89816), Q(327.7645, -330.9467, 256.16547, -360.38184), Q(225.93475, -372.31503, 204.85281, -382.25934), Q(183.77087, -392.20364, 163.88225, -404.93237), Q(150.35799, -392.20364, 143.59586, -378.28162), Q(136.83372, -364.3596, 136.83372, -346.06204), Q(136.83372, -301.51154, 171.43993, -273.26968), Q(206.04613, -245.02783, 277.64517, -214.79713), L(294.35162, -207.63722), Z];
+pub const MERRIWEATHER_REGULAR_167: &'static [PathSegment] = &[M(147.97136, 1.5910898), Q(160.70007, 14.319809, 192.91965, 24.661892), Q(225.1392, 35.003975, 260.93872, 35.003975), Q(320.6046, 35.003975, 350.43753, 15.115354), Q(380.27048, -4.7732697, 380.27048, -36.595066), Q(380.27048, -63.643593, 361.1774, -82.73667), Q(342.08432, -101.82975, 314.24023, -114.95624), Q(286.39618, -128.08273, 233.89021, -147.97136), Q(159.10898, -177.40652, 115.751785, -217.18376), Q(72.394585, -256.961, 72.394585, -323.78677), Q(72.394585, -389.81702, 130.46936, -431.18533), Q(87.50994, -472.55368, 87.50994, -525.05963), Q(87.50994, -563.2458, 110.978516, -592.68097), Q(134.4471, -622.11615, 177.40652, -638.02704), Q(220.36594, -653.9379, 276.84964, -653.9379), Q(311.8536, -653.9379, 341.68655, -647.9713), Q(371.51947, -642.00476, 404.93237, -632.4582), Q(428.7987, -625.2983, 431.18533, -625.2983), L(426.41208, -500.39777), L(378.67938, -500.39777), L(358.79077, -587.9077), Q(353.22195, -594.27203, 328.95782, -601.43195), Q(304.6937, -608.59186, 273.66745, -608.59186), Q(219.5704, -608.59186, 190.93079, -591.0899), Q(162.29117, -573.5879, 162.29117, -540.9705), Q(162.29117, -511.53537, 178.9976, -492.04453), Q(195.70406, -472.55368, 218.37708, -461.41605), Q(241.05011, -450.2784, 293.5561, -428.7987), Q(347.65314, -404.93237, 379.4749, -387.82816), Q(411.29672, -370.72394, 438.743, -338.50436), Q(466.18933, -306.2848, 466.18933, -261.73428), Q(466.18933, -193.31741, 408.11453, -144.78917), Q(455.84723, -105.01193, 455.84723, -56.48369), Q(455.84723, -21.479713, 434.7653, 9.1487665), Q(413.68335, 39.777245, 368.73508, 59.268097), Q(323.78677, 78.75895, 256.961, 78.75895), Q(216.38821, 78.75895, 183.77087, 71.599045), Q(151.15353, 64.43914, 108.989655, 51.71042), Q(81.145584, 42.959427, 73.98568, 42.16388), L(82.73667, -93.07876), L(134.4471, -93.07876), L(147.97136, 1.5910898), Z, M(294.35162, -207.63722), Q(298.32935, -206.04613, 322.99124, -195.30627), Q(347.65314, -184.56642, 369.92838, -171.04216), Q(396.18137, -198.88623, 396.18137, -233.89021), Q(396.18137, -276.84964, 361.97293, -303.89816), Q(327.7645, -330.9467, 256.16547, -360.38184), Q(225.93475, -372.31503, 204.85281, -382.25934), Q(183.77087, -392.20364, 163.88225, -404.93237), Q(150.35799, -392.20364, 143.59586, -378.28162), Q(136.83372, -364.3596, 136.83372, -346.06204), Q(136.83372, -301.51154, 171.43993, -273.26968), Q(206.04613, -245.02783, 277.64517, -214.79713), L(294.35162, -207.63722), Z];
 
 // // ¨ ------------------------------
-// pub const MERRIWEATHER_REGULAR_168: &'static [PathSegment] = &[M(70.40572, -543.3572), Q(58.07478, -557.677, 58.07478, -579.1567), Q(58.07478, -603.8186, 71.599045, -620.9228), Q(85.12331, -638.02704, 111.37629, -638.02704), Q(133.65155, -638.02704, 146.38026, -623.30945), Q(159.10898, -608.59186, 159.10898, -587.1122), Q(159.10898, -561.6547, 144.78917, -545.34607), Q(130.46936, -529.03735, 103.42084, -529.03735), Q(82.73667, -529.03735, 70.40572, -543.3572), Z, M(250.99443, -543.75494), Q(238.66348, -558.47253, 238.66348, -579.1567), Q(238.66348, -603.8186, 252.58551, -620.9228), Q(266.50754, -638.02704, 291.16943, -638.02704), Q(314.24023, -638.02704, 326.96896, -622.9117), Q(339.6977, -607.7963, 339.6977, -587.1122), Q(339.6977, -561.6547, 325.37787, -545.34607), Q(311.05807, -529.03735, 283.214, -529.03735), Q(263.32538, -529.03735, 250.99443, -543.75494), Z];
+pub const MERRIWEATHER_REGULAR_168: &'static [PathSegment] = &[M(70.40572, -543.3572), Q(58.07478, -557.677, 58.07478, -579.1567), Q(58.07478, -603.8186, 71.599045, -620.9228), Q(85.12331, -638.02704, 111.37629, -638.02704), Q(133.65155, -638.02704, 146.38026, -623.30945), Q(159.10898, -608.59186, 159.10898, -587.1122), Q(159.10898, -561.6547, 144.78917, -545.34607), Q(130.46936, -529.03735, 103.42084, -529.03735), Q(82.73667, -529.03735, 70.40572, -543.3572), Z, M(250.99443, -543.75494), Q(238.66348, -558.47253, 238.66348, -579.1567), Q(238.66348, -603.8186, 252.58551, -620.9228), Q(266.50754, -638.02704, 291.16943, -638.02704), Q(314.24023, -638.02704, 326.96896, -622.9117), Q(339.6977, -607.7963, 339.6977, -587.1122), Q(339.6977, -561.6547, 325.37787, -545.34607), Q(311.05807, -529.03735, 283.214, -529.03735), Q(263.32538, -529.03735, 250.99443, -543.75494), Z];
 
 // // © ------------------------------
-// pub const MERRIWEATHER_REGULAR_169: &'static [PathSegment] = &[M(120.525055, -560.8592), Q(165.47334, -638.8226, 243.03897, -684.16864), Q(320.6046, -729.5147, 413.68335, -729.5147), Q(506.76212, -729.5147, 584.32776, -684.16864), Q(661.8934, -638.8226, 706.8417, -560.8592), Q(751.79, -482.89578, 751.79, -389.81702), Q(751.79, -296.73825, 706.8417, -218.37708), Q(661.8934, -140.0159, 584.32776, -94.27207), Q(506.76212, -48.52824, 413.68335, -48.52824), Q(320.6046, -48.52824, 243.03897, -94.27207), Q(165.47334, -140.0159, 120.525055, -218.37708), Q(75.57677, -296.73825, 75.57677, -389.81702), Q(75.57677, -482.89578, 120.525055, -560.8592), Z, M(161.09784, -239.8568), Q(199.68178, -171.04216, 266.50754, -130.46936), Q(333.3333, -89.896576, 413.68335, -89.896576), Q(494.0334, -89.896576, 560.8592, -130.46936), Q(627.68494, -171.04216, 665.8711, -239.8568), Q(704.05725, -308.67142, 704.05725, -389.81702), Q(704.05725, -470.16705, 665.8711, -538.9817), Q(627.68494, -607.7963, 560.8592, -648.36914), Q(494.0334, -688.9419, 413.68335, -688.9419), Q(332.53778, -688.9419, 265.712, -648.36914), Q(198.88623, -607.7963, 160.70007, -538.9817), Q(122.513916, -470.16705, 122.513916, -389.81702), Q(122.513916, -308.67142, 161.09784, -239.8568), Z, M(287.1917, -251.3922), Q(242.6412, -302.30707, 242.6412, -386.63483), Q(242.6412, -440.73187, 268.09863, -485.2824), Q(293.5561, -529.83295, 338.50436, -555.6881), Q(383.45267, -581.54333, 439.1408, -581.54333), Q(466.98486, -581.54333, 497.61334, -574.7812), Q(528.2418, -568.0191, 538.5839, -557.677), L(538.5839, -470.16705), L(496.42004, -470.16705), L(480.50912, -521.0819), Q(462.2116, -535.40173, 415.27444, -535.40173), Q(366.74622, -535.40173, 338.90213, -498.80667), Q(311.05807, -462.2116, 311.05807, -399.36356), Q(311.05807, -334.9244, 346.4598, -295.54495), Q(381.86157, -256.16547, 439.1408, -256.16547), Q(464.59824, -256.16547, 486.8735, -265.712), Q(509.14874, -275.25854, 525.05963, -291.16943), L(543.3572, -259.34766), Q(524.2641, -236.27684, 489.6579, -218.37708), Q(455.0517, -200.47733, 416.07, -200.47733), Q(331.74222, -200.47733, 287.1917, -251.3922), Z];
+pub const MERRIWEATHER_REGULAR_169: &'static [PathSegment] = &[M(120.525055, -560.8592), Q(165.47334, -638.8226, 243.03897, -684.16864), Q(320.6046, -729.5147, 413.68335, -729.5147), Q(506.76212, -729.5147, 584.32776, -684.16864), Q(661.8934, -638.8226, 706.8417, -560.8592), Q(751.79, -482.89578, 751.79, -389.81702), Q(751.79, -296.73825, 706.8417, -218.37708), Q(661.8934, -140.0159, 584.32776, -94.27207), Q(506.76212, -48.52824, 413.68335, -48.52824), Q(320.6046, -48.52824, 243.03897, -94.27207), Q(165.47334, -140.0159, 120.525055, -218.37708), Q(75.57677, -296.73825, 75.57677, -389.81702), Q(75.57677, -482.89578, 120.525055, -560.8592), Z, M(161.09784, -239.8568), Q(199.68178, -171.04216, 266.50754, -130.46936), Q(333.3333, -89.896576, 413.68335, -89.896576), Q(494.0334, -89.896576, 560.8592, -130.46936), Q(627.68494, -171.04216, 665.8711, -239.8568), Q(704.05725, -308.67142, 704.05725, -389.81702), Q(704.05725, -470.16705, 665.8711, -538.9817), Q(627.68494, -607.7963, 560.8592, -648.36914), Q(494.0334, -688.9419, 413.68335, -688.9419), Q(332.53778, -688.9419, 265.712, -648.36914), Q(198.88623, -607.7963, 160.70007, -538.9817), Q(122.513916, -470.16705, 122.513916, -389.81702), Q(122.513916, -308.67142, 161.09784, -239.8568), Z, M(287.1917, -251.3922), Q(242.6412, -302.30707, 242.6412, -386.63483), Q(242.6412, -440.73187, 268.09863, -485.2824), Q(293.5561, -529.83295, 338.50436, -555.6881), Q(383.45267, -581.54333, 439.1408, -581.54333), Q(466.98486, -581.54333, 497.61334, -574.7812), Q(528.2418, -568.0191, 538.5839, -557.677), L(538.5839, -470.16705), L(496.42004, -470.16705), L(480.50912, -521.0819), Q(462.2116, -535.40173, 415.27444, -535.40173), Q(366.74622, -535.40173, 338.90213, -498.80667), Q(311.05807, -462.2116, 311.05807, -399.36356), Q(311.05807, -334.9244, 346.4598, -295.54495), Q(381.86157, -256.16547, 439.1408, -256.16547), Q(464.59824, -256.16547, 486.8735, -265.712), Q(509.14874, -275.25854, 525.05963, -291.16943), L(543.3572, -259.34766), Q(524.2641, -236.27684, 489.6579, -218.37708), Q(455.0517, -200.47733, 416.07, -200.47733), Q(331.74222, -200.47733, 287.1917, -251.3922), Z];
 
 // // ª ------------------------------
-// pub const MERRIWEATHER_REGULAR_170: &'static [PathSegment] = &[M(78.75895, -301.9093), Q(46.93715, -329.3556, 46.93715, -373.90613), Q(46.93715, -431.18533, 103.42084, -459.4272), Q(159.90453, -487.66904, 275.25854, -491.64676), L(275.25854, -505.17102), Q(275.25854, -553.6993, 257.75656, -571.9968), Q(240.25456, -590.2943, 194.11296, -590.2943), Q(163.88225, -590.2943, 131.66269, -580.7478), Q(99.443115, -571.20123, 74.78122, -559.26807), L(54.097054, -598.24976), Q(79.55449, -614.1607, 124.90055, -628.0827), Q(170.24661, -642.00476, 208.43277, -642.00476), Q(279.23627, -642.00476, 313.84247, -612.5696), Q(348.44867, -583.1344, 348.44867, -513.12646), L(348.44867, -319.01352), L(392.9992, -319.01352), L(392.9992, -284.80508), Q(354.81305, -275.25854, 322.99124, -275.25854), Q(299.92044, -275.25854, 289.18057, -282.02066), Q(278.44073, -288.7828, 278.44073, -305.48926), L(278.44073, -331.74222), Q(258.5521, -307.8759, 227.12808, -291.16943), Q(195.70406, -274.463, 156.72235, -274.463), Q(110.58074, -274.463, 78.75895, -301.9093), Z, M(237.86794, -333.7311), Q(263.32538, -347.65314, 275.25854, -361.1774), L(275.25854, -458.2339), Q(195.70406, -456.6428, 160.3023, -437.5497), Q(124.90055, -418.45663, 124.90055, -378.67938), Q(124.90055, -352.4264, 142.00478, -336.11774), Q(159.10898, -319.80905, 187.7486, -319.80905), Q(212.41049, -319.80905, 237.86794, -333.7311), Z];
+pub const MERRIWEATHER_REGULAR_170: &'static [PathSegment] = &[M(78.75895, -301.9093), Q(46.93715, -329.3556, 46.93715, -373.90613), Q(46.93715, -431.18533, 103.42084, -459.4272), Q(159.90453, -487.66904, 275.25854, -491.64676), L(275.25854, -505.17102), Q(275.25854, -553.6993, 257.75656, -571.9968), Q(240.25456, -590.2943, 194.11296, -590.2943), Q(163.88225, -590.2943, 131.66269, -580.7478), Q(99.443115, -571.20123, 74.78122, -559.26807), L(54.097054, -598.24976), Q(79.55449, -614.1607, 124.90055, -628.0827), Q(170.24661, -642.00476, 208.43277, -642.00476), Q(279.23627, -642.00476, 313.84247, -612.5696), Q(348.44867, -583.1344, 348.44867, -513.12646), L(348.44867, -319.01352), L(392.9992, -319.01352), L(392.9992, -284.80508), Q(354.81305, -275.25854, 322.99124, -275.25854), Q(299.92044, -275.25854, 289.18057, -282.02066), Q(278.44073, -288.7828, 278.44073, -305.48926), L(278.44073, -331.74222), Q(258.5521, -307.8759, 227.12808, -291.16943), Q(195.70406, -274.463, 156.72235, -274.463), Q(110.58074, -274.463, 78.75895, -301.9093), Z, M(237.86794, -333.7311), Q(263.32538, -347.65314, 275.25854, -361.1774), L(275.25854, -458.2339), Q(195.70406, -456.6428, 160.3023, -437.5497), Q(124.90055, -418.45663, 124.90055, -378.67938), Q(124.90055, -352.4264, 142.00478, -336.11774), Q(159.10898, -319.80905, 187.7486, -319.80905), Q(212.41049, -319.80905, 237.86794, -333.7311), Z];
 
 // // « ------------------------------
-// pub const MERRIWEATHER_REGULAR_171: &'static [PathSegment] = &[M(257.75656, -413.68335), L(132.856, -237.86794), L(257.75656, -68.41686), L(217.97931, -41.368336), L(44.550514, -210.02386), L(44.550514, -264.91647), L(217.97931, -438.34525), L(257.75656, -413.68335), Z, M(480.50912, -413.68335), L(344.47095, -237.86794), L(480.50912, -68.41686), L(439.93634, -41.368336), L(256.16547, -210.02386), L(256.16547, -264.91647), L(439.93634, -438.34525), L(480.50912, -413.68335), Z];
+pub const MERRIWEATHER_REGULAR_171: &'static [PathSegment] = &[M(257.75656, -413.68335), L(132.856, -237.86794), L(257.75656, -68.41686), L(217.97931, -41.368336), L(44.550514, -210.02386), L(44.550514, -264.91647), L(217.97931, -438.34525), L(257.75656, -413.68335), Z, M(480.50912, -413.68335), L(344.47095, -237.86794), L(480.50912, -68.41686), L(439.93634, -41.368336), L(256.16547, -210.02386), L(256.16547, -264.91647), L(439.93634, -438.34525), L(480.50912, -413.68335), Z];
 
 // // ¬ ------------------------------
-// pub const MERRIWEATHER_REGULAR_172: &'static [PathSegment] = &[M(466.18933, -70.8035), L(403.34128, -70.8035), L(403.34128, -228.3214), L(81.941124, -228.3214), L(81.941124, -280.0318), L(466.18933, -280.0318), L(466.18933, -70.8035), Z];
+pub const MERRIWEATHER_REGULAR_172: &'static [PathSegment] = &[M(466.18933, -70.8035), L(403.34128, -70.8035), L(403.34128, -228.3214), L(81.941124, -228.3214), L(81.941124, -280.0318), L(466.18933, -280.0318), L(466.18933, -70.8035), Z];
 
 // // ­ ------------------------------
-// pub const MERRIWEATHER_REGULAR_173: &'static [PathSegment] = &[M(410.5012, -225.93475), L(89.10103, -225.93475), L(89.10103, -284.00955), L(410.5012, -284.00955), L(410.5012, -225.93475), Z];
+pub const MERRIWEATHER_REGULAR_173: &'static [PathSegment] = &[M(410.5012, -225.93475), L(89.10103, -225.93475), L(89.10103, -284.00955), L(410.5012, -284.00955), L(410.5012, -225.93475), Z];
 
 // // ® ------------------------------
-// pub const MERRIWEATHER_REGULAR_174: &'static [PathSegment] = &[M(120.525055, -560.8592), Q(165.47334, -638.8226, 243.03897, -684.16864), Q(320.6046, -729.5147, 413.68335, -729.5147), Q(506.76212, -729.5147, 584.32776, -684.16864), Q(661.8934, -638.8226, 706.8417, -560.8592), Q(751.79, -482.89578, 751.79, -389.81702), Q(751.79, -296.73825, 706.8417, -218.37708), Q(661.8934, -140.0159, 584.32776, -94.27207), Q(506.76212, -48.52824, 413.68335, -48.52824), Q(320.6046, -48.52824, 243.03897, -94.27207), Q(165.47334, -140.0159, 120.525055, -218.37708), Q(75.57677, -296.73825, 75.57677, -389.81702), Q(75.57677, -482.89578, 120.525055, -560.8592), Z, M(161.09784, -239.8568), Q(199.68178, -171.04216, 266.50754, -130.46936), Q(333.3333, -89.896576, 413.68335, -89.896576), Q(494.0334, -89.896576, 560.8592, -130.46936), Q(627.68494, -171.04216, 665.8711, -239.8568), Q(704.05725, -308.67142, 704.05725, -389.81702), Q(704.05725, -470.16705, 665.8711, -538.9817), Q(627.68494, -607.7963, 560.8592, -648.36914), Q(494.0334, -688.9419, 413.68335, -688.9419), Q(332.53778, -688.9419, 265.712, -648.36914), Q(198.88623, -607.7963, 160.70007, -538.9817), Q(122.513916, -470.16705, 122.513916, -389.81702), Q(122.513916, -308.67142, 161.09784, -239.8568), Z, M(295.14716, -532.21954), L(259.34766, -540.175), L(259.34766, -575.17896), L(322.19568, -575.17896), L(378.67938, -575.97455), Q(404.1368, -577.5656, 420.84326, -577.5656), Q(490.85123, -577.5656, 527.4463, -551.7104), Q(564.0413, -525.85516, 564.0413, -479.7136), Q(564.0413, -435.95862, 541.36835, -405.7279), Q(518.6953, -375.4972, 478.1225, -366.74622), Q(502.7844, -355.60858, 514.7176, -338.1066), Q(526.65076, -320.6046, 538.5839, -291.965), Q(549.72156, -264.91647, 559.66583, -252.18774), Q(569.61017, -239.45901, 591.88544, -237.86794), L(591.88544, -201.27287), L(532.21954, -201.27287), Q(508.3532, -201.27287, 497.61334, -217.97931), Q(486.8735, -234.68575, 476.5314, -268.8942), Q(466.98486, -303.10263, 455.44946, -323.78677), Q(443.91406, -344.47095, 420.04773, -353.22195), L(357.9952, -354.0175), L(357.9952, -241.05011), L(398.568, -233.09467), L(398.568, -201.27287), L(259.34766, -201.27287), L(259.34766, -233.09467), L(295.14716, -242.6412), L(295.14716, -532.21954), Z, M(422.43436, -395.38583), Q(451.8695, -395.38583, 472.55368, -417.2633), Q(493.23785, -439.1408, 493.23785, -469.3715), Q(493.23785, -506.76212, 472.95145, -522.67303), Q(452.66507, -538.5839, 410.5012, -538.5839), Q(384.2482, -538.5839, 357.9952, -534.6062), L(357.9952, -397.77246), Q(370.72394, -395.38583, 422.43436, -395.38583), Z];
+pub const MERRIWEATHER_REGULAR_174: &'static [PathSegment] = &[M(120.525055, -560.8592), Q(165.47334, -638.8226, 243.03897, -684.16864), Q(320.6046, -729.5147, 413.68335, -729.5147), Q(506.76212, -729.5147, 584.32776, -684.16864), Q(661.8934, -638.8226, 706.8417, -560.8592), Q(751.79, -482.89578, 751.79, -389.81702), Q(751.79, -296.73825, 706.8417, -218.37708), Q(661.8934, -140.0159, 584.32776, -94.27207), Q(506.76212, -48.52824, 413.68335, -48.52824), Q(320.6046, -48.52824, 243.03897, -94.27207), Q(165.47334, -140.0159, 120.525055, -218.37708), Q(75.57677, -296.73825, 75.57677, -389.81702), Q(75.57677, -482.89578, 120.525055, -560.8592), Z, M(161.09784, -239.8568), Q(199.68178, -171.04216, 266.50754, -130.46936), Q(333.3333, -89.896576, 413.68335, -89.896576), Q(494.0334, -89.896576, 560.8592, -130.46936), Q(627.68494, -171.04216, 665.8711, -239.8568), Q(704.05725, -308.67142, 704.05725, -389.81702), Q(704.05725, -470.16705, 665.8711, -538.9817), Q(627.68494, -607.7963, 560.8592, -648.36914), Q(494.0334, -688.9419, 413.68335, -688.9419), Q(332.53778, -688.9419, 265.712, -648.36914), Q(198.88623, -607.7963, 160.70007, -538.9817), Q(122.513916, -470.16705, 122.513916, -389.81702), Q(122.513916, -308.67142, 161.09784, -239.8568), Z, M(295.14716, -532.21954), L(259.34766, -540.175), L(259.34766, -575.17896), L(322.19568, -575.17896), L(378.67938, -575.97455), Q(404.1368, -577.5656, 420.84326, -577.5656), Q(490.85123, -577.5656, 527.4463, -551.7104), Q(564.0413, -525.85516, 564.0413, -479.7136), Q(564.0413, -435.95862, 541.36835, -405.7279), Q(518.6953, -375.4972, 478.1225, -366.74622), Q(502.7844, -355.60858, 514.7176, -338.1066), Q(526.65076, -320.6046, 538.5839, -291.965), Q(549.72156, -264.91647, 559.66583, -252.18774), Q(569.61017, -239.45901, 591.88544, -237.86794), L(591.88544, -201.27287), L(532.21954, -201.27287), Q(508.3532, -201.27287, 497.61334, -217.97931), Q(486.8735, -234.68575, 476.5314, -268.8942), Q(466.98486, -303.10263, 455.44946, -323.78677), Q(443.91406, -344.47095, 420.04773, -353.22195), L(357.9952, -354.0175), L(357.9952, -241.05011), L(398.568, -233.09467), L(398.568, -201.27287), L(259.34766, -201.27287), L(259.34766, -233.09467), L(295.14716, -242.6412), L(295.14716, -532.21954), Z, M(422.43436, -395.38583), Q(451.8695, -395.38583, 472.55368, -417.2633), Q(493.23785, -439.1408, 493.23785, -469.3715), Q(493.23785, -506.76212, 472.95145, -522.67303), Q(452.66507, -538.5839, 410.5012, -538.5839), Q(384.2482, -538.5839, 357.9952, -534.6062), L(357.9952, -397.77246), Q(370.72394, -395.38583, 422.43436, -395.38583), Z];
 
 // // ¯ ------------------------------
-// pub const MERRIWEATHER_REGULAR_175: &'static [PathSegment] = &[M(241.05011, -554.4948), L(22.275257, -554.4948), L(22.275257, -610.9785), L(241.05011, -610.9785), L(241.05011, -554.4948), Z];
+pub const MERRIWEATHER_REGULAR_175: &'static [PathSegment] = &[M(241.05011, -554.4948), L(22.275257, -554.4948), L(22.275257, -610.9785), L(241.05011, -610.9785), L(241.05011, -554.4948), Z];
 
 // // ° ------------------------------
-// pub const MERRIWEATHER_REGULAR_176: &'static [PathSegment] = &[M(115.751785, -384.64597), Q(84.32776, -403.34128, 65.632454, -434.36752), Q(46.93715, -465.39377, 46.93715, -501.98886), Q(46.93715, -540.175, 65.632454, -571.20123), Q(84.32776, -602.2275, 115.751785, -620.12726), Q(147.17581, -638.02704, 183.77087, -638.02704), Q(222.75258, -638.02704, 254.57437, -619.7295), Q(286.39618, -601.43195, 304.29593, -570.00793), Q(322.19568, -538.5839, 322.19568, -501.98886), Q(322.19568, -463.8027, 303.5004, -432.77643), Q(284.80508, -401.75018, 253.38106, -383.85043), Q(221.95703, -365.95065, 183.77087, -365.95065), Q(147.17581, -365.95065, 115.751785, -384.64597), Z, M(243.83452, -440.73187), Q(266.50754, -464.59824, 266.50754, -501.98886), Q(266.50754, -536.9928, 242.6412, -562.45026), Q(218.77486, -587.9077, 183.77087, -587.9077), Q(147.97136, -587.9077, 124.90055, -563.2458), Q(101.82975, -538.5839, 101.82975, -501.98886), Q(101.82975, -466.98486, 126.09387, -441.9252), Q(150.35799, -416.86554, 184.56642, -416.86554), Q(221.16148, -416.86554, 243.83452, -440.73187), Z];
+pub const MERRIWEATHER_REGULAR_176: &'static [PathSegment] = &[M(115.751785, -384.64597), Q(84.32776, -403.34128, 65.632454, -434.36752), Q(46.93715, -465.39377, 46.93715, -501.98886), Q(46.93715, -540.175, 65.632454, -571.20123), Q(84.32776, -602.2275, 115.751785, -620.12726), Q(147.17581, -638.02704, 183.77087, -638.02704), Q(222.75258, -638.02704, 254.57437, -619.7295), Q(286.39618, -601.43195, 304.29593, -570.00793), Q(322.19568, -538.5839, 322.19568, -501.98886), Q(322.19568, -463.8027, 303.5004, -432.77643), Q(284.80508, -401.75018, 253.38106, -383.85043), Q(221.95703, -365.95065, 183.77087, -365.95065), Q(147.17581, -365.95065, 115.751785, -384.64597), Z, M(243.83452, -440.73187), Q(266.50754, -464.59824, 266.50754, -501.98886), Q(266.50754, -536.9928, 242.6412, -562.45026), Q(218.77486, -587.9077, 183.77087, -587.9077), Q(147.97136, -587.9077, 124.90055, -563.2458), Q(101.82975, -538.5839, 101.82975, -501.98886), Q(101.82975, -466.98486, 126.09387, -441.9252), Q(150.35799, -416.86554, 184.56642, -416.86554), Q(221.16148, -416.86554, 243.83452, -440.73187), Z];
 
 // // ± ------------------------------
-// pub const MERRIWEATHER_REGULAR_177: &'static [PathSegment] = &[M(234.68575, -287.98727), L(232.29912, -355.60858), L(232.29912, -443.91406), L(293.5561, -443.91406), L(293.5561, -355.60858), L(289.57834, -287.98727), L(350.03976, -291.965), L(442.32297, -291.965), L(442.32297, -232.29912), L(350.03976, -232.29912), L(289.57834, -234.68575), L(293.5561, -169.45107), L(293.5561, -81.145584), L(232.29912, -81.145584), L(232.29912, -169.45107), L(234.68575, -234.68575), L(165.47334, -232.29912), L(83.53222, -232.29912), L(83.53222, -291.965), L(165.47334, -291.965), L(234.68575, -287.98727), Z, M(436.75415, -0.0), L(88.30549, -0.0), L(88.30549, -56.48369), L(436.75415, -56.48369), L(436.75415, -0.0), Z];
+pub const MERRIWEATHER_REGULAR_177: &'static [PathSegment] = &[M(234.68575, -287.98727), L(232.29912, -355.60858), L(232.29912, -443.91406), L(293.5561, -443.91406), L(293.5561, -355.60858), L(289.57834, -287.98727), L(350.03976, -291.965), L(442.32297, -291.965), L(442.32297, -232.29912), L(350.03976, -232.29912), L(289.57834, -234.68575), L(293.5561, -169.45107), L(293.5561, -81.145584), L(232.29912, -81.145584), L(232.29912, -169.45107), L(234.68575, -234.68575), L(165.47334, -232.29912), L(83.53222, -232.29912), L(83.53222, -291.965), L(165.47334, -291.965), L(234.68575, -287.98727), Z, M(436.75415, -0.0), L(88.30549, -0.0), L(88.30549, -56.48369), L(436.75415, -56.48369), L(436.75415, -0.0), Z];
 
 // // ² ------------------------------
 // SUPERSCRIPT 2
@@ -3388,7 +3407,7 @@ pub const MERRIWEATHER_REGULAR_179: &'static [PathSegment] = &[
 ];
 
 // // ´ ------------------------------
-// pub const MERRIWEATHER_REGULAR_180: &'static [PathSegment] = &[M(81.145584, -545.74384), L(210.02386, -703.2617), L(267.3031, -653.1424), Q(249.8011, -626.0939, 194.11296, -578.7589), Q(138.42482, -531.424, 110.58074, -519.49084), L(81.145584, -545.74384), Z];
+pub const MERRIWEATHER_REGULAR_180: &'static [PathSegment] = &[M(81.145584, -545.74384), L(210.02386, -703.2617), L(267.3031, -653.1424), Q(249.8011, -626.0939, 194.11296, -578.7589), Q(138.42482, -531.424, 110.58074, -519.49084), L(81.145584, -545.74384), Z];
 
 // // µ ------------------------------
 // pub const MERRIWEATHER_REGULAR_181: &'static [PathSegment] = &[M(95.46539, -392.9992), L(48.52824, -409.70563), L(48.52824, -439.93634), L(163.08672, -449.48288), L(178.20206, -440.73187), L(178.20206, -128.87828), Q(178.20206, -112.96738, 188.94193, -93.8743), Q(199.68178, -74.78122, 221.16148, -61.256958), Q(242.6412, -47.732697, 272.87192, -47.732697), Q(310.2625, -47.732697, 334.9244, -65.632454), Q(359.5863, -83.53222, 369.13284, -103.42084), L(369.13284, -392.9992), L(315.0358, -409.70563), L(314.24023, -439.93634), L(439.93634, -449.48288), L(451.8695, -440.73187), L(451.8695, -39.777245), L(498.80667, -39.777245), L(498.80667, -3.1821797), Q(484.48685, 2.3866348, 462.60938, 6.3643594), Q(440.73187, 10.342084, 420.04773, 10.342084), Q(381.86157, 10.342084, 381.86157, -23.866348), L(381.86157, -60.461414), Q(367.54175, -31.026253, 337.31104, -11.535401), Q(307.08035, 7.955449, 270.48526, 7.955449), Q(241.05011, 7.955449, 214.39935, -2.7844071), Q(187.7486, -13.524263, 172.63326, -32.61734), L(172.63326, 144.78917), L(96.26093, 144.78917), L(95.46539, -392.9992), Z];
@@ -3455,10 +3474,10 @@ pub const MERRIWEATHER_REGULAR_182: &'static [PathSegment] = &[
 ];
 
 // // · ------------------------------
-// pub const MERRIWEATHER_REGULAR_183: &'static [PathSegment] = &[M(29.435162, -264.1209), Q(14.319809, -278.44073, 14.319809, -300.71597), Q(14.319809, -325.37787, 32.21957, -342.08432), Q(50.11933, -358.79077, 73.98568, -358.79077), Q(98.64757, -358.79077, 111.77406, -344.07318), Q(124.90055, -329.3556, 124.90055, -307.8759), Q(124.90055, -282.41846, 107.39857, -266.10977), Q(89.896576, -249.8011, 65.23468, -249.8011), Q(44.550514, -249.8011, 29.435162, -264.1209), Z];
+pub const MERRIWEATHER_REGULAR_183: &'static [PathSegment] = &[M(29.435162, -264.1209), Q(14.319809, -278.44073, 14.319809, -300.71597), Q(14.319809, -325.37787, 32.21957, -342.08432), Q(50.11933, -358.79077, 73.98568, -358.79077), Q(98.64757, -358.79077, 111.77406, -344.07318), Q(124.90055, -329.3556, 124.90055, -307.8759), Q(124.90055, -282.41846, 107.39857, -266.10977), Q(89.896576, -249.8011, 65.23468, -249.8011), Q(44.550514, -249.8011, 29.435162, -264.1209), Z];
 
 // // ¸ ------------------------------
-// pub const MERRIWEATHER_REGULAR_184: &'static [PathSegment] = &[M(31.821796, 188.54414), Q(15.910898, 185.36197, 4.7732697, 181.38425), L(5.5688143, 126.491646), Q(13.524263, 129.67383, 23.866348, 131.26491), Q(34.20843, 132.856, 41.368336, 132.856), Q(73.19013, 132.856, 86.71439, 122.11614), Q(100.23866, 111.37629, 100.23866, 76.372314), Q(100.23866, 55.688145, 91.0899, 33.015114), Q(81.941124, 10.342084, 73.19013, -0.7955449), L(97.85203, -4.7732697), L(112.96738, -0.7955449), Q(129.67383, 14.319809, 147.17581, 45.34606), Q(164.6778, 76.372314, 164.6778, 108.989655), Q(164.6778, 153.54018, 138.82259, 172.63326), Q(112.96738, 191.72633, 61.256958, 191.72633), Q(47.732697, 191.72633, 31.821796, 188.54414), Z];
+pub const MERRIWEATHER_REGULAR_184: &'static [PathSegment] = &[M(31.821796, 188.54414), Q(15.910898, 185.36197, 4.7732697, 181.38425), L(5.5688143, 126.491646), Q(13.524263, 129.67383, 23.866348, 131.26491), Q(34.20843, 132.856, 41.368336, 132.856), Q(73.19013, 132.856, 86.71439, 122.11614), Q(100.23866, 111.37629, 100.23866, 76.372314), Q(100.23866, 55.688145, 91.0899, 33.015114), Q(81.941124, 10.342084, 73.19013, -0.7955449), L(97.85203, -4.7732697), L(112.96738, -0.7955449), Q(129.67383, 14.319809, 147.17581, 45.34606), Q(164.6778, 76.372314, 164.6778, 108.989655), Q(164.6778, 153.54018, 138.82259, 172.63326), Q(112.96738, 191.72633, 61.256958, 191.72633), Q(47.732697, 191.72633, 31.821796, 188.54414), Z];
 
 // // ¹ ------------------------------
 // SUPERSCRIPT 1
@@ -3527,208 +3546,208 @@ pub const MERRIWEATHER_REGULAR_186: &'static [PathSegment] = &[
 ];
 
 // // » ------------------------------
-// pub const MERRIWEATHER_REGULAR_187: &'static [PathSegment] = &[M(276.84964, -264.91647), L(276.84964, -210.02386), L(92.28321, -41.368336), L(52.505966, -68.41686), L(188.54414, -237.86794), L(52.505966, -413.68335), L(92.28321, -438.34525), L(276.84964, -264.91647), Z, M(488.46457, -264.91647), L(488.46457, -210.02386), L(315.83133, -41.368336), L(275.25854, -68.41686), L(400.1591, -237.86794), L(275.25854, -413.68335), L(315.83133, -438.34525), L(488.46457, -264.91647), Z];
+pub const MERRIWEATHER_REGULAR_187: &'static [PathSegment] = &[M(276.84964, -264.91647), L(276.84964, -210.02386), L(92.28321, -41.368336), L(52.505966, -68.41686), L(188.54414, -237.86794), L(52.505966, -413.68335), L(92.28321, -438.34525), L(276.84964, -264.91647), Z, M(488.46457, -264.91647), L(488.46457, -210.02386), L(315.83133, -41.368336), L(275.25854, -68.41686), L(400.1591, -237.86794), L(275.25854, -413.68335), L(315.83133, -438.34525), L(488.46457, -264.91647), Z];
 
 // // ¼ ------------------------------
-// pub const MERRIWEATHER_REGULAR_188: &'static [PathSegment] = &[M(175.81543, -536.19727), Q(142.40254, -522.67303, 73.98568, -509.14874), L(63.643593, -550.5171), Q(105.80747, -563.2458, 139.22037, -577.5656), Q(172.63326, -591.88544, 202.86395, -614.95624), L(204.45505, -614.95624), L(245.02783, -607.0008), L(245.02783, -251.3922), L(334.12888, -243.43675), L(334.12888, -205.2506), L(73.98568, -205.2506), L(73.98568, -243.43675), L(175.81543, -251.3922), L(175.81543, -536.19727), Z, M(631.66266, -642.00476), L(344.47095, 61.256958), L(287.98727, 58.07478), L(576.7701, -646.778), L(631.66266, -642.00476), Z, M(569.61017, -136.03818), Q(610.183, -208.43277, 658.3134, -294.7494), Q(706.4439, -381.066, 730.31024, -424.82098), L(794.7494, -424.82098), L(807.4781, -409.70563), L(653.1424, -159.10898), L(622.11615, -117.74065), L(775.6563, -124.10501), L(782.8162, -231.50357), L(840.09546, -235.4813), L(840.09546, -127.287186), L(911.69446, -131.26491), L(911.69446, -78.75895), L(840.09546, -78.75895), L(840.09546, -0.0), L(774.8608, -0.0), L(774.8608, -78.75895), L(579.1567, -78.75895), L(555.29034, -109.7852), L(569.61017, -136.03818), Z];
+pub const MERRIWEATHER_REGULAR_188: &'static [PathSegment] = &[M(175.81543, -536.19727), Q(142.40254, -522.67303, 73.98568, -509.14874), L(63.643593, -550.5171), Q(105.80747, -563.2458, 139.22037, -577.5656), Q(172.63326, -591.88544, 202.86395, -614.95624), L(204.45505, -614.95624), L(245.02783, -607.0008), L(245.02783, -251.3922), L(334.12888, -243.43675), L(334.12888, -205.2506), L(73.98568, -205.2506), L(73.98568, -243.43675), L(175.81543, -251.3922), L(175.81543, -536.19727), Z, M(631.66266, -642.00476), L(344.47095, 61.256958), L(287.98727, 58.07478), L(576.7701, -646.778), L(631.66266, -642.00476), Z, M(569.61017, -136.03818), Q(610.183, -208.43277, 658.3134, -294.7494), Q(706.4439, -381.066, 730.31024, -424.82098), L(794.7494, -424.82098), L(807.4781, -409.70563), L(653.1424, -159.10898), L(622.11615, -117.74065), L(775.6563, -124.10501), L(782.8162, -231.50357), L(840.09546, -235.4813), L(840.09546, -127.287186), L(911.69446, -131.26491), L(911.69446, -78.75895), L(840.09546, -78.75895), L(840.09546, -0.0), L(774.8608, -0.0), L(774.8608, -78.75895), L(579.1567, -78.75895), L(555.29034, -109.7852), L(569.61017, -136.03818), Z];
 
 // // ½ ------------------------------
-// pub const MERRIWEATHER_REGULAR_189: &'static [PathSegment] = &[M(175.81543, -536.19727), Q(142.40254, -522.67303, 73.98568, -509.14874), L(63.643593, -550.5171), Q(105.80747, -563.2458, 139.22037, -577.5656), Q(172.63326, -591.88544, 202.86395, -614.95624), L(204.45505, -614.95624), L(245.02783, -607.0008), L(245.02783, -251.3922), L(334.12888, -243.43675), L(334.12888, -205.2506), L(73.98568, -205.2506), L(73.98568, -243.43675), L(175.81543, -251.3922), L(175.81543, -536.19727), Z, M(631.66266, -642.00476), L(344.47095, 61.256958), L(287.98727, 58.07478), L(576.7701, -646.778), L(631.66266, -642.00476), Z, M(670.2466, -91.48766), Q(717.58154, -131.26491, 753.7788, -178.9976), Q(789.97614, -226.7303, 789.97614, -268.8942), Q(789.97614, -303.89816, 769.6897, -325.37787), Q(749.4033, -346.85757, 712.8082, -346.85757), Q(680.1909, -346.85757, 651.94904, -332.93555), Q(623.7072, -319.01352, 607.0008, -302.30707), L(581.54333, -330.15115), Q(599.8409, -354.0175, 640.01587, -375.895), Q(680.1909, -397.77246, 728.7192, -397.77246), Q(792.36273, -397.77246, 827.76447, -366.74622), Q(863.16626, -335.71997, 863.16626, -284.00955), Q(863.16626, -226.7303, 800.71594, -156.32457), Q(738.2657, -85.91885, 654.73346, -35.799522), L(827.3667, -47.732697), L(856.8019, -107.39857), L(895.78357, -99.443115), L(875.89496, 7.1599045), L(598.24976, 7.1599045), L(584.7255, -28.639618), Q(622.9117, -51.71042, 670.2466, -91.48766), Z];
+pub const MERRIWEATHER_REGULAR_189: &'static [PathSegment] = &[M(175.81543, -536.19727), Q(142.40254, -522.67303, 73.98568, -509.14874), L(63.643593, -550.5171), Q(105.80747, -563.2458, 139.22037, -577.5656), Q(172.63326, -591.88544, 202.86395, -614.95624), L(204.45505, -614.95624), L(245.02783, -607.0008), L(245.02783, -251.3922), L(334.12888, -243.43675), L(334.12888, -205.2506), L(73.98568, -205.2506), L(73.98568, -243.43675), L(175.81543, -251.3922), L(175.81543, -536.19727), Z, M(631.66266, -642.00476), L(344.47095, 61.256958), L(287.98727, 58.07478), L(576.7701, -646.778), L(631.66266, -642.00476), Z, M(670.2466, -91.48766), Q(717.58154, -131.26491, 753.7788, -178.9976), Q(789.97614, -226.7303, 789.97614, -268.8942), Q(789.97614, -303.89816, 769.6897, -325.37787), Q(749.4033, -346.85757, 712.8082, -346.85757), Q(680.1909, -346.85757, 651.94904, -332.93555), Q(623.7072, -319.01352, 607.0008, -302.30707), L(581.54333, -330.15115), Q(599.8409, -354.0175, 640.01587, -375.895), Q(680.1909, -397.77246, 728.7192, -397.77246), Q(792.36273, -397.77246, 827.76447, -366.74622), Q(863.16626, -335.71997, 863.16626, -284.00955), Q(863.16626, -226.7303, 800.71594, -156.32457), Q(738.2657, -85.91885, 654.73346, -35.799522), L(827.3667, -47.732697), L(856.8019, -107.39857), L(895.78357, -99.443115), L(875.89496, 7.1599045), L(598.24976, 7.1599045), L(584.7255, -28.639618), Q(622.9117, -51.71042, 670.2466, -91.48766), Z];
 
 // // ¾ ------------------------------
-// pub const MERRIWEATHER_REGULAR_190: &'static [PathSegment] = &[M(113.762924, -210.42163), Q(82.73667, -216.38821, 59.66587, -227.52585), L(59.66587, -269.68973), Q(81.145584, -261.73428, 106.205246, -256.16547), Q(131.26491, -250.59665, 153.54018, -250.59665), Q(278.44073, -250.59665, 278.44073, -328.56006), Q(278.44073, -356.4041, 253.38106, -376.29276), Q(228.3214, -396.18137, 185.36197, -395.38583), Q(174.22433, -390.61255, 138.42482, -378.67938), L(125.6961, -416.86554), Q(146.38026, -420.84326, 180.5887, -435.95862), Q(245.82338, -466.98486, 245.82338, -512.33093), Q(245.82338, -538.5839, 228.71916, -551.3126), Q(211.61494, -564.0413, 175.01988, -564.0413), Q(147.17581, -564.0413, 119.33174, -555.6881), Q(91.48766, -547.3349, 75.57677, -533.81067), L(53.30151, -569.61017), Q(73.19013, -588.70325, 111.77406, -601.0342), Q(150.35799, -613.3651, 194.90851, -613.3651), Q(251.3922, -613.3651, 285.20285, -587.50995), Q(319.01352, -561.6547, 319.01352, -521.87744), Q(319.01352, -488.46457, 297.5338, -462.60938), Q(276.05408, -436.75415, 228.3214, -416.86554), Q(270.48526, -419.25217, 299.12488, -406.52347), Q(327.7645, -393.79474, 341.28876, -371.91724), Q(354.81305, -350.03976, 354.81305, -326.96896), Q(354.81305, -272.07635, 306.68256, -238.2657), Q(258.5521, -204.45505, 170.24661, -204.45505), Q(144.78917, -204.45505, 113.762924, -210.42163), Z, M(634.84485, -642.00476), L(347.65314, 61.256958), L(291.16943, 58.07478), L(579.9523, -646.778), L(634.84485, -642.00476), Z, M(572.79236, -136.03818), Q(613.3651, -208.43277, 661.4956, -294.7494), Q(709.6261, -381.066, 733.49243, -424.82098), L(797.9316, -424.82098), L(810.6603, -409.70563), L(656.3246, -159.10898), L(625.2983, -117.74065), L(778.8385, -124.10501), L(785.9984, -231.50357), L(843.2776, -235.4813), L(843.2776, -127.287186), L(914.87665, -131.26491), L(914.87665, -78.75895), L(843.2776, -78.75895), L(843.2776, -0.0), L(778.0429, -0.0), L(778.0429, -78.75895), L(582.33887, -78.75895), L(558.47253, -109.7852), L(572.79236, -136.03818), Z];
+pub const MERRIWEATHER_REGULAR_190: &'static [PathSegment] = &[M(113.762924, -210.42163), Q(82.73667, -216.38821, 59.66587, -227.52585), L(59.66587, -269.68973), Q(81.145584, -261.73428, 106.205246, -256.16547), Q(131.26491, -250.59665, 153.54018, -250.59665), Q(278.44073, -250.59665, 278.44073, -328.56006), Q(278.44073, -356.4041, 253.38106, -376.29276), Q(228.3214, -396.18137, 185.36197, -395.38583), Q(174.22433, -390.61255, 138.42482, -378.67938), L(125.6961, -416.86554), Q(146.38026, -420.84326, 180.5887, -435.95862), Q(245.82338, -466.98486, 245.82338, -512.33093), Q(245.82338, -538.5839, 228.71916, -551.3126), Q(211.61494, -564.0413, 175.01988, -564.0413), Q(147.17581, -564.0413, 119.33174, -555.6881), Q(91.48766, -547.3349, 75.57677, -533.81067), L(53.30151, -569.61017), Q(73.19013, -588.70325, 111.77406, -601.0342), Q(150.35799, -613.3651, 194.90851, -613.3651), Q(251.3922, -613.3651, 285.20285, -587.50995), Q(319.01352, -561.6547, 319.01352, -521.87744), Q(319.01352, -488.46457, 297.5338, -462.60938), Q(276.05408, -436.75415, 228.3214, -416.86554), Q(270.48526, -419.25217, 299.12488, -406.52347), Q(327.7645, -393.79474, 341.28876, -371.91724), Q(354.81305, -350.03976, 354.81305, -326.96896), Q(354.81305, -272.07635, 306.68256, -238.2657), Q(258.5521, -204.45505, 170.24661, -204.45505), Q(144.78917, -204.45505, 113.762924, -210.42163), Z, M(634.84485, -642.00476), L(347.65314, 61.256958), L(291.16943, 58.07478), L(579.9523, -646.778), L(634.84485, -642.00476), Z, M(572.79236, -136.03818), Q(613.3651, -208.43277, 661.4956, -294.7494), Q(709.6261, -381.066, 733.49243, -424.82098), L(797.9316, -424.82098), L(810.6603, -409.70563), L(656.3246, -159.10898), L(625.2983, -117.74065), L(778.8385, -124.10501), L(785.9984, -231.50357), L(843.2776, -235.4813), L(843.2776, -127.287186), L(914.87665, -131.26491), L(914.87665, -78.75895), L(843.2776, -78.75895), L(843.2776, -0.0), L(778.0429, -0.0), L(778.0429, -78.75895), L(582.33887, -78.75895), L(558.47253, -109.7852), L(572.79236, -136.03818), Z];
 
 // // ¿ ------------------------------
-// pub const MERRIWEATHER_REGULAR_191: &'static [PathSegment] = &[M(276.05408, -545.34607), Q(291.16943, -531.424, 291.16943, -509.9443), Q(291.16943, -485.2824, 272.87192, -468.57596), Q(254.57437, -451.8695, 231.50357, -451.8695), Q(206.04613, -451.8695, 192.52187, -466.5871), Q(178.9976, -481.3047, 178.9976, -501.98886), Q(178.9976, -528.2418, 196.4996, -543.75494), Q(214.00159, -559.26807, 240.25456, -559.26807), Q(260.93872, -559.26807, 276.05408, -545.34607), Z, M(276.84964, -249.8011), Q(276.84964, -218.77486, 253.38106, -195.70406), Q(229.91248, -172.63326, 183.77087, -142.40254), Q(154.33571, -123.30946, 136.43596, -109.38743), Q(118.536194, -95.46539, 106.60302, -79.55449), Q(105.01193, -71.599045, 103.42084, -56.48369), Q(101.82975, -41.368336, 101.82975, -28.639618), Q(101.82975, 7.955449, 122.91169, 40.17502), Q(143.99364, 72.394585, 184.96419, 92.28321), Q(225.93475, 112.17184, 281.6229, 112.17184), Q(316.6269, 112.17184, 341.28876, 101.0342), L(354.0175, 150.35799), Q(340.49323, 158.31345, 313.4447, 163.88225), Q(286.39618, 169.45107, 256.961, 169.45107), Q(140.81145, 169.45107, 84.32776, 115.35401), Q(27.844072, 61.256958, 27.844072, -24.661892), Q(27.844072, -62.052505, 43.357197, -90.69212), Q(58.870323, -119.33174, 79.95226, -138.82259), Q(101.0342, -158.31345, 135.24263, -182.97533), Q(159.90453, -200.47733, 174.22433, -212.80827), Q(188.54414, -225.1392, 195.70406, -238.66348), Q(197.29514, -245.02783, 197.29514, -249.8011), L(196.4996, -259.34766), Q(196.4996, -330.15115, 193.31741, -359.5863), L(249.00555, -359.5863), Q(272.07635, -308.67142, 276.84964, -249.8011), Z];
+pub const MERRIWEATHER_REGULAR_191: &'static [PathSegment] = &[M(276.05408, -545.34607), Q(291.16943, -531.424, 291.16943, -509.9443), Q(291.16943, -485.2824, 272.87192, -468.57596), Q(254.57437, -451.8695, 231.50357, -451.8695), Q(206.04613, -451.8695, 192.52187, -466.5871), Q(178.9976, -481.3047, 178.9976, -501.98886), Q(178.9976, -528.2418, 196.4996, -543.75494), Q(214.00159, -559.26807, 240.25456, -559.26807), Q(260.93872, -559.26807, 276.05408, -545.34607), Z, M(276.84964, -249.8011), Q(276.84964, -218.77486, 253.38106, -195.70406), Q(229.91248, -172.63326, 183.77087, -142.40254), Q(154.33571, -123.30946, 136.43596, -109.38743), Q(118.536194, -95.46539, 106.60302, -79.55449), Q(105.01193, -71.599045, 103.42084, -56.48369), Q(101.82975, -41.368336, 101.82975, -28.639618), Q(101.82975, 7.955449, 122.91169, 40.17502), Q(143.99364, 72.394585, 184.96419, 92.28321), Q(225.93475, 112.17184, 281.6229, 112.17184), Q(316.6269, 112.17184, 341.28876, 101.0342), L(354.0175, 150.35799), Q(340.49323, 158.31345, 313.4447, 163.88225), Q(286.39618, 169.45107, 256.961, 169.45107), Q(140.81145, 169.45107, 84.32776, 115.35401), Q(27.844072, 61.256958, 27.844072, -24.661892), Q(27.844072, -62.052505, 43.357197, -90.69212), Q(58.870323, -119.33174, 79.95226, -138.82259), Q(101.0342, -158.31345, 135.24263, -182.97533), Q(159.90453, -200.47733, 174.22433, -212.80827), Q(188.54414, -225.1392, 195.70406, -238.66348), Q(197.29514, -245.02783, 197.29514, -249.8011), L(196.4996, -259.34766), Q(196.4996, -330.15115, 193.31741, -359.5863), L(249.00555, -359.5863), Q(272.07635, -308.67142, 276.84964, -249.8011), Z];
 
 // // À ------------------------------
-// pub const MERRIWEATHER_REGULAR_192: &'static [PathSegment] = &[M(230.70802, -595.86316), L(318.21796, -595.86316), L(511.53537, -46.93715), L(560.8592, -38.9817), L(560.8592, -0.0), L(350.8353, -0.0), L(350.8353, -38.9817), L(416.86554, -46.93715), L(379.4749, -164.6778), L(147.17581, -164.6778), L(109.7852, -46.93715), L(178.20206, -38.9817), L(178.20206, -0.0), L(-10.342084, -0.0), L(-10.342084, -38.9817), L(38.9817, -46.93715), L(230.70802, -595.86316), Z, M(280.82736, -468.57596), L(260.93872, -538.5839), L(241.84566, -466.98486), L(161.49562, -211.61494), L(364.3596, -211.61494), L(280.82736, -468.57596), Z, M(221.55927, -681.3842), Q(180.5887, -712.8082, 159.90453, -739.85675), L(223.54813, -780.42957), L(330.9467, -659.5067), L(292.76053, -635.6404), Q(262.52982, -649.9602, 221.55927, -681.3842), Z];
+pub const MERRIWEATHER_REGULAR_192: &'static [PathSegment] = &[M(230.70802, -595.86316), L(318.21796, -595.86316), L(511.53537, -46.93715), L(560.8592, -38.9817), L(560.8592, -0.0), L(350.8353, -0.0), L(350.8353, -38.9817), L(416.86554, -46.93715), L(379.4749, -164.6778), L(147.17581, -164.6778), L(109.7852, -46.93715), L(178.20206, -38.9817), L(178.20206, -0.0), L(-10.342084, -0.0), L(-10.342084, -38.9817), L(38.9817, -46.93715), L(230.70802, -595.86316), Z, M(280.82736, -468.57596), L(260.93872, -538.5839), L(241.84566, -466.98486), L(161.49562, -211.61494), L(364.3596, -211.61494), L(280.82736, -468.57596), Z, M(221.55927, -681.3842), Q(180.5887, -712.8082, 159.90453, -739.85675), L(223.54813, -780.42957), L(330.9467, -659.5067), L(292.76053, -635.6404), Q(262.52982, -649.9602, 221.55927, -681.3842), Z];
 
 // // Á ------------------------------
-// pub const MERRIWEATHER_REGULAR_193: &'static [PathSegment] = &[M(230.70802, -595.86316), L(318.21796, -595.86316), L(511.53537, -46.93715), L(560.8592, -38.9817), L(560.8592, -0.0), L(350.8353, -0.0), L(350.8353, -38.9817), L(416.86554, -46.93715), L(379.4749, -164.6778), L(147.17581, -164.6778), L(109.7852, -46.93715), L(178.20206, -38.9817), L(178.20206, -0.0), L(-10.342084, -0.0), L(-10.342084, -38.9817), L(38.9817, -46.93715), L(230.70802, -595.86316), Z, M(280.82736, -468.57596), L(260.93872, -538.5839), L(241.84566, -466.98486), L(161.49562, -211.61494), L(364.3596, -211.61494), L(280.82736, -468.57596), Z, M(213.20604, -659.5067), L(319.80905, -780.42957), L(384.2482, -739.85675), Q(361.97293, -712.0127, 322.59348, -680.98645), Q(283.214, -649.9602, 252.98329, -635.6404), L(213.20604, -659.5067), Z];
+pub const MERRIWEATHER_REGULAR_193: &'static [PathSegment] = &[M(230.70802, -595.86316), L(318.21796, -595.86316), L(511.53537, -46.93715), L(560.8592, -38.9817), L(560.8592, -0.0), L(350.8353, -0.0), L(350.8353, -38.9817), L(416.86554, -46.93715), L(379.4749, -164.6778), L(147.17581, -164.6778), L(109.7852, -46.93715), L(178.20206, -38.9817), L(178.20206, -0.0), L(-10.342084, -0.0), L(-10.342084, -38.9817), L(38.9817, -46.93715), L(230.70802, -595.86316), Z, M(280.82736, -468.57596), L(260.93872, -538.5839), L(241.84566, -466.98486), L(161.49562, -211.61494), L(364.3596, -211.61494), L(280.82736, -468.57596), Z, M(213.20604, -659.5067), L(319.80905, -780.42957), L(384.2482, -739.85675), Q(361.97293, -712.0127, 322.59348, -680.98645), Q(283.214, -649.9602, 252.98329, -635.6404), L(213.20604, -659.5067), Z];
 
 // // Â ------------------------------
-// pub const MERRIWEATHER_REGULAR_194: &'static [PathSegment] = &[M(230.70802, -595.86316), L(318.21796, -595.86316), L(511.53537, -46.93715), L(560.8592, -38.9817), L(560.8592, -0.0), L(350.8353, -0.0), L(350.8353, -38.9817), L(416.86554, -46.93715), L(379.4749, -164.6778), L(147.17581, -164.6778), L(109.7852, -46.93715), L(178.20206, -38.9817), L(178.20206, -0.0), L(-10.342084, -0.0), L(-10.342084, -38.9817), L(38.9817, -46.93715), L(230.70802, -595.86316), Z, M(280.82736, -468.57596), L(260.93872, -538.5839), L(241.84566, -466.98486), L(161.49562, -211.61494), L(364.3596, -211.61494), L(280.82736, -468.57596), Z, M(305.48926, -781.2251), L(412.88782, -666.6666), L(380.27048, -643.5958), L(273.66745, -725.537), L(165.47334, -643.5958), L(134.4471, -665.8711), L(241.84566, -781.2251), L(305.48926, -781.2251), Z];
+pub const MERRIWEATHER_REGULAR_194: &'static [PathSegment] = &[M(230.70802, -595.86316), L(318.21796, -595.86316), L(511.53537, -46.93715), L(560.8592, -38.9817), L(560.8592, -0.0), L(350.8353, -0.0), L(350.8353, -38.9817), L(416.86554, -46.93715), L(379.4749, -164.6778), L(147.17581, -164.6778), L(109.7852, -46.93715), L(178.20206, -38.9817), L(178.20206, -0.0), L(-10.342084, -0.0), L(-10.342084, -38.9817), L(38.9817, -46.93715), L(230.70802, -595.86316), Z, M(280.82736, -468.57596), L(260.93872, -538.5839), L(241.84566, -466.98486), L(161.49562, -211.61494), L(364.3596, -211.61494), L(280.82736, -468.57596), Z, M(305.48926, -781.2251), L(412.88782, -666.6666), L(380.27048, -643.5958), L(273.66745, -725.537), L(165.47334, -643.5958), L(134.4471, -665.8711), L(241.84566, -781.2251), L(305.48926, -781.2251), Z];
 
 // // Ã ------------------------------
-// pub const MERRIWEATHER_REGULAR_195: &'static [PathSegment] = &[M(230.70802, -595.86316), L(318.21796, -595.86316), L(511.53537, -46.93715), L(560.8592, -38.9817), L(560.8592, -0.0), L(350.8353, -0.0), L(350.8353, -38.9817), L(416.86554, -46.93715), L(379.4749, -164.6778), L(147.17581, -164.6778), L(109.7852, -46.93715), L(178.20206, -38.9817), L(178.20206, -0.0), L(-10.342084, -0.0), L(-10.342084, -38.9817), L(38.9817, -46.93715), L(230.70802, -595.86316), Z, M(280.82736, -468.57596), L(260.93872, -538.5839), L(241.84566, -466.98486), L(161.49562, -211.61494), L(364.3596, -211.61494), L(280.82736, -468.57596), Z, M(295.14716, -656.72235), Q(280.0318, -665.07556, 260.1432, -680.98645), Q(246.61893, -692.9196, 236.67462, -698.8862), Q(226.7303, -704.8528, 214.79713, -704.8528), Q(194.90851, -704.8528, 184.96419, -692.52185), Q(175.01988, -680.1909, 167.06444, -657.91565), L(129.67383, -679.3954), Q(140.81145, -715.1949, 163.08672, -737.47015), Q(185.36197, -759.7454, 219.5704, -759.7454), Q(244.23228, -759.7454, 259.74542, -750.9944), Q(275.25854, -742.2434, 294.35162, -724.7414), Q(307.8759, -712.8082, 316.6269, -707.23944), Q(325.37787, -701.6706, 335.71997, -701.6706), Q(353.22195, -701.6706, 363.9618, -715.1949), Q(374.70166, -728.7192, 382.6571, -750.19885), L(420.04773, -728.7192), Q(408.11453, -691.32855, 388.22592, -669.8488), Q(368.3373, -648.36914, 333.3333, -648.36914), Q(310.2625, -648.36914, 295.14716, -656.72235), Z];
+pub const MERRIWEATHER_REGULAR_195: &'static [PathSegment] = &[M(230.70802, -595.86316), L(318.21796, -595.86316), L(511.53537, -46.93715), L(560.8592, -38.9817), L(560.8592, -0.0), L(350.8353, -0.0), L(350.8353, -38.9817), L(416.86554, -46.93715), L(379.4749, -164.6778), L(147.17581, -164.6778), L(109.7852, -46.93715), L(178.20206, -38.9817), L(178.20206, -0.0), L(-10.342084, -0.0), L(-10.342084, -38.9817), L(38.9817, -46.93715), L(230.70802, -595.86316), Z, M(280.82736, -468.57596), L(260.93872, -538.5839), L(241.84566, -466.98486), L(161.49562, -211.61494), L(364.3596, -211.61494), L(280.82736, -468.57596), Z, M(295.14716, -656.72235), Q(280.0318, -665.07556, 260.1432, -680.98645), Q(246.61893, -692.9196, 236.67462, -698.8862), Q(226.7303, -704.8528, 214.79713, -704.8528), Q(194.90851, -704.8528, 184.96419, -692.52185), Q(175.01988, -680.1909, 167.06444, -657.91565), L(129.67383, -679.3954), Q(140.81145, -715.1949, 163.08672, -737.47015), Q(185.36197, -759.7454, 219.5704, -759.7454), Q(244.23228, -759.7454, 259.74542, -750.9944), Q(275.25854, -742.2434, 294.35162, -724.7414), Q(307.8759, -712.8082, 316.6269, -707.23944), Q(325.37787, -701.6706, 335.71997, -701.6706), Q(353.22195, -701.6706, 363.9618, -715.1949), Q(374.70166, -728.7192, 382.6571, -750.19885), L(420.04773, -728.7192), Q(408.11453, -691.32855, 388.22592, -669.8488), Q(368.3373, -648.36914, 333.3333, -648.36914), Q(310.2625, -648.36914, 295.14716, -656.72235), Z];
 
 // // Ä ------------------------------
-// pub const MERRIWEATHER_REGULAR_196: &'static [PathSegment] = &[M(230.70802, -595.86316), L(318.21796, -595.86316), L(511.53537, -46.93715), L(560.8592, -38.9817), L(560.8592, -0.0), L(350.8353, -0.0), L(350.8353, -38.9817), L(416.86554, -46.93715), L(379.4749, -164.6778), L(147.17581, -164.6778), L(109.7852, -46.93715), L(178.20206, -38.9817), L(178.20206, -0.0), L(-10.342084, -0.0), L(-10.342084, -38.9817), L(38.9817, -46.93715), L(230.70802, -595.86316), Z, M(280.82736, -468.57596), L(260.93872, -538.5839), L(241.84566, -466.98486), L(161.49562, -211.61494), L(364.3596, -211.61494), L(280.82736, -468.57596), Z, M(137.62927, -666.26886), Q(125.6961, -680.98645, 125.6961, -701.6706), Q(125.6961, -727.12805, 138.82259, -743.8345), Q(151.94908, -760.54095, 177.40652, -760.54095), L(178.20206, -760.54095), Q(200.47733, -760.54095, 213.20604, -745.4256), Q(225.93475, -730.31024, 225.93475, -709.6261), Q(225.93475, -684.16864, 211.61494, -667.86), Q(197.29514, -651.5513, 170.24661, -651.5513), L(169.45107, -651.5513), Q(149.56244, -651.5513, 137.62927, -666.26886), Z, M(332.14, -666.26886), Q(319.80905, -680.98645, 319.80905, -701.6706), Q(319.80905, -726.3325, 333.3333, -743.4367), Q(346.85757, -760.54095, 371.51947, -760.54095), L(372.31503, -760.54095), Q(394.59027, -760.54095, 406.92123, -745.4256), Q(419.25217, -730.31024, 419.25217, -709.6261), Q(419.25217, -684.16864, 405.33014, -667.86), Q(391.4081, -651.5513, 364.3596, -651.5513), Q(344.47095, -651.5513, 332.14, -666.26886), Z];
+pub const MERRIWEATHER_REGULAR_196: &'static [PathSegment] = &[M(230.70802, -595.86316), L(318.21796, -595.86316), L(511.53537, -46.93715), L(560.8592, -38.9817), L(560.8592, -0.0), L(350.8353, -0.0), L(350.8353, -38.9817), L(416.86554, -46.93715), L(379.4749, -164.6778), L(147.17581, -164.6778), L(109.7852, -46.93715), L(178.20206, -38.9817), L(178.20206, -0.0), L(-10.342084, -0.0), L(-10.342084, -38.9817), L(38.9817, -46.93715), L(230.70802, -595.86316), Z, M(280.82736, -468.57596), L(260.93872, -538.5839), L(241.84566, -466.98486), L(161.49562, -211.61494), L(364.3596, -211.61494), L(280.82736, -468.57596), Z, M(137.62927, -666.26886), Q(125.6961, -680.98645, 125.6961, -701.6706), Q(125.6961, -727.12805, 138.82259, -743.8345), Q(151.94908, -760.54095, 177.40652, -760.54095), L(178.20206, -760.54095), Q(200.47733, -760.54095, 213.20604, -745.4256), Q(225.93475, -730.31024, 225.93475, -709.6261), Q(225.93475, -684.16864, 211.61494, -667.86), Q(197.29514, -651.5513, 170.24661, -651.5513), L(169.45107, -651.5513), Q(149.56244, -651.5513, 137.62927, -666.26886), Z, M(332.14, -666.26886), Q(319.80905, -680.98645, 319.80905, -701.6706), Q(319.80905, -726.3325, 333.3333, -743.4367), Q(346.85757, -760.54095, 371.51947, -760.54095), L(372.31503, -760.54095), Q(394.59027, -760.54095, 406.92123, -745.4256), Q(419.25217, -730.31024, 419.25217, -709.6261), Q(419.25217, -684.16864, 405.33014, -667.86), Q(391.4081, -651.5513, 364.3596, -651.5513), Q(344.47095, -651.5513, 332.14, -666.26886), Z];
 
 // // Å ------------------------------
-// pub const MERRIWEATHER_REGULAR_197: &'static [PathSegment] = &[M(-10.342084, -38.9817), L(38.9817, -46.93715), L(228.3214, -589.4988), Q(186.15752, -598.24976, 165.07558, -622.5139), Q(143.99364, -646.778, 143.99364, -680.98645), Q(143.99364, -729.5147, 182.57756, -755.7677), Q(221.16148, -782.0206, 276.05408, -782.0206), Q(337.31104, -782.0206, 369.92838, -755.7677), Q(402.54575, -729.5147, 402.54575, -684.9642), Q(402.54575, -648.36914, 379.8727, -624.50275), Q(357.19968, -600.6364, 319.80905, -591.0899), L(511.53537, -46.93715), L(560.8592, -38.9817), L(560.8592, -0.0), L(350.8353, -0.0), L(350.8353, -38.9817), L(416.86554, -46.93715), L(379.4749, -164.6778), L(147.17581, -164.6778), L(109.7852, -46.93715), L(178.20206, -38.9817), L(178.20206, -0.0), L(-10.342084, -0.0), L(-10.342084, -38.9817), Z, M(218.77486, -638.4248), Q(237.07239, -619.7295, 273.66745, -619.7295), Q(345.2665, -619.7295, 345.2665, -680.1909), Q(345.2665, -709.6261, 328.1623, -727.9236), Q(311.05807, -746.2211, 274.463, -746.2211), Q(237.86794, -746.2211, 219.17262, -730.708), Q(200.47733, -715.1949, 200.47733, -684.9642), Q(200.47733, -657.1201, 218.77486, -638.4248), Z, M(364.3596, -211.61494), L(280.82736, -468.57596), L(260.93872, -538.5839), L(241.84566, -466.98486), L(161.49562, -211.61494), L(364.3596, -211.61494), Z];
+pub const MERRIWEATHER_REGULAR_197: &'static [PathSegment] = &[M(-10.342084, -38.9817), L(38.9817, -46.93715), L(228.3214, -589.4988), Q(186.15752, -598.24976, 165.07558, -622.5139), Q(143.99364, -646.778, 143.99364, -680.98645), Q(143.99364, -729.5147, 182.57756, -755.7677), Q(221.16148, -782.0206, 276.05408, -782.0206), Q(337.31104, -782.0206, 369.92838, -755.7677), Q(402.54575, -729.5147, 402.54575, -684.9642), Q(402.54575, -648.36914, 379.8727, -624.50275), Q(357.19968, -600.6364, 319.80905, -591.0899), L(511.53537, -46.93715), L(560.8592, -38.9817), L(560.8592, -0.0), L(350.8353, -0.0), L(350.8353, -38.9817), L(416.86554, -46.93715), L(379.4749, -164.6778), L(147.17581, -164.6778), L(109.7852, -46.93715), L(178.20206, -38.9817), L(178.20206, -0.0), L(-10.342084, -0.0), L(-10.342084, -38.9817), Z, M(218.77486, -638.4248), Q(237.07239, -619.7295, 273.66745, -619.7295), Q(345.2665, -619.7295, 345.2665, -680.1909), Q(345.2665, -709.6261, 328.1623, -727.9236), Q(311.05807, -746.2211, 274.463, -746.2211), Q(237.86794, -746.2211, 219.17262, -730.708), Q(200.47733, -715.1949, 200.47733, -684.9642), Q(200.47733, -657.1201, 218.77486, -638.4248), Z, M(364.3596, -211.61494), L(280.82736, -468.57596), L(260.93872, -538.5839), L(241.84566, -466.98486), L(161.49562, -211.61494), L(364.3596, -211.61494), Z];
 
 // // Æ ------------------------------
-// pub const MERRIWEATHER_REGULAR_198: &'static [PathSegment] = &[M(319.01352, -171.8377), L(112.96738, -171.8377), L(62.84805, -46.93715), L(124.10501, -38.186157), L(124.10501, -0.0), L(-70.00795, -0.0), L(-70.00795, -38.186157), L(-11.933174, -46.93715), L(206.84167, -542.56165), L(101.0342, -552.10815), L(101.0342, -591.0899), L(657.1201, -591.0899), L(669.8488, -465.39377), L(624.50275, -465.39377), L(599.8409, -546.53937), L(404.1368, -550.5171), L(404.1368, -325.37787), L(534.6062, -327.7645), L(544.94824, -393.79474), L(589.4988, -393.79474), L(589.4988, -210.8194), L(544.94824, -210.8194), L(534.6062, -280.0318), L(404.1368, -281.6229), L(404.1368, -43.75497), L(627.68494, -52.505966), L(675.41766, -152.74463), L(723.94586, -139.22037), L(694.51074, -0.0), L(250.59665, -0.0), L(250.59665, -38.186157), L(319.01352, -46.93715), L(319.01352, -171.8377), Z, M(319.01352, -547.3349), L(266.50754, -547.3349), L(222.75258, -443.11853), L(132.06046, -218.77486), L(319.01352, -218.77486), L(319.01352, -547.3349), Z];
+pub const MERRIWEATHER_REGULAR_198: &'static [PathSegment] = &[M(319.01352, -171.8377), L(112.96738, -171.8377), L(62.84805, -46.93715), L(124.10501, -38.186157), L(124.10501, -0.0), L(-70.00795, -0.0), L(-70.00795, -38.186157), L(-11.933174, -46.93715), L(206.84167, -542.56165), L(101.0342, -552.10815), L(101.0342, -591.0899), L(657.1201, -591.0899), L(669.8488, -465.39377), L(624.50275, -465.39377), L(599.8409, -546.53937), L(404.1368, -550.5171), L(404.1368, -325.37787), L(534.6062, -327.7645), L(544.94824, -393.79474), L(589.4988, -393.79474), L(589.4988, -210.8194), L(544.94824, -210.8194), L(534.6062, -280.0318), L(404.1368, -281.6229), L(404.1368, -43.75497), L(627.68494, -52.505966), L(675.41766, -152.74463), L(723.94586, -139.22037), L(694.51074, -0.0), L(250.59665, -0.0), L(250.59665, -38.186157), L(319.01352, -46.93715), L(319.01352, -171.8377), Z, M(319.01352, -547.3349), L(266.50754, -547.3349), L(222.75258, -443.11853), L(132.06046, -218.77486), L(319.01352, -218.77486), L(319.01352, -547.3349), Z];
 
 // // Ç ------------------------------
-// pub const MERRIWEATHER_REGULAR_199: &'static [PathSegment] = &[M(151.55132, -29.03739), Q(89.896576, -66.825775, 58.07478, -135.24263), Q(26.252983, -203.6595, 26.252983, -295.94272), Q(26.252983, -385.04373, 63.643593, -453.4606), Q(101.0342, -521.87744, 166.26889, -559.66583), Q(231.50357, -597.4542, 312.64914, -597.4542), Q(373.90613, -597.4542, 459.82495, -578.36115), L(486.07794, -573.5879), L(478.1225, -430.3898), L(424.82098, -430.3898), L(404.93237, -531.424), Q(393.79474, -540.9705, 366.34845, -547.73267), Q(338.90213, -554.4948, 299.12488, -554.4948), Q(248.21002, -554.4948, 208.43277, -525.4574), Q(168.65552, -496.42004, 145.9825, -439.93634), Q(123.30946, -383.45267, 123.30946, -304.6937), Q(123.30946, -229.91248, 143.19809, -168.65552), Q(163.08672, -107.39857, 203.6595, -71.20127), Q(244.23228, -35.003975, 303.89816, -35.003975), Q(346.06204, -35.003975, 368.73508, -42.16388), Q(391.4081, -49.323784, 407.319, -62.84805), L(440.73187, -140.81145), L(490.85123, -134.4471), L(471.75815, -21.479713), Q(455.0517, -20.684168, 422.43436, -10.342084), Q(393.79474, -1.5910898, 367.14398, 3.5799522), Q(340.49323, 8.750994, 300.71597, 8.750994), Q(213.20604, 8.750994, 151.55132, -29.03739), Z, M(229.11694, 188.54414), Q(213.20604, 185.36197, 202.0684, 181.38425), L(202.86395, 126.491646), Q(210.8194, 129.67383, 221.16148, 131.26491), Q(231.50357, 132.856, 238.66348, 132.856), Q(270.48526, 132.856, 284.00955, 122.11614), Q(297.5338, 111.37629, 297.5338, 76.372314), Q(297.5338, 55.688145, 288.38504, 33.015114), Q(279.23627, 10.342084, 270.48526, -0.7955449), L(295.14716, -4.7732697), L(310.2625, -0.7955449), Q(326.96896, 14.319809, 344.47095, 45.34606), Q(361.97293, 76.372314, 361.97293, 108.989655), Q(361.97293, 153.54018, 336.11774, 172.63326), Q(310.2625, 191.72633, 258.5521, 191.72633), Q(245.02783, 191.72633, 229.11694, 188.54414), Z];
+pub const MERRIWEATHER_REGULAR_199: &'static [PathSegment] = &[M(151.55132, -29.03739), Q(89.896576, -66.825775, 58.07478, -135.24263), Q(26.252983, -203.6595, 26.252983, -295.94272), Q(26.252983, -385.04373, 63.643593, -453.4606), Q(101.0342, -521.87744, 166.26889, -559.66583), Q(231.50357, -597.4542, 312.64914, -597.4542), Q(373.90613, -597.4542, 459.82495, -578.36115), L(486.07794, -573.5879), L(478.1225, -430.3898), L(424.82098, -430.3898), L(404.93237, -531.424), Q(393.79474, -540.9705, 366.34845, -547.73267), Q(338.90213, -554.4948, 299.12488, -554.4948), Q(248.21002, -554.4948, 208.43277, -525.4574), Q(168.65552, -496.42004, 145.9825, -439.93634), Q(123.30946, -383.45267, 123.30946, -304.6937), Q(123.30946, -229.91248, 143.19809, -168.65552), Q(163.08672, -107.39857, 203.6595, -71.20127), Q(244.23228, -35.003975, 303.89816, -35.003975), Q(346.06204, -35.003975, 368.73508, -42.16388), Q(391.4081, -49.323784, 407.319, -62.84805), L(440.73187, -140.81145), L(490.85123, -134.4471), L(471.75815, -21.479713), Q(455.0517, -20.684168, 422.43436, -10.342084), Q(393.79474, -1.5910898, 367.14398, 3.5799522), Q(340.49323, 8.750994, 300.71597, 8.750994), Q(213.20604, 8.750994, 151.55132, -29.03739), Z, M(229.11694, 188.54414), Q(213.20604, 185.36197, 202.0684, 181.38425), L(202.86395, 126.491646), Q(210.8194, 129.67383, 221.16148, 131.26491), Q(231.50357, 132.856, 238.66348, 132.856), Q(270.48526, 132.856, 284.00955, 122.11614), Q(297.5338, 111.37629, 297.5338, 76.372314), Q(297.5338, 55.688145, 288.38504, 33.015114), Q(279.23627, 10.342084, 270.48526, -0.7955449), L(295.14716, -4.7732697), L(310.2625, -0.7955449), Q(326.96896, 14.319809, 344.47095, 45.34606), Q(361.97293, 76.372314, 361.97293, 108.989655), Q(361.97293, 153.54018, 336.11774, 172.63326), Q(310.2625, 191.72633, 258.5521, 191.72633), Q(245.02783, 191.72633, 229.11694, 188.54414), Z];
 
 // // È ------------------------------
-// pub const MERRIWEATHER_REGULAR_200: &'static [PathSegment] = &[M(102.6253, -541.7661), L(37.39061, -551.3126), L(37.39061, -591.0899), L(442.32297, -591.0899), L(453.4606, -467.78043), L(408.11453, -467.78043), L(385.04373, -544.94824), L(187.7486, -549.72156), L(187.7486, -325.37787), L(320.6046, -327.7645), L(331.74222, -393.79474), L(375.4972, -393.79474), L(375.4972, -210.8194), L(331.74222, -210.8194), L(320.6046, -279.23627), L(187.7486, -281.6229), L(187.7486, -44.550514), L(412.09225, -52.505966), L(454.25616, -152.74463), L(501.1933, -138.42482), L(478.1225, -0.0), L(36.595066, -0.0), L(36.595066, -38.9817), L(102.6253, -46.93715), L(102.6253, -541.7661), Z, M(219.96817, -681.3842), Q(178.9976, -712.8082, 158.31345, -739.85675), L(221.95703, -780.42957), L(329.3556, -659.5067), L(291.16943, -635.6404), Q(260.93872, -649.9602, 219.96817, -681.3842), Z];
+pub const MERRIWEATHER_REGULAR_200: &'static [PathSegment] = &[M(102.6253, -541.7661), L(37.39061, -551.3126), L(37.39061, -591.0899), L(442.32297, -591.0899), L(453.4606, -467.78043), L(408.11453, -467.78043), L(385.04373, -544.94824), L(187.7486, -549.72156), L(187.7486, -325.37787), L(320.6046, -327.7645), L(331.74222, -393.79474), L(375.4972, -393.79474), L(375.4972, -210.8194), L(331.74222, -210.8194), L(320.6046, -279.23627), L(187.7486, -281.6229), L(187.7486, -44.550514), L(412.09225, -52.505966), L(454.25616, -152.74463), L(501.1933, -138.42482), L(478.1225, -0.0), L(36.595066, -0.0), L(36.595066, -38.9817), L(102.6253, -46.93715), L(102.6253, -541.7661), Z, M(219.96817, -681.3842), Q(178.9976, -712.8082, 158.31345, -739.85675), L(221.95703, -780.42957), L(329.3556, -659.5067), L(291.16943, -635.6404), Q(260.93872, -649.9602, 219.96817, -681.3842), Z];
 
 // // É ------------------------------
-// pub const MERRIWEATHER_REGULAR_201: &'static [PathSegment] = &[M(102.6253, -541.7661), L(37.39061, -551.3126), L(37.39061, -591.0899), L(442.32297, -591.0899), L(453.4606, -467.78043), L(408.11453, -467.78043), L(385.04373, -544.94824), L(187.7486, -549.72156), L(187.7486, -325.37787), L(320.6046, -327.7645), L(331.74222, -393.79474), L(375.4972, -393.79474), L(375.4972, -210.8194), L(331.74222, -210.8194), L(320.6046, -279.23627), L(187.7486, -281.6229), L(187.7486, -44.550514), L(412.09225, -52.505966), L(454.25616, -152.74463), L(501.1933, -138.42482), L(478.1225, -0.0), L(36.595066, -0.0), L(36.595066, -38.9817), L(102.6253, -46.93715), L(102.6253, -541.7661), Z, M(211.61494, -659.5067), L(318.21796, -780.42957), L(382.6571, -739.85675), Q(360.38184, -712.0127, 321.00238, -680.98645), Q(281.6229, -649.9602, 251.3922, -635.6404), L(211.61494, -659.5067), Z];
+pub const MERRIWEATHER_REGULAR_201: &'static [PathSegment] = &[M(102.6253, -541.7661), L(37.39061, -551.3126), L(37.39061, -591.0899), L(442.32297, -591.0899), L(453.4606, -467.78043), L(408.11453, -467.78043), L(385.04373, -544.94824), L(187.7486, -549.72156), L(187.7486, -325.37787), L(320.6046, -327.7645), L(331.74222, -393.79474), L(375.4972, -393.79474), L(375.4972, -210.8194), L(331.74222, -210.8194), L(320.6046, -279.23627), L(187.7486, -281.6229), L(187.7486, -44.550514), L(412.09225, -52.505966), L(454.25616, -152.74463), L(501.1933, -138.42482), L(478.1225, -0.0), L(36.595066, -0.0), L(36.595066, -38.9817), L(102.6253, -46.93715), L(102.6253, -541.7661), Z, M(211.61494, -659.5067), L(318.21796, -780.42957), L(382.6571, -739.85675), Q(360.38184, -712.0127, 321.00238, -680.98645), Q(281.6229, -649.9602, 251.3922, -635.6404), L(211.61494, -659.5067), Z];
 
 // // Ê ------------------------------
-// pub const MERRIWEATHER_REGULAR_202: &'static [PathSegment] = &[M(102.6253, -541.7661), L(37.39061, -551.3126), L(37.39061, -591.0899), L(442.32297, -591.0899), L(453.4606, -467.78043), L(408.11453, -467.78043), L(385.04373, -544.94824), L(187.7486, -549.72156), L(187.7486, -325.37787), L(320.6046, -327.7645), L(331.74222, -393.79474), L(375.4972, -393.79474), L(375.4972, -210.8194), L(331.74222, -210.8194), L(320.6046, -279.23627), L(187.7486, -281.6229), L(187.7486, -44.550514), L(412.09225, -52.505966), L(454.25616, -152.74463), L(501.1933, -138.42482), L(478.1225, -0.0), L(36.595066, -0.0), L(36.595066, -38.9817), L(102.6253, -46.93715), L(102.6253, -541.7661), Z, M(303.89816, -781.2251), L(411.29672, -666.6666), L(378.67938, -643.5958), L(272.07635, -725.537), L(163.88225, -643.5958), L(132.856, -665.8711), L(240.25456, -781.2251), L(303.89816, -781.2251), Z];
+pub const MERRIWEATHER_REGULAR_202: &'static [PathSegment] = &[M(102.6253, -541.7661), L(37.39061, -551.3126), L(37.39061, -591.0899), L(442.32297, -591.0899), L(453.4606, -467.78043), L(408.11453, -467.78043), L(385.04373, -544.94824), L(187.7486, -549.72156), L(187.7486, -325.37787), L(320.6046, -327.7645), L(331.74222, -393.79474), L(375.4972, -393.79474), L(375.4972, -210.8194), L(331.74222, -210.8194), L(320.6046, -279.23627), L(187.7486, -281.6229), L(187.7486, -44.550514), L(412.09225, -52.505966), L(454.25616, -152.74463), L(501.1933, -138.42482), L(478.1225, -0.0), L(36.595066, -0.0), L(36.595066, -38.9817), L(102.6253, -46.93715), L(102.6253, -541.7661), Z, M(303.89816, -781.2251), L(411.29672, -666.6666), L(378.67938, -643.5958), L(272.07635, -725.537), L(163.88225, -643.5958), L(132.856, -665.8711), L(240.25456, -781.2251), L(303.89816, -781.2251), Z];
 
 // // Ë ------------------------------
-// pub const MERRIWEATHER_REGULAR_203: &'static [PathSegment] = &[M(102.6253, -541.7661), L(37.39061, -551.3126), L(37.39061, -591.0899), L(442.32297, -591.0899), L(453.4606, -467.78043), L(408.11453, -467.78043), L(385.04373, -544.94824), L(187.7486, -549.72156), L(187.7486, -325.37787), L(320.6046, -327.7645), L(331.74222, -393.79474), L(375.4972, -393.79474), L(375.4972, -210.8194), L(331.74222, -210.8194), L(320.6046, -279.23627), L(187.7486, -281.6229), L(187.7486, -44.550514), L(412.09225, -52.505966), L(454.25616, -152.74463), L(501.1933, -138.42482), L(478.1225, -0.0), L(36.595066, -0.0), L(36.595066, -38.9817), L(102.6253, -46.93715), L(102.6253, -541.7661), Z, M(136.03818, -666.26886), Q(124.10501, -680.98645, 124.10501, -701.6706), Q(124.10501, -727.12805, 137.2315, -743.8345), Q(150.35799, -760.54095, 175.81543, -760.54095), L(176.61098, -760.54095), Q(198.88623, -760.54095, 211.61494, -745.4256), Q(224.34367, -730.31024, 224.34367, -709.6261), Q(224.34367, -684.16864, 210.02386, -667.86), Q(195.70406, -651.5513, 168.65552, -651.5513), L(167.85999, -651.5513), Q(147.97136, -651.5513, 136.03818, -666.26886), Z, M(330.54892, -666.26886), Q(318.21796, -680.98645, 318.21796, -701.6706), Q(318.21796, -726.3325, 331.74222, -743.4367), Q(345.2665, -760.54095, 369.92838, -760.54095), L(370.72394, -760.54095), Q(392.9992, -760.54095, 405.33014, -745.4256), Q(417.66107, -730.31024, 417.66107, -709.6261), Q(417.66107, -684.16864, 403.73904, -667.86), Q(389.81702, -651.5513, 362.7685, -651.5513), Q(342.87985, -651.5513, 330.54892, -666.26886), Z];
+pub const MERRIWEATHER_REGULAR_203: &'static [PathSegment] = &[M(102.6253, -541.7661), L(37.39061, -551.3126), L(37.39061, -591.0899), L(442.32297, -591.0899), L(453.4606, -467.78043), L(408.11453, -467.78043), L(385.04373, -544.94824), L(187.7486, -549.72156), L(187.7486, -325.37787), L(320.6046, -327.7645), L(331.74222, -393.79474), L(375.4972, -393.79474), L(375.4972, -210.8194), L(331.74222, -210.8194), L(320.6046, -279.23627), L(187.7486, -281.6229), L(187.7486, -44.550514), L(412.09225, -52.505966), L(454.25616, -152.74463), L(501.1933, -138.42482), L(478.1225, -0.0), L(36.595066, -0.0), L(36.595066, -38.9817), L(102.6253, -46.93715), L(102.6253, -541.7661), Z, M(136.03818, -666.26886), Q(124.10501, -680.98645, 124.10501, -701.6706), Q(124.10501, -727.12805, 137.2315, -743.8345), Q(150.35799, -760.54095, 175.81543, -760.54095), L(176.61098, -760.54095), Q(198.88623, -760.54095, 211.61494, -745.4256), Q(224.34367, -730.31024, 224.34367, -709.6261), Q(224.34367, -684.16864, 210.02386, -667.86), Q(195.70406, -651.5513, 168.65552, -651.5513), L(167.85999, -651.5513), Q(147.97136, -651.5513, 136.03818, -666.26886), Z, M(330.54892, -666.26886), Q(318.21796, -680.98645, 318.21796, -701.6706), Q(318.21796, -726.3325, 331.74222, -743.4367), Q(345.2665, -760.54095, 369.92838, -760.54095), L(370.72394, -760.54095), Q(392.9992, -760.54095, 405.33014, -745.4256), Q(417.66107, -730.31024, 417.66107, -709.6261), Q(417.66107, -684.16864, 403.73904, -667.86), Q(389.81702, -651.5513, 362.7685, -651.5513), Q(342.87985, -651.5513, 330.54892, -666.26886), Z];
 
 // // Ì ------------------------------
-// pub const MERRIWEATHER_REGULAR_204: &'static [PathSegment] = &[M(116.94511, -541.7661), L(42.959427, -551.3126), L(42.959427, -591.0899), L(276.84964, -591.0899), L(276.84964, -551.3126), L(202.86395, -541.7661), L(202.86395, -46.93715), L(276.84964, -38.186157), L(276.84964, -0.0), L(43.75497, -0.0), L(43.75497, -38.9817), L(116.94511, -46.93715), L(116.94511, -541.7661), Z, M(109.38743, -681.3842), Q(68.41686, -712.8082, 47.732697, -739.85675), L(111.37629, -780.42957), L(218.77486, -659.5067), L(180.5887, -635.6404), Q(150.35799, -649.9602, 109.38743, -681.3842), Z];
+pub const MERRIWEATHER_REGULAR_204: &'static [PathSegment] = &[M(116.94511, -541.7661), L(42.959427, -551.3126), L(42.959427, -591.0899), L(276.84964, -591.0899), L(276.84964, -551.3126), L(202.86395, -541.7661), L(202.86395, -46.93715), L(276.84964, -38.186157), L(276.84964, -0.0), L(43.75497, -0.0), L(43.75497, -38.9817), L(116.94511, -46.93715), L(116.94511, -541.7661), Z, M(109.38743, -681.3842), Q(68.41686, -712.8082, 47.732697, -739.85675), L(111.37629, -780.42957), L(218.77486, -659.5067), L(180.5887, -635.6404), Q(150.35799, -649.9602, 109.38743, -681.3842), Z];
 
 // // Í ------------------------------
-// pub const MERRIWEATHER_REGULAR_205: &'static [PathSegment] = &[M(116.94511, -541.7661), L(42.959427, -551.3126), L(42.959427, -591.0899), L(276.84964, -591.0899), L(276.84964, -551.3126), L(202.86395, -541.7661), L(202.86395, -46.93715), L(276.84964, -38.186157), L(276.84964, -0.0), L(43.75497, -0.0), L(43.75497, -38.9817), L(116.94511, -46.93715), L(116.94511, -541.7661), Z, M(101.0342, -659.5067), L(207.63722, -780.42957), L(272.07635, -739.85675), Q(249.8011, -712.0127, 210.42163, -680.98645), Q(171.04216, -649.9602, 140.81145, -635.6404), L(101.0342, -659.5067), Z];
+pub const MERRIWEATHER_REGULAR_205: &'static [PathSegment] = &[M(116.94511, -541.7661), L(42.959427, -551.3126), L(42.959427, -591.0899), L(276.84964, -591.0899), L(276.84964, -551.3126), L(202.86395, -541.7661), L(202.86395, -46.93715), L(276.84964, -38.186157), L(276.84964, -0.0), L(43.75497, -0.0), L(43.75497, -38.9817), L(116.94511, -46.93715), L(116.94511, -541.7661), Z, M(101.0342, -659.5067), L(207.63722, -780.42957), L(272.07635, -739.85675), Q(249.8011, -712.0127, 210.42163, -680.98645), Q(171.04216, -649.9602, 140.81145, -635.6404), L(101.0342, -659.5067), Z];
 
 // // Î ------------------------------
-// pub const MERRIWEATHER_REGULAR_206: &'static [PathSegment] = &[M(116.94511, -541.7661), L(42.959427, -551.3126), L(42.959427, -591.0899), L(276.84964, -591.0899), L(276.84964, -551.3126), L(202.86395, -541.7661), L(202.86395, -46.93715), L(276.84964, -38.186157), L(276.84964, -0.0), L(43.75497, -0.0), L(43.75497, -38.9817), L(116.94511, -46.93715), L(116.94511, -541.7661), Z, M(193.31741, -781.2251), L(300.71597, -666.6666), L(268.09863, -643.5958), L(161.49562, -725.537), L(53.30151, -643.5958), L(22.275257, -665.8711), L(129.67383, -781.2251), L(193.31741, -781.2251), Z];
+pub const MERRIWEATHER_REGULAR_206: &'static [PathSegment] = &[M(116.94511, -541.7661), L(42.959427, -551.3126), L(42.959427, -591.0899), L(276.84964, -591.0899), L(276.84964, -551.3126), L(202.86395, -541.7661), L(202.86395, -46.93715), L(276.84964, -38.186157), L(276.84964, -0.0), L(43.75497, -0.0), L(43.75497, -38.9817), L(116.94511, -46.93715), L(116.94511, -541.7661), Z, M(193.31741, -781.2251), L(300.71597, -666.6666), L(268.09863, -643.5958), L(161.49562, -725.537), L(53.30151, -643.5958), L(22.275257, -665.8711), L(129.67383, -781.2251), L(193.31741, -781.2251), Z];
 
 // // Ï ------------------------------
-// pub const MERRIWEATHER_REGULAR_207: &'static [PathSegment] = &[M(116.94511, -541.7661), L(42.959427, -551.3126), L(42.959427, -591.0899), L(276.84964, -591.0899), L(276.84964, -551.3126), L(202.86395, -541.7661), L(202.86395, -46.93715), L(276.84964, -38.186157), L(276.84964, -0.0), L(43.75497, -0.0), L(43.75497, -38.9817), L(116.94511, -46.93715), L(116.94511, -541.7661), Z, M(25.457438, -666.26886), Q(13.524263, -680.98645, 13.524263, -701.6706), Q(13.524263, -727.12805, 26.650755, -743.8345), Q(39.777245, -760.54095, 65.23468, -760.54095), L(66.03023, -760.54095), Q(88.30549, -760.54095, 101.0342, -745.4256), Q(113.762924, -730.31024, 113.762924, -709.6261), Q(113.762924, -684.16864, 99.443115, -667.86), Q(85.12331, -651.5513, 58.07478, -651.5513), L(57.279236, -651.5513), Q(37.39061, -651.5513, 25.457438, -666.26886), Z, M(219.96817, -666.26886), Q(207.63722, -680.98645, 207.63722, -701.6706), Q(207.63722, -726.3325, 221.16148, -743.4367), Q(234.68575, -760.54095, 259.34766, -760.54095), L(260.1432, -760.54095), Q(282.41846, -760.54095, 294.7494, -745.4256), Q(307.08035, -730.31024, 307.08035, -709.6261), Q(307.08035, -684.16864, 293.1583, -667.86), Q(279.23627, -651.5513, 252.18774, -651.5513), Q(232.29912, -651.5513, 219.96817, -666.26886), Z];
+pub const MERRIWEATHER_REGULAR_207: &'static [PathSegment] = &[M(116.94511, -541.7661), L(42.959427, -551.3126), L(42.959427, -591.0899), L(276.84964, -591.0899), L(276.84964, -551.3126), L(202.86395, -541.7661), L(202.86395, -46.93715), L(276.84964, -38.186157), L(276.84964, -0.0), L(43.75497, -0.0), L(43.75497, -38.9817), L(116.94511, -46.93715), L(116.94511, -541.7661), Z, M(25.457438, -666.26886), Q(13.524263, -680.98645, 13.524263, -701.6706), Q(13.524263, -727.12805, 26.650755, -743.8345), Q(39.777245, -760.54095, 65.23468, -760.54095), L(66.03023, -760.54095), Q(88.30549, -760.54095, 101.0342, -745.4256), Q(113.762924, -730.31024, 113.762924, -709.6261), Q(113.762924, -684.16864, 99.443115, -667.86), Q(85.12331, -651.5513, 58.07478, -651.5513), L(57.279236, -651.5513), Q(37.39061, -651.5513, 25.457438, -666.26886), Z, M(219.96817, -666.26886), Q(207.63722, -680.98645, 207.63722, -701.6706), Q(207.63722, -726.3325, 221.16148, -743.4367), Q(234.68575, -760.54095, 259.34766, -760.54095), L(260.1432, -760.54095), Q(282.41846, -760.54095, 294.7494, -745.4256), Q(307.08035, -730.31024, 307.08035, -709.6261), Q(307.08035, -684.16864, 293.1583, -667.86), Q(279.23627, -651.5513, 252.18774, -651.5513), Q(232.29912, -651.5513, 219.96817, -666.26886), Z];
 
 // // Ð ------------------------------
-// pub const MERRIWEATHER_REGULAR_208: &'static [PathSegment] = &[M(102.6253, -541.7661), L(40.572792, -551.3126), L(40.572792, -591.0899), L(144.78917, -591.0899), Q(169.45107, -591.0899, 215.59267, -594.27203), Q(261.73428, -597.4542, 284.80508, -597.4542), Q(432.77643, -597.4542, 504.77325, -523.0708), Q(576.7701, -448.68735, 576.7701, -314.24023), Q(576.7701, -220.36594, 539.7772, -147.57358), Q(502.7844, -74.78122, 435.95862, -34.20843), Q(369.13284, 6.3643594, 282.41846, 6.3643594), Q(263.32538, 6.3643594, 217.18376, 3.1821797), Q(169.45107, -0.0, 145.58472, -0.0), L(36.595066, -0.0), L(36.595066, -38.9817), L(102.6253, -46.93715), L(102.6253, -287.1917), L(42.959427, -287.1917), L(42.959427, -328.56006), L(102.6253, -328.56006), L(102.6253, -541.7661), Z, M(326.17343, -287.1917), L(186.95306, -287.1917), L(186.95306, -44.550514), Q(214.79713, -38.186157, 258.5521, -38.186157), L(282.41846, -38.186157), Q(342.08432, -38.9817, 387.82816, -68.41686), Q(433.572, -97.85203, 459.02942, -156.32457), Q(484.48685, -214.79713, 484.48685, -298.32935), Q(484.48685, -424.02545, 429.99203, -489.26013), Q(375.4972, -554.4948, 265.712, -554.4948), Q(242.6412, -554.4948, 210.8194, -551.3126), Q(197.29514, -548.926, 186.95306, -548.926), L(186.95306, -328.56006), L(326.17343, -328.56006), L(326.17343, -287.1917), Z];
+pub const MERRIWEATHER_REGULAR_208: &'static [PathSegment] = &[M(102.6253, -541.7661), L(40.572792, -551.3126), L(40.572792, -591.0899), L(144.78917, -591.0899), Q(169.45107, -591.0899, 215.59267, -594.27203), Q(261.73428, -597.4542, 284.80508, -597.4542), Q(432.77643, -597.4542, 504.77325, -523.0708), Q(576.7701, -448.68735, 576.7701, -314.24023), Q(576.7701, -220.36594, 539.7772, -147.57358), Q(502.7844, -74.78122, 435.95862, -34.20843), Q(369.13284, 6.3643594, 282.41846, 6.3643594), Q(263.32538, 6.3643594, 217.18376, 3.1821797), Q(169.45107, -0.0, 145.58472, -0.0), L(36.595066, -0.0), L(36.595066, -38.9817), L(102.6253, -46.93715), L(102.6253, -287.1917), L(42.959427, -287.1917), L(42.959427, -328.56006), L(102.6253, -328.56006), L(102.6253, -541.7661), Z, M(326.17343, -287.1917), L(186.95306, -287.1917), L(186.95306, -44.550514), Q(214.79713, -38.186157, 258.5521, -38.186157), L(282.41846, -38.186157), Q(342.08432, -38.9817, 387.82816, -68.41686), Q(433.572, -97.85203, 459.02942, -156.32457), Q(484.48685, -214.79713, 484.48685, -298.32935), Q(484.48685, -424.02545, 429.99203, -489.26013), Q(375.4972, -554.4948, 265.712, -554.4948), Q(242.6412, -554.4948, 210.8194, -551.3126), Q(197.29514, -548.926, 186.95306, -548.926), L(186.95306, -328.56006), L(326.17343, -328.56006), L(326.17343, -287.1917), Z];
 
 // // Ñ ------------------------------
-// pub const MERRIWEATHER_REGULAR_209: &'static [PathSegment] = &[M(103.42084, -541.7661), L(39.777245, -551.3126), L(39.777245, -591.0899), L(187.7486, -591.0899), L(452.66507, -202.86395), L(497.21558, -118.536194), L(497.21558, -541.7661), L(428.00317, -551.3126), L(428.00317, -591.0899), L(615.7518, -591.0899), L(615.7518, -551.3126), L(552.10815, -541.7661), L(552.10815, -0.0), L(489.26013, -0.0), L(218.77486, -396.97693), L(157.5179, -501.98886), L(157.5179, -46.93715), L(233.09467, -38.9817), L(233.09467, -0.0), L(39.777245, -0.0), L(39.777245, -38.9817), L(103.42084, -47.732697), L(103.42084, -541.7661), Z, M(350.03976, -656.72235), Q(334.9244, -665.07556, 315.0358, -680.98645), Q(301.51154, -692.9196, 291.5672, -698.8862), Q(281.6229, -704.8528, 269.68973, -704.8528), Q(249.8011, -704.8528, 239.8568, -692.52185), Q(229.91248, -680.1909, 221.95703, -657.91565), L(184.56642, -679.3954), Q(195.70406, -715.1949, 217.97931, -737.47015), Q(240.25456, -759.7454, 274.463, -759.7454), Q(299.12488, -759.7454, 314.63803, -750.9944), Q(330.15115, -742.2434, 349.24423, -724.7414), Q(362.7685, -712.8082, 371.51947, -707.23944), Q(380.27048, -701.6706, 390.61255, -701.6706), Q(408.11453, -701.6706, 418.8544, -715.1949), Q(429.59427, -728.7192, 437.5497, -750.19885), L(474.9403, -728.7192), Q(463.00714, -691.32855, 443.11853, -669.8488), Q(423.2299, -648.36914, 388.22592, -648.36914), Q(365.15512, -648.36914, 350.03976, -656.72235), Z];
+pub const MERRIWEATHER_REGULAR_209: &'static [PathSegment] = &[M(103.42084, -541.7661), L(39.777245, -551.3126), L(39.777245, -591.0899), L(187.7486, -591.0899), L(452.66507, -202.86395), L(497.21558, -118.536194), L(497.21558, -541.7661), L(428.00317, -551.3126), L(428.00317, -591.0899), L(615.7518, -591.0899), L(615.7518, -551.3126), L(552.10815, -541.7661), L(552.10815, -0.0), L(489.26013, -0.0), L(218.77486, -396.97693), L(157.5179, -501.98886), L(157.5179, -46.93715), L(233.09467, -38.9817), L(233.09467, -0.0), L(39.777245, -0.0), L(39.777245, -38.9817), L(103.42084, -47.732697), L(103.42084, -541.7661), Z, M(350.03976, -656.72235), Q(334.9244, -665.07556, 315.0358, -680.98645), Q(301.51154, -692.9196, 291.5672, -698.8862), Q(281.6229, -704.8528, 269.68973, -704.8528), Q(249.8011, -704.8528, 239.8568, -692.52185), Q(229.91248, -680.1909, 221.95703, -657.91565), L(184.56642, -679.3954), Q(195.70406, -715.1949, 217.97931, -737.47015), Q(240.25456, -759.7454, 274.463, -759.7454), Q(299.12488, -759.7454, 314.63803, -750.9944), Q(330.15115, -742.2434, 349.24423, -724.7414), Q(362.7685, -712.8082, 371.51947, -707.23944), Q(380.27048, -701.6706, 390.61255, -701.6706), Q(408.11453, -701.6706, 418.8544, -715.1949), Q(429.59427, -728.7192, 437.5497, -750.19885), L(474.9403, -728.7192), Q(463.00714, -691.32855, 443.11853, -669.8488), Q(423.2299, -648.36914, 388.22592, -648.36914), Q(365.15512, -648.36914, 350.03976, -656.72235), Z];
 
 // // Ò ------------------------------
-// pub const MERRIWEATHER_REGULAR_210: &'static [PathSegment] = &[M(140.41368, -28.639618), Q(84.32776, -66.03023, 55.688145, -134.04932), Q(27.048527, -202.0684, 27.048527, -291.965), Q(27.048527, -383.45267, 61.65473, -452.66507), Q(96.26093, -521.87744, 156.72235, -559.66583), Q(217.18376, -597.4542, 292.76053, -597.4542), Q(416.86554, -597.4542, 482.498, -519.0931), Q(548.13043, -440.73187, 548.13043, -302.30707), Q(548.13043, -209.22832, 512.7287, -138.82259), Q(477.32697, -68.41686, 415.6722, -29.832935), Q(354.0175, 8.750994, 277.64517, 8.750994), Q(196.4996, 8.750994, 140.41368, -28.639618), Z, M(166.26889, -488.86237), Q(121.718376, -421.6388, 121.718376, -291.965), Q(121.718376, -172.63326, 165.07558, -103.02306), Q(208.43277, -33.412888, 286.39618, -33.412888), Q(362.7685, -33.412888, 407.71677, -102.6253), Q(452.66507, -171.8377, 452.66507, -301.51154), Q(452.66507, -421.6388, 409.70563, -488.86237), Q(366.74622, -556.0859, 287.98727, -556.0859), Q(210.8194, -556.0859, 166.26889, -488.86237), Z, M(237.47015, -681.3842), Q(196.4996, -712.8082, 175.81543, -739.85675), L(239.45901, -780.42957), L(346.85757, -659.5067), L(308.67142, -635.6404), Q(278.44073, -649.9602, 237.47015, -681.3842), Z];
+pub const MERRIWEATHER_REGULAR_210: &'static [PathSegment] = &[M(140.41368, -28.639618), Q(84.32776, -66.03023, 55.688145, -134.04932), Q(27.048527, -202.0684, 27.048527, -291.965), Q(27.048527, -383.45267, 61.65473, -452.66507), Q(96.26093, -521.87744, 156.72235, -559.66583), Q(217.18376, -597.4542, 292.76053, -597.4542), Q(416.86554, -597.4542, 482.498, -519.0931), Q(548.13043, -440.73187, 548.13043, -302.30707), Q(548.13043, -209.22832, 512.7287, -138.82259), Q(477.32697, -68.41686, 415.6722, -29.832935), Q(354.0175, 8.750994, 277.64517, 8.750994), Q(196.4996, 8.750994, 140.41368, -28.639618), Z, M(166.26889, -488.86237), Q(121.718376, -421.6388, 121.718376, -291.965), Q(121.718376, -172.63326, 165.07558, -103.02306), Q(208.43277, -33.412888, 286.39618, -33.412888), Q(362.7685, -33.412888, 407.71677, -102.6253), Q(452.66507, -171.8377, 452.66507, -301.51154), Q(452.66507, -421.6388, 409.70563, -488.86237), Q(366.74622, -556.0859, 287.98727, -556.0859), Q(210.8194, -556.0859, 166.26889, -488.86237), Z, M(237.47015, -681.3842), Q(196.4996, -712.8082, 175.81543, -739.85675), L(239.45901, -780.42957), L(346.85757, -659.5067), L(308.67142, -635.6404), Q(278.44073, -649.9602, 237.47015, -681.3842), Z];
 
 // // Ó ------------------------------
-// pub const MERRIWEATHER_REGULAR_211: &'static [PathSegment] = &[M(140.41368, -28.639618), Q(84.32776, -66.03023, 55.688145, -134.04932), Q(27.048527, -202.0684, 27.048527, -291.965), Q(27.048527, -383.45267, 61.65473, -452.66507), Q(96.26093, -521.87744, 156.72235, -559.66583), Q(217.18376, -597.4542, 292.76053, -597.4542), Q(416.86554, -597.4542, 482.498, -519.0931), Q(548.13043, -440.73187, 548.13043, -302.30707), Q(548.13043, -209.22832, 512.7287, -138.82259), Q(477.32697, -68.41686, 415.6722, -29.832935), Q(354.0175, 8.750994, 277.64517, 8.750994), Q(196.4996, 8.750994, 140.41368, -28.639618), Z, M(166.26889, -488.86237), Q(121.718376, -421.6388, 121.718376, -291.965), Q(121.718376, -172.63326, 165.07558, -103.02306), Q(208.43277, -33.412888, 286.39618, -33.412888), Q(362.7685, -33.412888, 407.71677, -102.6253), Q(452.66507, -171.8377, 452.66507, -301.51154), Q(452.66507, -421.6388, 409.70563, -488.86237), Q(366.74622, -556.0859, 287.98727, -556.0859), Q(210.8194, -556.0859, 166.26889, -488.86237), Z, M(229.11694, -659.5067), L(335.71997, -780.42957), L(400.1591, -739.85675), Q(377.88385, -712.0127, 338.50436, -680.98645), Q(299.12488, -649.9602, 268.8942, -635.6404), L(229.11694, -659.5067), Z];
+pub const MERRIWEATHER_REGULAR_211: &'static [PathSegment] = &[M(140.41368, -28.639618), Q(84.32776, -66.03023, 55.688145, -134.04932), Q(27.048527, -202.0684, 27.048527, -291.965), Q(27.048527, -383.45267, 61.65473, -452.66507), Q(96.26093, -521.87744, 156.72235, -559.66583), Q(217.18376, -597.4542, 292.76053, -597.4542), Q(416.86554, -597.4542, 482.498, -519.0931), Q(548.13043, -440.73187, 548.13043, -302.30707), Q(548.13043, -209.22832, 512.7287, -138.82259), Q(477.32697, -68.41686, 415.6722, -29.832935), Q(354.0175, 8.750994, 277.64517, 8.750994), Q(196.4996, 8.750994, 140.41368, -28.639618), Z, M(166.26889, -488.86237), Q(121.718376, -421.6388, 121.718376, -291.965), Q(121.718376, -172.63326, 165.07558, -103.02306), Q(208.43277, -33.412888, 286.39618, -33.412888), Q(362.7685, -33.412888, 407.71677, -102.6253), Q(452.66507, -171.8377, 452.66507, -301.51154), Q(452.66507, -421.6388, 409.70563, -488.86237), Q(366.74622, -556.0859, 287.98727, -556.0859), Q(210.8194, -556.0859, 166.26889, -488.86237), Z, M(229.11694, -659.5067), L(335.71997, -780.42957), L(400.1591, -739.85675), Q(377.88385, -712.0127, 338.50436, -680.98645), Q(299.12488, -649.9602, 268.8942, -635.6404), L(229.11694, -659.5067), Z];
 
 // // Ô ------------------------------
-// pub const MERRIWEATHER_REGULAR_212: &'static [PathSegment] = &[M(140.41368, -28.639618), Q(84.32776, -66.03023, 55.688145, -134.04932), Q(27.048527, -202.0684, 27.048527, -291.965), Q(27.048527, -383.45267, 61.65473, -452.66507), Q(96.26093, -521.87744, 156.72235, -559.66583), Q(217.18376, -597.4542, 292.76053, -597.4542), Q(416.86554, -597.4542, 482.498, -519.0931), Q(548.13043, -440.73187, 548.13043, -302.30707), Q(548.13043, -209.22832, 512.7287, -138.82259), Q(477.32697, -68.41686, 415.6722, -29.832935), Q(354.0175, 8.750994, 277.64517, 8.750994), Q(196.4996, 8.750994, 140.41368, -28.639618), Z, M(166.26889, -488.86237), Q(121.718376, -421.6388, 121.718376, -291.965), Q(121.718376, -172.63326, 165.07558, -103.02306), Q(208.43277, -33.412888, 286.39618, -33.412888), Q(362.7685, -33.412888, 407.71677, -102.6253), Q(452.66507, -171.8377, 452.66507, -301.51154), Q(452.66507, -421.6388, 409.70563, -488.86237), Q(366.74622, -556.0859, 287.98727, -556.0859), Q(210.8194, -556.0859, 166.26889, -488.86237), Z, M(321.40015, -781.2251), L(428.7987, -666.6666), L(396.18137, -643.5958), L(289.57834, -725.537), L(181.38425, -643.5958), L(150.35799, -665.8711), L(257.75656, -781.2251), L(321.40015, -781.2251), Z];
+pub const MERRIWEATHER_REGULAR_212: &'static [PathSegment] = &[M(140.41368, -28.639618), Q(84.32776, -66.03023, 55.688145, -134.04932), Q(27.048527, -202.0684, 27.048527, -291.965), Q(27.048527, -383.45267, 61.65473, -452.66507), Q(96.26093, -521.87744, 156.72235, -559.66583), Q(217.18376, -597.4542, 292.76053, -597.4542), Q(416.86554, -597.4542, 482.498, -519.0931), Q(548.13043, -440.73187, 548.13043, -302.30707), Q(548.13043, -209.22832, 512.7287, -138.82259), Q(477.32697, -68.41686, 415.6722, -29.832935), Q(354.0175, 8.750994, 277.64517, 8.750994), Q(196.4996, 8.750994, 140.41368, -28.639618), Z, M(166.26889, -488.86237), Q(121.718376, -421.6388, 121.718376, -291.965), Q(121.718376, -172.63326, 165.07558, -103.02306), Q(208.43277, -33.412888, 286.39618, -33.412888), Q(362.7685, -33.412888, 407.71677, -102.6253), Q(452.66507, -171.8377, 452.66507, -301.51154), Q(452.66507, -421.6388, 409.70563, -488.86237), Q(366.74622, -556.0859, 287.98727, -556.0859), Q(210.8194, -556.0859, 166.26889, -488.86237), Z, M(321.40015, -781.2251), L(428.7987, -666.6666), L(396.18137, -643.5958), L(289.57834, -725.537), L(181.38425, -643.5958), L(150.35799, -665.8711), L(257.75656, -781.2251), L(321.40015, -781.2251), Z];
 
 // // Õ ------------------------------
-// pub const MERRIWEATHER_REGULAR_213: &'static [PathSegment] = &[M(140.41368, -28.639618), Q(84.32776, -66.03023, 55.688145, -134.04932), Q(27.048527, -202.0684, 27.048527, -291.965), Q(27.048527, -383.45267, 61.65473, -452.66507), Q(96.26093, -521.87744, 156.72235, -559.66583), Q(217.18376, -597.4542, 292.76053, -597.4542), Q(416.86554, -597.4542, 482.498, -519.0931), Q(548.13043, -440.73187, 548.13043, -302.30707), Q(548.13043, -209.22832, 512.7287, -138.82259), Q(477.32697, -68.41686, 415.6722, -29.832935), Q(354.0175, 8.750994, 277.64517, 8.750994), Q(196.4996, 8.750994, 140.41368, -28.639618), Z, M(166.26889, -488.86237), Q(121.718376, -421.6388, 121.718376, -291.965), Q(121.718376, -172.63326, 165.07558, -103.02306), Q(208.43277, -33.412888, 286.39618, -33.412888), Q(362.7685, -33.412888, 407.71677, -102.6253), Q(452.66507, -171.8377, 452.66507, -301.51154), Q(452.66507, -421.6388, 409.70563, -488.86237), Q(366.74622, -556.0859, 287.98727, -556.0859), Q(210.8194, -556.0859, 166.26889, -488.86237), Z, M(311.05807, -656.72235), Q(295.94272, -665.07556, 276.05408, -680.98645), Q(262.52982, -692.9196, 252.58551, -698.8862), Q(242.6412, -704.8528, 230.70802, -704.8528), Q(210.8194, -704.8528, 200.87509, -692.52185), Q(190.93079, -680.1909, 182.97533, -657.91565), L(145.58472, -679.3954), Q(156.72235, -715.1949, 178.9976, -737.47015), Q(201.27287, -759.7454, 235.4813, -759.7454), Q(260.1432, -759.7454, 275.6563, -750.9944), Q(291.16943, -742.2434, 310.2625, -724.7414), Q(323.78677, -712.8082, 332.53778, -707.23944), Q(341.28876, -701.6706, 351.63086, -701.6706), Q(369.13284, -701.6706, 379.8727, -715.1949), Q(390.61255, -728.7192, 398.568, -750.19885), L(435.95862, -728.7192), Q(424.02545, -691.32855, 404.1368, -669.8488), Q(384.2482, -648.36914, 349.24423, -648.36914), Q(326.17343, -648.36914, 311.05807, -656.72235), Z];
+pub const MERRIWEATHER_REGULAR_213: &'static [PathSegment] = &[M(140.41368, -28.639618), Q(84.32776, -66.03023, 55.688145, -134.04932), Q(27.048527, -202.0684, 27.048527, -291.965), Q(27.048527, -383.45267, 61.65473, -452.66507), Q(96.26093, -521.87744, 156.72235, -559.66583), Q(217.18376, -597.4542, 292.76053, -597.4542), Q(416.86554, -597.4542, 482.498, -519.0931), Q(548.13043, -440.73187, 548.13043, -302.30707), Q(548.13043, -209.22832, 512.7287, -138.82259), Q(477.32697, -68.41686, 415.6722, -29.832935), Q(354.0175, 8.750994, 277.64517, 8.750994), Q(196.4996, 8.750994, 140.41368, -28.639618), Z, M(166.26889, -488.86237), Q(121.718376, -421.6388, 121.718376, -291.965), Q(121.718376, -172.63326, 165.07558, -103.02306), Q(208.43277, -33.412888, 286.39618, -33.412888), Q(362.7685, -33.412888, 407.71677, -102.6253), Q(452.66507, -171.8377, 452.66507, -301.51154), Q(452.66507, -421.6388, 409.70563, -488.86237), Q(366.74622, -556.0859, 287.98727, -556.0859), Q(210.8194, -556.0859, 166.26889, -488.86237), Z, M(311.05807, -656.72235), Q(295.94272, -665.07556, 276.05408, -680.98645), Q(262.52982, -692.9196, 252.58551, -698.8862), Q(242.6412, -704.8528, 230.70802, -704.8528), Q(210.8194, -704.8528, 200.87509, -692.52185), Q(190.93079, -680.1909, 182.97533, -657.91565), L(145.58472, -679.3954), Q(156.72235, -715.1949, 178.9976, -737.47015), Q(201.27287, -759.7454, 235.4813, -759.7454), Q(260.1432, -759.7454, 275.6563, -750.9944), Q(291.16943, -742.2434, 310.2625, -724.7414), Q(323.78677, -712.8082, 332.53778, -707.23944), Q(341.28876, -701.6706, 351.63086, -701.6706), Q(369.13284, -701.6706, 379.8727, -715.1949), Q(390.61255, -728.7192, 398.568, -750.19885), L(435.95862, -728.7192), Q(424.02545, -691.32855, 404.1368, -669.8488), Q(384.2482, -648.36914, 349.24423, -648.36914), Q(326.17343, -648.36914, 311.05807, -656.72235), Z];
 
 // // Ö ------------------------------
-// pub const MERRIWEATHER_REGULAR_214: &'static [PathSegment] = &[M(140.41368, -28.639618), Q(84.32776, -66.03023, 55.688145, -134.04932), Q(27.048527, -202.0684, 27.048527, -291.965), Q(27.048527, -383.45267, 61.65473, -452.66507), Q(96.26093, -521.87744, 156.72235, -559.66583), Q(217.18376, -597.4542, 292.76053, -597.4542), Q(416.86554, -597.4542, 482.498, -519.0931), Q(548.13043, -440.73187, 548.13043, -302.30707), Q(548.13043, -209.22832, 512.7287, -138.82259), Q(477.32697, -68.41686, 415.6722, -29.832935), Q(354.0175, 8.750994, 277.64517, 8.750994), Q(196.4996, 8.750994, 140.41368, -28.639618), Z, M(166.26889, -488.86237), Q(121.718376, -421.6388, 121.718376, -291.965), Q(121.718376, -172.63326, 165.07558, -103.02306), Q(208.43277, -33.412888, 286.39618, -33.412888), Q(362.7685, -33.412888, 407.71677, -102.6253), Q(452.66507, -171.8377, 452.66507, -301.51154), Q(452.66507, -421.6388, 409.70563, -488.86237), Q(366.74622, -556.0859, 287.98727, -556.0859), Q(210.8194, -556.0859, 166.26889, -488.86237), Z, M(153.54018, -666.26886), Q(141.607, -680.98645, 141.607, -701.6706), Q(141.607, -727.12805, 154.73349, -743.8345), Q(167.85999, -760.54095, 193.31741, -760.54095), L(194.11296, -760.54095), Q(216.38821, -760.54095, 229.11694, -745.4256), Q(241.84566, -730.31024, 241.84566, -709.6261), Q(241.84566, -684.16864, 227.52585, -667.86), Q(213.20604, -651.5513, 186.15752, -651.5513), L(185.36197, -651.5513), Q(165.47334, -651.5513, 153.54018, -666.26886), Z, M(348.0509, -666.26886), Q(335.71997, -680.98645, 335.71997, -701.6706), Q(335.71997, -726.3325, 349.24423, -743.4367), Q(362.7685, -760.54095, 387.4304, -760.54095), L(388.22592, -760.54095), Q(410.5012, -760.54095, 422.83212, -745.4256), Q(435.1631, -730.31024, 435.1631, -709.6261), Q(435.1631, -684.16864, 421.24103, -667.86), Q(407.319, -651.5513, 380.27048, -651.5513), Q(360.38184, -651.5513, 348.0509, -666.26886), Z];
+pub const MERRIWEATHER_REGULAR_214: &'static [PathSegment] = &[M(140.41368, -28.639618), Q(84.32776, -66.03023, 55.688145, -134.04932), Q(27.048527, -202.0684, 27.048527, -291.965), Q(27.048527, -383.45267, 61.65473, -452.66507), Q(96.26093, -521.87744, 156.72235, -559.66583), Q(217.18376, -597.4542, 292.76053, -597.4542), Q(416.86554, -597.4542, 482.498, -519.0931), Q(548.13043, -440.73187, 548.13043, -302.30707), Q(548.13043, -209.22832, 512.7287, -138.82259), Q(477.32697, -68.41686, 415.6722, -29.832935), Q(354.0175, 8.750994, 277.64517, 8.750994), Q(196.4996, 8.750994, 140.41368, -28.639618), Z, M(166.26889, -488.86237), Q(121.718376, -421.6388, 121.718376, -291.965), Q(121.718376, -172.63326, 165.07558, -103.02306), Q(208.43277, -33.412888, 286.39618, -33.412888), Q(362.7685, -33.412888, 407.71677, -102.6253), Q(452.66507, -171.8377, 452.66507, -301.51154), Q(452.66507, -421.6388, 409.70563, -488.86237), Q(366.74622, -556.0859, 287.98727, -556.0859), Q(210.8194, -556.0859, 166.26889, -488.86237), Z, M(153.54018, -666.26886), Q(141.607, -680.98645, 141.607, -701.6706), Q(141.607, -727.12805, 154.73349, -743.8345), Q(167.85999, -760.54095, 193.31741, -760.54095), L(194.11296, -760.54095), Q(216.38821, -760.54095, 229.11694, -745.4256), Q(241.84566, -730.31024, 241.84566, -709.6261), Q(241.84566, -684.16864, 227.52585, -667.86), Q(213.20604, -651.5513, 186.15752, -651.5513), L(185.36197, -651.5513), Q(165.47334, -651.5513, 153.54018, -666.26886), Z, M(348.0509, -666.26886), Q(335.71997, -680.98645, 335.71997, -701.6706), Q(335.71997, -726.3325, 349.24423, -743.4367), Q(362.7685, -760.54095, 387.4304, -760.54095), L(388.22592, -760.54095), Q(410.5012, -760.54095, 422.83212, -745.4256), Q(435.1631, -730.31024, 435.1631, -709.6261), Q(435.1631, -684.16864, 421.24103, -667.86), Q(407.319, -651.5513, 380.27048, -651.5513), Q(360.38184, -651.5513, 348.0509, -666.26886), Z];
 
 // // × ------------------------------
-// pub const MERRIWEATHER_REGULAR_215: &'static [PathSegment] = &[M(66.825775, -369.13284), L(113.762924, -417.66107), L(242.6412, -280.0318), L(370.72394, -417.66107), L(417.66107, -369.13284), L(283.214, -239.45901), L(417.66107, -109.7852), L(370.72394, -61.256958), L(242.6412, -198.88623), L(113.762924, -61.256958), L(66.825775, -109.7852), L(202.0684, -239.45901), L(66.825775, -369.13284), Z];
+pub const MERRIWEATHER_REGULAR_215: &'static [PathSegment] = &[M(66.825775, -369.13284), L(113.762924, -417.66107), L(242.6412, -280.0318), L(370.72394, -417.66107), L(417.66107, -369.13284), L(283.214, -239.45901), L(417.66107, -109.7852), L(370.72394, -61.256958), L(242.6412, -198.88623), L(113.762924, -61.256958), L(66.825775, -109.7852), L(202.0684, -239.45901), L(66.825775, -369.13284), Z];
 
 // // Ø ------------------------------
-// pub const MERRIWEATHER_REGULAR_216: &'static [PathSegment] = &[M(60.859188, -122.91169), Q(27.048527, -194.11296, 27.048527, -291.965), Q(27.048527, -383.45267, 61.65473, -452.66507), Q(96.26093, -521.87744, 156.72235, -559.66583), Q(217.18376, -597.4542, 292.76053, -597.4542), Q(339.6977, -597.4542, 380.27048, -585.52106), L(412.09225, -667.4622), L(449.48288, -654.73346), L(416.07, -571.20123), Q(481.3047, -538.5839, 514.7176, -469.3715), Q(548.13043, -400.1591, 548.13043, -302.30707), Q(548.13043, -209.22832, 512.7287, -138.82259), Q(477.32697, -68.41686, 415.27444, -29.832935), Q(353.22195, 8.750994, 275.25854, 8.750994), Q(237.07239, 8.750994, 195.70406, -3.1821797), L(159.90453, 82.73667), L(120.92283, 70.00795), L(159.10898, -18.297533), Q(94.669846, -51.71042, 60.859188, -122.91169), Z, M(166.66666, -489.26013), Q(121.718376, -422.43436, 121.718376, -291.965), Q(121.718376, -221.95703, 138.82259, -167.4622), Q(155.9268, -112.96738, 188.54414, -81.941124), L(360.38184, -536.19727), Q(329.3556, -556.0859, 288.7828, -556.0859), Q(211.61494, -556.0859, 166.66666, -489.26013), Z, M(436.35638, -424.02545), Q(419.25217, -477.32697, 386.63483, -509.14874), L(214.79713, -54.8926), Q(245.82338, -33.412888, 287.1917, -33.412888), Q(337.31104, -33.412888, 374.70166, -63.643593), Q(412.09225, -93.8743, 432.77643, -154.33571), Q(453.4606, -214.79713, 453.4606, -301.51154), Q(453.4606, -370.72394, 436.35638, -424.02545), Z];
+pub const MERRIWEATHER_REGULAR_216: &'static [PathSegment] = &[M(60.859188, -122.91169), Q(27.048527, -194.11296, 27.048527, -291.965), Q(27.048527, -383.45267, 61.65473, -452.66507), Q(96.26093, -521.87744, 156.72235, -559.66583), Q(217.18376, -597.4542, 292.76053, -597.4542), Q(339.6977, -597.4542, 380.27048, -585.52106), L(412.09225, -667.4622), L(449.48288, -654.73346), L(416.07, -571.20123), Q(481.3047, -538.5839, 514.7176, -469.3715), Q(548.13043, -400.1591, 548.13043, -302.30707), Q(548.13043, -209.22832, 512.7287, -138.82259), Q(477.32697, -68.41686, 415.27444, -29.832935), Q(353.22195, 8.750994, 275.25854, 8.750994), Q(237.07239, 8.750994, 195.70406, -3.1821797), L(159.90453, 82.73667), L(120.92283, 70.00795), L(159.10898, -18.297533), Q(94.669846, -51.71042, 60.859188, -122.91169), Z, M(166.66666, -489.26013), Q(121.718376, -422.43436, 121.718376, -291.965), Q(121.718376, -221.95703, 138.82259, -167.4622), Q(155.9268, -112.96738, 188.54414, -81.941124), L(360.38184, -536.19727), Q(329.3556, -556.0859, 288.7828, -556.0859), Q(211.61494, -556.0859, 166.66666, -489.26013), Z, M(436.35638, -424.02545), Q(419.25217, -477.32697, 386.63483, -509.14874), L(214.79713, -54.8926), Q(245.82338, -33.412888, 287.1917, -33.412888), Q(337.31104, -33.412888, 374.70166, -63.643593), Q(412.09225, -93.8743, 432.77643, -154.33571), Q(453.4606, -214.79713, 453.4606, -301.51154), Q(453.4606, -370.72394, 436.35638, -424.02545), Z];
 
 // // Ù ------------------------------
-// pub const MERRIWEATHER_REGULAR_217: &'static [PathSegment] = &[M(130.86714, -56.08592), Q(75.57677, -118.536194, 75.57677, -249.8011), L(75.57677, -541.7661), L(19.093079, -551.3126), L(19.093079, -591.0899), L(231.50357, -591.0899), L(231.50357, -551.3126), L(163.88225, -540.9705), L(163.88225, -241.05011), Q(163.88225, -132.856, 202.46619, -85.12331), Q(241.05011, -37.39061, 313.4447, -37.39061), Q(385.8393, -37.39061, 422.0366, -88.30549), Q(458.2339, -139.22037, 458.2339, -236.27684), L(458.2339, -540.9705), L(391.4081, -551.3126), L(391.4081, -591.0899), L(576.7701, -591.0899), L(576.7701, -551.3126), L(518.6953, -541.7661), L(518.6953, -240.25456), Q(518.6953, -116.14956, 459.82495, -54.8926), Q(400.95465, 6.3643594, 303.10263, 6.3643594), Q(186.15752, 6.3643594, 130.86714, -56.08592), Z, M(259.74542, -681.3842), Q(218.77486, -712.8082, 198.09068, -739.85675), L(261.73428, -780.42957), L(369.13284, -659.5067), L(330.9467, -635.6404), Q(300.71597, -649.9602, 259.74542, -681.3842), Z];
+pub const MERRIWEATHER_REGULAR_217: &'static [PathSegment] = &[M(130.86714, -56.08592), Q(75.57677, -118.536194, 75.57677, -249.8011), L(75.57677, -541.7661), L(19.093079, -551.3126), L(19.093079, -591.0899), L(231.50357, -591.0899), L(231.50357, -551.3126), L(163.88225, -540.9705), L(163.88225, -241.05011), Q(163.88225, -132.856, 202.46619, -85.12331), Q(241.05011, -37.39061, 313.4447, -37.39061), Q(385.8393, -37.39061, 422.0366, -88.30549), Q(458.2339, -139.22037, 458.2339, -236.27684), L(458.2339, -540.9705), L(391.4081, -551.3126), L(391.4081, -591.0899), L(576.7701, -591.0899), L(576.7701, -551.3126), L(518.6953, -541.7661), L(518.6953, -240.25456), Q(518.6953, -116.14956, 459.82495, -54.8926), Q(400.95465, 6.3643594, 303.10263, 6.3643594), Q(186.15752, 6.3643594, 130.86714, -56.08592), Z, M(259.74542, -681.3842), Q(218.77486, -712.8082, 198.09068, -739.85675), L(261.73428, -780.42957), L(369.13284, -659.5067), L(330.9467, -635.6404), Q(300.71597, -649.9602, 259.74542, -681.3842), Z];
 
 // // Ú ------------------------------
-// pub const MERRIWEATHER_REGULAR_218: &'static [PathSegment] = &[M(130.86714, -56.08592), Q(75.57677, -118.536194, 75.57677, -249.8011), L(75.57677, -541.7661), L(19.093079, -551.3126), L(19.093079, -591.0899), L(231.50357, -591.0899), L(231.50357, -551.3126), L(163.88225, -540.9705), L(163.88225, -241.05011), Q(163.88225, -132.856, 202.46619, -85.12331), Q(241.05011, -37.39061, 313.4447, -37.39061), Q(385.8393, -37.39061, 422.0366, -88.30549), Q(458.2339, -139.22037, 458.2339, -236.27684), L(458.2339, -540.9705), L(391.4081, -551.3126), L(391.4081, -591.0899), L(576.7701, -591.0899), L(576.7701, -551.3126), L(518.6953, -541.7661), L(518.6953, -240.25456), Q(518.6953, -116.14956, 459.82495, -54.8926), Q(400.95465, 6.3643594, 303.10263, 6.3643594), Q(186.15752, 6.3643594, 130.86714, -56.08592), Z, M(251.3922, -659.5067), L(357.9952, -780.42957), L(422.43436, -739.85675), Q(400.1591, -712.0127, 360.77963, -680.98645), Q(321.40015, -649.9602, 291.16943, -635.6404), L(251.3922, -659.5067), Z];
+pub const MERRIWEATHER_REGULAR_218: &'static [PathSegment] = &[M(130.86714, -56.08592), Q(75.57677, -118.536194, 75.57677, -249.8011), L(75.57677, -541.7661), L(19.093079, -551.3126), L(19.093079, -591.0899), L(231.50357, -591.0899), L(231.50357, -551.3126), L(163.88225, -540.9705), L(163.88225, -241.05011), Q(163.88225, -132.856, 202.46619, -85.12331), Q(241.05011, -37.39061, 313.4447, -37.39061), Q(385.8393, -37.39061, 422.0366, -88.30549), Q(458.2339, -139.22037, 458.2339, -236.27684), L(458.2339, -540.9705), L(391.4081, -551.3126), L(391.4081, -591.0899), L(576.7701, -591.0899), L(576.7701, -551.3126), L(518.6953, -541.7661), L(518.6953, -240.25456), Q(518.6953, -116.14956, 459.82495, -54.8926), Q(400.95465, 6.3643594, 303.10263, 6.3643594), Q(186.15752, 6.3643594, 130.86714, -56.08592), Z, M(251.3922, -659.5067), L(357.9952, -780.42957), L(422.43436, -739.85675), Q(400.1591, -712.0127, 360.77963, -680.98645), Q(321.40015, -649.9602, 291.16943, -635.6404), L(251.3922, -659.5067), Z];
 
 // // Û ------------------------------
-// pub const MERRIWEATHER_REGULAR_219: &'static [PathSegment] = &[M(130.86714, -56.08592), Q(75.57677, -118.536194, 75.57677, -249.8011), L(75.57677, -541.7661), L(19.093079, -551.3126), L(19.093079, -591.0899), L(231.50357, -591.0899), L(231.50357, -551.3126), L(163.88225, -540.9705), L(163.88225, -241.05011), Q(163.88225, -132.856, 202.46619, -85.12331), Q(241.05011, -37.39061, 313.4447, -37.39061), Q(385.8393, -37.39061, 422.0366, -88.30549), Q(458.2339, -139.22037, 458.2339, -236.27684), L(458.2339, -540.9705), L(391.4081, -551.3126), L(391.4081, -591.0899), L(576.7701, -591.0899), L(576.7701, -551.3126), L(518.6953, -541.7661), L(518.6953, -240.25456), Q(518.6953, -116.14956, 459.82495, -54.8926), Q(400.95465, 6.3643594, 303.10263, 6.3643594), Q(186.15752, 6.3643594, 130.86714, -56.08592), Z, M(343.6754, -781.2251), L(451.07397, -666.6666), L(418.45663, -643.5958), L(311.8536, -725.537), L(203.6595, -643.5958), L(172.63326, -665.8711), L(280.0318, -781.2251), L(343.6754, -781.2251), Z];
+pub const MERRIWEATHER_REGULAR_219: &'static [PathSegment] = &[M(130.86714, -56.08592), Q(75.57677, -118.536194, 75.57677, -249.8011), L(75.57677, -541.7661), L(19.093079, -551.3126), L(19.093079, -591.0899), L(231.50357, -591.0899), L(231.50357, -551.3126), L(163.88225, -540.9705), L(163.88225, -241.05011), Q(163.88225, -132.856, 202.46619, -85.12331), Q(241.05011, -37.39061, 313.4447, -37.39061), Q(385.8393, -37.39061, 422.0366, -88.30549), Q(458.2339, -139.22037, 458.2339, -236.27684), L(458.2339, -540.9705), L(391.4081, -551.3126), L(391.4081, -591.0899), L(576.7701, -591.0899), L(576.7701, -551.3126), L(518.6953, -541.7661), L(518.6953, -240.25456), Q(518.6953, -116.14956, 459.82495, -54.8926), Q(400.95465, 6.3643594, 303.10263, 6.3643594), Q(186.15752, 6.3643594, 130.86714, -56.08592), Z, M(343.6754, -781.2251), L(451.07397, -666.6666), L(418.45663, -643.5958), L(311.8536, -725.537), L(203.6595, -643.5958), L(172.63326, -665.8711), L(280.0318, -781.2251), L(343.6754, -781.2251), Z];
 
 // // Ü ------------------------------
-// pub const MERRIWEATHER_REGULAR_220: &'static [PathSegment] = &[M(130.86714, -56.08592), Q(75.57677, -118.536194, 75.57677, -249.8011), L(75.57677, -541.7661), L(19.093079, -551.3126), L(19.093079, -591.0899), L(231.50357, -591.0899), L(231.50357, -551.3126), L(163.88225, -540.9705), L(163.88225, -241.05011), Q(163.88225, -132.856, 202.46619, -85.12331), Q(241.05011, -37.39061, 313.4447, -37.39061), Q(385.8393, -37.39061, 422.0366, -88.30549), Q(458.2339, -139.22037, 458.2339, -236.27684), L(458.2339, -540.9705), L(391.4081, -551.3126), L(391.4081, -591.0899), L(576.7701, -591.0899), L(576.7701, -551.3126), L(518.6953, -541.7661), L(518.6953, -240.25456), Q(518.6953, -116.14956, 459.82495, -54.8926), Q(400.95465, 6.3643594, 303.10263, 6.3643594), Q(186.15752, 6.3643594, 130.86714, -56.08592), Z, M(175.81543, -666.26886), Q(163.88225, -680.98645, 163.88225, -701.6706), Q(163.88225, -727.12805, 177.00874, -743.8345), Q(190.13524, -760.54095, 215.59267, -760.54095), L(216.38821, -760.54095), Q(238.66348, -760.54095, 251.3922, -745.4256), Q(264.1209, -730.31024, 264.1209, -709.6261), Q(264.1209, -684.16864, 249.8011, -667.86), Q(235.4813, -651.5513, 208.43277, -651.5513), L(207.63722, -651.5513), Q(187.7486, -651.5513, 175.81543, -666.26886), Z, M(370.32617, -666.26886), Q(357.9952, -680.98645, 357.9952, -701.6706), Q(357.9952, -726.3325, 371.51947, -743.4367), Q(385.04373, -760.54095, 409.70563, -760.54095), L(410.5012, -760.54095), Q(432.77643, -760.54095, 445.1074, -745.4256), Q(457.43832, -730.31024, 457.43832, -709.6261), Q(457.43832, -684.16864, 443.5163, -667.86), Q(429.59427, -651.5513, 402.54575, -651.5513), Q(382.6571, -651.5513, 370.32617, -666.26886), Z];
+pub const MERRIWEATHER_REGULAR_220: &'static [PathSegment] = &[M(130.86714, -56.08592), Q(75.57677, -118.536194, 75.57677, -249.8011), L(75.57677, -541.7661), L(19.093079, -551.3126), L(19.093079, -591.0899), L(231.50357, -591.0899), L(231.50357, -551.3126), L(163.88225, -540.9705), L(163.88225, -241.05011), Q(163.88225, -132.856, 202.46619, -85.12331), Q(241.05011, -37.39061, 313.4447, -37.39061), Q(385.8393, -37.39061, 422.0366, -88.30549), Q(458.2339, -139.22037, 458.2339, -236.27684), L(458.2339, -540.9705), L(391.4081, -551.3126), L(391.4081, -591.0899), L(576.7701, -591.0899), L(576.7701, -551.3126), L(518.6953, -541.7661), L(518.6953, -240.25456), Q(518.6953, -116.14956, 459.82495, -54.8926), Q(400.95465, 6.3643594, 303.10263, 6.3643594), Q(186.15752, 6.3643594, 130.86714, -56.08592), Z, M(175.81543, -666.26886), Q(163.88225, -680.98645, 163.88225, -701.6706), Q(163.88225, -727.12805, 177.00874, -743.8345), Q(190.13524, -760.54095, 215.59267, -760.54095), L(216.38821, -760.54095), Q(238.66348, -760.54095, 251.3922, -745.4256), Q(264.1209, -730.31024, 264.1209, -709.6261), Q(264.1209, -684.16864, 249.8011, -667.86), Q(235.4813, -651.5513, 208.43277, -651.5513), L(207.63722, -651.5513), Q(187.7486, -651.5513, 175.81543, -666.26886), Z, M(370.32617, -666.26886), Q(357.9952, -680.98645, 357.9952, -701.6706), Q(357.9952, -726.3325, 371.51947, -743.4367), Q(385.04373, -760.54095, 409.70563, -760.54095), L(410.5012, -760.54095), Q(432.77643, -760.54095, 445.1074, -745.4256), Q(457.43832, -730.31024, 457.43832, -709.6261), Q(457.43832, -684.16864, 443.5163, -667.86), Q(429.59427, -651.5513, 402.54575, -651.5513), Q(382.6571, -651.5513, 370.32617, -666.26886), Z];
 
 // // Ý ------------------------------
-// pub const MERRIWEATHER_REGULAR_221: &'static [PathSegment] = &[M(217.18376, -229.91248), L(38.186157, -540.9705), L(-9.546539, -551.3126), L(-9.546539, -591.0899), L(198.09068, -591.0899), L(198.09068, -551.3126), L(135.24263, -541.7661), L(247.41447, -328.56006), L(272.87192, -268.09863), L(301.51154, -328.56006), L(407.319, -541.7661), L(342.08432, -551.3126), L(342.08432, -591.0899), L(523.46857, -591.0899), L(523.46857, -551.3126), L(471.75815, -541.7661), L(305.48926, -230.70802), L(305.48926, -47.732697), L(394.59027, -38.9817), L(394.59027, -0.0), L(130.46936, -0.0), L(130.46936, -38.9817), L(217.18376, -46.93715), L(217.18376, -229.91248), Z, M(202.0684, -659.5067), L(308.67142, -780.42957), L(373.11057, -739.85675), Q(350.8353, -712.0127, 311.45584, -680.98645), Q(272.07635, -649.9602, 241.84566, -635.6404), L(202.0684, -659.5067), Z];
+pub const MERRIWEATHER_REGULAR_221: &'static [PathSegment] = &[M(217.18376, -229.91248), L(38.186157, -540.9705), L(-9.546539, -551.3126), L(-9.546539, -591.0899), L(198.09068, -591.0899), L(198.09068, -551.3126), L(135.24263, -541.7661), L(247.41447, -328.56006), L(272.87192, -268.09863), L(301.51154, -328.56006), L(407.319, -541.7661), L(342.08432, -551.3126), L(342.08432, -591.0899), L(523.46857, -591.0899), L(523.46857, -551.3126), L(471.75815, -541.7661), L(305.48926, -230.70802), L(305.48926, -47.732697), L(394.59027, -38.9817), L(394.59027, -0.0), L(130.46936, -0.0), L(130.46936, -38.9817), L(217.18376, -46.93715), L(217.18376, -229.91248), Z, M(202.0684, -659.5067), L(308.67142, -780.42957), L(373.11057, -739.85675), Q(350.8353, -712.0127, 311.45584, -680.98645), Q(272.07635, -649.9602, 241.84566, -635.6404), L(202.0684, -659.5067), Z];
 
 // // Þ ------------------------------
-// pub const MERRIWEATHER_REGULAR_222: &'static [PathSegment] = &[M(101.82975, -541.7661), L(36.595066, -552.10815), L(36.595066, -591.88544), L(256.16547, -591.88544), L(256.961, -552.10815), L(186.95306, -541.7661), L(186.95306, -454.25616), L(213.20604, -456.6428), Q(221.16148, -457.43832, 251.78996, -459.82495), Q(282.41846, -462.2116, 310.2625, -462.2116), Q(396.97693, -462.2116, 443.91406, -422.83212), Q(490.85123, -383.45267, 490.85123, -305.48926), Q(490.85123, -217.18376, 436.75415, -173.03102), Q(382.6571, -128.87828, 284.80508, -128.87828), Q(225.1392, -128.87828, 186.95306, -132.856), L(186.95306, -46.93715), L(272.07635, -38.186157), L(272.07635, -0.0), L(35.799522, -0.0), L(35.799522, -38.186157), L(101.82975, -46.93715), L(101.82975, -541.7661), Z, M(364.75735, -201.67064), Q(398.568, -233.89021, 398.568, -300.71597), Q(398.568, -356.4041, 368.73508, -387.4304), Q(338.90213, -418.45663, 276.05408, -418.45663), Q(237.07239, -418.45663, 186.95306, -411.29672), L(186.95306, -172.63326), Q(214.00159, -169.45107, 241.84566, -169.45107), Q(269.68973, -169.45107, 275.25854, -169.45107), Q(330.9467, -169.45107, 364.75735, -201.67064), Z];
+pub const MERRIWEATHER_REGULAR_222: &'static [PathSegment] = &[M(101.82975, -541.7661), L(36.595066, -552.10815), L(36.595066, -591.88544), L(256.16547, -591.88544), L(256.961, -552.10815), L(186.95306, -541.7661), L(186.95306, -454.25616), L(213.20604, -456.6428), Q(221.16148, -457.43832, 251.78996, -459.82495), Q(282.41846, -462.2116, 310.2625, -462.2116), Q(396.97693, -462.2116, 443.91406, -422.83212), Q(490.85123, -383.45267, 490.85123, -305.48926), Q(490.85123, -217.18376, 436.75415, -173.03102), Q(382.6571, -128.87828, 284.80508, -128.87828), Q(225.1392, -128.87828, 186.95306, -132.856), L(186.95306, -46.93715), L(272.07635, -38.186157), L(272.07635, -0.0), L(35.799522, -0.0), L(35.799522, -38.186157), L(101.82975, -46.93715), L(101.82975, -541.7661), Z, M(364.75735, -201.67064), Q(398.568, -233.89021, 398.568, -300.71597), Q(398.568, -356.4041, 368.73508, -387.4304), Q(338.90213, -418.45663, 276.05408, -418.45663), Q(237.07239, -418.45663, 186.95306, -411.29672), L(186.95306, -172.63326), Q(214.00159, -169.45107, 241.84566, -169.45107), Q(269.68973, -169.45107, 275.25854, -169.45107), Q(330.9467, -169.45107, 364.75735, -201.67064), Z];
 
 // // ß ------------------------------
-// pub const MERRIWEATHER_REGULAR_223: &'static [PathSegment] = &[M(72.394585, -370.72394), Q(72.394585, -448.68735, 102.6253, -505.96658), Q(132.856, -563.2458, 184.16866, -593.4765), Q(235.4813, -623.7072, 298.32935, -623.7072), Q(361.1774, -623.7072, 398.9658, -597.05646), Q(436.75415, -570.4057, 436.75415, -517.8997), Q(436.75415, -484.48685, 423.6277, -463.00714), Q(410.5012, -441.52744, 385.04373, -415.27444), Q(365.95065, -396.18137, 356.8019, -382.6571), Q(347.65314, -369.13284, 347.65314, -351.63086), Q(347.65314, -331.74222, 361.1774, -315.0358), Q(374.70166, -298.32935, 403.34128, -274.463), Q(430.3898, -250.59665, 447.09625, -232.69688), Q(463.8027, -214.79713, 476.13364, -188.94193), Q(488.46457, -163.08672, 488.46457, -131.26491), Q(488.46457, -63.643593, 439.1408, -27.4463), Q(389.81702, 8.750994, 307.08035, 8.750994), Q(292.76053, 8.750994, 272.47412, 5.966587), Q(252.18774, 3.1821797, 240.25456, -0.0), L(240.25456, -56.48369), Q(274.463, -42.959427, 312.64914, -42.959427), Q(355.60858, -42.959427, 377.48605, -62.84805), Q(399.36356, -82.73667, 399.36356, -115.35401), Q(399.36356, -147.97136, 383.45267, -170.64438), Q(367.54175, -193.31741, 335.71997, -222.75258), Q(304.6937, -251.3922, 288.7828, -274.463), Q(272.87192, -297.5338, 272.87192, -328.56006), Q(272.87192, -347.65314, 281.6229, -362.7685), Q(290.3739, -377.88385, 309.46698, -400.95465), Q(331.74222, -427.2076, 343.6754, -448.68735), Q(355.60858, -470.16705, 355.60858, -499.6022), Q(355.60858, -577.5656, 280.0318, -577.5656), Q(221.95703, -577.5656, 189.33969, -528.2418), Q(156.72235, -478.91803, 156.72235, -383.45267), L(156.72235, -0.0), L(19.888622, -0.0), L(19.888622, -38.9817), L(72.394585, -46.141605), L(72.394585, -370.72394), Z];
+pub const MERRIWEATHER_REGULAR_223: &'static [PathSegment] = &[M(72.394585, -370.72394), Q(72.394585, -448.68735, 102.6253, -505.96658), Q(132.856, -563.2458, 184.16866, -593.4765), Q(235.4813, -623.7072, 298.32935, -623.7072), Q(361.1774, -623.7072, 398.9658, -597.05646), Q(436.75415, -570.4057, 436.75415, -517.8997), Q(436.75415, -484.48685, 423.6277, -463.00714), Q(410.5012, -441.52744, 385.04373, -415.27444), Q(365.95065, -396.18137, 356.8019, -382.6571), Q(347.65314, -369.13284, 347.65314, -351.63086), Q(347.65314, -331.74222, 361.1774, -315.0358), Q(374.70166, -298.32935, 403.34128, -274.463), Q(430.3898, -250.59665, 447.09625, -232.69688), Q(463.8027, -214.79713, 476.13364, -188.94193), Q(488.46457, -163.08672, 488.46457, -131.26491), Q(488.46457, -63.643593, 439.1408, -27.4463), Q(389.81702, 8.750994, 307.08035, 8.750994), Q(292.76053, 8.750994, 272.47412, 5.966587), Q(252.18774, 3.1821797, 240.25456, -0.0), L(240.25456, -56.48369), Q(274.463, -42.959427, 312.64914, -42.959427), Q(355.60858, -42.959427, 377.48605, -62.84805), Q(399.36356, -82.73667, 399.36356, -115.35401), Q(399.36356, -147.97136, 383.45267, -170.64438), Q(367.54175, -193.31741, 335.71997, -222.75258), Q(304.6937, -251.3922, 288.7828, -274.463), Q(272.87192, -297.5338, 272.87192, -328.56006), Q(272.87192, -347.65314, 281.6229, -362.7685), Q(290.3739, -377.88385, 309.46698, -400.95465), Q(331.74222, -427.2076, 343.6754, -448.68735), Q(355.60858, -470.16705, 355.60858, -499.6022), Q(355.60858, -577.5656, 280.0318, -577.5656), Q(221.95703, -577.5656, 189.33969, -528.2418), Q(156.72235, -478.91803, 156.72235, -383.45267), L(156.72235, -0.0), L(19.888622, -0.0), L(19.888622, -38.9817), L(72.394585, -46.141605), L(72.394585, -370.72394), Z];
 
 // // à ------------------------------
-// pub const MERRIWEATHER_REGULAR_224: &'static [PathSegment] = &[M(110.58074, -229.51471), Q(185.36197, -264.91647, 299.92044, -267.3031), L(299.92044, -289.57834), Q(299.92044, -326.96896, 291.965, -348.44867), Q(284.00955, -369.92838, 264.51868, -379.8727), Q(245.02783, -389.81702, 209.22832, -389.81702), Q(168.65552, -389.81702, 136.43596, -378.28162), Q(104.216385, -366.74622, 72.394585, -350.03976), L(52.505966, -391.4081), Q(62.84805, -400.1591, 90.69212, -414.4789), Q(118.536194, -428.7987, 155.13126, -439.93634), Q(191.72633, -451.07397, 228.3214, -451.07397), Q(284.80508, -451.07397, 317.8202, -435.56085), Q(350.8353, -420.04773, 365.15512, -385.8393), Q(379.4749, -351.63086, 379.4749, -294.35162), L(379.4749, -38.9817), L(426.41208, -38.9817), L(426.41208, -3.9777246), Q(410.5012, -0.0, 386.23706, 3.9777246), Q(361.97293, 7.955449, 343.6754, 7.955449), Q(321.40015, 7.955449, 313.4447, 1.1933174), Q(305.48926, -5.5688143, 305.48926, -28.639618), L(305.48926, -54.8926), Q(281.6229, -31.026253, 247.0167, -11.137629), Q(212.41049, 8.750994, 168.65552, 8.750994), Q(111.37629, 8.750994, 73.587906, -24.26412), Q(35.799522, -57.279236, 35.799522, -119.33174), Q(35.799522, -194.11296, 110.58074, -229.51471), Z, M(250.59665, -58.07478), Q(277.64517, -70.8035, 299.92044, -88.30549), L(299.92044, -225.1392), Q(213.20604, -225.1392, 169.0533, -198.48846), Q(124.90055, -171.8377, 124.90055, -128.87828), Q(124.90055, -85.91885, 145.9825, -65.632454), Q(167.06444, -45.34606, 202.86395, -45.34606), Q(223.54813, -45.34606, 250.59665, -58.07478), Z, M(190.93079, -551.3126), Q(157.5179, -576.7701, 126.491646, -606.20526), Q(95.46539, -635.6404, 85.12331, -653.1424), L(142.40254, -703.2617), L(270.48526, -545.74384), L(241.05011, -519.49084), L(240.25456, -519.49084), Q(224.34367, -525.85516, 190.93079, -551.3126), Z];
+pub const MERRIWEATHER_REGULAR_224: &'static [PathSegment] = &[M(110.58074, -229.51471), Q(185.36197, -264.91647, 299.92044, -267.3031), L(299.92044, -289.57834), Q(299.92044, -326.96896, 291.965, -348.44867), Q(284.00955, -369.92838, 264.51868, -379.8727), Q(245.02783, -389.81702, 209.22832, -389.81702), Q(168.65552, -389.81702, 136.43596, -378.28162), Q(104.216385, -366.74622, 72.394585, -350.03976), L(52.505966, -391.4081), Q(62.84805, -400.1591, 90.69212, -414.4789), Q(118.536194, -428.7987, 155.13126, -439.93634), Q(191.72633, -451.07397, 228.3214, -451.07397), Q(284.80508, -451.07397, 317.8202, -435.56085), Q(350.8353, -420.04773, 365.15512, -385.8393), Q(379.4749, -351.63086, 379.4749, -294.35162), L(379.4749, -38.9817), L(426.41208, -38.9817), L(426.41208, -3.9777246), Q(410.5012, -0.0, 386.23706, 3.9777246), Q(361.97293, 7.955449, 343.6754, 7.955449), Q(321.40015, 7.955449, 313.4447, 1.1933174), Q(305.48926, -5.5688143, 305.48926, -28.639618), L(305.48926, -54.8926), Q(281.6229, -31.026253, 247.0167, -11.137629), Q(212.41049, 8.750994, 168.65552, 8.750994), Q(111.37629, 8.750994, 73.587906, -24.26412), Q(35.799522, -57.279236, 35.799522, -119.33174), Q(35.799522, -194.11296, 110.58074, -229.51471), Z, M(250.59665, -58.07478), Q(277.64517, -70.8035, 299.92044, -88.30549), L(299.92044, -225.1392), Q(213.20604, -225.1392, 169.0533, -198.48846), Q(124.90055, -171.8377, 124.90055, -128.87828), Q(124.90055, -85.91885, 145.9825, -65.632454), Q(167.06444, -45.34606, 202.86395, -45.34606), Q(223.54813, -45.34606, 250.59665, -58.07478), Z, M(190.93079, -551.3126), Q(157.5179, -576.7701, 126.491646, -606.20526), Q(95.46539, -635.6404, 85.12331, -653.1424), L(142.40254, -703.2617), L(270.48526, -545.74384), L(241.05011, -519.49084), L(240.25456, -519.49084), Q(224.34367, -525.85516, 190.93079, -551.3126), Z];
 
 // // á ------------------------------
-// pub const MERRIWEATHER_REGULAR_225: &'static [PathSegment] = &[M(110.58074, -229.51471), Q(185.36197, -264.91647, 299.92044, -267.3031), L(299.92044, -289.57834), Q(299.92044, -326.96896, 291.965, -348.44867), Q(284.00955, -369.92838, 264.51868, -379.8727), Q(245.02783, -389.81702, 209.22832, -389.81702), Q(168.65552, -389.81702, 136.43596, -378.28162), Q(104.216385, -366.74622, 72.394585, -350.03976), L(52.505966, -391.4081), Q(62.84805, -400.1591, 90.69212, -414.4789), Q(118.536194, -428.7987, 155.13126, -439.93634), Q(191.72633, -451.07397, 228.3214, -451.07397), Q(284.80508, -451.07397, 317.8202, -435.56085), Q(350.8353, -420.04773, 365.15512, -385.8393), Q(379.4749, -351.63086, 379.4749, -294.35162), L(379.4749, -38.9817), L(426.41208, -38.9817), L(426.41208, -3.9777246), Q(410.5012, -0.0, 386.23706, 3.9777246), Q(361.97293, 7.955449, 343.6754, 7.955449), Q(321.40015, 7.955449, 313.4447, 1.1933174), Q(305.48926, -5.5688143, 305.48926, -28.639618), L(305.48926, -54.8926), Q(281.6229, -31.026253, 247.0167, -11.137629), Q(212.41049, 8.750994, 168.65552, 8.750994), Q(111.37629, 8.750994, 73.587906, -24.26412), Q(35.799522, -57.279236, 35.799522, -119.33174), Q(35.799522, -194.11296, 110.58074, -229.51471), Z, M(250.59665, -58.07478), Q(277.64517, -70.8035, 299.92044, -88.30549), L(299.92044, -225.1392), Q(213.20604, -225.1392, 169.0533, -198.48846), Q(124.90055, -171.8377, 124.90055, -128.87828), Q(124.90055, -85.91885, 145.9825, -65.632454), Q(167.06444, -45.34606, 202.86395, -45.34606), Q(223.54813, -45.34606, 250.59665, -58.07478), Z, M(155.13126, -545.74384), L(282.41846, -704.05725), L(342.87985, -653.1424), Q(326.17343, -626.0939, 271.28082, -579.1567), Q(216.38821, -532.21954, 186.15752, -519.49084), L(155.13126, -545.74384), Z];
+pub const MERRIWEATHER_REGULAR_225: &'static [PathSegment] = &[M(110.58074, -229.51471), Q(185.36197, -264.91647, 299.92044, -267.3031), L(299.92044, -289.57834), Q(299.92044, -326.96896, 291.965, -348.44867), Q(284.00955, -369.92838, 264.51868, -379.8727), Q(245.02783, -389.81702, 209.22832, -389.81702), Q(168.65552, -389.81702, 136.43596, -378.28162), Q(104.216385, -366.74622, 72.394585, -350.03976), L(52.505966, -391.4081), Q(62.84805, -400.1591, 90.69212, -414.4789), Q(118.536194, -428.7987, 155.13126, -439.93634), Q(191.72633, -451.07397, 228.3214, -451.07397), Q(284.80508, -451.07397, 317.8202, -435.56085), Q(350.8353, -420.04773, 365.15512, -385.8393), Q(379.4749, -351.63086, 379.4749, -294.35162), L(379.4749, -38.9817), L(426.41208, -38.9817), L(426.41208, -3.9777246), Q(410.5012, -0.0, 386.23706, 3.9777246), Q(361.97293, 7.955449, 343.6754, 7.955449), Q(321.40015, 7.955449, 313.4447, 1.1933174), Q(305.48926, -5.5688143, 305.48926, -28.639618), L(305.48926, -54.8926), Q(281.6229, -31.026253, 247.0167, -11.137629), Q(212.41049, 8.750994, 168.65552, 8.750994), Q(111.37629, 8.750994, 73.587906, -24.26412), Q(35.799522, -57.279236, 35.799522, -119.33174), Q(35.799522, -194.11296, 110.58074, -229.51471), Z, M(250.59665, -58.07478), Q(277.64517, -70.8035, 299.92044, -88.30549), L(299.92044, -225.1392), Q(213.20604, -225.1392, 169.0533, -198.48846), Q(124.90055, -171.8377, 124.90055, -128.87828), Q(124.90055, -85.91885, 145.9825, -65.632454), Q(167.06444, -45.34606, 202.86395, -45.34606), Q(223.54813, -45.34606, 250.59665, -58.07478), Z, M(155.13126, -545.74384), L(282.41846, -704.05725), L(342.87985, -653.1424), Q(326.17343, -626.0939, 271.28082, -579.1567), Q(216.38821, -532.21954, 186.15752, -519.49084), L(155.13126, -545.74384), Z];
 
 // // â ------------------------------
-// pub const MERRIWEATHER_REGULAR_226: &'static [PathSegment] = &[M(110.58074, -229.51471), Q(185.36197, -264.91647, 299.92044, -267.3031), L(299.92044, -289.57834), Q(299.92044, -326.96896, 291.965, -348.44867), Q(284.00955, -369.92838, 264.51868, -379.8727), Q(245.02783, -389.81702, 209.22832, -389.81702), Q(168.65552, -389.81702, 136.43596, -378.28162), Q(104.216385, -366.74622, 72.394585, -350.03976), L(52.505966, -391.4081), Q(62.84805, -400.1591, 90.69212, -414.4789), Q(118.536194, -428.7987, 155.13126, -439.93634), Q(191.72633, -451.07397, 228.3214, -451.07397), Q(284.80508, -451.07397, 317.8202, -435.56085), Q(350.8353, -420.04773, 365.15512, -385.8393), Q(379.4749, -351.63086, 379.4749, -294.35162), L(379.4749, -38.9817), L(426.41208, -38.9817), L(426.41208, -3.9777246), Q(410.5012, -0.0, 386.23706, 3.9777246), Q(361.97293, 7.955449, 343.6754, 7.955449), Q(321.40015, 7.955449, 313.4447, 1.1933174), Q(305.48926, -5.5688143, 305.48926, -28.639618), L(305.48926, -54.8926), Q(281.6229, -31.026253, 247.0167, -11.137629), Q(212.41049, 8.750994, 168.65552, 8.750994), Q(111.37629, 8.750994, 73.587906, -24.26412), Q(35.799522, -57.279236, 35.799522, -119.33174), Q(35.799522, -194.11296, 110.58074, -229.51471), Z, M(250.59665, -58.07478), Q(277.64517, -70.8035, 299.92044, -88.30549), L(299.92044, -225.1392), Q(213.20604, -225.1392, 169.0533, -198.48846), Q(124.90055, -171.8377, 124.90055, -128.87828), Q(124.90055, -85.91885, 145.9825, -65.632454), Q(167.06444, -45.34606, 202.86395, -45.34606), Q(223.54813, -45.34606, 250.59665, -58.07478), Z, M(249.00555, -684.16864), L(360.38184, -529.03735), L(328.56006, -506.76212), L(217.18376, -621.32056), L(101.82975, -506.76212), L(71.599045, -528.2418), L(185.36197, -684.16864), L(249.00555, -684.16864), Z];
+pub const MERRIWEATHER_REGULAR_226: &'static [PathSegment] = &[M(110.58074, -229.51471), Q(185.36197, -264.91647, 299.92044, -267.3031), L(299.92044, -289.57834), Q(299.92044, -326.96896, 291.965, -348.44867), Q(284.00955, -369.92838, 264.51868, -379.8727), Q(245.02783, -389.81702, 209.22832, -389.81702), Q(168.65552, -389.81702, 136.43596, -378.28162), Q(104.216385, -366.74622, 72.394585, -350.03976), L(52.505966, -391.4081), Q(62.84805, -400.1591, 90.69212, -414.4789), Q(118.536194, -428.7987, 155.13126, -439.93634), Q(191.72633, -451.07397, 228.3214, -451.07397), Q(284.80508, -451.07397, 317.8202, -435.56085), Q(350.8353, -420.04773, 365.15512, -385.8393), Q(379.4749, -351.63086, 379.4749, -294.35162), L(379.4749, -38.9817), L(426.41208, -38.9817), L(426.41208, -3.9777246), Q(410.5012, -0.0, 386.23706, 3.9777246), Q(361.97293, 7.955449, 343.6754, 7.955449), Q(321.40015, 7.955449, 313.4447, 1.1933174), Q(305.48926, -5.5688143, 305.48926, -28.639618), L(305.48926, -54.8926), Q(281.6229, -31.026253, 247.0167, -11.137629), Q(212.41049, 8.750994, 168.65552, 8.750994), Q(111.37629, 8.750994, 73.587906, -24.26412), Q(35.799522, -57.279236, 35.799522, -119.33174), Q(35.799522, -194.11296, 110.58074, -229.51471), Z, M(250.59665, -58.07478), Q(277.64517, -70.8035, 299.92044, -88.30549), L(299.92044, -225.1392), Q(213.20604, -225.1392, 169.0533, -198.48846), Q(124.90055, -171.8377, 124.90055, -128.87828), Q(124.90055, -85.91885, 145.9825, -65.632454), Q(167.06444, -45.34606, 202.86395, -45.34606), Q(223.54813, -45.34606, 250.59665, -58.07478), Z, M(249.00555, -684.16864), L(360.38184, -529.03735), L(328.56006, -506.76212), L(217.18376, -621.32056), L(101.82975, -506.76212), L(71.599045, -528.2418), L(185.36197, -684.16864), L(249.00555, -684.16864), Z];
 
 // // ã ------------------------------
-// pub const MERRIWEATHER_REGULAR_227: &'static [PathSegment] = &[M(110.58074, -229.51471), Q(185.36197, -264.91647, 299.92044, -267.3031), L(299.92044, -289.57834), Q(299.92044, -326.96896, 291.965, -348.44867), Q(284.00955, -369.92838, 264.51868, -379.8727), Q(245.02783, -389.81702, 209.22832, -389.81702), Q(168.65552, -389.81702, 136.43596, -378.28162), Q(104.216385, -366.74622, 72.394585, -350.03976), L(52.505966, -391.4081), Q(62.84805, -400.1591, 90.69212, -414.4789), Q(118.536194, -428.7987, 155.13126, -439.93634), Q(191.72633, -451.07397, 228.3214, -451.07397), Q(284.80508, -451.07397, 317.8202, -435.56085), Q(350.8353, -420.04773, 365.15512, -385.8393), Q(379.4749, -351.63086, 379.4749, -294.35162), L(379.4749, -38.9817), L(426.41208, -38.9817), L(426.41208, -3.9777246), Q(410.5012, -0.0, 386.23706, 3.9777246), Q(361.97293, 7.955449, 343.6754, 7.955449), Q(321.40015, 7.955449, 313.4447, 1.1933174), Q(305.48926, -5.5688143, 305.48926, -28.639618), L(305.48926, -54.8926), Q(281.6229, -31.026253, 247.0167, -11.137629), Q(212.41049, 8.750994, 168.65552, 8.750994), Q(111.37629, 8.750994, 73.587906, -24.26412), Q(35.799522, -57.279236, 35.799522, -119.33174), Q(35.799522, -194.11296, 110.58074, -229.51471), Z, M(250.59665, -58.07478), Q(277.64517, -70.8035, 299.92044, -88.30549), L(299.92044, -225.1392), Q(213.20604, -225.1392, 169.0533, -198.48846), Q(124.90055, -171.8377, 124.90055, -128.87828), Q(124.90055, -85.91885, 145.9825, -65.632454), Q(167.06444, -45.34606, 202.86395, -45.34606), Q(223.54813, -45.34606, 250.59665, -58.07478), Z, M(97.454254, -616.14954), Q(122.513916, -640.41364, 160.70007, -640.41364), Q(184.56642, -640.41364, 200.87509, -630.8671), Q(217.18376, -621.32056, 237.07239, -603.0231), Q(252.18774, -589.4988, 261.33652, -583.5322), Q(270.48526, -577.5656, 281.6229, -577.5656), Q(303.10263, -577.5656, 314.63803, -592.68097), Q(326.17343, -607.7963, 330.15115, -624.50275), L(370.72394, -604.61414), Q(361.97293, -568.0191, 338.90213, -544.1527), Q(315.83133, -520.2864, 278.44073, -520.2864), Q(255.36992, -520.2864, 239.8568, -529.4351), Q(224.34367, -538.5839, 204.45505, -556.8815), Q(189.33969, -570.4057, 179.39539, -576.7701), Q(169.45107, -583.1344, 157.5179, -583.1344), Q(137.62927, -583.1344, 126.88941, -572.79236), Q(116.14956, -562.45026, 110.18297, -550.91486), Q(104.216385, -539.37946, 101.82975, -535.40173), L(64.43914, -556.8815), Q(72.394585, -591.88544, 97.454254, -616.14954), Z];
+pub const MERRIWEATHER_REGULAR_227: &'static [PathSegment] = &[M(110.58074, -229.51471), Q(185.36197, -264.91647, 299.92044, -267.3031), L(299.92044, -289.57834), Q(299.92044, -326.96896, 291.965, -348.44867), Q(284.00955, -369.92838, 264.51868, -379.8727), Q(245.02783, -389.81702, 209.22832, -389.81702), Q(168.65552, -389.81702, 136.43596, -378.28162), Q(104.216385, -366.74622, 72.394585, -350.03976), L(52.505966, -391.4081), Q(62.84805, -400.1591, 90.69212, -414.4789), Q(118.536194, -428.7987, 155.13126, -439.93634), Q(191.72633, -451.07397, 228.3214, -451.07397), Q(284.80508, -451.07397, 317.8202, -435.56085), Q(350.8353, -420.04773, 365.15512, -385.8393), Q(379.4749, -351.63086, 379.4749, -294.35162), L(379.4749, -38.9817), L(426.41208, -38.9817), L(426.41208, -3.9777246), Q(410.5012, -0.0, 386.23706, 3.9777246), Q(361.97293, 7.955449, 343.6754, 7.955449), Q(321.40015, 7.955449, 313.4447, 1.1933174), Q(305.48926, -5.5688143, 305.48926, -28.639618), L(305.48926, -54.8926), Q(281.6229, -31.026253, 247.0167, -11.137629), Q(212.41049, 8.750994, 168.65552, 8.750994), Q(111.37629, 8.750994, 73.587906, -24.26412), Q(35.799522, -57.279236, 35.799522, -119.33174), Q(35.799522, -194.11296, 110.58074, -229.51471), Z, M(250.59665, -58.07478), Q(277.64517, -70.8035, 299.92044, -88.30549), L(299.92044, -225.1392), Q(213.20604, -225.1392, 169.0533, -198.48846), Q(124.90055, -171.8377, 124.90055, -128.87828), Q(124.90055, -85.91885, 145.9825, -65.632454), Q(167.06444, -45.34606, 202.86395, -45.34606), Q(223.54813, -45.34606, 250.59665, -58.07478), Z, M(97.454254, -616.14954), Q(122.513916, -640.41364, 160.70007, -640.41364), Q(184.56642, -640.41364, 200.87509, -630.8671), Q(217.18376, -621.32056, 237.07239, -603.0231), Q(252.18774, -589.4988, 261.33652, -583.5322), Q(270.48526, -577.5656, 281.6229, -577.5656), Q(303.10263, -577.5656, 314.63803, -592.68097), Q(326.17343, -607.7963, 330.15115, -624.50275), L(370.72394, -604.61414), Q(361.97293, -568.0191, 338.90213, -544.1527), Q(315.83133, -520.2864, 278.44073, -520.2864), Q(255.36992, -520.2864, 239.8568, -529.4351), Q(224.34367, -538.5839, 204.45505, -556.8815), Q(189.33969, -570.4057, 179.39539, -576.7701), Q(169.45107, -583.1344, 157.5179, -583.1344), Q(137.62927, -583.1344, 126.88941, -572.79236), Q(116.14956, -562.45026, 110.18297, -550.91486), Q(104.216385, -539.37946, 101.82975, -535.40173), L(64.43914, -556.8815), Q(72.394585, -591.88544, 97.454254, -616.14954), Z];
 
 // // ä ------------------------------
-// pub const MERRIWEATHER_REGULAR_228: &'static [PathSegment] = &[M(110.58074, -229.51471), Q(185.36197, -264.91647, 299.92044, -267.3031), L(299.92044, -289.57834), Q(299.92044, -326.96896, 291.965, -348.44867), Q(284.00955, -369.92838, 264.51868, -379.8727), Q(245.02783, -389.81702, 209.22832, -389.81702), Q(168.65552, -389.81702, 136.43596, -378.28162), Q(104.216385, -366.74622, 72.394585, -350.03976), L(52.505966, -391.4081), Q(62.84805, -400.1591, 90.69212, -414.4789), Q(118.536194, -428.7987, 155.13126, -439.93634), Q(191.72633, -451.07397, 228.3214, -451.07397), Q(284.80508, -451.07397, 317.8202, -435.56085), Q(350.8353, -420.04773, 365.15512, -385.8393), Q(379.4749, -351.63086, 379.4749, -294.35162), L(379.4749, -38.9817), L(426.41208, -38.9817), L(426.41208, -3.9777246), Q(410.5012, -0.0, 386.23706, 3.9777246), Q(361.97293, 7.955449, 343.6754, 7.955449), Q(321.40015, 7.955449, 313.4447, 1.1933174), Q(305.48926, -5.5688143, 305.48926, -28.639618), L(305.48926, -54.8926), Q(281.6229, -31.026253, 247.0167, -11.137629), Q(212.41049, 8.750994, 168.65552, 8.750994), Q(111.37629, 8.750994, 73.587906, -24.26412), Q(35.799522, -57.279236, 35.799522, -119.33174), Q(35.799522, -194.11296, 110.58074, -229.51471), Z, M(250.59665, -58.07478), Q(277.64517, -70.8035, 299.92044, -88.30549), L(299.92044, -225.1392), Q(213.20604, -225.1392, 169.0533, -198.48846), Q(124.90055, -171.8377, 124.90055, -128.87828), Q(124.90055, -85.91885, 145.9825, -65.632454), Q(167.06444, -45.34606, 202.86395, -45.34606), Q(223.54813, -45.34606, 250.59665, -58.07478), Z, M(89.4988, -536.9928), Q(77.167854, -551.3126, 77.167854, -572.79236), Q(77.167854, -597.4542, 90.69212, -614.5585), Q(104.216385, -631.66266, 129.67383, -631.66266), Q(151.94908, -631.66266, 164.6778, -616.5473), Q(177.40652, -601.43195, 177.40652, -580.7478), Q(177.40652, -555.29034, 163.88225, -538.9817), Q(150.35799, -522.67303, 123.30946, -522.67303), Q(101.82975, -522.67303, 89.4988, -536.9928), Z, M(270.0875, -537.3906), Q(257.75656, -552.10815, 257.75656, -572.79236), Q(257.75656, -597.4542, 271.28082, -614.5585), Q(284.80508, -631.66266, 310.2625, -631.66266), Q(332.53778, -631.66266, 344.8687, -616.5473), Q(357.19968, -601.43195, 357.19968, -580.7478), Q(357.19968, -555.29034, 343.6754, -538.9817), Q(330.15115, -522.67303, 302.30707, -522.67303), Q(282.41846, -522.67303, 270.0875, -537.3906), Z];
+pub const MERRIWEATHER_REGULAR_228: &'static [PathSegment] = &[M(110.58074, -229.51471), Q(185.36197, -264.91647, 299.92044, -267.3031), L(299.92044, -289.57834), Q(299.92044, -326.96896, 291.965, -348.44867), Q(284.00955, -369.92838, 264.51868, -379.8727), Q(245.02783, -389.81702, 209.22832, -389.81702), Q(168.65552, -389.81702, 136.43596, -378.28162), Q(104.216385, -366.74622, 72.394585, -350.03976), L(52.505966, -391.4081), Q(62.84805, -400.1591, 90.69212, -414.4789), Q(118.536194, -428.7987, 155.13126, -439.93634), Q(191.72633, -451.07397, 228.3214, -451.07397), Q(284.80508, -451.07397, 317.8202, -435.56085), Q(350.8353, -420.04773, 365.15512, -385.8393), Q(379.4749, -351.63086, 379.4749, -294.35162), L(379.4749, -38.9817), L(426.41208, -38.9817), L(426.41208, -3.9777246), Q(410.5012, -0.0, 386.23706, 3.9777246), Q(361.97293, 7.955449, 343.6754, 7.955449), Q(321.40015, 7.955449, 313.4447, 1.1933174), Q(305.48926, -5.5688143, 305.48926, -28.639618), L(305.48926, -54.8926), Q(281.6229, -31.026253, 247.0167, -11.137629), Q(212.41049, 8.750994, 168.65552, 8.750994), Q(111.37629, 8.750994, 73.587906, -24.26412), Q(35.799522, -57.279236, 35.799522, -119.33174), Q(35.799522, -194.11296, 110.58074, -229.51471), Z, M(250.59665, -58.07478), Q(277.64517, -70.8035, 299.92044, -88.30549), L(299.92044, -225.1392), Q(213.20604, -225.1392, 169.0533, -198.48846), Q(124.90055, -171.8377, 124.90055, -128.87828), Q(124.90055, -85.91885, 145.9825, -65.632454), Q(167.06444, -45.34606, 202.86395, -45.34606), Q(223.54813, -45.34606, 250.59665, -58.07478), Z, M(89.4988, -536.9928), Q(77.167854, -551.3126, 77.167854, -572.79236), Q(77.167854, -597.4542, 90.69212, -614.5585), Q(104.216385, -631.66266, 129.67383, -631.66266), Q(151.94908, -631.66266, 164.6778, -616.5473), Q(177.40652, -601.43195, 177.40652, -580.7478), Q(177.40652, -555.29034, 163.88225, -538.9817), Q(150.35799, -522.67303, 123.30946, -522.67303), Q(101.82975, -522.67303, 89.4988, -536.9928), Z, M(270.0875, -537.3906), Q(257.75656, -552.10815, 257.75656, -572.79236), Q(257.75656, -597.4542, 271.28082, -614.5585), Q(284.80508, -631.66266, 310.2625, -631.66266), Q(332.53778, -631.66266, 344.8687, -616.5473), Q(357.19968, -601.43195, 357.19968, -580.7478), Q(357.19968, -555.29034, 343.6754, -538.9817), Q(330.15115, -522.67303, 302.30707, -522.67303), Q(282.41846, -522.67303, 270.0875, -537.3906), Z];
 
 // // å ------------------------------
-// pub const MERRIWEATHER_REGULAR_229: &'static [PathSegment] = &[M(110.58074, -229.51471), Q(185.36197, -264.91647, 299.92044, -267.3031), L(299.92044, -289.57834), Q(299.92044, -326.96896, 291.965, -348.44867), Q(284.00955, -369.92838, 264.51868, -379.8727), Q(245.02783, -389.81702, 209.22832, -389.81702), Q(168.65552, -389.81702, 136.43596, -378.28162), Q(104.216385, -366.74622, 72.394585, -350.03976), L(52.505966, -391.4081), Q(62.84805, -400.1591, 90.69212, -414.4789), Q(118.536194, -428.7987, 155.13126, -439.93634), Q(191.72633, -451.07397, 228.3214, -451.07397), Q(284.80508, -451.07397, 317.8202, -435.56085), Q(350.8353, -420.04773, 365.15512, -385.8393), Q(379.4749, -351.63086, 379.4749, -294.35162), L(379.4749, -38.9817), L(426.41208, -38.9817), L(426.41208, -3.9777246), Q(410.5012, -0.0, 386.23706, 3.9777246), Q(361.97293, 7.955449, 343.6754, 7.955449), Q(321.40015, 7.955449, 313.4447, 1.1933174), Q(305.48926, -5.5688143, 305.48926, -28.639618), L(305.48926, -54.8926), Q(281.6229, -31.026253, 247.0167, -11.137629), Q(212.41049, 8.750994, 168.65552, 8.750994), Q(111.37629, 8.750994, 73.587906, -24.26412), Q(35.799522, -57.279236, 35.799522, -119.33174), Q(35.799522, -194.11296, 110.58074, -229.51471), Z, M(250.59665, -58.07478), Q(277.64517, -70.8035, 299.92044, -88.30549), L(299.92044, -225.1392), Q(213.20604, -225.1392, 169.0533, -198.48846), Q(124.90055, -171.8377, 124.90055, -128.87828), Q(124.90055, -85.91885, 145.9825, -65.632454), Q(167.06444, -45.34606, 202.86395, -45.34606), Q(223.54813, -45.34606, 250.59665, -58.07478), Z, M(120.12728, -539.7772), Q(87.50994, -570.4057, 87.50994, -618.93396), Q(87.50994, -652.34686, 106.60302, -676.61096), Q(125.6961, -700.87506, 156.72235, -713.6038), Q(187.7486, -726.3325, 221.95703, -726.3325), Q(280.82736, -726.3325, 313.4447, -696.89734), Q(346.06204, -667.4622, 346.06204, -619.7295), Q(346.06204, -568.81464, 308.67142, -538.9817), Q(271.28082, -509.14874, 216.38821, -509.14874), Q(152.74463, -509.14874, 120.12728, -539.7772), Z, M(290.3739, -614.95624), Q(290.3739, -647.57355, 272.87192, -667.86), Q(255.36992, -688.14636, 218.77486, -688.14636), Q(182.97533, -688.14636, 163.08672, -670.2466), Q(143.19809, -652.34686, 143.19809, -620.525), Q(143.19809, -588.70325, 162.29117, -567.6213), Q(181.38425, -546.53937, 217.97931, -546.53937), Q(290.3739, -546.53937, 290.3739, -614.95624), Z];
+pub const MERRIWEATHER_REGULAR_229: &'static [PathSegment] = &[M(110.58074, -229.51471), Q(185.36197, -264.91647, 299.92044, -267.3031), L(299.92044, -289.57834), Q(299.92044, -326.96896, 291.965, -348.44867), Q(284.00955, -369.92838, 264.51868, -379.8727), Q(245.02783, -389.81702, 209.22832, -389.81702), Q(168.65552, -389.81702, 136.43596, -378.28162), Q(104.216385, -366.74622, 72.394585, -350.03976), L(52.505966, -391.4081), Q(62.84805, -400.1591, 90.69212, -414.4789), Q(118.536194, -428.7987, 155.13126, -439.93634), Q(191.72633, -451.07397, 228.3214, -451.07397), Q(284.80508, -451.07397, 317.8202, -435.56085), Q(350.8353, -420.04773, 365.15512, -385.8393), Q(379.4749, -351.63086, 379.4749, -294.35162), L(379.4749, -38.9817), L(426.41208, -38.9817), L(426.41208, -3.9777246), Q(410.5012, -0.0, 386.23706, 3.9777246), Q(361.97293, 7.955449, 343.6754, 7.955449), Q(321.40015, 7.955449, 313.4447, 1.1933174), Q(305.48926, -5.5688143, 305.48926, -28.639618), L(305.48926, -54.8926), Q(281.6229, -31.026253, 247.0167, -11.137629), Q(212.41049, 8.750994, 168.65552, 8.750994), Q(111.37629, 8.750994, 73.587906, -24.26412), Q(35.799522, -57.279236, 35.799522, -119.33174), Q(35.799522, -194.11296, 110.58074, -229.51471), Z, M(250.59665, -58.07478), Q(277.64517, -70.8035, 299.92044, -88.30549), L(299.92044, -225.1392), Q(213.20604, -225.1392, 169.0533, -198.48846), Q(124.90055, -171.8377, 124.90055, -128.87828), Q(124.90055, -85.91885, 145.9825, -65.632454), Q(167.06444, -45.34606, 202.86395, -45.34606), Q(223.54813, -45.34606, 250.59665, -58.07478), Z, M(120.12728, -539.7772), Q(87.50994, -570.4057, 87.50994, -618.93396), Q(87.50994, -652.34686, 106.60302, -676.61096), Q(125.6961, -700.87506, 156.72235, -713.6038), Q(187.7486, -726.3325, 221.95703, -726.3325), Q(280.82736, -726.3325, 313.4447, -696.89734), Q(346.06204, -667.4622, 346.06204, -619.7295), Q(346.06204, -568.81464, 308.67142, -538.9817), Q(271.28082, -509.14874, 216.38821, -509.14874), Q(152.74463, -509.14874, 120.12728, -539.7772), Z, M(290.3739, -614.95624), Q(290.3739, -647.57355, 272.87192, -667.86), Q(255.36992, -688.14636, 218.77486, -688.14636), Q(182.97533, -688.14636, 163.08672, -670.2466), Q(143.19809, -652.34686, 143.19809, -620.525), Q(143.19809, -588.70325, 162.29117, -567.6213), Q(181.38425, -546.53937, 217.97931, -546.53937), Q(290.3739, -546.53937, 290.3739, -614.95624), Z];
 
 // // æ ------------------------------
-// pub const MERRIWEATHER_REGULAR_230: &'static [PathSegment] = &[M(71.20127, -23.468575), Q(32.61734, -55.688145, 32.61734, -117.74065), Q(32.61734, -192.52187, 106.60302, -228.71916), Q(180.5887, -264.91647, 294.35162, -267.3031), Q(294.35162, -320.6046, 287.98727, -346.06204), Q(281.6229, -371.51947, 263.32538, -381.46378), Q(245.02783, -391.4081, 206.04613, -391.4081), Q(166.26889, -391.4081, 133.25377, -379.8727), Q(100.23866, -368.3373, 63.643593, -349.24423), L(43.75497, -389.81702), Q(54.8926, -400.1591, 84.72553, -414.87668), Q(114.55847, -429.59427, 152.34685, -440.3341), Q(190.13524, -451.07397, 226.7303, -451.07397), Q(279.23627, -451.07397, 310.2625, -437.15195), Q(341.28876, -423.2299, 355.60858, -392.20364), Q(383.45267, -421.6388, 420.4455, -437.5497), Q(457.43832, -453.4606, 500.39777, -453.4606), Q(575.17896, -453.4606, 616.14954, -412.49005), Q(657.1201, -371.51947, 659.5067, -295.14716), Q(659.5067, -246.61893, 653.9379, -221.16148), L(379.4749, -221.16148), Q(381.066, -142.40254, 416.07, -94.27207), Q(451.07397, -46.141605, 515.5131, -46.141605), Q(547.3349, -46.141605, 581.9411, -57.677006), Q(616.5473, -69.21241, 635.6404, -84.32776), L(650.75574, -50.11933), Q(627.68494, -26.252983, 582.73663, -8.750994), Q(537.7884, 8.750994, 491.64676, 8.750994), Q(435.1631, 8.750994, 394.1925, -13.524263), Q(353.22195, -35.799522, 328.56006, -76.372314), Q(305.48926, -42.959427, 263.32538, -17.104216), Q(221.16148, 8.750994, 167.06444, 8.750994), Q(109.7852, 8.750994, 71.20127, -23.468575), Z, M(571.9968, -263.32538), Q(572.79236, -273.66745, 572.79236, -297.5338), Q(572.79236, -349.24423, 551.7104, -379.07715), Q(530.6285, -408.9101, 486.07794, -408.9101), Q(439.93634, -408.9101, 412.09225, -375.4972), Q(384.2482, -342.08432, 380.27048, -263.32538), L(571.9968, -263.32538), Z, M(266.50754, -65.23468), Q(297.5338, -85.12331, 311.8536, -110.58074), Q(293.5561, -162.29117, 293.5561, -228.3214), Q(210.02386, -227.52585, 166.66666, -199.68178), Q(123.30946, -171.8377, 123.30946, -128.08273), Q(123.30946, -85.12331, 144.3914, -65.23468), Q(165.47334, -45.34606, 201.27287, -45.34606), Q(235.4813, -45.34606, 266.50754, -65.23468), Z];
+pub const MERRIWEATHER_REGULAR_230: &'static [PathSegment] = &[M(71.20127, -23.468575), Q(32.61734, -55.688145, 32.61734, -117.74065), Q(32.61734, -192.52187, 106.60302, -228.71916), Q(180.5887, -264.91647, 294.35162, -267.3031), Q(294.35162, -320.6046, 287.98727, -346.06204), Q(281.6229, -371.51947, 263.32538, -381.46378), Q(245.02783, -391.4081, 206.04613, -391.4081), Q(166.26889, -391.4081, 133.25377, -379.8727), Q(100.23866, -368.3373, 63.643593, -349.24423), L(43.75497, -389.81702), Q(54.8926, -400.1591, 84.72553, -414.87668), Q(114.55847, -429.59427, 152.34685, -440.3341), Q(190.13524, -451.07397, 226.7303, -451.07397), Q(279.23627, -451.07397, 310.2625, -437.15195), Q(341.28876, -423.2299, 355.60858, -392.20364), Q(383.45267, -421.6388, 420.4455, -437.5497), Q(457.43832, -453.4606, 500.39777, -453.4606), Q(575.17896, -453.4606, 616.14954, -412.49005), Q(657.1201, -371.51947, 659.5067, -295.14716), Q(659.5067, -246.61893, 653.9379, -221.16148), L(379.4749, -221.16148), Q(381.066, -142.40254, 416.07, -94.27207), Q(451.07397, -46.141605, 515.5131, -46.141605), Q(547.3349, -46.141605, 581.9411, -57.677006), Q(616.5473, -69.21241, 635.6404, -84.32776), L(650.75574, -50.11933), Q(627.68494, -26.252983, 582.73663, -8.750994), Q(537.7884, 8.750994, 491.64676, 8.750994), Q(435.1631, 8.750994, 394.1925, -13.524263), Q(353.22195, -35.799522, 328.56006, -76.372314), Q(305.48926, -42.959427, 263.32538, -17.104216), Q(221.16148, 8.750994, 167.06444, 8.750994), Q(109.7852, 8.750994, 71.20127, -23.468575), Z, M(571.9968, -263.32538), Q(572.79236, -273.66745, 572.79236, -297.5338), Q(572.79236, -349.24423, 551.7104, -379.07715), Q(530.6285, -408.9101, 486.07794, -408.9101), Q(439.93634, -408.9101, 412.09225, -375.4972), Q(384.2482, -342.08432, 380.27048, -263.32538), L(571.9968, -263.32538), Z, M(266.50754, -65.23468), Q(297.5338, -85.12331, 311.8536, -110.58074), Q(293.5561, -162.29117, 293.5561, -228.3214), Q(210.02386, -227.52585, 166.66666, -199.68178), Q(123.30946, -171.8377, 123.30946, -128.08273), Q(123.30946, -85.12331, 144.3914, -65.23468), Q(165.47334, -45.34606, 201.27287, -45.34606), Q(235.4813, -45.34606, 266.50754, -65.23468), Z];
 
 // // ç ------------------------------
-// pub const MERRIWEATHER_REGULAR_231: &'static [PathSegment] = &[M(52.108192, -337.31104), Q(77.9634, -390.61255, 128.4805, -422.0366), Q(178.9976, -453.4606, 249.8011, -453.4606), Q(284.00955, -453.4606, 308.67142, -447.09625), Q(333.3333, -440.73187, 367.54175, -429.59427), L(364.3596, -307.08035), L(312.64914, -307.08035), L(295.14716, -388.22592), Q(290.3739, -410.5012, 234.68575, -410.5012), Q(178.9976, -410.5012, 146.77805, -365.5529), Q(114.55847, -320.6046, 114.55847, -237.86794), Q(114.55847, -143.99364, 150.75577, -95.06762), Q(186.95306, -46.141605, 245.02783, -46.141605), Q(278.44073, -46.141605, 308.27365, -56.48369), Q(338.1066, -66.825775, 359.5863, -81.145584), L(374.70166, -49.323784), Q(354.0175, -27.844072, 315.83133, -11.137629), Q(277.64517, 5.5688143, 237.86794, 7.955449), Q(254.57437, 27.048527, 267.70087, 53.699284), Q(280.82736, 80.35004, 280.82736, 108.19411), Q(280.82736, 152.74463, 254.97215, 172.23547), Q(229.11694, 191.72633, 177.40652, 191.72633), Q(163.88225, 191.72633, 147.97136, 188.54414), Q(132.06046, 185.36197, 120.92283, 181.38425), L(121.718376, 126.491646), Q(129.67383, 129.67383, 140.0159, 131.26491), Q(150.35799, 132.856, 157.5179, 132.856), Q(189.33969, 132.856, 202.86395, 122.11614), Q(216.38821, 111.37629, 216.38821, 76.372314), Q(216.38821, 44.550514, 194.11296, 6.3643594), Q(112.17184, -3.9777246, 69.21241, -65.632454), Q(26.252983, -127.287186, 26.252983, -217.97931), Q(26.252983, -284.00955, 52.108192, -337.31104), Z];
+pub const MERRIWEATHER_REGULAR_231: &'static [PathSegment] = &[M(52.108192, -337.31104), Q(77.9634, -390.61255, 128.4805, -422.0366), Q(178.9976, -453.4606, 249.8011, -453.4606), Q(284.00955, -453.4606, 308.67142, -447.09625), Q(333.3333, -440.73187, 367.54175, -429.59427), L(364.3596, -307.08035), L(312.64914, -307.08035), L(295.14716, -388.22592), Q(290.3739, -410.5012, 234.68575, -410.5012), Q(178.9976, -410.5012, 146.77805, -365.5529), Q(114.55847, -320.6046, 114.55847, -237.86794), Q(114.55847, -143.99364, 150.75577, -95.06762), Q(186.95306, -46.141605, 245.02783, -46.141605), Q(278.44073, -46.141605, 308.27365, -56.48369), Q(338.1066, -66.825775, 359.5863, -81.145584), L(374.70166, -49.323784), Q(354.0175, -27.844072, 315.83133, -11.137629), Q(277.64517, 5.5688143, 237.86794, 7.955449), Q(254.57437, 27.048527, 267.70087, 53.699284), Q(280.82736, 80.35004, 280.82736, 108.19411), Q(280.82736, 152.74463, 254.97215, 172.23547), Q(229.11694, 191.72633, 177.40652, 191.72633), Q(163.88225, 191.72633, 147.97136, 188.54414), Q(132.06046, 185.36197, 120.92283, 181.38425), L(121.718376, 126.491646), Q(129.67383, 129.67383, 140.0159, 131.26491), Q(150.35799, 132.856, 157.5179, 132.856), Q(189.33969, 132.856, 202.86395, 122.11614), Q(216.38821, 111.37629, 216.38821, 76.372314), Q(216.38821, 44.550514, 194.11296, 6.3643594), Q(112.17184, -3.9777246, 69.21241, -65.632454), Q(26.252983, -127.287186, 26.252983, -217.97931), Q(26.252983, -284.00955, 52.108192, -337.31104), Z];
 
 // // è ------------------------------
-// pub const MERRIWEATHER_REGULAR_232: &'static [PathSegment] = &[M(84.72553, -54.097054), Q(34.20843, -116.94511, 34.20843, -221.95703), Q(34.20843, -291.16943, 60.461414, -343.6754), Q(86.71439, -396.18137, 134.04932, -424.82098), Q(181.38425, -453.4606, 241.84566, -453.4606), Q(315.83133, -453.4606, 356.8019, -412.49005), Q(397.77246, -371.51947, 400.1591, -295.14716), Q(400.1591, -246.61893, 394.59027, -221.16148), L(120.92283, -221.16148), Q(122.513916, -142.40254, 157.5179, -94.27207), Q(192.52187, -46.141605, 256.961, -46.141605), Q(288.7828, -46.141605, 323.389, -57.677006), Q(357.9952, -69.21241, 377.0883, -85.12331), L(392.20364, -50.11933), Q(369.13284, -26.252983, 323.78677, -8.750994), Q(278.44073, 8.750994, 231.50357, 8.750994), Q(135.24263, 8.750994, 84.72553, -54.097054), Z, M(312.64914, -263.32538), Q(315.0358, -280.0318, 315.0358, -297.5338), Q(314.24023, -349.24423, 293.1583, -379.07715), Q(272.07635, -408.9101, 226.7303, -408.9101), Q(180.5887, -408.9101, 153.1424, -375.4972), Q(125.6961, -342.08432, 121.718376, -263.32538), L(312.64914, -263.32538), Z, M(206.04613, -551.3126), Q(172.63326, -576.7701, 141.607, -606.20526), Q(110.58074, -635.6404, 100.23866, -653.1424), L(157.5179, -703.2617), L(285.60062, -545.74384), L(256.16547, -519.49084), L(255.36992, -519.49084), Q(239.45901, -525.85516, 206.04613, -551.3126), Z];
+pub const MERRIWEATHER_REGULAR_232: &'static [PathSegment] = &[M(84.72553, -54.097054), Q(34.20843, -116.94511, 34.20843, -221.95703), Q(34.20843, -291.16943, 60.461414, -343.6754), Q(86.71439, -396.18137, 134.04932, -424.82098), Q(181.38425, -453.4606, 241.84566, -453.4606), Q(315.83133, -453.4606, 356.8019, -412.49005), Q(397.77246, -371.51947, 400.1591, -295.14716), Q(400.1591, -246.61893, 394.59027, -221.16148), L(120.92283, -221.16148), Q(122.513916, -142.40254, 157.5179, -94.27207), Q(192.52187, -46.141605, 256.961, -46.141605), Q(288.7828, -46.141605, 323.389, -57.677006), Q(357.9952, -69.21241, 377.0883, -85.12331), L(392.20364, -50.11933), Q(369.13284, -26.252983, 323.78677, -8.750994), Q(278.44073, 8.750994, 231.50357, 8.750994), Q(135.24263, 8.750994, 84.72553, -54.097054), Z, M(312.64914, -263.32538), Q(315.0358, -280.0318, 315.0358, -297.5338), Q(314.24023, -349.24423, 293.1583, -379.07715), Q(272.07635, -408.9101, 226.7303, -408.9101), Q(180.5887, -408.9101, 153.1424, -375.4972), Q(125.6961, -342.08432, 121.718376, -263.32538), L(312.64914, -263.32538), Z, M(206.04613, -551.3126), Q(172.63326, -576.7701, 141.607, -606.20526), Q(110.58074, -635.6404, 100.23866, -653.1424), L(157.5179, -703.2617), L(285.60062, -545.74384), L(256.16547, -519.49084), L(255.36992, -519.49084), Q(239.45901, -525.85516, 206.04613, -551.3126), Z];
 
 // // é ------------------------------
-// pub const MERRIWEATHER_REGULAR_233: &'static [PathSegment] = &[M(84.72553, -54.097054), Q(34.20843, -116.94511, 34.20843, -221.95703), Q(34.20843, -291.16943, 60.461414, -343.6754), Q(86.71439, -396.18137, 134.04932, -424.82098), Q(181.38425, -453.4606, 241.84566, -453.4606), Q(315.83133, -453.4606, 356.8019, -412.49005), Q(397.77246, -371.51947, 400.1591, -295.14716), Q(400.1591, -246.61893, 394.59027, -221.16148), L(120.92283, -221.16148), Q(122.513916, -142.40254, 157.5179, -94.27207), Q(192.52187, -46.141605, 256.961, -46.141605), Q(288.7828, -46.141605, 323.389, -57.677006), Q(357.9952, -69.21241, 377.0883, -85.12331), L(392.20364, -50.11933), Q(369.13284, -26.252983, 323.78677, -8.750994), Q(278.44073, 8.750994, 231.50357, 8.750994), Q(135.24263, 8.750994, 84.72553, -54.097054), Z, M(312.64914, -263.32538), Q(315.0358, -280.0318, 315.0358, -297.5338), Q(314.24023, -349.24423, 293.1583, -379.07715), Q(272.07635, -408.9101, 226.7303, -408.9101), Q(180.5887, -408.9101, 153.1424, -375.4972), Q(125.6961, -342.08432, 121.718376, -263.32538), L(312.64914, -263.32538), Z, M(170.24661, -545.74384), L(297.5338, -704.05725), L(357.9952, -653.1424), Q(341.28876, -626.0939, 286.39618, -579.1567), Q(231.50357, -532.21954, 201.27287, -519.49084), L(170.24661, -545.74384), Z];
+pub const MERRIWEATHER_REGULAR_233: &'static [PathSegment] = &[M(84.72553, -54.097054), Q(34.20843, -116.94511, 34.20843, -221.95703), Q(34.20843, -291.16943, 60.461414, -343.6754), Q(86.71439, -396.18137, 134.04932, -424.82098), Q(181.38425, -453.4606, 241.84566, -453.4606), Q(315.83133, -453.4606, 356.8019, -412.49005), Q(397.77246, -371.51947, 400.1591, -295.14716), Q(400.1591, -246.61893, 394.59027, -221.16148), L(120.92283, -221.16148), Q(122.513916, -142.40254, 157.5179, -94.27207), Q(192.52187, -46.141605, 256.961, -46.141605), Q(288.7828, -46.141605, 323.389, -57.677006), Q(357.9952, -69.21241, 377.0883, -85.12331), L(392.20364, -50.11933), Q(369.13284, -26.252983, 323.78677, -8.750994), Q(278.44073, 8.750994, 231.50357, 8.750994), Q(135.24263, 8.750994, 84.72553, -54.097054), Z, M(312.64914, -263.32538), Q(315.0358, -280.0318, 315.0358, -297.5338), Q(314.24023, -349.24423, 293.1583, -379.07715), Q(272.07635, -408.9101, 226.7303, -408.9101), Q(180.5887, -408.9101, 153.1424, -375.4972), Q(125.6961, -342.08432, 121.718376, -263.32538), L(312.64914, -263.32538), Z, M(170.24661, -545.74384), L(297.5338, -704.05725), L(357.9952, -653.1424), Q(341.28876, -626.0939, 286.39618, -579.1567), Q(231.50357, -532.21954, 201.27287, -519.49084), L(170.24661, -545.74384), Z];
 
 // // ê ------------------------------
-// pub const MERRIWEATHER_REGULAR_234: &'static [PathSegment] = &[M(84.72553, -54.097054), Q(34.20843, -116.94511, 34.20843, -221.95703), Q(34.20843, -291.16943, 60.461414, -343.6754), Q(86.71439, -396.18137, 134.04932, -424.82098), Q(181.38425, -453.4606, 241.84566, -453.4606), Q(315.83133, -453.4606, 356.8019, -412.49005), Q(397.77246, -371.51947, 400.1591, -295.14716), Q(400.1591, -246.61893, 394.59027, -221.16148), L(120.92283, -221.16148), Q(122.513916, -142.40254, 157.5179, -94.27207), Q(192.52187, -46.141605, 256.961, -46.141605), Q(288.7828, -46.141605, 323.389, -57.677006), Q(357.9952, -69.21241, 377.0883, -85.12331), L(392.20364, -50.11933), Q(369.13284, -26.252983, 323.78677, -8.750994), Q(278.44073, 8.750994, 231.50357, 8.750994), Q(135.24263, 8.750994, 84.72553, -54.097054), Z, M(312.64914, -263.32538), Q(315.0358, -280.0318, 315.0358, -297.5338), Q(314.24023, -349.24423, 293.1583, -379.07715), Q(272.07635, -408.9101, 226.7303, -408.9101), Q(180.5887, -408.9101, 153.1424, -375.4972), Q(125.6961, -342.08432, 121.718376, -263.32538), L(312.64914, -263.32538), Z, M(264.1209, -684.16864), L(375.4972, -529.03735), L(343.6754, -506.76212), L(232.29912, -621.32056), L(116.94511, -506.76212), L(86.71439, -528.2418), L(200.47733, -684.16864), L(264.1209, -684.16864), Z];
+pub const MERRIWEATHER_REGULAR_234: &'static [PathSegment] = &[M(84.72553, -54.097054), Q(34.20843, -116.94511, 34.20843, -221.95703), Q(34.20843, -291.16943, 60.461414, -343.6754), Q(86.71439, -396.18137, 134.04932, -424.82098), Q(181.38425, -453.4606, 241.84566, -453.4606), Q(315.83133, -453.4606, 356.8019, -412.49005), Q(397.77246, -371.51947, 400.1591, -295.14716), Q(400.1591, -246.61893, 394.59027, -221.16148), L(120.92283, -221.16148), Q(122.513916, -142.40254, 157.5179, -94.27207), Q(192.52187, -46.141605, 256.961, -46.141605), Q(288.7828, -46.141605, 323.389, -57.677006), Q(357.9952, -69.21241, 377.0883, -85.12331), L(392.20364, -50.11933), Q(369.13284, -26.252983, 323.78677, -8.750994), Q(278.44073, 8.750994, 231.50357, 8.750994), Q(135.24263, 8.750994, 84.72553, -54.097054), Z, M(312.64914, -263.32538), Q(315.0358, -280.0318, 315.0358, -297.5338), Q(314.24023, -349.24423, 293.1583, -379.07715), Q(272.07635, -408.9101, 226.7303, -408.9101), Q(180.5887, -408.9101, 153.1424, -375.4972), Q(125.6961, -342.08432, 121.718376, -263.32538), L(312.64914, -263.32538), Z, M(264.1209, -684.16864), L(375.4972, -529.03735), L(343.6754, -506.76212), L(232.29912, -621.32056), L(116.94511, -506.76212), L(86.71439, -528.2418), L(200.47733, -684.16864), L(264.1209, -684.16864), Z];
 
 // // ë ------------------------------
-// pub const MERRIWEATHER_REGULAR_235: &'static [PathSegment] = &[M(84.72553, -54.097054), Q(34.20843, -116.94511, 34.20843, -221.95703), Q(34.20843, -291.16943, 60.461414, -343.6754), Q(86.71439, -396.18137, 134.04932, -424.82098), Q(181.38425, -453.4606, 241.84566, -453.4606), Q(315.83133, -453.4606, 356.8019, -412.49005), Q(397.77246, -371.51947, 400.1591, -295.14716), Q(400.1591, -246.61893, 394.59027, -221.16148), L(120.92283, -221.16148), Q(122.513916, -142.40254, 157.5179, -94.27207), Q(192.52187, -46.141605, 256.961, -46.141605), Q(288.7828, -46.141605, 323.389, -57.677006), Q(357.9952, -69.21241, 377.0883, -85.12331), L(392.20364, -50.11933), Q(369.13284, -26.252983, 323.78677, -8.750994), Q(278.44073, 8.750994, 231.50357, 8.750994), Q(135.24263, 8.750994, 84.72553, -54.097054), Z, M(312.64914, -263.32538), Q(315.0358, -280.0318, 315.0358, -297.5338), Q(314.24023, -349.24423, 293.1583, -379.07715), Q(272.07635, -408.9101, 226.7303, -408.9101), Q(180.5887, -408.9101, 153.1424, -375.4972), Q(125.6961, -342.08432, 121.718376, -263.32538), L(312.64914, -263.32538), Z, M(104.61416, -536.9928), Q(92.28321, -551.3126, 92.28321, -572.79236), Q(92.28321, -597.4542, 105.80747, -614.5585), Q(119.33174, -631.66266, 144.78917, -631.66266), Q(167.06444, -631.66266, 179.79315, -616.5473), Q(192.52187, -601.43195, 192.52187, -580.7478), Q(192.52187, -555.29034, 178.9976, -538.9817), Q(165.47334, -522.67303, 138.42482, -522.67303), Q(116.94511, -522.67303, 104.61416, -536.9928), Z, M(285.20285, -537.3906), Q(272.87192, -552.10815, 272.87192, -572.79236), Q(272.87192, -597.4542, 286.39618, -614.5585), Q(299.92044, -631.66266, 325.37787, -631.66266), Q(347.65314, -631.66266, 359.98407, -616.5473), Q(372.31503, -601.43195, 372.31503, -580.7478), Q(372.31503, -555.29034, 358.79077, -538.9817), Q(345.2665, -522.67303, 317.42242, -522.67303), Q(297.5338, -522.67303, 285.20285, -537.3906), Z];
+pub const MERRIWEATHER_REGULAR_235: &'static [PathSegment] = &[M(84.72553, -54.097054), Q(34.20843, -116.94511, 34.20843, -221.95703), Q(34.20843, -291.16943, 60.461414, -343.6754), Q(86.71439, -396.18137, 134.04932, -424.82098), Q(181.38425, -453.4606, 241.84566, -453.4606), Q(315.83133, -453.4606, 356.8019, -412.49005), Q(397.77246, -371.51947, 400.1591, -295.14716), Q(400.1591, -246.61893, 394.59027, -221.16148), L(120.92283, -221.16148), Q(122.513916, -142.40254, 157.5179, -94.27207), Q(192.52187, -46.141605, 256.961, -46.141605), Q(288.7828, -46.141605, 323.389, -57.677006), Q(357.9952, -69.21241, 377.0883, -85.12331), L(392.20364, -50.11933), Q(369.13284, -26.252983, 323.78677, -8.750994), Q(278.44073, 8.750994, 231.50357, 8.750994), Q(135.24263, 8.750994, 84.72553, -54.097054), Z, M(312.64914, -263.32538), Q(315.0358, -280.0318, 315.0358, -297.5338), Q(314.24023, -349.24423, 293.1583, -379.07715), Q(272.07635, -408.9101, 226.7303, -408.9101), Q(180.5887, -408.9101, 153.1424, -375.4972), Q(125.6961, -342.08432, 121.718376, -263.32538), L(312.64914, -263.32538), Z, M(104.61416, -536.9928), Q(92.28321, -551.3126, 92.28321, -572.79236), Q(92.28321, -597.4542, 105.80747, -614.5585), Q(119.33174, -631.66266, 144.78917, -631.66266), Q(167.06444, -631.66266, 179.79315, -616.5473), Q(192.52187, -601.43195, 192.52187, -580.7478), Q(192.52187, -555.29034, 178.9976, -538.9817), Q(165.47334, -522.67303, 138.42482, -522.67303), Q(116.94511, -522.67303, 104.61416, -536.9928), Z, M(285.20285, -537.3906), Q(272.87192, -552.10815, 272.87192, -572.79236), Q(272.87192, -597.4542, 286.39618, -614.5585), Q(299.92044, -631.66266, 325.37787, -631.66266), Q(347.65314, -631.66266, 359.98407, -616.5473), Q(372.31503, -601.43195, 372.31503, -580.7478), Q(372.31503, -555.29034, 358.79077, -538.9817), Q(345.2665, -522.67303, 317.42242, -522.67303), Q(297.5338, -522.67303, 285.20285, -537.3906), Z];
 
 // // ì ------------------------------
-// pub const MERRIWEATHER_REGULAR_236: &'static [PathSegment] = &[M(97.85203, -380.27048), L(37.39061, -394.59027), L(37.39061, -436.75415), L(160.70007, -451.8695), L(162.29117, -451.8695), L(180.5887, -437.5497), L(180.5887, -45.34606), L(244.23228, -38.9817), L(244.23228, -0.0), L(33.412888, -0.0), L(33.412888, -38.9817), L(97.85203, -46.141605), L(97.85203, -380.27048), Z, M(106.60302, -551.3126), Q(73.19013, -576.7701, 42.16388, -606.20526), Q(11.137629, -635.6404, 0.7955449, -653.1424), L(58.07478, -703.2617), L(186.15752, -545.74384), L(156.72235, -519.49084), L(155.9268, -519.49084), Q(140.0159, -525.85516, 106.60302, -551.3126), Z];
+pub const MERRIWEATHER_REGULAR_236: &'static [PathSegment] = &[M(97.85203, -380.27048), L(37.39061, -394.59027), L(37.39061, -436.75415), L(160.70007, -451.8695), L(162.29117, -451.8695), L(180.5887, -437.5497), L(180.5887, -45.34606), L(244.23228, -38.9817), L(244.23228, -0.0), L(33.412888, -0.0), L(33.412888, -38.9817), L(97.85203, -46.141605), L(97.85203, -380.27048), Z, M(106.60302, -551.3126), Q(73.19013, -576.7701, 42.16388, -606.20526), Q(11.137629, -635.6404, 0.7955449, -653.1424), L(58.07478, -703.2617), L(186.15752, -545.74384), L(156.72235, -519.49084), L(155.9268, -519.49084), Q(140.0159, -525.85516, 106.60302, -551.3126), Z];
 
 // // í ------------------------------
-// pub const MERRIWEATHER_REGULAR_237: &'static [PathSegment] = &[M(97.85203, -380.27048), L(37.39061, -394.59027), L(37.39061, -436.75415), L(160.70007, -451.8695), L(162.29117, -451.8695), L(180.5887, -437.5497), L(180.5887, -45.34606), L(244.23228, -38.9817), L(244.23228, -0.0), L(33.412888, -0.0), L(33.412888, -38.9817), L(97.85203, -46.141605), L(97.85203, -380.27048), Z, M(70.8035, -545.74384), L(198.09068, -704.05725), L(258.5521, -653.1424), Q(241.84566, -626.0939, 186.95306, -579.1567), Q(132.06046, -532.21954, 101.82975, -519.49084), L(70.8035, -545.74384), Z];
+pub const MERRIWEATHER_REGULAR_237: &'static [PathSegment] = &[M(97.85203, -380.27048), L(37.39061, -394.59027), L(37.39061, -436.75415), L(160.70007, -451.8695), L(162.29117, -451.8695), L(180.5887, -437.5497), L(180.5887, -45.34606), L(244.23228, -38.9817), L(244.23228, -0.0), L(33.412888, -0.0), L(33.412888, -38.9817), L(97.85203, -46.141605), L(97.85203, -380.27048), Z, M(70.8035, -545.74384), L(198.09068, -704.05725), L(258.5521, -653.1424), Q(241.84566, -626.0939, 186.95306, -579.1567), Q(132.06046, -532.21954, 101.82975, -519.49084), L(70.8035, -545.74384), Z];
 
 // // î ------------------------------
-// pub const MERRIWEATHER_REGULAR_238: &'static [PathSegment] = &[M(97.85203, -380.27048), L(37.39061, -394.59027), L(37.39061, -436.75415), L(160.70007, -451.8695), L(162.29117, -451.8695), L(180.5887, -437.5497), L(180.5887, -45.34606), L(244.23228, -38.9817), L(244.23228, -0.0), L(33.412888, -0.0), L(33.412888, -38.9817), L(97.85203, -46.141605), L(97.85203, -380.27048), Z, M(164.6778, -684.16864), L(276.05408, -529.03735), L(244.23228, -506.76212), L(132.856, -621.32056), L(17.501987, -506.76212), L(-12.728719, -528.2418), L(101.0342, -684.16864), L(164.6778, -684.16864), Z];
+pub const MERRIWEATHER_REGULAR_238: &'static [PathSegment] = &[M(97.85203, -380.27048), L(37.39061, -394.59027), L(37.39061, -436.75415), L(160.70007, -451.8695), L(162.29117, -451.8695), L(180.5887, -437.5497), L(180.5887, -45.34606), L(244.23228, -38.9817), L(244.23228, -0.0), L(33.412888, -0.0), L(33.412888, -38.9817), L(97.85203, -46.141605), L(97.85203, -380.27048), Z, M(164.6778, -684.16864), L(276.05408, -529.03735), L(244.23228, -506.76212), L(132.856, -621.32056), L(17.501987, -506.76212), L(-12.728719, -528.2418), L(101.0342, -684.16864), L(164.6778, -684.16864), Z];
 
 // // ï ------------------------------
-// pub const MERRIWEATHER_REGULAR_239: &'static [PathSegment] = &[M(97.85203, -380.27048), L(37.39061, -394.59027), L(37.39061, -436.75415), L(160.70007, -451.8695), L(162.29117, -451.8695), L(180.5887, -437.5497), L(180.5887, -45.34606), L(244.23228, -38.9817), L(244.23228, -0.0), L(33.412888, -0.0), L(33.412888, -38.9817), L(97.85203, -46.141605), L(97.85203, -380.27048), Z, M(5.171042, -536.9928), Q(-7.1599045, -551.3126, -7.1599045, -572.79236), Q(-7.1599045, -597.4542, 6.3643594, -614.5585), Q(19.888622, -631.66266, 45.34606, -631.66266), Q(67.621315, -631.66266, 80.35004, -616.5473), Q(93.07876, -601.43195, 93.07876, -580.7478), Q(93.07876, -555.29034, 79.55449, -538.9817), Q(66.03023, -522.67303, 38.9817, -522.67303), Q(17.501987, -522.67303, 5.171042, -536.9928), Z, M(185.75974, -537.3906), Q(173.42879, -552.10815, 173.42879, -572.79236), Q(173.42879, -597.4542, 186.95306, -614.5585), Q(200.47733, -631.66266, 225.93475, -631.66266), Q(248.21002, -631.66266, 260.54095, -616.5473), Q(272.87192, -601.43195, 272.87192, -580.7478), Q(272.87192, -555.29034, 259.34766, -538.9817), Q(245.82338, -522.67303, 217.97931, -522.67303), Q(198.09068, -522.67303, 185.75974, -537.3906), Z];
+pub const MERRIWEATHER_REGULAR_239: &'static [PathSegment] = &[M(97.85203, -380.27048), L(37.39061, -394.59027), L(37.39061, -436.75415), L(160.70007, -451.8695), L(162.29117, -451.8695), L(180.5887, -437.5497), L(180.5887, -45.34606), L(244.23228, -38.9817), L(244.23228, -0.0), L(33.412888, -0.0), L(33.412888, -38.9817), L(97.85203, -46.141605), L(97.85203, -380.27048), Z, M(5.171042, -536.9928), Q(-7.1599045, -551.3126, -7.1599045, -572.79236), Q(-7.1599045, -597.4542, 6.3643594, -614.5585), Q(19.888622, -631.66266, 45.34606, -631.66266), Q(67.621315, -631.66266, 80.35004, -616.5473), Q(93.07876, -601.43195, 93.07876, -580.7478), Q(93.07876, -555.29034, 79.55449, -538.9817), Q(66.03023, -522.67303, 38.9817, -522.67303), Q(17.501987, -522.67303, 5.171042, -536.9928), Z, M(185.75974, -537.3906), Q(173.42879, -552.10815, 173.42879, -572.79236), Q(173.42879, -597.4542, 186.95306, -614.5585), Q(200.47733, -631.66266, 225.93475, -631.66266), Q(248.21002, -631.66266, 260.54095, -616.5473), Q(272.87192, -601.43195, 272.87192, -580.7478), Q(272.87192, -555.29034, 259.34766, -538.9817), Q(245.82338, -522.67303, 217.97931, -522.67303), Q(198.09068, -522.67303, 185.75974, -537.3906), Z];
 
 // // ð ------------------------------
-// pub const MERRIWEATHER_REGULAR_240: &'static [PathSegment] = &[M(81.941124, -50.11933), Q(31.026253, -108.989655, 31.026253, -207.63722), Q(31.026253, -286.39618, 59.268097, -342.08432), Q(87.50994, -397.77246, 134.4471, -425.61655), Q(181.38425, -453.4606, 235.4813, -453.4606), Q(280.0318, -453.4606, 313.4447, -437.5497), Q(295.94272, -482.10022, 263.32538, -523.46857), Q(230.70802, -564.8369, 186.15752, -593.4765), L(131.26491, -525.05963), L(93.07876, -551.3126), L(151.15353, -618.1384), Q(129.67383, -631.66266, 105.80747, -643.5958), Q(81.941124, -655.529, 65.23468, -659.5067), L(81.145584, -698.48846), Q(133.65155, -682.5775, 183.77087, -657.1201), L(234.68575, -719.1726), L(270.48526, -690.533), L(222.75258, -634.0493), Q(319.80905, -568.0191, 375.895, -473.747), Q(431.9809, -379.4749, 431.9809, -258.5521), Q(431.9809, -188.54414, 411.6945, -128.08273), Q(391.4081, -67.621315, 346.85757, -29.435162), Q(302.30707, 8.750994, 232.29912, 8.750994), Q(132.856, 8.750994, 81.941124, -50.11933), Z, M(342.87985, -250.59665), Q(342.87985, -291.16943, 337.70883, -323.78677), Q(332.53778, -356.4041, 326.17343, -376.29276), Q(315.83133, -389.81702, 293.1583, -401.75018), Q(270.48526, -413.68335, 237.86794, -413.68335), Q(184.56642, -413.68335, 153.1424, -361.57516), Q(121.718376, -309.46698, 121.718376, -215.59267), Q(121.718376, -136.83372, 148.7669, -85.52108), Q(175.81543, -34.20843, 234.68575, -34.20843), Q(342.87985, -34.20843, 342.87985, -250.59665), Z];
+pub const MERRIWEATHER_REGULAR_240: &'static [PathSegment] = &[M(81.941124, -50.11933), Q(31.026253, -108.989655, 31.026253, -207.63722), Q(31.026253, -286.39618, 59.268097, -342.08432), Q(87.50994, -397.77246, 134.4471, -425.61655), Q(181.38425, -453.4606, 235.4813, -453.4606), Q(280.0318, -453.4606, 313.4447, -437.5497), Q(295.94272, -482.10022, 263.32538, -523.46857), Q(230.70802, -564.8369, 186.15752, -593.4765), L(131.26491, -525.05963), L(93.07876, -551.3126), L(151.15353, -618.1384), Q(129.67383, -631.66266, 105.80747, -643.5958), Q(81.941124, -655.529, 65.23468, -659.5067), L(81.145584, -698.48846), Q(133.65155, -682.5775, 183.77087, -657.1201), L(234.68575, -719.1726), L(270.48526, -690.533), L(222.75258, -634.0493), Q(319.80905, -568.0191, 375.895, -473.747), Q(431.9809, -379.4749, 431.9809, -258.5521), Q(431.9809, -188.54414, 411.6945, -128.08273), Q(391.4081, -67.621315, 346.85757, -29.435162), Q(302.30707, 8.750994, 232.29912, 8.750994), Q(132.856, 8.750994, 81.941124, -50.11933), Z, M(342.87985, -250.59665), Q(342.87985, -291.16943, 337.70883, -323.78677), Q(332.53778, -356.4041, 326.17343, -376.29276), Q(315.83133, -389.81702, 293.1583, -401.75018), Q(270.48526, -413.68335, 237.86794, -413.68335), Q(184.56642, -413.68335, 153.1424, -361.57516), Q(121.718376, -309.46698, 121.718376, -215.59267), Q(121.718376, -136.83372, 148.7669, -85.52108), Q(175.81543, -34.20843, 234.68575, -34.20843), Q(342.87985, -34.20843, 342.87985, -250.59665), Z];
 
 // // ñ ------------------------------
-// pub const MERRIWEATHER_REGULAR_241: &'static [PathSegment] = &[M(83.53222, -380.27048), L(26.252983, -394.59027), L(26.252983, -437.5497), L(140.0159, -451.8695), L(142.40254, -451.8695), L(159.10898, -437.5497), L(159.10898, -404.1368), L(158.31345, -385.04373), Q(186.95306, -410.5012, 233.89021, -431.18533), Q(280.82736, -451.8695, 323.78677, -451.8695), Q(373.90613, -451.8695, 401.35242, -432.77643), Q(428.7987, -413.68335, 439.93634, -374.3039), Q(451.07397, -334.9244, 451.07397, -268.09863), L(451.07397, -45.34606), L(506.76212, -39.777245), L(506.76212, -0.0), L(318.21796, -0.0), L(318.21796, -38.9817), L(367.54175, -45.34606), L(367.54175, -268.8942), Q(367.54175, -315.83133, 361.1774, -342.4821), Q(354.81305, -369.13284, 337.31104, -382.25934), Q(319.80905, -395.38583, 286.39618, -395.38583), Q(257.75656, -395.38583, 225.1392, -381.066), Q(192.52187, -366.74622, 167.06444, -347.65314), L(167.06444, -46.141605), L(221.16148, -38.9817), L(221.16148, -0.0), L(33.412888, -0.0), L(33.412888, -38.9817), L(83.53222, -46.141605), L(83.53222, -380.27048), Z, M(134.04932, -616.14954), Q(159.10898, -640.41364, 197.29514, -640.41364), Q(221.16148, -640.41364, 237.47015, -630.8671), Q(253.77882, -621.32056, 273.66745, -603.0231), Q(288.7828, -589.4988, 297.93158, -583.5322), Q(307.08035, -577.5656, 318.21796, -577.5656), Q(339.6977, -577.5656, 351.2331, -592.68097), Q(362.7685, -607.7963, 366.74622, -624.50275), L(407.319, -604.61414), Q(398.568, -568.0191, 375.4972, -544.1527), Q(352.4264, -520.2864, 315.0358, -520.2864), Q(291.965, -520.2864, 276.45187, -529.4351), Q(260.93872, -538.5839, 241.05011, -556.8815), Q(225.93475, -570.4057, 215.99045, -576.7701), Q(206.04613, -583.1344, 194.11296, -583.1344), Q(174.22433, -583.1344, 163.48448, -572.79236), Q(152.74463, -562.45026, 146.77805, -550.91486), Q(140.81145, -539.37946, 138.42482, -535.40173), L(101.0342, -556.8815), Q(108.989655, -591.88544, 134.04932, -616.14954), Z];
+pub const MERRIWEATHER_REGULAR_241: &'static [PathSegment] = &[M(83.53222, -380.27048), L(26.252983, -394.59027), L(26.252983, -437.5497), L(140.0159, -451.8695), L(142.40254, -451.8695), L(159.10898, -437.5497), L(159.10898, -404.1368), L(158.31345, -385.04373), Q(186.95306, -410.5012, 233.89021, -431.18533), Q(280.82736, -451.8695, 323.78677, -451.8695), Q(373.90613, -451.8695, 401.35242, -432.77643), Q(428.7987, -413.68335, 439.93634, -374.3039), Q(451.07397, -334.9244, 451.07397, -268.09863), L(451.07397, -45.34606), L(506.76212, -39.777245), L(506.76212, -0.0), L(318.21796, -0.0), L(318.21796, -38.9817), L(367.54175, -45.34606), L(367.54175, -268.8942), Q(367.54175, -315.83133, 361.1774, -342.4821), Q(354.81305, -369.13284, 337.31104, -382.25934), Q(319.80905, -395.38583, 286.39618, -395.38583), Q(257.75656, -395.38583, 225.1392, -381.066), Q(192.52187, -366.74622, 167.06444, -347.65314), L(167.06444, -46.141605), L(221.16148, -38.9817), L(221.16148, -0.0), L(33.412888, -0.0), L(33.412888, -38.9817), L(83.53222, -46.141605), L(83.53222, -380.27048), Z, M(134.04932, -616.14954), Q(159.10898, -640.41364, 197.29514, -640.41364), Q(221.16148, -640.41364, 237.47015, -630.8671), Q(253.77882, -621.32056, 273.66745, -603.0231), Q(288.7828, -589.4988, 297.93158, -583.5322), Q(307.08035, -577.5656, 318.21796, -577.5656), Q(339.6977, -577.5656, 351.2331, -592.68097), Q(362.7685, -607.7963, 366.74622, -624.50275), L(407.319, -604.61414), Q(398.568, -568.0191, 375.4972, -544.1527), Q(352.4264, -520.2864, 315.0358, -520.2864), Q(291.965, -520.2864, 276.45187, -529.4351), Q(260.93872, -538.5839, 241.05011, -556.8815), Q(225.93475, -570.4057, 215.99045, -576.7701), Q(206.04613, -583.1344, 194.11296, -583.1344), Q(174.22433, -583.1344, 163.48448, -572.79236), Q(152.74463, -562.45026, 146.77805, -550.91486), Q(140.81145, -539.37946, 138.42482, -535.40173), L(101.0342, -556.8815), Q(108.989655, -591.88544, 134.04932, -616.14954), Z];
 
 // // ò ------------------------------
-// pub const MERRIWEATHER_REGULAR_242: &'static [PathSegment] = &[M(65.23468, -346.06204), Q(94.669846, -398.568, 142.80031, -426.0143), Q(190.93079, -453.4606, 245.82338, -453.4606), Q(346.06204, -453.4606, 396.57913, -389.81702), Q(447.09625, -326.17343, 447.09625, -222.75258), Q(447.09625, -150.35799, 417.66107, -97.85203), Q(388.22592, -45.34606, 340.09546, -18.297533), Q(291.965, 8.750994, 237.07239, 8.750994), Q(136.83372, 8.750994, 86.31663, -54.8926), Q(35.799522, -118.536194, 35.799522, -221.95703), Q(35.799522, -293.5561, 65.23468, -346.06204), Z, M(326.17343, -81.941124), Q(355.60858, -127.287186, 355.60858, -216.38821), Q(355.60858, -304.6937, 328.56006, -356.4041), Q(301.51154, -408.11453, 241.84566, -408.11453), Q(186.95306, -408.11453, 157.12012, -362.7685), Q(127.287186, -317.42242, 127.287186, -228.3214), Q(127.287186, -140.0159, 155.13126, -88.30549), Q(182.97533, -36.595066, 241.84566, -36.595066), Q(296.73825, -36.595066, 326.17343, -81.941124), Z, M(216.38821, -551.3126), Q(182.97533, -576.7701, 151.94908, -606.20526), Q(120.92283, -635.6404, 110.58074, -653.1424), L(167.85999, -703.2617), L(295.94272, -545.74384), L(266.50754, -519.49084), L(265.712, -519.49084), Q(249.8011, -525.85516, 216.38821, -551.3126), Z];
+pub const MERRIWEATHER_REGULAR_242: &'static [PathSegment] = &[M(65.23468, -346.06204), Q(94.669846, -398.568, 142.80031, -426.0143), Q(190.93079, -453.4606, 245.82338, -453.4606), Q(346.06204, -453.4606, 396.57913, -389.81702), Q(447.09625, -326.17343, 447.09625, -222.75258), Q(447.09625, -150.35799, 417.66107, -97.85203), Q(388.22592, -45.34606, 340.09546, -18.297533), Q(291.965, 8.750994, 237.07239, 8.750994), Q(136.83372, 8.750994, 86.31663, -54.8926), Q(35.799522, -118.536194, 35.799522, -221.95703), Q(35.799522, -293.5561, 65.23468, -346.06204), Z, M(326.17343, -81.941124), Q(355.60858, -127.287186, 355.60858, -216.38821), Q(355.60858, -304.6937, 328.56006, -356.4041), Q(301.51154, -408.11453, 241.84566, -408.11453), Q(186.95306, -408.11453, 157.12012, -362.7685), Q(127.287186, -317.42242, 127.287186, -228.3214), Q(127.287186, -140.0159, 155.13126, -88.30549), Q(182.97533, -36.595066, 241.84566, -36.595066), Q(296.73825, -36.595066, 326.17343, -81.941124), Z, M(216.38821, -551.3126), Q(182.97533, -576.7701, 151.94908, -606.20526), Q(120.92283, -635.6404, 110.58074, -653.1424), L(167.85999, -703.2617), L(295.94272, -545.74384), L(266.50754, -519.49084), L(265.712, -519.49084), Q(249.8011, -525.85516, 216.38821, -551.3126), Z];
 
 // // ó ------------------------------
-// pub const MERRIWEATHER_REGULAR_243: &'static [PathSegment] = &[M(65.23468, -346.06204), Q(94.669846, -398.568, 142.80031, -426.0143), Q(190.93079, -453.4606, 245.82338, -453.4606), Q(346.06204, -453.4606, 396.57913, -389.81702), Q(447.09625, -326.17343, 447.09625, -222.75258), Q(447.09625, -150.35799, 417.66107, -97.85203), Q(388.22592, -45.34606, 340.09546, -18.297533), Q(291.965, 8.750994, 237.07239, 8.750994), Q(136.83372, 8.750994, 86.31663, -54.8926), Q(35.799522, -118.536194, 35.799522, -221.95703), Q(35.799522, -293.5561, 65.23468, -346.06204), Z, M(326.17343, -81.941124), Q(355.60858, -127.287186, 355.60858, -216.38821), Q(355.60858, -304.6937, 328.56006, -356.4041), Q(301.51154, -408.11453, 241.84566, -408.11453), Q(186.95306, -408.11453, 157.12012, -362.7685), Q(127.287186, -317.42242, 127.287186, -228.3214), Q(127.287186, -140.0159, 155.13126, -88.30549), Q(182.97533, -36.595066, 241.84566, -36.595066), Q(296.73825, -36.595066, 326.17343, -81.941124), Z, M(180.5887, -545.74384), L(307.8759, -704.05725), L(368.3373, -653.1424), Q(351.63086, -626.0939, 296.73825, -579.1567), Q(241.84566, -532.21954, 211.61494, -519.49084), L(180.5887, -545.74384), Z];
+pub const MERRIWEATHER_REGULAR_243: &'static [PathSegment] = &[M(65.23468, -346.06204), Q(94.669846, -398.568, 142.80031, -426.0143), Q(190.93079, -453.4606, 245.82338, -453.4606), Q(346.06204, -453.4606, 396.57913, -389.81702), Q(447.09625, -326.17343, 447.09625, -222.75258), Q(447.09625, -150.35799, 417.66107, -97.85203), Q(388.22592, -45.34606, 340.09546, -18.297533), Q(291.965, 8.750994, 237.07239, 8.750994), Q(136.83372, 8.750994, 86.31663, -54.8926), Q(35.799522, -118.536194, 35.799522, -221.95703), Q(35.799522, -293.5561, 65.23468, -346.06204), Z, M(326.17343, -81.941124), Q(355.60858, -127.287186, 355.60858, -216.38821), Q(355.60858, -304.6937, 328.56006, -356.4041), Q(301.51154, -408.11453, 241.84566, -408.11453), Q(186.95306, -408.11453, 157.12012, -362.7685), Q(127.287186, -317.42242, 127.287186, -228.3214), Q(127.287186, -140.0159, 155.13126, -88.30549), Q(182.97533, -36.595066, 241.84566, -36.595066), Q(296.73825, -36.595066, 326.17343, -81.941124), Z, M(180.5887, -545.74384), L(307.8759, -704.05725), L(368.3373, -653.1424), Q(351.63086, -626.0939, 296.73825, -579.1567), Q(241.84566, -532.21954, 211.61494, -519.49084), L(180.5887, -545.74384), Z];
 
 // // ô ------------------------------
-// pub const MERRIWEATHER_REGULAR_244: &'static [PathSegment] = &[M(65.23468, -346.06204), Q(94.669846, -398.568, 142.80031, -426.0143), Q(190.93079, -453.4606, 245.82338, -453.4606), Q(346.06204, -453.4606, 396.57913, -389.81702), Q(447.09625, -326.17343, 447.09625, -222.75258), Q(447.09625, -150.35799, 417.66107, -97.85203), Q(388.22592, -45.34606, 340.09546, -18.297533), Q(291.965, 8.750994, 237.07239, 8.750994), Q(136.83372, 8.750994, 86.31663, -54.8926), Q(35.799522, -118.536194, 35.799522, -221.95703), Q(35.799522, -293.5561, 65.23468, -346.06204), Z, M(326.17343, -81.941124), Q(355.60858, -127.287186, 355.60858, -216.38821), Q(355.60858, -304.6937, 328.56006, -356.4041), Q(301.51154, -408.11453, 241.84566, -408.11453), Q(186.95306, -408.11453, 157.12012, -362.7685), Q(127.287186, -317.42242, 127.287186, -228.3214), Q(127.287186, -140.0159, 155.13126, -88.30549), Q(182.97533, -36.595066, 241.84566, -36.595066), Q(296.73825, -36.595066, 326.17343, -81.941124), Z, M(274.463, -684.16864), L(385.8393, -529.03735), L(354.0175, -506.76212), L(242.6412, -621.32056), L(127.287186, -506.76212), L(97.05648, -528.2418), L(210.8194, -684.16864), L(274.463, -684.16864), Z];
+pub const MERRIWEATHER_REGULAR_244: &'static [PathSegment] = &[M(65.23468, -346.06204), Q(94.669846, -398.568, 142.80031, -426.0143), Q(190.93079, -453.4606, 245.82338, -453.4606), Q(346.06204, -453.4606, 396.57913, -389.81702), Q(447.09625, -326.17343, 447.09625, -222.75258), Q(447.09625, -150.35799, 417.66107, -97.85203), Q(388.22592, -45.34606, 340.09546, -18.297533), Q(291.965, 8.750994, 237.07239, 8.750994), Q(136.83372, 8.750994, 86.31663, -54.8926), Q(35.799522, -118.536194, 35.799522, -221.95703), Q(35.799522, -293.5561, 65.23468, -346.06204), Z, M(326.17343, -81.941124), Q(355.60858, -127.287186, 355.60858, -216.38821), Q(355.60858, -304.6937, 328.56006, -356.4041), Q(301.51154, -408.11453, 241.84566, -408.11453), Q(186.95306, -408.11453, 157.12012, -362.7685), Q(127.287186, -317.42242, 127.287186, -228.3214), Q(127.287186, -140.0159, 155.13126, -88.30549), Q(182.97533, -36.595066, 241.84566, -36.595066), Q(296.73825, -36.595066, 326.17343, -81.941124), Z, M(274.463, -684.16864), L(385.8393, -529.03735), L(354.0175, -506.76212), L(242.6412, -621.32056), L(127.287186, -506.76212), L(97.05648, -528.2418), L(210.8194, -684.16864), L(274.463, -684.16864), Z];
 
 // // õ ------------------------------
-// pub const MERRIWEATHER_REGULAR_245: &'static [PathSegment] = &[M(65.23468, -346.06204), Q(94.669846, -398.568, 142.80031, -426.0143), Q(190.93079, -453.4606, 245.82338, -453.4606), Q(346.06204, -453.4606, 396.57913, -389.81702), Q(447.09625, -326.17343, 447.09625, -222.75258), Q(447.09625, -150.35799, 417.66107, -97.85203), Q(388.22592, -45.34606, 340.09546, -18.297533), Q(291.965, 8.750994, 237.07239, 8.750994), Q(136.83372, 8.750994, 86.31663, -54.8926), Q(35.799522, -118.536194, 35.799522, -221.95703), Q(35.799522, -293.5561, 65.23468, -346.06204), Z, M(326.17343, -81.941124), Q(355.60858, -127.287186, 355.60858, -216.38821), Q(355.60858, -304.6937, 328.56006, -356.4041), Q(301.51154, -408.11453, 241.84566, -408.11453), Q(186.95306, -408.11453, 157.12012, -362.7685), Q(127.287186, -317.42242, 127.287186, -228.3214), Q(127.287186, -140.0159, 155.13126, -88.30549), Q(182.97533, -36.595066, 241.84566, -36.595066), Q(296.73825, -36.595066, 326.17343, -81.941124), Z, M(122.91169, -616.14954), Q(147.97136, -640.41364, 186.15752, -640.41364), Q(210.02386, -640.41364, 226.33253, -630.8671), Q(242.6412, -621.32056, 262.52982, -603.0231), Q(277.64517, -589.4988, 286.79395, -583.5322), Q(295.94272, -577.5656, 307.08035, -577.5656), Q(328.56006, -577.5656, 340.09546, -592.68097), Q(351.63086, -607.7963, 355.60858, -624.50275), L(396.18137, -604.61414), Q(387.4304, -568.0191, 364.3596, -544.1527), Q(341.28876, -520.2864, 303.89816, -520.2864), Q(280.82736, -520.2864, 265.31424, -529.4351), Q(249.8011, -538.5839, 229.91248, -556.8815), Q(214.79713, -570.4057, 204.85281, -576.7701), Q(194.90851, -583.1344, 182.97533, -583.1344), Q(163.08672, -583.1344, 152.34685, -572.79236), Q(141.607, -562.45026, 135.64041, -550.91486), Q(129.67383, -539.37946, 127.287186, -535.40173), L(89.896576, -556.8815), Q(97.85203, -591.88544, 122.91169, -616.14954), Z];
+pub const MERRIWEATHER_REGULAR_245: &'static [PathSegment] = &[M(65.23468, -346.06204), Q(94.669846, -398.568, 142.80031, -426.0143), Q(190.93079, -453.4606, 245.82338, -453.4606), Q(346.06204, -453.4606, 396.57913, -389.81702), Q(447.09625, -326.17343, 447.09625, -222.75258), Q(447.09625, -150.35799, 417.66107, -97.85203), Q(388.22592, -45.34606, 340.09546, -18.297533), Q(291.965, 8.750994, 237.07239, 8.750994), Q(136.83372, 8.750994, 86.31663, -54.8926), Q(35.799522, -118.536194, 35.799522, -221.95703), Q(35.799522, -293.5561, 65.23468, -346.06204), Z, M(326.17343, -81.941124), Q(355.60858, -127.287186, 355.60858, -216.38821), Q(355.60858, -304.6937, 328.56006, -356.4041), Q(301.51154, -408.11453, 241.84566, -408.11453), Q(186.95306, -408.11453, 157.12012, -362.7685), Q(127.287186, -317.42242, 127.287186, -228.3214), Q(127.287186, -140.0159, 155.13126, -88.30549), Q(182.97533, -36.595066, 241.84566, -36.595066), Q(296.73825, -36.595066, 326.17343, -81.941124), Z, M(122.91169, -616.14954), Q(147.97136, -640.41364, 186.15752, -640.41364), Q(210.02386, -640.41364, 226.33253, -630.8671), Q(242.6412, -621.32056, 262.52982, -603.0231), Q(277.64517, -589.4988, 286.79395, -583.5322), Q(295.94272, -577.5656, 307.08035, -577.5656), Q(328.56006, -577.5656, 340.09546, -592.68097), Q(351.63086, -607.7963, 355.60858, -624.50275), L(396.18137, -604.61414), Q(387.4304, -568.0191, 364.3596, -544.1527), Q(341.28876, -520.2864, 303.89816, -520.2864), Q(280.82736, -520.2864, 265.31424, -529.4351), Q(249.8011, -538.5839, 229.91248, -556.8815), Q(214.79713, -570.4057, 204.85281, -576.7701), Q(194.90851, -583.1344, 182.97533, -583.1344), Q(163.08672, -583.1344, 152.34685, -572.79236), Q(141.607, -562.45026, 135.64041, -550.91486), Q(129.67383, -539.37946, 127.287186, -535.40173), L(89.896576, -556.8815), Q(97.85203, -591.88544, 122.91169, -616.14954), Z];
 
 // // ö ------------------------------
-// pub const MERRIWEATHER_REGULAR_246: &'static [PathSegment] = &[M(65.23468, -346.06204), Q(94.669846, -398.568, 142.80031, -426.0143), Q(190.93079, -453.4606, 245.82338, -453.4606), Q(346.06204, -453.4606, 396.57913, -389.81702), Q(447.09625, -326.17343, 447.09625, -222.75258), Q(447.09625, -150.35799, 417.66107, -97.85203), Q(388.22592, -45.34606, 340.09546, -18.297533), Q(291.965, 8.750994, 237.07239, 8.750994), Q(136.83372, 8.750994, 86.31663, -54.8926), Q(35.799522, -118.536194, 35.799522, -221.95703), Q(35.799522, -293.5561, 65.23468, -346.06204), Z, M(326.17343, -81.941124), Q(355.60858, -127.287186, 355.60858, -216.38821), Q(355.60858, -304.6937, 328.56006, -356.4041), Q(301.51154, -408.11453, 241.84566, -408.11453), Q(186.95306, -408.11453, 157.12012, -362.7685), Q(127.287186, -317.42242, 127.287186, -228.3214), Q(127.287186, -140.0159, 155.13126, -88.30549), Q(182.97533, -36.595066, 241.84566, -36.595066), Q(296.73825, -36.595066, 326.17343, -81.941124), Z, M(114.95624, -536.9928), Q(102.6253, -551.3126, 102.6253, -572.79236), Q(102.6253, -597.4542, 116.14956, -614.5585), Q(129.67383, -631.66266, 155.13126, -631.66266), Q(177.40652, -631.66266, 190.13524, -616.5473), Q(202.86395, -601.43195, 202.86395, -580.7478), Q(202.86395, -555.29034, 189.33969, -538.9817), Q(175.81543, -522.67303, 148.7669, -522.67303), Q(127.287186, -522.67303, 114.95624, -536.9928), Z, M(295.54495, -537.3906), Q(283.214, -552.10815, 283.214, -572.79236), Q(283.214, -597.4542, 296.73825, -614.5585), Q(310.2625, -631.66266, 335.71997, -631.66266), Q(357.9952, -631.66266, 370.32617, -616.5473), Q(382.6571, -601.43195, 382.6571, -580.7478), Q(382.6571, -555.29034, 369.13284, -538.9817), Q(355.60858, -522.67303, 327.7645, -522.67303), Q(307.8759, -522.67303, 295.54495, -537.3906), Z];
+pub const MERRIWEATHER_REGULAR_246: &'static [PathSegment] = &[M(65.23468, -346.06204), Q(94.669846, -398.568, 142.80031, -426.0143), Q(190.93079, -453.4606, 245.82338, -453.4606), Q(346.06204, -453.4606, 396.57913, -389.81702), Q(447.09625, -326.17343, 447.09625, -222.75258), Q(447.09625, -150.35799, 417.66107, -97.85203), Q(388.22592, -45.34606, 340.09546, -18.297533), Q(291.965, 8.750994, 237.07239, 8.750994), Q(136.83372, 8.750994, 86.31663, -54.8926), Q(35.799522, -118.536194, 35.799522, -221.95703), Q(35.799522, -293.5561, 65.23468, -346.06204), Z, M(326.17343, -81.941124), Q(355.60858, -127.287186, 355.60858, -216.38821), Q(355.60858, -304.6937, 328.56006, -356.4041), Q(301.51154, -408.11453, 241.84566, -408.11453), Q(186.95306, -408.11453, 157.12012, -362.7685), Q(127.287186, -317.42242, 127.287186, -228.3214), Q(127.287186, -140.0159, 155.13126, -88.30549), Q(182.97533, -36.595066, 241.84566, -36.595066), Q(296.73825, -36.595066, 326.17343, -81.941124), Z, M(114.95624, -536.9928), Q(102.6253, -551.3126, 102.6253, -572.79236), Q(102.6253, -597.4542, 116.14956, -614.5585), Q(129.67383, -631.66266, 155.13126, -631.66266), Q(177.40652, -631.66266, 190.13524, -616.5473), Q(202.86395, -601.43195, 202.86395, -580.7478), Q(202.86395, -555.29034, 189.33969, -538.9817), Q(175.81543, -522.67303, 148.7669, -522.67303), Q(127.287186, -522.67303, 114.95624, -536.9928), Z, M(295.54495, -537.3906), Q(283.214, -552.10815, 283.214, -572.79236), Q(283.214, -597.4542, 296.73825, -614.5585), Q(310.2625, -631.66266, 335.71997, -631.66266), Q(357.9952, -631.66266, 370.32617, -616.5473), Q(382.6571, -601.43195, 382.6571, -580.7478), Q(382.6571, -555.29034, 369.13284, -538.9817), Q(355.60858, -522.67303, 327.7645, -522.67303), Q(307.8759, -522.67303, 295.54495, -537.3906), Z];
 
 // // ÷ ------------------------------
-// pub const MERRIWEATHER_REGULAR_247: &'static [PathSegment] = &[M(231.90135, -381.066), Q(217.18376, -394.59027, 217.18376, -416.07), Q(217.18376, -439.1408, 235.08353, -455.44946), Q(252.98329, -471.75815, 275.25854, -471.75815), Q(299.92044, -471.75815, 312.64914, -457.8361), Q(325.37787, -443.91406, 325.37787, -422.43436), Q(325.37787, -397.77246, 308.27365, -382.6571), Q(291.16943, -367.54175, 266.50754, -367.54175), Q(246.61893, -367.54175, 231.90135, -381.066), Z, M(445.50516, -226.7303), L(83.53222, -226.7303), L(83.53222, -284.00955), L(445.50516, -284.00955), L(445.50516, -226.7303), Z, M(227.92361, -129.27605), Q(245.82338, -145.58472, 268.8942, -145.58472), Q(293.5561, -145.58472, 306.2848, -131.66269), Q(319.01352, -117.74065, 319.01352, -97.05648), Q(319.01352, -72.394585, 301.51154, -56.881462), Q(284.00955, -41.368336, 260.1432, -41.368336), Q(240.25456, -41.368336, 225.1392, -54.8926), Q(210.02386, -68.41686, 210.02386, -89.896576), Q(210.02386, -112.96738, 227.92361, -129.27605), Z];
+pub const MERRIWEATHER_REGULAR_247: &'static [PathSegment] = &[M(231.90135, -381.066), Q(217.18376, -394.59027, 217.18376, -416.07), Q(217.18376, -439.1408, 235.08353, -455.44946), Q(252.98329, -471.75815, 275.25854, -471.75815), Q(299.92044, -471.75815, 312.64914, -457.8361), Q(325.37787, -443.91406, 325.37787, -422.43436), Q(325.37787, -397.77246, 308.27365, -382.6571), Q(291.16943, -367.54175, 266.50754, -367.54175), Q(246.61893, -367.54175, 231.90135, -381.066), Z, M(445.50516, -226.7303), L(83.53222, -226.7303), L(83.53222, -284.00955), L(445.50516, -284.00955), L(445.50516, -226.7303), Z, M(227.92361, -129.27605), Q(245.82338, -145.58472, 268.8942, -145.58472), Q(293.5561, -145.58472, 306.2848, -131.66269), Q(319.01352, -117.74065, 319.01352, -97.05648), Q(319.01352, -72.394585, 301.51154, -56.881462), Q(284.00955, -41.368336, 260.1432, -41.368336), Q(240.25456, -41.368336, 225.1392, -54.8926), Q(210.02386, -68.41686, 210.02386, -89.896576), Q(210.02386, -112.96738, 227.92361, -129.27605), Z];
 
 // // ø ------------------------------
-// pub const MERRIWEATHER_REGULAR_248: &'static [PathSegment] = &[M(62.052505, -346.06204), Q(91.48766, -398.568, 139.61813, -426.0143), Q(187.7486, -453.4606, 242.6412, -453.4606), Q(275.25854, -453.4606, 301.51154, -446.3007), L(329.3556, -522.67303), L(363.56403, -513.922), L(337.31104, -434.36752), Q(390.61255, -408.11453, 417.2633, -352.8242), Q(443.91406, -297.5338, 443.91406, -222.75258), Q(443.91406, -150.35799, 414.4789, -97.85203), Q(385.04373, -45.34606, 336.91327, -18.297533), Q(288.7828, 8.750994, 233.89021, 8.750994), Q(204.45505, 8.750994, 177.40652, 2.3866348), L(146.38026, 84.32776), L(109.7852, 73.98568), L(140.81145, -9.546539), Q(86.71439, -35.799522, 59.66587, -91.48766), Q(32.61734, -147.17581, 32.61734, -221.95703), Q(32.61734, -293.5561, 62.052505, -346.06204), Z, M(135.24263, -136.03818), Q(146.38026, -95.46539, 168.65552, -71.599045), L(285.60062, -396.18137), Q(266.50754, -408.11453, 238.66348, -408.11453), Q(183.77087, -408.11453, 153.93794, -362.7685), Q(124.10501, -317.42242, 124.10501, -228.3214), Q(124.10501, -176.61098, 135.24263, -136.03818), Z, M(322.99124, -81.941124), Q(352.4264, -127.287186, 352.4264, -216.38821), Q(352.4264, -323.78677, 309.46698, -371.51947), L(191.72633, -48.52824), Q(212.41049, -36.595066, 238.66348, -36.595066), Q(293.5561, -36.595066, 322.99124, -81.941124), Z];
+pub const MERRIWEATHER_REGULAR_248: &'static [PathSegment] = &[M(62.052505, -346.06204), Q(91.48766, -398.568, 139.61813, -426.0143), Q(187.7486, -453.4606, 242.6412, -453.4606), Q(275.25854, -453.4606, 301.51154, -446.3007), L(329.3556, -522.67303), L(363.56403, -513.922), L(337.31104, -434.36752), Q(390.61255, -408.11453, 417.2633, -352.8242), Q(443.91406, -297.5338, 443.91406, -222.75258), Q(443.91406, -150.35799, 414.4789, -97.85203), Q(385.04373, -45.34606, 336.91327, -18.297533), Q(288.7828, 8.750994, 233.89021, 8.750994), Q(204.45505, 8.750994, 177.40652, 2.3866348), L(146.38026, 84.32776), L(109.7852, 73.98568), L(140.81145, -9.546539), Q(86.71439, -35.799522, 59.66587, -91.48766), Q(32.61734, -147.17581, 32.61734, -221.95703), Q(32.61734, -293.5561, 62.052505, -346.06204), Z, M(135.24263, -136.03818), Q(146.38026, -95.46539, 168.65552, -71.599045), L(285.60062, -396.18137), Q(266.50754, -408.11453, 238.66348, -408.11453), Q(183.77087, -408.11453, 153.93794, -362.7685), Q(124.10501, -317.42242, 124.10501, -228.3214), Q(124.10501, -176.61098, 135.24263, -136.03818), Z, M(322.99124, -81.941124), Q(352.4264, -127.287186, 352.4264, -216.38821), Q(352.4264, -323.78677, 309.46698, -371.51947), L(191.72633, -48.52824), Q(212.41049, -36.595066, 238.66348, -36.595066), Q(293.5561, -36.595066, 322.99124, -81.941124), Z];
 
 // // ù ------------------------------
-// pub const MERRIWEATHER_REGULAR_249: &'static [PathSegment] = &[M(364.75735, -1.5910898), Q(354.0175, -10.342084, 354.0175, -30.230707), L(354.0175, -57.279236), Q(327.7645, -31.026253, 288.38504, -11.137629), Q(249.00555, 8.750994, 209.22832, 8.750994), Q(135.24263, 8.750994, 104.216385, -31.026253), Q(73.19013, -70.8035, 73.19013, -162.29117), L(73.19013, -392.9992), L(28.639618, -405.7279), L(28.639618, -440.73187), L(140.0159, -451.07397), L(140.81145, -451.07397), L(155.9268, -441.52744), L(155.9268, -168.65552), Q(155.9268, -122.513916, 162.68893, -96.65871), Q(169.45107, -70.8035, 186.55528, -58.472553), Q(203.6595, -46.141605, 236.27684, -46.141605), Q(268.09863, -46.141605, 295.94272, -59.66587), Q(323.78677, -73.19013, 344.47095, -91.48766), L(344.47095, -392.9992), L(287.1917, -405.7279), L(287.1917, -440.73187), L(408.11453, -451.07397), L(408.9101, -451.07397), L(427.2076, -441.52744), L(427.2076, -40.572792), L(474.9403, -40.572792), L(474.14478, -4.7732697), Q(451.8695, 0.7955449, 435.56085, 3.9777246), Q(419.25217, 7.1599045, 397.77246, 7.1599045), Q(375.4972, 7.1599045, 364.75735, -1.5910898), Z, M(214.79713, -551.3126), Q(181.38425, -576.7701, 150.35799, -606.20526), Q(119.33174, -635.6404, 108.989655, -653.1424), L(166.26889, -703.2617), L(294.35162, -545.74384), L(264.91647, -519.49084), L(264.1209, -519.49084), Q(248.21002, -525.85516, 214.79713, -551.3126), Z];
+pub const MERRIWEATHER_REGULAR_249: &'static [PathSegment] = &[M(364.75735, -1.5910898), Q(354.0175, -10.342084, 354.0175, -30.230707), L(354.0175, -57.279236), Q(327.7645, -31.026253, 288.38504, -11.137629), Q(249.00555, 8.750994, 209.22832, 8.750994), Q(135.24263, 8.750994, 104.216385, -31.026253), Q(73.19013, -70.8035, 73.19013, -162.29117), L(73.19013, -392.9992), L(28.639618, -405.7279), L(28.639618, -440.73187), L(140.0159, -451.07397), L(140.81145, -451.07397), L(155.9268, -441.52744), L(155.9268, -168.65552), Q(155.9268, -122.513916, 162.68893, -96.65871), Q(169.45107, -70.8035, 186.55528, -58.472553), Q(203.6595, -46.141605, 236.27684, -46.141605), Q(268.09863, -46.141605, 295.94272, -59.66587), Q(323.78677, -73.19013, 344.47095, -91.48766), L(344.47095, -392.9992), L(287.1917, -405.7279), L(287.1917, -440.73187), L(408.11453, -451.07397), L(408.9101, -451.07397), L(427.2076, -441.52744), L(427.2076, -40.572792), L(474.9403, -40.572792), L(474.14478, -4.7732697), Q(451.8695, 0.7955449, 435.56085, 3.9777246), Q(419.25217, 7.1599045, 397.77246, 7.1599045), Q(375.4972, 7.1599045, 364.75735, -1.5910898), Z, M(214.79713, -551.3126), Q(181.38425, -576.7701, 150.35799, -606.20526), Q(119.33174, -635.6404, 108.989655, -653.1424), L(166.26889, -703.2617), L(294.35162, -545.74384), L(264.91647, -519.49084), L(264.1209, -519.49084), Q(248.21002, -525.85516, 214.79713, -551.3126), Z];
 
 // // ú ------------------------------
-// pub const MERRIWEATHER_REGULAR_250: &'static [PathSegment] = &[M(364.75735, -1.5910898), Q(354.0175, -10.342084, 354.0175, -30.230707), L(354.0175, -57.279236), Q(327.7645, -31.026253, 288.38504, -11.137629), Q(249.00555, 8.750994, 209.22832, 8.750994), Q(135.24263, 8.750994, 104.216385, -31.026253), Q(73.19013, -70.8035, 73.19013, -162.29117), L(73.19013, -392.9992), L(28.639618, -405.7279), L(28.639618, -440.73187), L(140.0159, -451.07397), L(140.81145, -451.07397), L(155.9268, -441.52744), L(155.9268, -168.65552), Q(155.9268, -122.513916, 162.68893, -96.65871), Q(169.45107, -70.8035, 186.55528, -58.472553), Q(203.6595, -46.141605, 236.27684, -46.141605), Q(268.09863, -46.141605, 295.94272, -59.66587), Q(323.78677, -73.19013, 344.47095, -91.48766), L(344.47095, -392.9992), L(287.1917, -405.7279), L(287.1917, -440.73187), L(408.11453, -451.07397), L(408.9101, -451.07397), L(427.2076, -441.52744), L(427.2076, -40.572792), L(474.9403, -40.572792), L(474.14478, -4.7732697), Q(451.8695, 0.7955449, 435.56085, 3.9777246), Q(419.25217, 7.1599045, 397.77246, 7.1599045), Q(375.4972, 7.1599045, 364.75735, -1.5910898), Z, M(178.9976, -545.74384), L(306.2848, -704.05725), L(366.74622, -653.1424), Q(350.03976, -626.0939, 295.14716, -579.1567), Q(240.25456, -532.21954, 210.02386, -519.49084), L(178.9976, -545.74384), Z];
+pub const MERRIWEATHER_REGULAR_250: &'static [PathSegment] = &[M(364.75735, -1.5910898), Q(354.0175, -10.342084, 354.0175, -30.230707), L(354.0175, -57.279236), Q(327.7645, -31.026253, 288.38504, -11.137629), Q(249.00555, 8.750994, 209.22832, 8.750994), Q(135.24263, 8.750994, 104.216385, -31.026253), Q(73.19013, -70.8035, 73.19013, -162.29117), L(73.19013, -392.9992), L(28.639618, -405.7279), L(28.639618, -440.73187), L(140.0159, -451.07397), L(140.81145, -451.07397), L(155.9268, -441.52744), L(155.9268, -168.65552), Q(155.9268, -122.513916, 162.68893, -96.65871), Q(169.45107, -70.8035, 186.55528, -58.472553), Q(203.6595, -46.141605, 236.27684, -46.141605), Q(268.09863, -46.141605, 295.94272, -59.66587), Q(323.78677, -73.19013, 344.47095, -91.48766), L(344.47095, -392.9992), L(287.1917, -405.7279), L(287.1917, -440.73187), L(408.11453, -451.07397), L(408.9101, -451.07397), L(427.2076, -441.52744), L(427.2076, -40.572792), L(474.9403, -40.572792), L(474.14478, -4.7732697), Q(451.8695, 0.7955449, 435.56085, 3.9777246), Q(419.25217, 7.1599045, 397.77246, 7.1599045), Q(375.4972, 7.1599045, 364.75735, -1.5910898), Z, M(178.9976, -545.74384), L(306.2848, -704.05725), L(366.74622, -653.1424), Q(350.03976, -626.0939, 295.14716, -579.1567), Q(240.25456, -532.21954, 210.02386, -519.49084), L(178.9976, -545.74384), Z];
 
 // // û ------------------------------
-// pub const MERRIWEATHER_REGULAR_251: &'static [PathSegment] = &[M(364.75735, -1.5910898), Q(354.0175, -10.342084, 354.0175, -30.230707), L(354.0175, -57.279236), Q(327.7645, -31.026253, 288.38504, -11.137629), Q(249.00555, 8.750994, 209.22832, 8.750994), Q(135.24263, 8.750994, 104.216385, -31.026253), Q(73.19013, -70.8035, 73.19013, -162.29117), L(73.19013, -392.9992), L(28.639618, -405.7279), L(28.639618, -440.73187), L(140.0159, -451.07397), L(140.81145, -451.07397), L(155.9268, -441.52744), L(155.9268, -168.65552), Q(155.9268, -122.513916, 162.68893, -96.65871), Q(169.45107, -70.8035, 186.55528, -58.472553), Q(203.6595, -46.141605, 236.27684, -46.141605), Q(268.09863, -46.141605, 295.94272, -59.66587), Q(323.78677, -73.19013, 344.47095, -91.48766), L(344.47095, -392.9992), L(287.1917, -405.7279), L(287.1917, -440.73187), L(408.11453, -451.07397), L(408.9101, -451.07397), L(427.2076, -441.52744), L(427.2076, -40.572792), L(474.9403, -40.572792), L(474.14478, -4.7732697), Q(451.8695, 0.7955449, 435.56085, 3.9777246), Q(419.25217, 7.1599045, 397.77246, 7.1599045), Q(375.4972, 7.1599045, 364.75735, -1.5910898), Z, M(272.87192, -684.16864), L(384.2482, -529.03735), L(352.4264, -506.76212), L(241.05011, -621.32056), L(125.6961, -506.76212), L(95.46539, -528.2418), L(209.22832, -684.16864), L(272.87192, -684.16864), Z];
+pub const MERRIWEATHER_REGULAR_251: &'static [PathSegment] = &[M(364.75735, -1.5910898), Q(354.0175, -10.342084, 354.0175, -30.230707), L(354.0175, -57.279236), Q(327.7645, -31.026253, 288.38504, -11.137629), Q(249.00555, 8.750994, 209.22832, 8.750994), Q(135.24263, 8.750994, 104.216385, -31.026253), Q(73.19013, -70.8035, 73.19013, -162.29117), L(73.19013, -392.9992), L(28.639618, -405.7279), L(28.639618, -440.73187), L(140.0159, -451.07397), L(140.81145, -451.07397), L(155.9268, -441.52744), L(155.9268, -168.65552), Q(155.9268, -122.513916, 162.68893, -96.65871), Q(169.45107, -70.8035, 186.55528, -58.472553), Q(203.6595, -46.141605, 236.27684, -46.141605), Q(268.09863, -46.141605, 295.94272, -59.66587), Q(323.78677, -73.19013, 344.47095, -91.48766), L(344.47095, -392.9992), L(287.1917, -405.7279), L(287.1917, -440.73187), L(408.11453, -451.07397), L(408.9101, -451.07397), L(427.2076, -441.52744), L(427.2076, -40.572792), L(474.9403, -40.572792), L(474.14478, -4.7732697), Q(451.8695, 0.7955449, 435.56085, 3.9777246), Q(419.25217, 7.1599045, 397.77246, 7.1599045), Q(375.4972, 7.1599045, 364.75735, -1.5910898), Z, M(272.87192, -684.16864), L(384.2482, -529.03735), L(352.4264, -506.76212), L(241.05011, -621.32056), L(125.6961, -506.76212), L(95.46539, -528.2418), L(209.22832, -684.16864), L(272.87192, -684.16864), Z];
 
 // // ü ------------------------------
-// pub const MERRIWEATHER_REGULAR_252: &'static [PathSegment] = &[M(364.75735, -1.5910898), Q(354.0175, -10.342084, 354.0175, -30.230707), L(354.0175, -57.279236), Q(327.7645, -31.026253, 288.38504, -11.137629), Q(249.00555, 8.750994, 209.22832, 8.750994), Q(135.24263, 8.750994, 104.216385, -31.026253), Q(73.19013, -70.8035, 73.19013, -162.29117), L(73.19013, -392.9992), L(28.639618, -405.7279), L(28.639618, -440.73187), L(140.0159, -451.07397), L(140.81145, -451.07397), L(155.9268, -441.52744), L(155.9268, -168.65552), Q(155.9268, -122.513916, 162.68893, -96.65871), Q(169.45107, -70.8035, 186.55528, -58.472553), Q(203.6595, -46.141605, 236.27684, -46.141605), Q(268.09863, -46.141605, 295.94272, -59.66587), Q(323.78677, -73.19013, 344.47095, -91.48766), L(344.47095, -392.9992), L(287.1917, -405.7279), L(287.1917, -440.73187), L(408.11453, -451.07397), L(408.9101, -451.07397), L(427.2076, -441.52744), L(427.2076, -40.572792), L(474.9403, -40.572792), L(474.14478, -4.7732697), Q(451.8695, 0.7955449, 435.56085, 3.9777246), Q(419.25217, 7.1599045, 397.77246, 7.1599045), Q(375.4972, 7.1599045, 364.75735, -1.5910898), Z, M(113.36515, -536.9928), Q(101.0342, -551.3126, 101.0342, -572.79236), Q(101.0342, -597.4542, 114.55847, -614.5585), Q(128.08273, -631.66266, 153.54018, -631.66266), Q(175.81543, -631.66266, 188.54414, -616.5473), Q(201.27287, -601.43195, 201.27287, -580.7478), Q(201.27287, -555.29034, 187.7486, -538.9817), Q(174.22433, -522.67303, 147.17581, -522.67303), Q(125.6961, -522.67303, 113.36515, -536.9928), Z, M(293.95386, -537.3906), Q(281.6229, -552.10815, 281.6229, -572.79236), Q(281.6229, -597.4542, 295.14716, -614.5585), Q(308.67142, -631.66266, 334.12888, -631.66266), Q(356.4041, -631.66266, 368.73508, -616.5473), Q(381.066, -601.43195, 381.066, -580.7478), Q(381.066, -555.29034, 367.54175, -538.9817), Q(354.0175, -522.67303, 326.17343, -522.67303), Q(306.2848, -522.67303, 293.95386, -537.3906), Z];
+pub const MERRIWEATHER_REGULAR_252: &'static [PathSegment] = &[M(364.75735, -1.5910898), Q(354.0175, -10.342084, 354.0175, -30.230707), L(354.0175, -57.279236), Q(327.7645, -31.026253, 288.38504, -11.137629), Q(249.00555, 8.750994, 209.22832, 8.750994), Q(135.24263, 8.750994, 104.216385, -31.026253), Q(73.19013, -70.8035, 73.19013, -162.29117), L(73.19013, -392.9992), L(28.639618, -405.7279), L(28.639618, -440.73187), L(140.0159, -451.07397), L(140.81145, -451.07397), L(155.9268, -441.52744), L(155.9268, -168.65552), Q(155.9268, -122.513916, 162.68893, -96.65871), Q(169.45107, -70.8035, 186.55528, -58.472553), Q(203.6595, -46.141605, 236.27684, -46.141605), Q(268.09863, -46.141605, 295.94272, -59.66587), Q(323.78677, -73.19013, 344.47095, -91.48766), L(344.47095, -392.9992), L(287.1917, -405.7279), L(287.1917, -440.73187), L(408.11453, -451.07397), L(408.9101, -451.07397), L(427.2076, -441.52744), L(427.2076, -40.572792), L(474.9403, -40.572792), L(474.14478, -4.7732697), Q(451.8695, 0.7955449, 435.56085, 3.9777246), Q(419.25217, 7.1599045, 397.77246, 7.1599045), Q(375.4972, 7.1599045, 364.75735, -1.5910898), Z, M(113.36515, -536.9928), Q(101.0342, -551.3126, 101.0342, -572.79236), Q(101.0342, -597.4542, 114.55847, -614.5585), Q(128.08273, -631.66266, 153.54018, -631.66266), Q(175.81543, -631.66266, 188.54414, -616.5473), Q(201.27287, -601.43195, 201.27287, -580.7478), Q(201.27287, -555.29034, 187.7486, -538.9817), Q(174.22433, -522.67303, 147.17581, -522.67303), Q(125.6961, -522.67303, 113.36515, -536.9928), Z, M(293.95386, -537.3906), Q(281.6229, -552.10815, 281.6229, -572.79236), Q(281.6229, -597.4542, 295.14716, -614.5585), Q(308.67142, -631.66266, 334.12888, -631.66266), Q(356.4041, -631.66266, 368.73508, -616.5473), Q(381.066, -601.43195, 381.066, -580.7478), Q(381.066, -555.29034, 367.54175, -538.9817), Q(354.0175, -522.67303, 326.17343, -522.67303), Q(306.2848, -522.67303, 293.95386, -537.3906), Z];
 
 // // ý ------------------------------
-// pub const MERRIWEATHER_REGULAR_253: &'static [PathSegment] = &[M(73.587906, 196.4996), Q(60.461414, 194.90851, 54.8926, 191.72633), L(54.8926, 122.513916), Q(59.66587, 125.6961, 73.587906, 127.287186), Q(87.50994, 128.87828, 100.23866, 128.87828), Q(138.42482, 128.87828, 167.4622, 102.22752), Q(196.4996, 75.57677, 223.54813, -0.0), L(185.36197, -0.0), L(34.20843, -393.79474), L(-2.3866348, -404.1368), L(-2.3866348, -441.52744), L(189.33969, -441.52744), L(189.33969, -404.1368), L(128.08273, -394.59027), L(206.04613, -159.90453), L(242.6412, -43.75497), L(276.84964, -160.70007), L(347.65314, -394.59027), L(294.35162, -404.1368), L(294.35162, -441.52744), L(454.25616, -441.52744), L(454.25616, -404.1368), L(414.4789, -394.59027), Q(297.5338, -36.595066, 276.84964, 11.933174), Q(249.8011, 80.35004, 227.52585, 118.93397), Q(205.2506, 157.5179, 175.01988, 177.80429), Q(144.78917, 198.09068, 100.23866, 198.09068), Q(86.71439, 198.09068, 73.587906, 196.4996), Z, M(176.61098, -545.74384), L(303.89816, -704.05725), L(364.3596, -653.1424), Q(347.65314, -626.0939, 292.76053, -579.1567), Q(237.86794, -532.21954, 207.63722, -519.49084), L(176.61098, -545.74384), Z];
+pub const MERRIWEATHER_REGULAR_253: &'static [PathSegment] = &[M(73.587906, 196.4996), Q(60.461414, 194.90851, 54.8926, 191.72633), L(54.8926, 122.513916), Q(59.66587, 125.6961, 73.587906, 127.287186), Q(87.50994, 128.87828, 100.23866, 128.87828), Q(138.42482, 128.87828, 167.4622, 102.22752), Q(196.4996, 75.57677, 223.54813, -0.0), L(185.36197, -0.0), L(34.20843, -393.79474), L(-2.3866348, -404.1368), L(-2.3866348, -441.52744), L(189.33969, -441.52744), L(189.33969, -404.1368), L(128.08273, -394.59027), L(206.04613, -159.90453), L(242.6412, -43.75497), L(276.84964, -160.70007), L(347.65314, -394.59027), L(294.35162, -404.1368), L(294.35162, -441.52744), L(454.25616, -441.52744), L(454.25616, -404.1368), L(414.4789, -394.59027), Q(297.5338, -36.595066, 276.84964, 11.933174), Q(249.8011, 80.35004, 227.52585, 118.93397), Q(205.2506, 157.5179, 175.01988, 177.80429), Q(144.78917, 198.09068, 100.23866, 198.09068), Q(86.71439, 198.09068, 73.587906, 196.4996), Z, M(176.61098, -545.74384), L(303.89816, -704.05725), L(364.3596, -653.1424), Q(347.65314, -626.0939, 292.76053, -579.1567), Q(237.86794, -532.21954, 207.63722, -519.49084), L(176.61098, -545.74384), Z];
 
 // // þ ------------------------------
-// pub const MERRIWEATHER_REGULAR_254: &'static [PathSegment] = &[M(127.287186, -651.5513), L(128.87828, -651.5513), L(147.17581, -639.6181), L(147.17581, -400.1591), Q(169.45107, -422.43436, 203.26173, -437.94748), Q(237.07239, -453.4606, 276.84964, -453.4606), Q(324.58234, -453.4606, 363.9618, -430.3898), Q(403.34128, -407.319, 426.80984, -358.79077), Q(450.2784, -310.2625, 450.2784, -236.27684), Q(450.2784, -167.85999, 422.0366, -112.17184), Q(393.79474, -56.48369, 341.68655, -23.866348), Q(289.57834, 8.750994, 220.36594, 8.750994), Q(183.77087, 8.750994, 144.78917, 1.5910898), L(146.38026, 52.505966), L(147.17581, 147.17581), L(233.89021, 156.72235), L(233.89021, 192.52187), L(6.3643594, 192.52187), L(6.3643594, 156.72235), L(64.43914, 147.17581), L(64.43914, -594.27203), L(-0.7955449, -603.0231), L(-0.7955449, -636.4359), L(127.287186, -651.5513), Z, M(188.54414, -382.6571), Q(162.29117, -368.3373, 147.17581, -351.63086), L(147.17581, -58.870323), Q(151.94908, -47.732697, 174.62212, -40.970562), Q(197.29514, -34.20843, 225.1392, -34.20843), Q(287.1917, -34.20843, 324.18457, -83.92999), Q(361.1774, -133.65155, 361.1774, -230.70802), Q(361.1774, -314.24023, 328.56006, -355.60858), Q(295.94272, -396.97693, 244.23228, -396.97693), Q(214.79713, -396.97693, 188.54414, -382.6571), Z];
+pub const MERRIWEATHER_REGULAR_254: &'static [PathSegment] = &[M(127.287186, -651.5513), L(128.87828, -651.5513), L(147.17581, -639.6181), L(147.17581, -400.1591), Q(169.45107, -422.43436, 203.26173, -437.94748), Q(237.07239, -453.4606, 276.84964, -453.4606), Q(324.58234, -453.4606, 363.9618, -430.3898), Q(403.34128, -407.319, 426.80984, -358.79077), Q(450.2784, -310.2625, 450.2784, -236.27684), Q(450.2784, -167.85999, 422.0366, -112.17184), Q(393.79474, -56.48369, 341.68655, -23.866348), Q(289.57834, 8.750994, 220.36594, 8.750994), Q(183.77087, 8.750994, 144.78917, 1.5910898), L(146.38026, 52.505966), L(147.17581, 147.17581), L(233.89021, 156.72235), L(233.89021, 192.52187), L(6.3643594, 192.52187), L(6.3643594, 156.72235), L(64.43914, 147.17581), L(64.43914, -594.27203), L(-0.7955449, -603.0231), L(-0.7955449, -636.4359), L(127.287186, -651.5513), Z, M(188.54414, -382.6571), Q(162.29117, -368.3373, 147.17581, -351.63086), L(147.17581, -58.870323), Q(151.94908, -47.732697, 174.62212, -40.970562), Q(197.29514, -34.20843, 225.1392, -34.20843), Q(287.1917, -34.20843, 324.18457, -83.92999), Q(361.1774, -133.65155, 361.1774, -230.70802), Q(361.1774, -314.24023, 328.56006, -355.60858), Q(295.94272, -396.97693, 244.23228, -396.97693), Q(214.79713, -396.97693, 188.54414, -382.6571), Z];
 
 #[cfg(test)]
 mod tests2 {
@@ -0,0 +1,131 @@
+use crate::render::fonts::truetype::{FontError, LoadedFont, FONT_EM_SIZE};
+use graphics::path::PathSegment;
+use graphics::path::PathSegment::*;
+use std::fmt::Write;
+use std::ops::RangeInclusive;
+
+// Basic Latin, Latin-1 Supplement and Latin Extended-A
+pub const DEFAULT_CHAR_RANGES: [RangeInclusive<u32>; 2] = [32..=126, 160..=383];
+
+// Source of a glyph module in the shape of the compiled glyph tables:
+// get_path/get_glyph for outlines, get_advance for advance widths and get_kerning for kerning pairs.
+// Coordinates are in FONT_EM_SIZE units with the baseline at y = 0 and y pointing down.
+// Fails when the font has NaN or infinite metrics or coordinates, which would not compile.
+pub fn generate_module(font: &LoadedFont, module_name: &str, char_ranges: &[RangeInclusive<u32>]) -> Result<String, FontError> {
+    let prefix = module_name.to_uppercase();
+    let chars: Vec<char> = char_ranges.iter().flat_map(|range| range.clone()).filter_map(char::from_u32).filter(|c| font.has_glyph(*c)).collect();
+
+    let mut s = String::new();
+    writeln!(s, "// Generated by fontgen from {}, do not edit", font.name).unwrap();
+    writeln!(s, "use graphics::path::PathSegment;").unwrap();
+    writeln!(s, "use graphics::path::PathSegment::*;").unwrap();
+    writeln!(s).unwrap();
+
+    // outlines
+    writeln!(s, "// Glyph outline, falling back to the notdef glyph for characters the font does not cover").unwrap();
+    writeln!(s, "pub fn get_path(c: char) -> &'static [PathSegment] {{").unwrap();
+    writeln!(s, "    get_glyph(c).unwrap_or({}_NOTDEF)", prefix).unwrap();
+    writeln!(s, "}}").unwrap();
+    writeln!(s).unwrap();
+    writeln!(s, "pub fn get_glyph(c: char) -> Option<&'static [PathSegment]> {{").unwrap();
+    writeln!(s, "    let path = match c as u32 {{").unwrap();
+    for c in chars.iter() {
+        writeln!(s, "        {} => {}_{},", *c as u32, prefix, *c as u32).unwrap();
+    }
+    writeln!(s, "        _ => return None,").unwrap();
+    writeln!(s, "    }};").unwrap();
+    writeln!(s, "    Some(path)").unwrap();
+    writeln!(s, "}}").unwrap();
+    writeln!(s).unwrap();
+
+    // metrics
+    writeln!(s, "pub fn get_advance(c: char) -> Option<f32> {{").unwrap();
+    writeln!(s, "    let advance = match c as u32 {{").unwrap();
+    for c in chars.iter() {
+        if let Some(advance) = font.get_advance(*c) {
+            writeln!(s, "        {} => {},", *c as u32, format_value(advance)?).unwrap();
+        }
+    }
+    writeln!(s, "        _ => return None,").unwrap();
+    writeln!(s, "    }};").unwrap();
+    writeln!(s, "    Some(advance)").unwrap();
+    writeln!(s, "}}").unwrap();
+    writeln!(s).unwrap();
+    writeln!(s, "pub fn get_kerning(left: char, right: char) -> f32 {{").unwrap();
+    writeln!(s, "    match (left as u32, right as u32) {{").unwrap();
+    for (left, right, kerning) in font.get_kerning_pairs(&chars) {
+        writeln!(s, "        ({}, {}) => {},", left as u32, right as u32, format_value(kerning)?).unwrap();
+    }
+    writeln!(s, "        _ => 0.0,").unwrap();
+    writeln!(s, "    }}").unwrap();
+    writeln!(s, "}}").unwrap();
+    writeln!(s).unwrap();
+
+    // glyph tables
+    let notdef = font.get_notdef_path().filter(|path| !path.is_empty()).unwrap_or_else(get_notdef_box);
+    write_glyph_const(&mut s, &format!("{}_NOTDEF", prefix), "notdef", &notdef)?;
+    for c in chars.iter() {
+        let path = font.get_path(*c).unwrap_or_default();
+        write_glyph_const(&mut s, &format!("{}_{}", prefix, *c as u32), &c.to_string(), &path)?;
+    }
+    Ok(s)
+}
+
+fn write_glyph_const(s: &mut String, name: &str, label: &str, path: &[PathSegment]) -> Result<(), FontError> {
+    writeln!(s, "// {} ------------------------------", label).unwrap();
+    writeln!(s, "pub const {}: &'static [PathSegment] = &[", name).unwrap();
+    for segment in path {
+        let segment = format_segment(segment).map_err(|err| FontError::Parse(format!("{} in glyph {}", err, label)))?;
+        writeln!(s, "    {},", segment).unwrap();
+    }
+    writeln!(s, "];").unwrap();
+    writeln!(s).unwrap();
+    Ok(())
+}
+
+pub fn format_segment(segment: &PathSegment) -> Result<String, FontError> {
+    let segment = match *segment {
+        M(x, y) => format!("M({}, {})", format_value(x)?, format_value(y)?),
+        L(x, y) => format!("L({}, {})", format_value(x)?, format_value(y)?),
+        Q(x1, y1, x, y) => format!("Q({}, {}, {}, {})", format_value(x1)?, format_value(y1)?, format_value(x)?, format_value(y)?),
+        C(x1, y1, x2, y2, x, y) => format!(
+            "C({}, {}, {}, {}, {}, {})",
+            format_value(x1)?,
+            format_value(y1)?,
+            format_value(x2)?,
+            format_value(y2)?,
+            format_value(x)?,
+            format_value(y)?
+        ),
+        _ => "Z".to_string(),
+    };
+    Ok(segment)
+}
+
+// Float literal, NaN and infinity have none
+fn format_value(value: f32) -> Result<String, FontError> {
+    match value.is_finite() {
+        true => Ok(format!("{:?}", value)),
+        false => Err(FontError::Parse(format!("non-finite value {}", value))),
+    }
+}
+
+// Box used when the font has no outline for glyph 0
+fn get_notdef_box() -> Vec<PathSegment> {
+    let (x1, x2) = (FONT_EM_SIZE * 0.08, FONT_EM_SIZE * 0.6);
+    let (y1, y2) = (0.0, -FONT_EM_SIZE * 0.7);
+    vec![M(x1, y1), L(x2, y1), L(x2, y2), L(x1, y2), Z]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_segments_rejects_non_finite() {
+        assert_eq!(format_segment(&Q(1.5, -2.0, 3.0, -0.0)).unwrap(), "Q(1.5, -2.0, 3.0, -0.0)");
+        assert_eq!(format_segment(&Z).unwrap(), "Z");
+        assert!(matches!(format_segment(&L(f32::NAN, 0.0)), Err(FontError::Parse(_))));
+        assert!(matches!(format_segment(&M(0.0, f32::INFINITY)), Err(FontError::Parse(_))));
+    }
+}
@@ -0,0 +1,346 @@
+use crate::render::fonts::merriweather_regular;
+use crate::render::fonts::metrics::{get_path_advance, get_path_bbox, GlyphBBox};
+use graphics::path::PathSegment;
+use graphics::path::PathSegment::*;
+use graphics::prelude::*;
+
+// Distance between base letter and mark, in em units
+const MARK_GAP: f32 = 40.0;
+const STROKE_THICKNESS: f32 = 45.0;
+const BREVE_THICKNESS: f32 = 40.0;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Diacritic {
+    Acute,
+    Breve,
+    Caron,
+    Cedilla,
+    Circumflex,
+    Diaeresis,
+    DotAbove,
+    DoubleAcute,
+    Macron,
+    MiddleDot,
+    Ogonek,
+    Ring,
+    Stroke,
+    Tilde,
+    // Second letter of a ligature, set right after the base
+    Ligature(char),
+    // No mark, the base letter is the closest available shape
+    None,
+}
+
+// Latin Extended-A, U+0100 to U+017F, as base letter and diacritic
+const LATIN_EXTENDED_A: [(char, Diacritic); 128] = [
+    ('A', Diacritic::Macron),
+    ('a', Diacritic::Macron),
+    ('A', Diacritic::Breve),
+    ('a', Diacritic::Breve),
+    ('A', Diacritic::Ogonek),
+    ('a', Diacritic::Ogonek),
+    ('C', Diacritic::Acute),
+    ('c', Diacritic::Acute),
+    ('C', Diacritic::Circumflex),
+    ('c', Diacritic::Circumflex),
+    ('C', Diacritic::DotAbove),
+    ('c', Diacritic::DotAbove),
+    ('C', Diacritic::Caron),
+    ('c', Diacritic::Caron),
+    ('D', Diacritic::Caron),
+    ('d', Diacritic::Caron),
+    ('D', Diacritic::Stroke),
+    ('d', Diacritic::Stroke),
+    ('E', Diacritic::Macron),
+    ('e', Diacritic::Macron),
+    ('E', Diacritic::Breve),
+    ('e', Diacritic::Breve),
+    ('E', Diacritic::DotAbove),
+    ('e', Diacritic::DotAbove),
+    ('E', Diacritic::Ogonek),
+    ('e', Diacritic::Ogonek),
+    ('E', Diacritic::Caron),
+    ('e', Diacritic::Caron),
+    ('G', Diacritic::Circumflex),
+    ('g', Diacritic::Circumflex),
+    ('G', Diacritic::Breve),
+    ('g', Diacritic::Breve),
+    ('G', Diacritic::DotAbove),
+    ('g', Diacritic::DotAbove),
+    ('G', Diacritic::Cedilla),
+    ('g', Diacritic::Acute),
+    ('H', Diacritic::Circumflex),
+    ('h', Diacritic::Circumflex),
+    ('H', Diacritic::Stroke),
+    ('h', Diacritic::Stroke),
+    ('I', Diacritic::Tilde),
+    ('i', Diacritic::Tilde),
+    ('I', Diacritic::Macron),
+    ('i', Diacritic::Macron),
+    ('I', Diacritic::Breve),
+    ('i', Diacritic::Breve),
+    ('I', Diacritic::Ogonek),
+    ('i', Diacritic::Ogonek),
+    ('I', Diacritic::DotAbove),
+    ('i', Diacritic::None),
+    ('I', Diacritic::Ligature('J')),
+    ('i', Diacritic::Ligature('j')),
+    ('J', Diacritic::Circumflex),
+    ('j', Diacritic::Circumflex),
+    ('K', Diacritic::Cedilla),
+    ('k', Diacritic::Cedilla),
+    ('k', Diacritic::None),
+    ('L', Diacritic::Acute),
+    ('l', Diacritic::Acute),
+    ('L', Diacritic::Cedilla),
+    ('l', Diacritic::Cedilla),
+    ('L', Diacritic::Caron),
+    ('l', Diacritic::Caron),
+    ('L', Diacritic::MiddleDot),
+    ('l', Diacritic::MiddleDot),
+    ('L', Diacritic::Stroke),
+    ('l', Diacritic::Stroke),
+    ('N', Diacritic::Acute),
+    ('n', Diacritic::Acute),
+    ('N', Diacritic::Cedilla),
+    ('n', Diacritic::Cedilla),
+    ('N', Diacritic::Caron),
+    ('n', Diacritic::Caron),
+    ('\'', Diacritic::Ligature('n')),
+    ('N', Diacritic::None),
+    ('n', Diacritic::None),
+    ('O', Diacritic::Macron),
+    ('o', Diacritic::Macron),
+    ('O', Diacritic::Breve),
+    ('o', Diacritic::Breve),
+    ('O', Diacritic::DoubleAcute),
+    ('o', Diacritic::DoubleAcute),
+    ('O', Diacritic::Ligature('E')),
+    ('o', Diacritic::Ligature('e')),
+    ('R', Diacritic::Acute),
+    ('r', Diacritic::Acute),
+    ('R', Diacritic::Cedilla),
+    ('r', Diacritic::Cedilla),
+    ('R', Diacritic::Caron),
+    ('r', Diacritic::Caron),
+    ('S', Diacritic::Acute),
+    ('s', Diacritic::Acute),
+    ('S', Diacritic::Circumflex),
+    ('s', Diacritic::Circumflex),
+    ('S', Diacritic::Cedilla),
+    ('s', Diacritic::Cedilla),
+    ('S', Diacritic::Caron),
+    ('s', Diacritic::Caron),
+    ('T', Diacritic::Cedilla),
+    ('t', Diacritic::Cedilla),
+    ('T', Diacritic::Caron),
+    ('t', Diacritic::Caron),
+    ('T', Diacritic::Stroke),
+    ('t', Diacritic::Stroke),
+    ('U', Diacritic::Tilde),
+    ('u', Diacritic::Tilde),
+    ('U', Diacritic::Macron),
+    ('u', Diacritic::Macron),
+    ('U', Diacritic::Breve),
+    ('u', Diacritic::Breve),
+    ('U', Diacritic::Ring),
+    ('u', Diacritic::Ring),
+    ('U', Diacritic::DoubleAcute),
+    ('u', Diacritic::DoubleAcute),
+    ('U', Diacritic::Ogonek),
+    ('u', Diacritic::Ogonek),
+    ('W', Diacritic::Circumflex),
+    ('w', Diacritic::Circumflex),
+    ('Y', Diacritic::Circumflex),
+    ('y', Diacritic::Circumflex),
+    ('Y', Diacritic::Diaeresis),
+    ('Z', Diacritic::Acute),
+    ('z', Diacritic::Acute),
+    ('Z', Diacritic::DotAbove),
+    ('z', Diacritic::DotAbove),
+    ('Z', Diacritic::Caron),
+    ('z', Diacritic::Caron),
+    ('f', Diacritic::None),
+];
+
+pub fn decompose(c: char) -> Option<(char, Diacritic)> {
+    match c as u32 {
+        0x0100..=0x017F => Some(LATIN_EXTENDED_A[c as usize - 0x0100]),
+        _ => None,
+    }
+}
+
+// Outline of a character in the compiled text font, composing Latin Extended-A letters from base letter and mark.
+// Characters that can not be drawn get the fallback glyph and a diagnostic naming the code point.
+pub fn get_latin_path(c: char) -> Vec<PathSegment> {
+    if let Some(path) = merriweather_regular::get_glyph(c) {
+        return path.to_vec();
+    }
+    if let Some((base, diacritic)) = decompose(c) {
+        if let Some(path) = compose(base, diacritic) {
+            return path;
+        }
+    }
+    merriweather_regular::get_path(c).to_vec()
+}
+
+fn compose(base: char, diacritic: Diacritic) -> Option<Vec<PathSegment>> {
+    let base_path = merriweather_regular::get_glyph(base)?;
+    let bbox = get_path_bbox(base_path)?;
+    let mut p = PathSegments(base_path.to_vec());
+
+    // carons on letters with ascenders are written as an apostrophe
+    if diacritic == Diacritic::Caron && matches!(base, 'd' | 'l' | 't' | 'L') {
+        let mark = PathSegments(merriweather_regular::get_glyph('\'')?.to_vec());
+        p.extend(&mark.move_path(bbox.x_max + MARK_GAP * 0.5, 0.0));
+        return Some(p.0);
+    }
+
+    match diacritic {
+        Diacritic::None => {}
+        Diacritic::Ligature(next) => {
+            let next = merriweather_regular::get_glyph(next)?;
+            let advance = merriweather_regular::get_advance(base).unwrap_or_else(|| get_path_advance(base_path));
+            p.extend(&PathSegments(next.to_vec()).move_path(advance, 0.0));
+        }
+        Diacritic::Stroke => {
+            p.extend(&PathSegments(get_stroke_path(base, &bbox)));
+        }
+        Diacritic::Breve => {
+            p.extend(&PathSegments(get_breve_path(&bbox)));
+        }
+        Diacritic::MiddleDot => {
+            let mark = merriweather_regular::get_glyph('·')?;
+            p.extend(&PathSegments(mark.to_vec()).move_path(bbox.x_max, 0.0));
+        }
+        Diacritic::Cedilla | Diacritic::Ogonek => {
+            // marks below keep their vertical position from the font
+            let mark = merriweather_regular::get_glyph('¸')?;
+            let mark_bbox = get_path_bbox(mark)?;
+            let mark = match diacritic {
+                Diacritic::Ogonek => mirror_x(mark, &mark_bbox).move_path(bbox.x_max - mark_bbox.x_max, 0.0),
+                _ => PathSegments(mark.to_vec()).move_path(center_x(&bbox) - center_x(&mark_bbox), 0.0),
+            };
+            p.extend(&mark);
+        }
+        _ => {
+            let (mark_char, caron) = match diacritic {
+                Diacritic::Acute | Diacritic::DoubleAcute => ('´', false),
+                Diacritic::Circumflex => ('^', false),
+                Diacritic::Caron => ('^', true),
+                Diacritic::Diaeresis => ('¨', false),
+                Diacritic::DotAbove => ('.', false),
+                Diacritic::Macron => ('¯', false),
+                Diacritic::Ring => ('°', false),
+                _ => ('~', false),
+            };
+            let mark = match mark_char {
+                // the ascii circumflex is too large to be used as a mark
+                '^' => get_mark_contours('â', 'a')?,
+                _ => merriweather_regular::get_glyph(mark_char)?.to_vec(),
+            };
+            let mark_bbox = get_path_bbox(&mark)?;
+            let mark = match caron {
+                true => mirror_y(&mark, &mark_bbox),
+                false => PathSegments(mark),
+            };
+            // above the base, centered
+            let dy = bbox.y_min - MARK_GAP - mark_bbox.y_max;
+            match diacritic {
+                Diacritic::DoubleAcute => {
+                    let offset = mark_bbox.width() * 0.4;
+                    p.extend(&mark.clone().move_path(center_x(&bbox) - center_x(&mark_bbox) - offset, dy));
+                    p.extend(&mark.move_path(center_x(&bbox) - center_x(&mark_bbox) + offset, dy));
+                }
+                _ => p.extend(&mark.move_path(center_x(&bbox) - center_x(&mark_bbox), dy)),
+            }
+        }
+    }
+    Some(p.0)
+}
+
+// Contours of a precomposed letter lying entirely above its base letter
+fn get_mark_contours(composed: char, base: char) -> Option<Vec<PathSegment>> {
+    let base_bbox = get_path_bbox(merriweather_regular::get_glyph(base)?)?;
+    let mut mark = vec![];
+    let mut contour: Vec<PathSegment> = vec![];
+    for segment in merriweather_regular::get_glyph(composed)?.iter().chain([M(0.0, 0.0)].iter()) {
+        if matches!(segment, M(_, _)) && !contour.is_empty() {
+            if get_path_bbox(&contour).is_some_and(|bbox| bbox.y_max < base_bbox.y_min) {
+                mark.extend(contour.iter().cloned());
+            }
+            contour.clear();
+        }
+        contour.push(segment.clone());
+    }
+    match mark.is_empty() {
+        true => None,
+        false => Some(mark),
+    }
+}
+
+fn center_x(bbox: &GlyphBBox) -> f32 {
+    bbox.x_min + bbox.width() / 2.0
+}
+
+fn mirror_x(path: &[PathSegment], bbox: &GlyphBBox) -> PathSegments {
+    let center = center_x(bbox);
+    PathSegments(path.to_vec()).move_path(-center, 0.0).scale_path(-1.0, 1.0).move_path(center, 0.0)
+}
+
+fn mirror_y(path: &[PathSegment], bbox: &GlyphBBox) -> PathSegments {
+    let center = bbox.y_min + bbox.height() / 2.0;
+    PathSegments(path.to_vec()).move_path(0.0, -center).scale_path(1.0, -1.0).move_path(0.0, center)
+}
+
+// Bar through the stem of đ, ħ, ŧ or diagonally through ł
+fn get_stroke_path(base: char, bbox: &GlyphBBox) -> Vec<PathSegment> {
+    let half = STROKE_THICKNESS / 2.0;
+    match base {
+        'L' | 'l' => {
+            let (x1, x2) = (bbox.x_min - MARK_GAP, bbox.x_min + bbox.width().min(300.0) * 0.8);
+            let (y1, y2) = (bbox.y_max - bbox.height() * 0.3, bbox.y_max - bbox.height() * 0.6);
+            vec![M(x1, y1 + half), L(x2, y2 + half), L(x2, y2 - half), L(x1, y1 - half), Z]
+        }
+        _ => {
+            let y = match base {
+                'd' | 'h' => bbox.y_min + bbox.height() * 0.2,
+                't' => bbox.y_max - bbox.height() * 0.55,
+                _ => bbox.y_min + bbox.height() * 0.5,
+            };
+            let (x1, x2) = match base {
+                'D' => (bbox.x_min - MARK_GAP, bbox.x_min + bbox.width() * 0.4),
+                _ => (bbox.x_min, bbox.x_max),
+            };
+            vec![M(x1, y - half), L(x2, y - half), L(x2, y + half), L(x1, y + half), Z]
+        }
+    }
+}
+
+fn get_breve_path(bbox: &GlyphBBox) -> Vec<PathSegment> {
+    let width = bbox.width().min(300.0) * 0.7;
+    let x1 = center_x(bbox) - width / 2.0;
+    let x2 = x1 + width;
+    let y = bbox.y_min - MARK_GAP - width / 2.0;
+    let depth = width / 2.0;
+    vec![
+        M(x1, y),
+        Q(x1 + width / 2.0, y + depth * 2.0, x2, y),
+        L(x2 - BREVE_THICKNESS / 2.0, y),
+        Q(x1 + width / 2.0, y + depth * 2.0 - BREVE_THICKNESS * 2.0, x1 + BREVE_THICKNESS / 2.0, y),
+        Z,
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn composed_latin_glyphs() {
+        assert_eq!(decompose('ā'), Some(('a', Diacritic::Macron)));
+        assert!(get_latin_path('å').len() > get_latin_path('a').len());
+        assert!(get_latin_path('ř').len() > get_latin_path('r').len());
+        assert_eq!(get_latin_path('\u{4e00}').len(), merriweather_regular::MERRIWEATHER_REGULAR_NOTDEF.len());
+    }
+}
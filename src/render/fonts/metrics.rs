@@ -0,0 +1,92 @@
+use crate::render::fonts::merriweather_regular;
+use crate::render::fonts::truetype::FONT_EM_SIZE;
+use graphics::path::PathSegment;
+use graphics::path::PathSegment::*;
+
+// Advance of the space character, the compiled glyph tables have no outline for it
+pub const SPACE_ADVANCE: f32 = FONT_EM_SIZE * 0.3;
+
+// Glyph bounding box in em units, y pointing down
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GlyphBBox {
+    pub x_min: f32,
+    pub y_min: f32,
+    pub x_max: f32,
+    pub y_max: f32,
+}
+
+impl GlyphBBox {
+    pub fn width(&self) -> f32 {
+        self.x_max - self.x_min
+    }
+
+    pub fn height(&self) -> f32 {
+        self.y_max - self.y_min
+    }
+}
+
+// Bounding box of an outline, including curve control points
+pub fn get_path_bbox(path: &[PathSegment]) -> Option<GlyphBBox> {
+    let mut points: Vec<(f32, f32)> = vec![];
+    for segment in path {
+        match *segment {
+            M(x, y) | L(x, y) => points.push((x, y)),
+            Q(x1, y1, x, y) => points.extend([(x1, y1), (x, y)]),
+            C(x1, y1, x2, y2, x, y) => points.extend([(x1, y1), (x2, y2), (x, y)]),
+            _ => {}
+        }
+    }
+    let (first_x, first_y) = *points.first()?;
+    let mut bbox = GlyphBBox {
+        x_min: first_x,
+        y_min: first_y,
+        x_max: first_x,
+        y_max: first_y,
+    };
+    for (x, y) in points {
+        bbox.x_min = bbox.x_min.min(x);
+        bbox.y_min = bbox.y_min.min(y);
+        bbox.x_max = bbox.x_max.max(x);
+        bbox.y_max = bbox.y_max.max(y);
+    }
+    Some(bbox)
+}
+
+// Advance for glyphs without hmtx metrics, such as the music glyphs:
+// the outline extent with the left side bearing repeated on the right
+pub fn get_path_advance(path: &[PathSegment]) -> f32 {
+    match get_path_bbox(path) {
+        Some(bbox) => bbox.x_max + bbox.x_min.max(0.0),
+        None => SPACE_ADVANCE,
+    }
+}
+
+// Advance of a glyph in the default compiled text font, from its hmtx advance when compiled in
+pub fn get_default_advance(c: char) -> f32 {
+    match (c, merriweather_regular::get_advance(c)) {
+        (_, Some(advance)) => advance,
+        (' ' | '\u{a0}', None) => SPACE_ADVANCE,
+        (_, None) => get_path_advance(&crate::render::fonts::latin::get_latin_path(c)),
+    }
+}
+
+// Width of a text run in the default compiled text font, advances plus kerning
+pub fn get_default_text_width(text: &str) -> f32 {
+    let chars: Vec<char> = text.chars().collect();
+    let kerning: f32 = chars.windows(2).map(|pair| merriweather_regular::get_kerning(pair[0], pair[1])).sum();
+    chars.iter().copied().map(get_default_advance).sum::<f32>() + kerning
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn path_bbox_and_advance() {
+        let bbox = get_path_bbox(&[M(10.0, -50.0), L(60.0, -50.0), Q(80.0, -20.0, 60.0, 0.0), Z]).unwrap();
+        assert_eq!(bbox, GlyphBBox { x_min: 10.0, y_min: -50.0, x_max: 80.0, y_max: 0.0 });
+        assert_eq!(get_path_advance(&[M(10.0, -50.0), L(80.0, 0.0)]), 90.0);
+        assert_eq!(get_path_advance(crate::render::fonts::merriweather_regular::get_path('\u{a0}')), SPACE_ADVANCE);
+        assert_eq!(get_default_advance('\u{a0}'), get_default_advance(' '));
+    }
+}
@@ -1,3 +1,9 @@
+pub mod codegen;
+pub mod latin;
 pub mod merriweather_regular;
+pub mod metrics;
 pub mod opensans_regular;
+pub mod registry;
+pub mod smufl;
 pub mod svg_symbols;
+pub mod truetype;
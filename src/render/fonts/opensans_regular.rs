@@ -1,8 +1,13 @@
 use graphics::path::PathSegment;
 use graphics::path::PathSegment::*;
 
+// Glyph outline, falling back to the notdef box for characters the font does not cover
 pub fn get_path(c: char) -> &'static [PathSegment] {
-    match c as u32 {
+    get_glyph(c).unwrap_or(OPENSANS_REGULAR_NOTDEF)
+}
+
+pub fn get_glyph(c: char) -> Option<&'static [PathSegment]> {
+    let path = match c as u32 {
         // 0 => OPENSANS_REGULAR_0,
         // 1 => OPENSANS_REGULAR_1,
         // 2 => OPENSANS_REGULAR_2,
@@ -163,7 +168,7 @@ pub fn get_path(c: char) -> &'static [PathSegment] {
         // 157 => OPENSANS_REGULAR_157,
         // 158 => OPENSANS_REGULAR_158,
         // 159 => OPENSANS_REGULAR_159,
-        // 160 => OPENSANS_REGULAR_160,
+        160 => OPENSANS_REGULAR_NBSP,
         // 161 => OPENSANS_REGULAR_161,
         // 162 => OPENSANS_REGULAR_162,
         // 163 => OPENSANS_REGULAR_163,
@@ -258,12 +263,29 @@ pub fn get_path(c: char) -> &'static [PathSegment] {
         // 252 => OPENSANS_REGULAR_252,
         // 253 => OPENSANS_REGULAR_253,
         // 254 => OPENSANS_REGULAR_254,
-        _ => todo!(),
-    }
+        _ => return None,
+    };
+    Some(path)
+}
+
+// Advance width from the hmtx table, in the units of the glyph tables.
+// These tables predate fontgen and carry no metrics yet, regenerate the module with fontgen from the font file to fill them.
+pub fn get_advance(_c: char) -> Option<f32> {
+    None
 }
 
+// Kerning of a pair from the kern/GPOS tables, 0.0 when the pair is not kerned
+pub fn get_kerning(_left: char, _right: char) -> f32 {
+    0.0
+}
+
+// no-break space, no outline and the advance of a space
+pub const OPENSANS_REGULAR_NBSP: &'static [PathSegment] = &[];
+
+pub const OPENSANS_REGULAR_NOTDEF: &'static [PathSegment] = OPENSANS_REGULAR_0;
+
 // //   ------------------------------
-// pub const OPENSANS_REGULAR_0: &'static [PathSegment] = &[M(69.20043, -524.2022), L(370.7422, -524.2022), L(370.7422, -0.0), L(69.20043, -0.0), L(69.20043, -524.2022), Z, M(106.48978, -37.28935), L(333.45285, -37.28935), L(333.45285, -486.91287), L(106.48978, -486.91287), L(106.48978, -37.28935), Z];
+pub const OPENSANS_REGULAR_0: &'static [PathSegment] = &[M(69.20043, -524.2022), L(370.7422, -524.2022), L(370.7422, -0.0), L(69.20043, -0.0), L(69.20043, -524.2022), Z, M(106.48978, -37.28935), L(333.45285, -37.28935), L(333.45285, -486.91287), L(106.48978, -486.91287), L(106.48978, -37.28935), Z];
 
 // //  ------------------------------
 // pub const OPENSANS_REGULAR_1: &'static [PathSegment] = &[M(69.20043, -524.2022), L(370.7422, -524.2022), L(370.7422, -0.0), L(69.20043, -0.0), L(69.20043, -524.2022), Z, M(106.48978, -37.28935), L(333.45285, -37.28935), L(333.45285, -486.91287), L(106.48978, -486.91287), L(106.48978, -37.28935), Z];
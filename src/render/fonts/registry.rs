@@ -0,0 +1,322 @@
+use crate::render::fonts::latin::get_latin_path;
+use crate::render::fonts::metrics::{get_default_advance, get_path_advance, get_path_bbox, GlyphBBox};
+use crate::render::fonts::truetype::LoadedFont;
+use crate::render::fonts::{merriweather_regular, opensans_regular};
+use graphics::path::PathSegment;
+use graphics::path::PathSegment::*;
+use graphics::prelude::*;
+use notation_rs::prelude::*;
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+// Outline width for synthetic bold, relative to the em size
+const SYNTHETIC_BOLD_STROKE: f32 = 30.0;
+// Horizontal shift per unit of height for synthetic italics
+const SYNTHETIC_ITALIC_SLANT: f32 = 0.2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TextRole {
+    Lyrics,
+    // Italic lyrics, as for refrains
+    Refrain,
+    Chord,
+    Function,
+    Title,
+    Tempo,
+    Fingering,
+    // Fret numbers and the TAB clef
+    Tab,
+    // Form labels and motif names on analysis brackets
+    Analysis,
+    // Figures and labels in analysis symbols and lines
+    Figure,
+    // Tuplet numbers and ratios
+    Tuplet,
+    // Trill signs and the labels of ottava and pedal lines
+    Marking,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FontWeight {
+    Regular,
+    Bold,
+}
+
+#[derive(Debug, Clone)]
+pub enum FontSource {
+    // Glyph module compiled into the crate, as written by fontgen
+    Compiled {
+        get_glyph: fn(char) -> Option<&'static [PathSegment]>,
+        get_advance: fn(char) -> Option<f32>,
+        get_kerning: fn(char, char) -> f32,
+    },
+    Loaded(Rc<LoadedFont>),
+}
+
+impl FontSource {
+    pub const MERRIWEATHER_REGULAR: FontSource = FontSource::Compiled {
+        get_glyph: merriweather_regular::get_glyph,
+        get_advance: merriweather_regular::get_advance,
+        get_kerning: merriweather_regular::get_kerning,
+    };
+
+    pub const OPENSANS_REGULAR: FontSource = FontSource::Compiled {
+        get_glyph: opensans_regular::get_glyph,
+        get_advance: opensans_regular::get_advance,
+        get_kerning: opensans_regular::get_kerning,
+    };
+
+    fn get_glyph(&self, c: char) -> Option<Vec<PathSegment>> {
+        match self {
+            FontSource::Compiled { get_glyph, .. } => get_glyph(c).map(|path| path.to_vec()),
+            FontSource::Loaded(font) => font.get_path(c),
+        }
+    }
+
+    fn has_glyph(&self, c: char) -> bool {
+        match self {
+            FontSource::Compiled { get_glyph, .. } => get_glyph(c).is_some(),
+            FontSource::Loaded(font) => font.has_glyph(c),
+        }
+    }
+
+    fn get_advance(&self, c: char) -> Option<f32> {
+        match self {
+            FontSource::Compiled { get_advance, .. } => get_advance(c),
+            FontSource::Loaded(font) => font.get_advance(c),
+        }
+        .or_else(|| self.get_glyph(c).map(|path| get_path_advance(&path)))
+    }
+
+    fn get_kerning(&self, left: char, right: char) -> f32 {
+        match self {
+            FontSource::Compiled { get_kerning, .. } => get_kerning(left, right),
+            FontSource::Loaded(font) => font.get_kerning(left, right),
+        }
+    }
+
+    fn is_same_font(&self, other: &FontSource) -> bool {
+        match (self, other) {
+            (FontSource::Compiled { get_glyph, .. }, FontSource::Compiled { get_glyph: other, .. }) => std::ptr::fn_addr_eq(*get_glyph, *other),
+            (FontSource::Loaded(font), FontSource::Loaded(other)) => Rc::ptr_eq(font, other),
+            _ => false,
+        }
+    }
+
+    // Whether the font itself is drawn bold, so that no synthetic bold is needed
+    fn is_bold(&self) -> bool {
+        match self {
+            FontSource::Loaded(font) => font.is_bold(),
+            _ => false,
+        }
+    }
+
+    fn is_italic(&self) -> bool {
+        match self {
+            FontSource::Loaded(font) => font.is_italic(),
+            _ => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TextStyle {
+    // Primary font first, then the fallback chain
+    pub fonts: Vec<FontSource>,
+    // Scale from em units to render units
+    pub size: f32,
+    pub weight: FontWeight,
+    pub italic: bool,
+}
+
+impl TextStyle {
+    pub fn new(fonts: Vec<FontSource>, size: f32, weight: FontWeight) -> Self {
+        Self { fonts, size, weight, italic: false }
+    }
+
+    pub fn italic(mut self) -> Self {
+        self.italic = true;
+        self
+    }
+
+    // First font in the chain that has the glyph
+    fn get_source(&self, c: char) -> Option<&FontSource> {
+        self.fonts.iter().find(|font| font.has_glyph(c))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FontRegistry {
+    styles: BTreeMap<TextRole, TextStyle>,
+}
+
+impl Default for FontRegistry {
+    fn default() -> Self {
+        let serif = || vec![FontSource::MERRIWEATHER_REGULAR, FontSource::OPENSANS_REGULAR];
+        let sans = || vec![FontSource::OPENSANS_REGULAR, FontSource::MERRIWEATHER_REGULAR];
+        let mut styles = BTreeMap::new();
+        styles.insert(TextRole::Lyrics, TextStyle::new(serif(), LYRICS_FONT_SCALE, FontWeight::Regular));
+        styles.insert(TextRole::Refrain, TextStyle::new(serif(), LYRICS_FONT_SCALE, FontWeight::Regular).italic());
+        styles.insert(TextRole::Chord, TextStyle::new(serif(), CHORD_FONT_SCALE, FontWeight::Regular));
+        styles.insert(TextRole::Function, TextStyle::new(serif(), FUNCTION_FONT_SCALE, FontWeight::Regular));
+        styles.insert(TextRole::Title, TextStyle::new(serif(), LYRICS_FONT_SCALE * 2.0, FontWeight::Bold));
+        styles.insert(TextRole::Tempo, TextStyle::new(serif(), LYRICS_FONT_SCALE, FontWeight::Bold));
+        styles.insert(TextRole::Analysis, TextStyle::new(serif(), LYRICS_FONT_SCALE * 0.8, FontWeight::Bold));
+        styles.insert(TextRole::Tab, TextStyle::new(sans(), LYRICS_FONT_SCALE * 0.7, FontWeight::Regular));
+        styles.insert(TextRole::Fingering, TextStyle::new(sans(), LYRICS_FONT_SCALE * 0.6, FontWeight::Regular));
+        styles.insert(TextRole::Figure, TextStyle::new(sans(), 0.07, FontWeight::Regular));
+        styles.insert(TextRole::Tuplet, TextStyle::new(serif(), 0.06, FontWeight::Regular));
+        styles.insert(TextRole::Marking, TextStyle::new(serif(), 0.05, FontWeight::Regular));
+        Self { styles }
+    }
+}
+
+impl FontRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set_style(&mut self, role: TextRole, style: TextStyle) {
+        self.styles.insert(role, style);
+    }
+
+    pub fn get_style(&self, role: TextRole) -> &TextStyle {
+        self.styles.get(&role).expect("FontRegistry has a style for every role")
+    }
+
+    // Puts the font first in the fallback chain of the role
+    pub fn set_font(&mut self, role: TextRole, font: LoadedFont) {
+        let style = self.styles.get_mut(&role).expect("FontRegistry has a style for every role");
+        style.fonts.insert(0, FontSource::Loaded(Rc::new(font)));
+    }
+
+    pub fn get_size(&self, role: TextRole) -> f32 {
+        self.get_style(role).size
+    }
+
+    // Glyph in em units from the first font in the chain that has it,
+    // composed or replaced by the fallback glyph when none has it
+    pub fn get_glyph(&self, role: TextRole, c: char) -> Vec<PathSegment> {
+        self.get_style(role).fonts.iter().find_map(|font| font.get_glyph(c)).unwrap_or_else(|| get_latin_path(c))
+    }
+
+    // Advance in em units
+    pub fn get_advance(&self, role: TextRole, c: char) -> f32 {
+        match self.get_style(role).get_source(c).and_then(|font| font.get_advance(c)) {
+            Some(advance) => advance,
+            None => get_default_advance(c),
+        }
+    }
+
+    pub fn get_bbox(&self, role: TextRole, c: char) -> Option<GlyphBBox> {
+        get_path_bbox(&self.get_glyph(role, c))
+    }
+
+    // Kerning in em units, only applied when both glyphs come from the same font
+    pub fn get_kerning(&self, role: TextRole, left: char, right: char) -> f32 {
+        let style = self.get_style(role);
+        match (style.get_source(left), style.get_source(right)) {
+            (Some(left_font), Some(right_font)) if left_font.is_same_font(right_font) => left_font.get_kerning(left, right),
+            _ => 0.0,
+        }
+    }
+
+    // Width in em units of a text run, advances plus kerning
+    pub fn get_text_width(&self, role: TextRole, text: &str) -> f32 {
+        let mut width = 0.0;
+        let mut previous: Option<char> = None;
+        for c in text.chars() {
+            if let Some(previous) = previous {
+                width += self.get_kerning(role, previous, c);
+            }
+            width += self.get_advance(role, c);
+            previous = Some(c);
+        }
+        width
+    }
+
+    // Outlines of a text run laid out with advances and kerning, in em units from the origin
+    pub fn get_text_path(&self, role: TextRole, text: &str) -> PathSegments {
+        let mut p = PathSegments(vec![]);
+        let mut x = 0.0;
+        let mut previous: Option<char> = None;
+        for c in text.chars() {
+            if let Some(previous) = previous {
+                x += self.get_kerning(role, previous, c);
+            }
+            if c != ' ' {
+                p.extend(&PathSegments(self.get_glyph(role, c)).move_path(x, 0.0));
+            }
+            x += self.get_advance(role, c);
+            previous = Some(c);
+        }
+        let style = self.get_style(role);
+        match style.italic && !style.fonts.first().is_some_and(|font| font.is_italic()) {
+            true => slant_path(&p, SYNTHETIC_ITALIC_SLANT),
+            false => p,
+        }
+    }
+
+    // Outline stroke for the style, emboldening regular fonts used for bold styles
+    pub fn get_stroke(&self, role: TextRole) -> Stroke {
+        let style = self.get_style(role);
+        let bold_font = style.fonts.first().is_some_and(|font| font.is_bold());
+        match style.weight {
+            FontWeight::Bold if !bold_font => Strokestyle(SYNTHETIC_BOLD_STROKE * style.size, Black),
+            _ => NoStroke,
+        }
+    }
+
+    // Text run as a filled path item with its baseline at x, y in render units
+    pub fn get_text_item(&self, role: TextRole, text: &str, x: f32, y: f32) -> GraphicItem {
+        let size = self.get_size(role);
+        Path(self.get_text_path(role, text).scale_path(size, size).move_path(x, y), self.get_stroke(role), Fillstyle(Black), PathCacheInfo::NoCache)
+    }
+}
+
+// Shears y-down outlines to the right above the baseline
+fn slant_path(p: &PathSegments, slant: f32) -> PathSegments {
+    let shear = |x: f32, y: f32| x - y * slant;
+    PathSegments(
+        p.0.iter()
+            .map(|segment| match *segment {
+                M(x1, y1) => M(shear(x1, y1), y1),
+                L(x1, y1) => L(shear(x1, y1), y1),
+                Q(x1, y1, x2, y2) => Q(shear(x1, y1), y1, shear(x2, y2), y2),
+                C(x1, y1, x2, y2, x3, y3) => C(shear(x1, y1), y1, shear(x2, y2), y2, shear(x3, y3), y3),
+                _ => segment.clone(),
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn role_fallback_and_widths() {
+        let registry = FontRegistry::default();
+        // Open Sans only carries digits, other glyphs fall back to Merriweather
+        assert_eq!(registry.get_glyph(TextRole::Figure, '7').len(), opensans_regular::get_glyph('7').unwrap().len());
+        assert_eq!(registry.get_glyph(TextRole::Figure, 'x').len(), merriweather_regular::get_glyph('x').unwrap().len());
+        let width = registry.get_advance(TextRole::Lyrics, 'a') + registry.get_advance(TextRole::Lyrics, 'b');
+        assert_eq!(registry.get_text_width(TextRole::Lyrics, "ab"), width);
+        assert!(matches!(registry.get_stroke(TextRole::Lyrics), NoStroke));
+    }
+
+    #[test]
+    fn compiled_source_metrics() {
+        let font = FontSource::Compiled {
+            get_glyph: merriweather_regular::get_glyph,
+            get_advance: |_| Some(500.0),
+            get_kerning: |left, right| if (left, right) == ('A', 'V') { -80.0 } else { 0.0 },
+        };
+        let mut registry = FontRegistry::default();
+        registry.set_style(TextRole::Title, TextStyle::new(vec![font], 1.0, FontWeight::Regular));
+        assert_eq!(registry.get_text_width(TextRole::Title, "AVA"), 1420.0);
+        let path = registry.get_text_path(TextRole::Title, "AV");
+        let v_width = get_path_bbox(merriweather_regular::get_glyph('V').unwrap()).unwrap().x_max;
+        assert_eq!(get_path_bbox(&path.0).unwrap().x_max, 420.0 + v_width);
+    }
+}
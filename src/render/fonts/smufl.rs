@@ -0,0 +1,260 @@
+use crate::render::fonts::truetype::{FontError, LoadedFont, FONT_EM_SIZE};
+use graphics::path::PathSegment;
+use graphics::prelude::*;
+use notation_rs::prelude::*;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MusicGlyph {
+    GClef,
+    FClef,
+    CClef,
+    NoteheadBlack,
+    NoteheadHalf,
+    NoteheadWhole,
+    AugmentationDot,
+    AccidentalFlat,
+    AccidentalNatural,
+    AccidentalSharp,
+    AccidentalDoubleSharp,
+    AccidentalDoubleFlat,
+    RestWhole,
+    RestHalf,
+    RestQuarter,
+    Rest8th,
+    Rest16th,
+    Rest32nd,
+    Flag8thUp,
+    Flag8thDown,
+    Flag16thUp,
+    Flag16thDown,
+    TimeSig(u8),
+    TimeSigCommon,
+    TimeSigCutCommon,
+}
+
+impl MusicGlyph {
+    // SMuFL canonical glyph name, as used in the font metadata
+    pub fn get_name(&self) -> String {
+        match self {
+            MusicGlyph::GClef => "gClef".to_string(),
+            MusicGlyph::FClef => "fClef".to_string(),
+            MusicGlyph::CClef => "cClef".to_string(),
+            MusicGlyph::NoteheadBlack => "noteheadBlack".to_string(),
+            MusicGlyph::NoteheadHalf => "noteheadHalf".to_string(),
+            MusicGlyph::NoteheadWhole => "noteheadWhole".to_string(),
+            MusicGlyph::AugmentationDot => "augmentationDot".to_string(),
+            MusicGlyph::AccidentalFlat => "accidentalFlat".to_string(),
+            MusicGlyph::AccidentalNatural => "accidentalNatural".to_string(),
+            MusicGlyph::AccidentalSharp => "accidentalSharp".to_string(),
+            MusicGlyph::AccidentalDoubleSharp => "accidentalDoubleSharp".to_string(),
+            MusicGlyph::AccidentalDoubleFlat => "accidentalDoubleFlat".to_string(),
+            MusicGlyph::RestWhole => "restWhole".to_string(),
+            MusicGlyph::RestHalf => "restHalf".to_string(),
+            MusicGlyph::RestQuarter => "restQuarter".to_string(),
+            MusicGlyph::Rest8th => "rest8th".to_string(),
+            MusicGlyph::Rest16th => "rest16th".to_string(),
+            MusicGlyph::Rest32nd => "rest32nd".to_string(),
+            MusicGlyph::Flag8thUp => "flag8thUp".to_string(),
+            MusicGlyph::Flag8thDown => "flag8thDown".to_string(),
+            MusicGlyph::Flag16thUp => "flag16thUp".to_string(),
+            MusicGlyph::Flag16thDown => "flag16thDown".to_string(),
+            MusicGlyph::TimeSig(n) => format!("timeSig{}", (*n).min(9)),
+            MusicGlyph::TimeSigCommon => "timeSigCommon".to_string(),
+            MusicGlyph::TimeSigCutCommon => "timeSigCutCommon".to_string(),
+        }
+    }
+
+    // SMuFL code point in the Private Use Area
+    pub fn get_codepoint(&self) -> char {
+        let codepoint: u32 = match self {
+            MusicGlyph::GClef => 0xE050,
+            MusicGlyph::FClef => 0xE062,
+            MusicGlyph::CClef => 0xE05C,
+            MusicGlyph::NoteheadBlack => 0xE0A4,
+            MusicGlyph::NoteheadHalf => 0xE0A3,
+            MusicGlyph::NoteheadWhole => 0xE0A2,
+            MusicGlyph::AugmentationDot => 0xE1E7,
+            MusicGlyph::AccidentalFlat => 0xE260,
+            MusicGlyph::AccidentalNatural => 0xE261,
+            MusicGlyph::AccidentalSharp => 0xE262,
+            MusicGlyph::AccidentalDoubleSharp => 0xE263,
+            MusicGlyph::AccidentalDoubleFlat => 0xE264,
+            MusicGlyph::RestWhole => 0xE4E3,
+            MusicGlyph::RestHalf => 0xE4E4,
+            MusicGlyph::RestQuarter => 0xE4E5,
+            MusicGlyph::Rest8th => 0xE4E6,
+            MusicGlyph::Rest16th => 0xE4E7,
+            MusicGlyph::Rest32nd => 0xE4E8,
+            MusicGlyph::Flag8thUp => 0xE240,
+            MusicGlyph::Flag8thDown => 0xE241,
+            MusicGlyph::Flag16thUp => 0xE242,
+            MusicGlyph::Flag16thDown => 0xE243,
+            MusicGlyph::TimeSig(n) => 0xE080 + (*n).min(9) as u32,
+            MusicGlyph::TimeSigCommon => 0xE08A,
+            MusicGlyph::TimeSigCutCommon => 0xE08B,
+        };
+        char::from_u32(codepoint).unwrap()
+    }
+}
+
+// Engraving measures in render units
+#[derive(Debug, Clone, Copy)]
+pub struct EngravingDefaults {
+    pub stem_width: f32,
+    pub beam_height: f32,
+    pub staff_line_width: f32,
+    pub ledger_line_width: f32,
+    pub barline_width: f32,
+    // Stem attachment points of the black notehead relative to its origin, None without music font anchors
+    pub stem_up_se: Option<(f32, f32)>,
+    pub stem_down_nw: Option<(f32, f32)>,
+}
+
+impl Default for EngravingDefaults {
+    fn default() -> Self {
+        Self {
+            stem_width: STEM_WIDTH,
+            beam_height: BEAM_HEIGHT,
+            staff_line_width: NOTELINES_WIDTH,
+            ledger_line_width: NOTELINES_WIDTH,
+            barline_width: BARLINE_WIDTH_SINGLE,
+            stem_up_se: None,
+            stem_down_nw: None,
+        }
+    }
+}
+
+// Glyph anchors and engraving defaults read from the SMuFL font metadata json
+#[derive(Debug, Default)]
+pub struct SmuflMetadata {
+    // glyph name -> anchor name -> position in staff spaces, y up
+    anchors: BTreeMap<String, BTreeMap<String, (f32, f32)>>,
+    // in staff spaces
+    engraving_defaults: BTreeMap<String, f32>,
+}
+
+impl SmuflMetadata {
+    pub fn parse(metadata: &str) -> Result<Self, FontError> {
+        let json: serde_json::Value = serde_json::from_str(metadata).map_err(|err| FontError::Metadata(err.to_string()))?;
+
+        let mut anchors = BTreeMap::new();
+        if let Some(glyphs) = json["glyphsWithAnchors"].as_object() {
+            for (glyph_name, glyph_anchors) in glyphs {
+                let mut map = BTreeMap::new();
+                if let Some(glyph_anchors) = glyph_anchors.as_object() {
+                    for (anchor_name, value) in glyph_anchors {
+                        if let (Some(x), Some(y)) = (value[0].as_f64(), value[1].as_f64()) {
+                            map.insert(anchor_name.clone(), (x as f32, y as f32));
+                        }
+                    }
+                }
+                anchors.insert(glyph_name.clone(), map);
+            }
+        }
+
+        let mut engraving_defaults = BTreeMap::new();
+        if let Some(defaults) = json["engravingDefaults"].as_object() {
+            for (key, value) in defaults {
+                if let Some(value) = value.as_f64() {
+                    engraving_defaults.insert(key.clone(), value as f32);
+                }
+            }
+        }
+
+        Ok(Self { anchors, engraving_defaults })
+    }
+
+    // Anchor position in render units relative to the glyph origin, y pointing down
+    pub fn get_anchor(&self, glyph: MusicGlyph, anchor: &str) -> Option<(f32, f32)> {
+        let (x, y) = self.anchors.get(&glyph.get_name())?.get(anchor)?;
+        Some((x * SPACE, -y * SPACE))
+    }
+
+    // Engraving default in render units, for example "stemThickness"
+    pub fn get_engraving_default(&self, key: &str) -> Option<f32> {
+        self.engraving_defaults.get(key).map(|value| value * SPACE)
+    }
+}
+
+// A SMuFL compliant music font together with its metadata
+#[derive(Debug)]
+pub struct MusicFont {
+    pub font: LoadedFont,
+    metadata: SmuflMetadata,
+}
+
+impl MusicFont {
+    pub fn load<P: AsRef<Path>, Q: AsRef<Path>>(font_path: P, metadata_path: Q) -> Result<Self, FontError> {
+        let font = LoadedFont::load(font_path)?;
+        let metadata = std::fs::read_to_string(metadata_path)?;
+        Self::from_font(font, &metadata)
+    }
+
+    pub fn from_font(font: LoadedFont, metadata: &str) -> Result<Self, FontError> {
+        let metadata = SmuflMetadata::parse(metadata)?;
+        Ok(Self { font, metadata })
+    }
+
+    // Factor from compiled em units to render units, the SMuFL em being four staff spaces
+    fn get_scale(&self) -> f32 {
+        4.0 * SPACE / FONT_EM_SIZE
+    }
+
+    // Glyph outline in render units with the SMuFL origin at 0,0 and y pointing down
+    pub fn get_path(&self, glyph: MusicGlyph) -> Option<Vec<PathSegment>> {
+        let path = self.font.get_path(glyph.get_codepoint())?;
+        Some(PathSegments(path).scale_path(self.get_scale(), self.get_scale()).0)
+    }
+
+    pub fn get_advance(&self, glyph: MusicGlyph) -> Option<f32> {
+        self.font.get_advance(glyph.get_codepoint()).map(|advance| advance * self.get_scale())
+    }
+
+    // Anchor position in render units relative to the glyph origin, y pointing down
+    pub fn get_anchor(&self, glyph: MusicGlyph, anchor: &str) -> Option<(f32, f32)> {
+        self.metadata.get_anchor(glyph, anchor)
+    }
+
+    // Engraving default in render units, for example "stemThickness"
+    pub fn get_engraving_default(&self, key: &str) -> Option<f32> {
+        self.metadata.get_engraving_default(key)
+    }
+
+    pub fn get_engraving_defaults(&self) -> EngravingDefaults {
+        let defaults = EngravingDefaults::default();
+        EngravingDefaults {
+            stem_width: self.get_engraving_default("stemThickness").unwrap_or(defaults.stem_width),
+            beam_height: self.get_engraving_default("beamThickness").unwrap_or(defaults.beam_height),
+            staff_line_width: self.get_engraving_default("staffLineThickness").unwrap_or(defaults.staff_line_width),
+            ledger_line_width: self.get_engraving_default("legerLineThickness").unwrap_or(defaults.ledger_line_width),
+            barline_width: self.get_engraving_default("thinBarlineThickness").unwrap_or(defaults.barline_width),
+            stem_up_se: self.get_anchor(MusicGlyph::NoteheadBlack, "stemUpSE"),
+            stem_down_nw: self.get_anchor(MusicGlyph::NoteheadBlack, "stemDownNW"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_metadata_anchors_and_defaults() {
+        let metadata = SmuflMetadata::parse(
+            r#"{
+                "engravingDefaults": { "stemThickness": 0.12, "textFontFamily": ["serif"] },
+                "glyphsWithAnchors": { "noteheadBlack": { "stemUpSE": [1.18, 0.168], "stemDownNW": [0.0, -0.168] } }
+            }"#,
+        )
+        .unwrap();
+        // staff spaces with y up become render units with y down
+        assert_eq!(metadata.get_anchor(MusicGlyph::NoteheadBlack, "stemUpSE"), Some((1.18 * SPACE, -0.168 * SPACE)));
+        assert_eq!(metadata.get_anchor(MusicGlyph::NoteheadBlack, "stemDownNW"), Some((0.0, 0.168 * SPACE)));
+        assert_eq!(metadata.get_anchor(MusicGlyph::NoteheadHalf, "stemUpSE"), None);
+        assert_eq!(metadata.get_engraving_default("stemThickness"), Some(0.12 * SPACE));
+        assert_eq!(metadata.get_engraving_default("textFontFamily"), None);
+        assert!(matches!(SmuflMetadata::parse("{"), Err(FontError::Metadata(_))));
+    }
+}
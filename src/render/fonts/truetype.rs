@@ -0,0 +1,315 @@
+use crate::render::fonts::metrics::GlyphBBox;
+use graphics::path::PathSegment;
+use graphics::path::PathSegment::*;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+
+// Em size of the compiled glyph tables, loaded outlines are scaled to match
+pub const FONT_EM_SIZE: f32 = 800.0;
+
+#[derive(Debug)]
+pub enum FontError {
+    Io(std::io::Error),
+    Parse(String),
+    Metadata(String),
+}
+
+impl fmt::Display for FontError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FontError::Io(err) => write!(f, "Could not read font file: {}", err),
+            FontError::Parse(msg) => write!(f, "Could not parse font: {}", msg),
+            FontError::Metadata(msg) => write!(f, "Could not parse font metadata: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for FontError {}
+
+impl From<std::io::Error> for FontError {
+    fn from(err: std::io::Error) -> Self {
+        FontError::Io(err)
+    }
+}
+
+// A TrueType/OpenType font read at runtime
+pub struct LoadedFont {
+    pub name: String,
+    // validated in from_data, the tables are parsed from it on demand
+    data: Vec<u8>,
+    units_per_em: f32,
+}
+
+impl fmt::Debug for LoadedFont {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LoadedFont").field("name", &self.name).field("units_per_em", &self.units_per_em).finish()
+    }
+}
+
+impl LoadedFont {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, FontError> {
+        let path = path.as_ref();
+        let data = std::fs::read(path)?;
+        let name = path.file_stem().map(|stem| stem.to_string_lossy().to_string()).unwrap_or_default();
+        Self::from_data(&name, data)
+    }
+
+    pub fn from_data(name: &str, data: Vec<u8>) -> Result<Self, FontError> {
+        let face = ttf_parser::Face::parse(&data, 0).map_err(|err| FontError::Parse(err.to_string()))?;
+        let units_per_em = face.units_per_em() as f32;
+        Ok(Self {
+            name: name.to_string(),
+            data,
+            units_per_em,
+        })
+    }
+
+    fn face(&self) -> ttf_parser::Face<'_> {
+        ttf_parser::Face::parse(&self.data, 0).expect("font data is validated when the font is loaded")
+    }
+
+    // Factor from font units to FONT_EM_SIZE units
+    pub fn get_scale(&self) -> f32 {
+        FONT_EM_SIZE / self.units_per_em
+    }
+
+    // Weight and style as marked in the OS/2 table of the font
+    pub fn is_bold(&self) -> bool {
+        self.face().is_bold()
+    }
+
+    // Italic or oblique
+    pub fn is_italic(&self) -> bool {
+        self.face().is_italic() || self.face().is_oblique()
+    }
+
+    pub fn has_glyph(&self, c: char) -> bool {
+        self.face().glyph_index(c).is_some()
+    }
+
+    pub fn get_advance(&self, c: char) -> Option<f32> {
+        let face = self.face();
+        let glyph_id = face.glyph_index(c)?;
+        face.glyph_hor_advance(glyph_id).map(|advance| advance as f32 * self.get_scale())
+    }
+
+    // Outline of glyph 0, drawn by fonts for characters they do not cover
+    pub fn get_notdef_path(&self) -> Option<Vec<PathSegment>> {
+        let face = self.face();
+        let mut builder = PathSegmentsBuilder::new(self.get_scale());
+        face.outline_glyph(ttf_parser::GlyphId(0), &mut builder)?;
+        Some(builder.segments)
+    }
+
+    // Bounding box in FONT_EM_SIZE units, y pointing down
+    pub fn get_bbox(&self, c: char) -> Option<GlyphBBox> {
+        let face = self.face();
+        let rect = face.glyph_bounding_box(face.glyph_index(c)?)?;
+        let scale = self.get_scale();
+        Some(GlyphBBox {
+            x_min: rect.x_min as f32 * scale,
+            y_min: -rect.y_max as f32 * scale,
+            x_max: rect.x_max as f32 * scale,
+            y_max: -rect.y_min as f32 * scale,
+        })
+    }
+
+    // Horizontal kerning in FONT_EM_SIZE units from the GPOS kern feature, or the kern table for older fonts.
+    // 0.0 when the pair is not kerned.
+    pub fn get_kerning(&self, left: char, right: char) -> f32 {
+        let face = self.face();
+        let (left, right) = match (face.glyph_index(left), face.glyph_index(right)) {
+            (Some(left), Some(right)) => (left, right),
+            _ => return 0.0,
+        };
+        get_gpos_kerning(&face, left, right)
+            .or_else(|| get_kern_kerning(&face, left, right))
+            .map(|value| value as f32 * self.get_scale())
+            .unwrap_or(0.0)
+    }
+
+    // Kerning pairs among the characters that the GPOS kern feature or the kern table list, in FONT_EM_SIZE units.
+    // A pair takes its value from the first table that lists it, as in get_kerning.
+    pub fn get_kerning_pairs(&self, chars: &[char]) -> Vec<(char, char, f32)> {
+        let face = self.face();
+        let glyphs: Vec<(char, ttf_parser::GlyphId)> = chars.iter().filter_map(|c| Some((*c, face.glyph_index(*c)?))).collect();
+        let mut pairs: BTreeMap<(char, char), i16> = BTreeMap::new();
+        add_gpos_pairs(&face, &glyphs, &mut pairs);
+        add_kern_pairs(&face, &glyphs, &mut pairs);
+        pairs
+            .into_iter()
+            .filter(|(_, value)| *value != 0)
+            .map(|((left, right), value)| (left, right, value as f32 * self.get_scale()))
+            .collect()
+    }
+
+    // Glyph outline in the coordinate system of the compiled glyph tables: baseline at y = 0, y pointing down
+    pub fn get_path(&self, c: char) -> Option<Vec<PathSegment>> {
+        let face = self.face();
+        let glyph_id = face.glyph_index(c)?;
+        let mut builder = PathSegmentsBuilder::new(self.get_scale());
+        face.outline_glyph(glyph_id, &mut builder);
+        Some(builder.segments)
+    }
+}
+
+// Advance adjustment of the first glyph from the pair adjustment lookups of the GPOS kern feature
+fn get_gpos_kerning(face: &ttf_parser::Face, left: ttf_parser::GlyphId, right: ttf_parser::GlyphId) -> Option<i16> {
+    use ttf_parser::gpos::{PairAdjustment, PositioningSubtable};
+    let gpos = face.tables().gpos?;
+    gpos.features
+        .into_iter()
+        .filter(|feature| feature.tag == ttf_parser::Tag::from_bytes(b"kern"))
+        .flat_map(|feature| feature.lookup_indices)
+        .filter_map(|lookupidx| gpos.lookups.get(lookupidx))
+        .flat_map(|lookup| lookup.subtables.into_iter::<PositioningSubtable>())
+        .find_map(|subtable| match subtable {
+            PositioningSubtable::Pair(PairAdjustment::Format1 { coverage, sets }) => sets.get(coverage.get(left)?)?.get(right).map(|(record, _)| record.x_advance),
+            PositioningSubtable::Pair(PairAdjustment::Format2 { coverage, classes, matrix }) => {
+                coverage.get(left)?;
+                matrix.get((classes.0.get(left), classes.1.get(right))).map(|(record, _)| record.x_advance)
+            }
+            _ => None,
+        })
+}
+
+// Pairs from the pair adjustment lookups of the GPOS kern feature, only for first glyphs in their coverage
+fn add_gpos_pairs(face: &ttf_parser::Face, glyphs: &[(char, ttf_parser::GlyphId)], pairs: &mut BTreeMap<(char, char), i16>) {
+    use ttf_parser::gpos::{PairAdjustment, PositioningSubtable};
+    let gpos = match face.tables().gpos {
+        Some(gpos) => gpos,
+        None => return,
+    };
+    let subtables = gpos
+        .features
+        .into_iter()
+        .filter(|feature| feature.tag == ttf_parser::Tag::from_bytes(b"kern"))
+        .flat_map(|feature| feature.lookup_indices)
+        .filter_map(|lookupidx| gpos.lookups.get(lookupidx))
+        .flat_map(|lookup| lookup.subtables.into_iter::<PositioningSubtable>());
+    for subtable in subtables {
+        let adjustment = match subtable {
+            PositioningSubtable::Pair(adjustment) => adjustment,
+            _ => continue,
+        };
+        for (left, left_id) in glyphs.iter() {
+            match adjustment {
+                PairAdjustment::Format1 { coverage, sets } => {
+                    let set = match coverage.get(*left_id).and_then(|index| sets.get(index)) {
+                        Some(set) => set,
+                        None => continue,
+                    };
+                    for (right, right_id) in glyphs.iter() {
+                        if let Some((record, _)) = set.get(*right_id) {
+                            pairs.entry((*left, *right)).or_insert(record.x_advance);
+                        }
+                    }
+                }
+                PairAdjustment::Format2 { coverage, classes, matrix } => {
+                    if !coverage.contains(*left_id) {
+                        continue;
+                    }
+                    for (right, right_id) in glyphs.iter() {
+                        if let Some((record, _)) = matrix.get((classes.0.get(*left_id), classes.1.get(*right_id))) {
+                            pairs.entry((*left, *right)).or_insert(record.x_advance);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+// Pairs from the horizontal subtables of the kern table
+fn add_kern_pairs(face: &ttf_parser::Face, glyphs: &[(char, ttf_parser::GlyphId)], pairs: &mut BTreeMap<(char, char), i16>) {
+    use ttf_parser::kern::Format;
+    let kern = match face.tables().kern {
+        Some(kern) => kern,
+        None => return,
+    };
+    let mut chars_by_glyph: BTreeMap<ttf_parser::GlyphId, Vec<char>> = BTreeMap::new();
+    for (c, glyph_id) in glyphs.iter() {
+        chars_by_glyph.entry(*glyph_id).or_default().push(*c);
+    }
+    for subtable in kern.subtables.into_iter().filter(|subtable| subtable.horizontal && !subtable.variable) {
+        match &subtable.format {
+            Format::Format0(table) => {
+                for pair in table.pairs {
+                    let (lefts, rights) = match (chars_by_glyph.get(&pair.left()), chars_by_glyph.get(&pair.right())) {
+                        (Some(lefts), Some(rights)) => (lefts, rights),
+                        _ => continue,
+                    };
+                    for left in lefts.iter() {
+                        for right in rights.iter() {
+                            pairs.entry((*left, *right)).or_insert(pair.value);
+                        }
+                    }
+                }
+            }
+            // class based subtables have no pair list, look up every pair
+            _ => {
+                for (left, left_id) in glyphs.iter() {
+                    for (right, right_id) in glyphs.iter() {
+                        if let Some(value) = subtable.glyphs_kerning(*left_id, *right_id) {
+                            pairs.entry((*left, *right)).or_insert(value);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn get_kern_kerning(face: &ttf_parser::Face, left: ttf_parser::GlyphId, right: ttf_parser::GlyphId) -> Option<i16> {
+    face.tables()
+        .kern?
+        .subtables
+        .into_iter()
+        .filter(|subtable| subtable.horizontal && !subtable.variable)
+        .find_map(|subtable| subtable.glyphs_kerning(left, right))
+}
+
+pub struct PathSegmentsBuilder {
+    pub segments: Vec<PathSegment>,
+    scale: f32,
+}
+
+impl PathSegmentsBuilder {
+    pub fn new(scale: f32) -> Self {
+        Self { segments: vec![], scale }
+    }
+}
+
+impl ttf_parser::OutlineBuilder for PathSegmentsBuilder {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.segments.push(M(x * self.scale, -y * self.scale));
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.segments.push(L(x * self.scale, -y * self.scale));
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        self.segments.push(Q(x1 * self.scale, -y1 * self.scale, x * self.scale, -y * self.scale));
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        self.segments.push(C(x1 * self.scale, -y1 * self.scale, x2 * self.scale, -y2 * self.scale, x * self.scale, -y * self.scale));
+    }
+
+    fn close(&mut self) {
+        self.segments.push(Z);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FontError, LoadedFont};
+
+    #[test]
+    fn invalid_font_data() {
+        let result = LoadedFont::from_data("invalid", vec![0, 1, 2, 3]);
+        assert!(matches!(result, Err(FontError::Parse(_))));
+    }
+}
@@ -0,0 +1,214 @@
+use crate::prelude::*;
+use graphics::path::PathSegment;
+use graphics::prelude::*;
+use notation_rs::prelude::*;
+
+// Distance between nesting levels, outer groups go further left
+const ACKOLADE_LEVEL_DISTANCE: f32 = SPACE * 1.5;
+const ACKOLADE_BRACKET_WIDTH: f32 = SPACE * 0.5;
+// Overshoot of brackets past the outer staff lines
+const ACKOLADE_BRACKET_OVERSHOOT: f32 = 5.0;
+const ACKOLADE_HOOK_RISE: f32 = 10.0;
+const ACKOLADE_SUB_BRACKET_WIDTH: f32 = 3.0;
+const ACKOLADE_BRACE_WIDTH: f32 = SPACE * 1.2;
+const ACKOLADE_BRACE_THICKNESS: f32 = SPACE * 0.35;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AckoladeStyle {
+    // Thick square bracket for choir and ensemble sections
+    Bracket,
+    // Curly brace for the staves of one instrument, such as the piano
+    Brace,
+    // Thin bracket for divisi and pairs of instruments inside a section
+    SubBracket,
+}
+
+#[derive(Debug, Clone)]
+pub struct AckoladeGroup {
+    // Rows of the group, rows without staff lines at its ends are left outside
+    pub from_rowidx: usize,
+    pub to_rowidx: usize,
+    pub style: AckoladeStyle,
+}
+
+impl AckoladeGroup {
+    pub fn new(from_rowidx: usize, to_rowidx: usize, style: AckoladeStyle) -> Self {
+        Self { from_rowidx, to_rowidx, style }
+    }
+
+    fn contains(&self, other: &AckoladeGroup) -> bool {
+        self.from_rowidx <= other.from_rowidx && other.to_rowidx <= self.to_rowidx
+    }
+
+    fn overlaps(&self, other: &AckoladeGroup) -> bool {
+        self.from_rowidx <= other.to_rowidx && other.from_rowidx <= self.to_rowidx
+    }
+}
+
+// Nesting level of each group, 0 next to the system line. Sub-brackets stay inside the groups that contain them.
+pub fn get_ackolade_levels(groups: &[AckoladeGroup]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..groups.len()).collect();
+    order.sort_by_key(|idx| groups[*idx].to_rowidx.saturating_sub(groups[*idx].from_rowidx));
+
+    let mut levels: Vec<Option<usize>> = vec![None; groups.len()];
+    for idx in order {
+        let group = &groups[idx];
+        let placed: Vec<(usize, usize)> = (0..groups.len()).filter(|other| *other != idx).filter_map(|other| levels[other].map(|level| (other, level))).collect();
+        let mut level = placed.iter().filter(|(other, _)| group.contains(&groups[*other])).map(|(_, level)| level + 1).max().unwrap_or(0);
+        while placed.iter().any(|(other, other_level)| *other_level == level && group.overlaps(&groups[*other])) {
+            level += 1;
+        }
+        levels[idx] = Some(level);
+    }
+    levels.into_iter().map(|level| level.unwrap_or(0)).collect()
+}
+
+fn get_staff_rows(matrix: &RMatrix, render_options: &RenderOptions) -> Vec<usize> {
+    (0..matrix.rows.len()).filter(|rowidx| is_staff_row(matrix, *rowidx, render_options)).collect()
+}
+
+// Top line of the first row and bottom line of the last row
+fn get_rows_extent(matrix: &RMatrix, first: usize, last: usize, render_options: &RenderOptions) -> Option<(f32, f32)> {
+    let (y1, _) = get_staff_extent(matrix, first, render_options)?;
+    let (_, y2) = get_staff_extent(matrix, last, render_options)?;
+    Some((y1, y2))
+}
+
+// Curly brace with its tips at x, y1 and x, y2, the point facing left
+fn do_brace(x: f32, y1: f32, y2: f32) -> GraphicItem {
+    let h = (y2 - y1) * 0.5;
+    let ym = y1 + h;
+    let w = ACKOLADE_BRACE_WIDTH;
+    let t = ACKOLADE_BRACE_THICKNESS;
+    let arm = x - w * 0.55;
+    // upper half from the tip to the point and back, the lower half mirrors it
+    let half = |dir: f32| {
+        let y = |dy: f32| ym - dir * (h - dy);
+        vec![
+            C(x - w * 0.45, y(0.0), arm, y(h * 0.1), arm, y(h * 0.3)),
+            C(arm, y(h * 0.55), arm, y(h * 0.95), x - w, ym),
+            C(arm + t, y(h * 0.95), arm + t, y(h * 0.55), arm + t, y(h * 0.3)),
+            C(arm + t, y(h * 0.1), x - w * 0.2, y(h * 0.02), x, y(0.0)),
+        ]
+    };
+    let mut segments: Vec<PathSegment> = vec![M(x, y1)];
+    segments.extend(half(1.0));
+    segments.push(M(x, y2));
+    segments.extend(half(-1.0));
+    Path(PathSegments(segments), NoStroke, Fillstyle(Black), PathCacheInfo::NoCache)
+}
+
+// Thick bracket with its inner edge at x, hooks bending back to the right
+fn do_bracket(x: f32, y1: f32, y2: f32) -> Vec<GraphicItem> {
+    let x1 = x - ACKOLADE_BRACKET_WIDTH;
+    let y1 = y1 - ACKOLADE_BRACKET_OVERSHOOT;
+    let y2 = y2 + ACKOLADE_BRACKET_OVERSHOOT;
+    let hook_x = x + ACKOLADE_BRACKET_WIDTH;
+    vec![
+        Rect(x1, y1, ACKOLADE_BRACKET_WIDTH, y2 - y1, NoStroke, Fillstyle(Black)),
+        Path(
+            PathSegments(vec![M(x1, y1), L(hook_x, y1 - ACKOLADE_HOOK_RISE), L(hook_x, y1 - ACKOLADE_HOOK_RISE + 2.0), L(x, y1)]),
+            NoStroke,
+            Fillstyle(Black),
+            PathCacheInfo::NoCache,
+        ),
+        Path(
+            PathSegments(vec![M(x1, y2), L(hook_x, y2 + ACKOLADE_HOOK_RISE), L(hook_x, y2 + ACKOLADE_HOOK_RISE - 2.0), L(x, y2)]),
+            NoStroke,
+            Fillstyle(Black),
+            PathCacheInfo::NoCache,
+        ),
+    ]
+}
+
+// Thin bracket at x with straight hooks to the right
+fn do_sub_bracket(x: f32, y1: f32, y2: f32) -> GraphicItem {
+    Path(
+        PathSegments(vec![M(x + SPACE_HALF, y1), L(x, y1), L(x, y2), L(x + SPACE_HALF, y2)]),
+        Strokestyle(ACKOLADE_SUB_BRACKET_WIDTH, Black),
+        NoFill,
+        PathCacheInfo::NoCache,
+    )
+}
+
+pub fn output_ackolades(matrix: &RMatrix, render_options: &RenderOptions) -> GraphicItems {
+    let mut graphic_items = GraphicItems::new();
+    let staff_rows = get_staff_rows(matrix, render_options);
+    if staff_rows.is_empty() {
+        return graphic_items;
+    }
+
+    // the system line joins all staves, one bracket over all of them unless groups are given
+    let first = staff_rows[0];
+    let last = staff_rows[staff_rows.len() - 1];
+    if first != last {
+        if let Some((y1, y2)) = get_rows_extent(matrix, first, last, render_options) {
+            graphic_items.push(Line(0., y1, 0., y2, Strokestyle(NOTELINES_WIDTH, Black)));
+        }
+    }
+    let default_groups = match first != last {
+        true => vec![AckoladeGroup::new(first, last, AckoladeStyle::Bracket)],
+        false => vec![],
+    };
+    let groups = match render_options.ackolades.is_empty() {
+        true => &default_groups,
+        false => &render_options.ackolades,
+    };
+
+    let levels = get_ackolade_levels(groups);
+    for (group, level) in groups.iter().zip(levels) {
+        let rows: Vec<usize> = staff_rows.iter().copied().filter(|rowidx| (group.from_rowidx..=group.to_rowidx).contains(rowidx)).collect();
+        if rows.is_empty() {
+            continue;
+        }
+        let (y1, y2) = match get_rows_extent(matrix, rows[0], rows[rows.len() - 1], render_options) {
+            Some(extent) => extent,
+            None => continue,
+        };
+        let x = -(level as f32) * ACKOLADE_LEVEL_DISTANCE;
+        match group.style {
+            AckoladeStyle::Bracket => graphic_items.extend(GraphicItems(do_bracket(x - ACKOLADE_BRACKET_WIDTH, y1, y2))),
+            AckoladeStyle::Brace => graphic_items.push(do_brace(x - SPACE_QUARTER, y1, y2)),
+            AckoladeStyle::SubBracket => graphic_items.push(do_sub_bracket(x - SPACE_HALF, y1, y2)),
+        }
+    }
+    graphic_items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nested_groups_stack_outwards() {
+        let groups = vec![
+            AckoladeGroup::new(0, 3, AckoladeStyle::Bracket),
+            AckoladeGroup::new(0, 1, AckoladeStyle::SubBracket),
+            AckoladeGroup::new(5, 6, AckoladeStyle::Brace),
+        ];
+        assert_eq!(get_ackolade_levels(&groups), vec![1, 0, 0]);
+    }
+
+    #[test]
+    fn groups_span_their_staves() {
+        use crate::render::output::elements::testutils::*;
+        let matrix = get_test_matrix("|clef G - G F | 0 / - / 0 / 0");
+        let mut render_options = RenderOptions::default();
+        let (top, _) = get_staff_extent(&matrix, 0, &render_options).unwrap();
+        let (_, bottom) = get_staff_extent(&matrix, 3, &render_options).unwrap();
+
+        // without groups the system line and one bracket join all staves, skipping the nonmusic row
+        let (x1, y1, x2, y2) = get_items_extent(&output_ackolades(&matrix, &render_options)).unwrap();
+        assert!(x2 <= ACKOLADE_BRACKET_WIDTH + 0.01 && x1 < 0.0);
+        assert!(y1 < top && y2 > bottom);
+
+        // a brace over the lower two staves, and a thin bracket at the top staff only
+        render_options.ackolades = vec![AckoladeGroup::new(2, 3, AckoladeStyle::Brace), AckoladeGroup::new(0, 0, AckoladeStyle::SubBracket)];
+        let graphic_items = output_ackolades(&matrix, &render_options);
+        let (brace_top, _) = get_staff_extent(&matrix, 2, &render_options).unwrap();
+        let brace = get_items_extent(&GraphicItems(vec![graphic_items.0[1].clone()])).unwrap();
+        assert!(brace.1 >= brace_top - SPACE && brace.3 <= bottom + SPACE);
+        let sub_bracket = get_items_extent(&GraphicItems(vec![graphic_items.0[2].clone()])).unwrap();
+        assert!(sub_bracket.3 < brace_top);
+    }
+}
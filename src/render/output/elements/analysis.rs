@@ -0,0 +1,164 @@
+use crate::prelude::*;
+use graphics::prelude::*;
+use notation_rs::prelude::*;
+
+// Distance from the outermost note to the innermost bracket
+const ANALYSIS_DISTANCE: f32 = SPACE * 1.5;
+// Distance between nesting levels
+const ANALYSIS_LEVEL_DISTANCE: f32 = SPACE * 2.5;
+const ANALYSIS_HOOK_LENGTH: f32 = SPACE_HALF;
+const ANALYSIS_BRACKET_WIDTH: f32 = 3.0;
+// Height of capitals in em units
+const ANALYSIS_CAP_HEIGHT: f32 = 700.0;
+const ANALYSIS_LABEL_GAP: f32 = SPACE_QUARTER;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AnalysisPlacement {
+    Above,
+    Below,
+}
+
+#[derive(Debug, Clone)]
+pub struct AnalysisBracket {
+    pub rowidx: usize,
+    pub from_colidx: usize,
+    pub to_colidx: usize,
+    // Form label, motif or cadence name in a gap of the bracket line, such as "A", "Motiv" or "Sats"
+    pub label: Option<String>,
+    pub placement: AnalysisPlacement,
+}
+
+impl AnalysisBracket {
+    pub fn new(rowidx: usize, from_colidx: usize, to_colidx: usize, placement: AnalysisPlacement) -> Self {
+        Self {
+            rowidx,
+            from_colidx,
+            to_colidx,
+            label: None,
+            placement,
+        }
+    }
+
+    pub fn with_label(mut self, label: &str) -> Self {
+        self.label = Some(label.to_string());
+        self
+    }
+
+    fn overlaps(&self, other: &AnalysisBracket) -> bool {
+        self.from_colidx <= other.to_colidx && other.from_colidx <= self.to_colidx
+    }
+
+    fn contains(&self, other: &AnalysisBracket) -> bool {
+        self.from_colidx <= other.from_colidx && other.to_colidx <= self.to_colidx
+    }
+}
+
+// Nesting level of each bracket, 0 next to the staff. Enclosing brackets go outside the brackets they contain,
+// and brackets that overlap without nesting are put on different levels.
+pub fn get_analysis_levels(brackets: &[AnalysisBracket]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..brackets.len()).collect();
+    order.sort_by_key(|idx| brackets[*idx].to_colidx.saturating_sub(brackets[*idx].from_colidx));
+
+    let mut levels: Vec<Option<usize>> = vec![None; brackets.len()];
+    for idx in order {
+        let bracket = &brackets[idx];
+        let same_side = |other: usize| other != idx && brackets[other].rowidx == bracket.rowidx && brackets[other].placement == bracket.placement;
+        let placed: Vec<(usize, usize)> = (0..brackets.len()).filter(|other| same_side(*other)).filter_map(|other| levels[other].map(|level| (other, level))).collect();
+
+        let mut level = placed.iter().filter(|(other, _)| bracket.contains(&brackets[*other])).map(|(_, level)| level + 1).max().unwrap_or(0);
+        while placed.iter().any(|(other, other_level)| *other_level == level && bracket.overlaps(&brackets[*other])) {
+            level += 1;
+        }
+        levels[idx] = Some(level);
+    }
+    levels.into_iter().map(|level| level.unwrap_or(0)).collect()
+}
+
+pub fn output_analysis_brackets(matrix: &RMatrix, render_options: &RenderOptions) -> GraphicItems {
+    let mut graphic_items = GraphicItems::new();
+    let brackets = &render_options.analysis_brackets;
+    let levels = get_analysis_levels(brackets);
+    let size = render_options.get_text_size(TextRole::Analysis);
+    let cap_height = ANALYSIS_CAP_HEIGHT * size;
+
+    for (bracket, level) in brackets.iter().zip(levels) {
+        let (staff_top, staff_bottom) = match get_staff_extent(matrix, bracket.rowidx, render_options) {
+            Some(extent) => extent,
+            None => continue,
+        };
+        let (x1, x2) = match (matrix.cols.get(bracket.from_colidx), matrix.cols.get(bracket.to_colidx)) {
+            (Some(first), Some(last)) => (first.borrow().x - SPACE_QUARTER, last.borrow().x + HEAD_WIDTH_BLACK + SPACE_QUARTER),
+            _ => continue,
+        };
+
+        // all brackets of a row and side line up from the outermost note of the row
+        let outermost = (0..matrix.cols.len()).filter_map(|colidx| get_row_item(matrix, bracket.rowidx, colidx)).map(|item| {
+            let item = item.borrow();
+            match bracket.placement {
+                AnalysisPlacement::Above => get_note_top_y(&item, staff_top),
+                AnalysisPlacement::Below => get_note_bottom_y(&item, staff_bottom),
+            }
+        });
+        let distance = ANALYSIS_DISTANCE + level as f32 * ANALYSIS_LEVEL_DISTANCE;
+        let (y, sign) = match bracket.placement {
+            AnalysisPlacement::Above => (outermost.fold(staff_top, f32::min) - distance, -1.0),
+            AnalysisPlacement::Below => (outermost.fold(staff_bottom, f32::max) + distance, 1.0),
+        };
+
+        // hooks point towards the staff, the label sits in a gap of the line
+        let hook_y = y - sign * ANALYSIS_HOOK_LENGTH;
+        let segments = match &bracket.label {
+            Some(label) => {
+                let width = render_options.get_text_width(TextRole::Analysis, label) * size;
+                let xmid = x1 + (x2 - x1) / 2.0;
+                let gap_x1 = (xmid - width / 2.0 - ANALYSIS_LABEL_GAP).max(x1);
+                let gap_x2 = (xmid + width / 2.0 + ANALYSIS_LABEL_GAP).min(x2);
+                graphic_items.push(render_options.get_text_item(TextRole::Analysis, label, xmid - width / 2.0, y + cap_height / 2.0));
+                vec![M(x1, hook_y), L(x1, y), L(gap_x1, y), M(gap_x2, y), L(x2, y), L(x2, hook_y)]
+            }
+            None => vec![M(x1, hook_y), L(x1, y), L(x2, y), L(x2, hook_y)],
+        };
+        graphic_items.push(Path(PathSegments(segments), Strokestyle(ANALYSIS_BRACKET_WIDTH, Black), NoFill, PathCacheInfo::NoCache));
+    }
+    graphic_items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nested_brackets_stack_outwards() {
+        let brackets = vec![
+            AnalysisBracket::new(0, 0, 7, AnalysisPlacement::Above).with_label("A"),
+            AnalysisBracket::new(0, 0, 3, AnalysisPlacement::Above).with_label("Motiv"),
+            AnalysisBracket::new(0, 4, 7, AnalysisPlacement::Above),
+            AnalysisBracket::new(0, 2, 5, AnalysisPlacement::Above),
+            AnalysisBracket::new(0, 0, 7, AnalysisPlacement::Below).with_label("Sats"),
+        ];
+        assert_eq!(get_analysis_levels(&brackets), vec![2, 0, 0, 1, 0]);
+    }
+
+    #[test]
+    fn brackets_clear_the_outermost_note() {
+        use crate::render::output::elements::testutils::*;
+        // the upward stem of the high note reaches above the staff
+        let matrix = get_test_matrix("|clef G | 1 -6 2 3");
+        let cols = get_test_note_cols(&matrix, 0);
+        let mut render_options = RenderOptions::default();
+        let (staff_top, staff_bottom) = get_staff_extent(&matrix, 0, &render_options).unwrap();
+        let high = get_row_item(&matrix, 0, cols[1]).unwrap();
+        let note_top = get_note_top_y(&high.borrow(), staff_top);
+
+        render_options.analysis_brackets.push(AnalysisBracket::new(0, cols[2], cols[3], AnalysisPlacement::Above));
+        let (x1, _, x2, y2) = get_items_extent(&output_analysis_brackets(&matrix, &render_options)).unwrap();
+        assert!((x1 - (get_test_col_x(&matrix, cols[2]) - SPACE_QUARTER)).abs() < 0.01);
+        assert!((x2 - (get_test_col_x(&matrix, cols[3]) + HEAD_WIDTH_BLACK + SPACE_QUARTER)).abs() < 0.01);
+        // brackets line up above the highest note of the row, also outside their own span
+        assert!(y2 < note_top);
+
+        render_options.analysis_brackets = vec![AnalysisBracket::new(0, cols[0], cols[3], AnalysisPlacement::Below).with_label("A")];
+        let (_, y1, _, _) = get_items_extent(&output_analysis_brackets(&matrix, &render_options)).unwrap();
+        assert!(y1 > staff_bottom);
+    }
+}
@@ -1,39 +1,44 @@
+use crate::prelude::*;
+use crate::render::fonts::smufl::EngravingDefaults;
 use graphics::prelude::*;
 use notation_rs::prelude::*;
 use std::cell::Ref;
 
-pub fn do_single(first_data: &RItemBeamData, coords: NPoint) -> GraphicItems {
+pub fn do_single(first_data: &RItemBeamData, coords: NPoint, tremolo_strokes: u8, engraving: &EngravingDefaults) -> GraphicItems {
     let mut graphic_items = GraphicItems::new();
 
     let direction_sign = first_data.direction.sign();
-    let first_coords = (*&coords.0 + get_head_x_adjustment(first_data), *&coords.1);
+    let (first_x, first_bop_y) = get_stem_base(first_data, coords, engraving);
+    let first_coords = (first_x, *&coords.1);
 
     let first_tip_y = first_coords.1 + (first_data.tip_level * SPACE_HALF) + (STEM_LENGTH * SPACE_HALF) * direction_sign;
-    let first_bop_y = first_coords.1
-        + match first_data.direction {
-            DirUD::Up => first_data.bottom_level as f32 * SPACE_HALF - STEM_HEAD_CORRECTION,
-            DirUD::Down => first_data.top_level as f32 * SPACE_HALF + STEM_HEAD_CORRECTION,
-        };
     let (rect_y, rect_y2, rect_h) = match first_data.direction {
         DirUD::Down => (first_bop_y, first_tip_y, first_tip_y - first_bop_y),
         DirUD::Up => (first_tip_y, first_bop_y, first_bop_y - first_tip_y),
     };
 
-    graphic_items.push(Line(first_coords.0, rect_y, first_coords.0, rect_y2, Strokestyle(STEM_WIDTH, Black)));
+    graphic_items.push(Line(first_coords.0, rect_y, first_coords.0, rect_y2, Strokestyle(engraving.stem_width, Black)));
+
+    if tremolo_strokes > 0 {
+        // keep clear of the flag
+        let tip_distance = match duration_to_beamtype(&first_data.duration) {
+            BeamType::B8 | BeamType::B16 => SPACE * 2.0,
+            _ => SPACE,
+        };
+        graphic_items.extend(do_tremolo_strokes(first_coords.0, first_tip_y - direction_sign * tip_distance, direction_sign, tremolo_strokes, engraving));
+    }
 
     graphic_items
 }
 
-pub fn do_beam(items: &Vec<(RItemBeamData, NPoint)>) -> GraphicItems {
+pub fn do_beam(items: &Vec<(RItemBeamData, NPoint)>, tremolo_strokes: &[u8], engraving: &EngravingDefaults) -> GraphicItems {
     let mut graphic_items = GraphicItems::new();
 
     match items.len() {
         0 => {
             panic!("do_beams: items.len() == 0");
         }
-        1 => {
-            println!("Single beam item");
-        }
+        1 => {}
         _ => {
             // println!("Multiple beam item - items.len():{}", items.len());
             let last_idx = items.len() - 1;
@@ -41,34 +46,28 @@ pub fn do_beam(items: &Vec<(RItemBeamData, NPoint)>) -> GraphicItems {
             // first
             let first_data: &RItemBeamData = &items[0].0;
             let direction_sign = first_data.direction.sign();
-            let first_coords = (*&items[0].1 .0 + get_head_x_adjustment(first_data), *&items[0].1 .1);
+            let (first_x, first_bop_y) = get_stem_base(first_data, items[0].1, engraving);
+            let first_coords = (first_x, *&items[0].1 .1);
             let first_tip_y = first_coords.1 + (first_data.tip_level * SPACE_HALF) + (STEM_LENGTH * SPACE_HALF) * direction_sign;
-            let first_bop_y = first_coords.1
-                + match first_data.direction {
-                    DirUD::Up => first_data.bottom_level as f32 * SPACE_HALF - STEM_HEAD_CORRECTION,
-                    DirUD::Down => first_data.top_level as f32 * SPACE_HALF + STEM_HEAD_CORRECTION,
-                };
             let (rect_y, rect_y2, rect_h) = match first_data.direction {
                 DirUD::Up => (first_tip_y, first_bop_y, first_bop_y - first_tip_y),
                 DirUD::Down => (first_bop_y, first_tip_y, first_tip_y - first_bop_y),
             };
-            graphic_items.push(Line(first_coords.0, rect_y, first_coords.0, rect_y2, Strokestyle(STEM_WIDTH, Black)));
+            graphic_items.push(Line(first_coords.0, rect_y, first_coords.0, rect_y2, Strokestyle(engraving.stem_width, Black)));
+            graphic_items.extend(do_beam_tremolo(first_data, first_coords.0, first_tip_y, tremolo_strokes.first(), engraving));
 
             // last
             let last_data = &items[last_idx].0;
-            let last_coords = (*&items[last_idx].1 .0 + get_head_x_adjustment(last_data), *&items[last_idx].1 .1);
+            let (last_x, last_bop_y) = get_stem_base(last_data, items[last_idx].1, engraving);
+            let last_coords = (last_x, *&items[last_idx].1 .1);
             let last_tip_y = last_coords.1 + (last_data.tip_level * SPACE_HALF) + (STEM_LENGTH * SPACE_HALF) * direction_sign;
-            let last_bop_y = last_coords.1
-                + match last_data.direction {
-                    DirUD::Up => last_data.bottom_level as f32 * SPACE_HALF - STEM_HEAD_CORRECTION,
-                    DirUD::Down => last_data.top_level as f32 * SPACE_HALF + STEM_HEAD_CORRECTION,
-                };
             let (rect_y, rect_y2, rect_h) = match last_data.direction {
                 DirUD::Up => (last_tip_y, last_bop_y, last_bop_y - last_tip_y),
                 DirUD::Down => (last_bop_y, last_tip_y, last_tip_y - last_bop_y),
             };
 
-            graphic_items.push(Line(last_coords.0, rect_y, last_coords.0, rect_y2, Strokestyle(STEM_WIDTH, Black)));
+            graphic_items.push(Line(last_coords.0, rect_y, last_coords.0, rect_y2, Strokestyle(engraving.stem_width, Black)));
+            graphic_items.extend(do_beam_tremolo(last_data, last_coords.0, last_tip_y, tremolo_strokes.get(last_idx), engraving));
 
             //================================================================
             let beam_width = last_coords.0 - first_coords.0;
@@ -78,31 +77,26 @@ pub fn do_beam(items: &Vec<(RItemBeamData, NPoint)>) -> GraphicItems {
             let mut tip_coords: Vec<(f32, f32, f32)> = vec![(first_coords.0, first_tip_y, 0.)];
             if items.len() > 2 {
                 let middle_items = &items[1..last_idx];
-                for middle_item in middle_items {
+                for (middle_idx, middle_item) in middle_items.iter().enumerate() {
                     let middle_data = &middle_item.0;
-                    let middle_coords = (middle_item.1 .0 + get_head_x_adjustment(last_data), middle_item.1 .1);
+                    let (middle_x, middle_bop_y) = get_stem_base(middle_data, middle_item.1, engraving);
+                    let middle_coords = (middle_x, middle_item.1 .1);
 
                     let fraction = (middle_coords.0 - first_coords.0) / beam_width;
                     let middle_tip_y = first_tip_y + (beam_height * fraction);
 
-                    let middle_coords = (middle_item.1 .0 + get_head_x_adjustment(last_data), middle_item.1 .1);
-                    let middle_bop_y = middle_coords.1
-                        + match middle_data.direction {
-                            DirUD::Up => middle_data.bottom_level as f32 * SPACE_HALF - STEM_HEAD_CORRECTION,
-                            DirUD::Down => middle_data.top_level as f32 * SPACE_HALF + STEM_HEAD_CORRECTION,
-                        };
-
                     let (rect_y, rect_y2, rect_h) = match middle_data.direction {
                         DirUD::Down => (middle_bop_y, middle_tip_y, middle_tip_y - middle_bop_y),
                         DirUD::Up => (middle_tip_y, middle_bop_y, middle_bop_y - middle_tip_y),
                     };
 
-                    graphic_items.push(Line(middle_coords.0, rect_y, middle_coords.0, rect_y2, Strokestyle(STEM_WIDTH, Black)));
+                    graphic_items.push(Line(middle_coords.0, rect_y, middle_coords.0, rect_y2, Strokestyle(engraving.stem_width, Black)));
+                    graphic_items.extend(do_beam_tremolo(middle_data, middle_coords.0, middle_tip_y, tremolo_strokes.get(middle_idx + 1), engraving));
                     tip_coords.push((middle_coords.0, middle_tip_y, fraction));
                 }
             }
             tip_coords.push((last_coords.0, last_tip_y, 1.0));
-            let sub_beam_graphic_items = do_sub_beams(beam_width, beam_height, &tip_coords, last_data.direction, &last_data.note_durations.as_ref().unwrap());
+            let sub_beam_graphic_items = do_sub_beams(beam_width, beam_height, &tip_coords, last_data.direction, &last_data.note_durations.as_ref().unwrap(), engraving.beam_height);
             graphic_items.extend(sub_beam_graphic_items);
         }
     }
@@ -110,7 +104,34 @@ pub fn do_beam(items: &Vec<(RItemBeamData, NPoint)>) -> GraphicItems {
     graphic_items
 }
 
-pub fn do_sub_beams(beam_width: f32, beam_height: f32, tip_coords: &Vec<(f32, f32, f32)>, direction: DirUD, durations: &Vec<Duration>) -> GraphicItems {
+// Stem x and the y where the stem meets the head. With a music font the stem attaches at the notehead anchors,
+// otherwise at the fixed offsets of the built-in heads.
+pub fn get_stem_base(data: &RItemBeamData, coords: NPoint, engraving: &EngravingDefaults) -> (f32, f32) {
+    let top_y = coords.1 + data.top_level as f32 * SPACE_HALF;
+    let bottom_y = coords.1 + data.bottom_level as f32 * SPACE_HALF;
+    match (data.direction, engraving.stem_up_se, engraving.stem_down_nw) {
+        (DirUD::Up, Some((x, y)), _) => (coords.0 + x - engraving.stem_width / 2.0, bottom_y + y),
+        (DirUD::Down, _, Some((x, y))) => (coords.0 + x + engraving.stem_width / 2.0, top_y + y),
+        (DirUD::Up, None, _) => (coords.0 + get_head_x_adjustment(data), bottom_y - STEM_HEAD_CORRECTION),
+        (DirUD::Down, _, None) => (coords.0 + get_head_x_adjustment(data), top_y + STEM_HEAD_CORRECTION),
+    }
+}
+
+// Tremolo strokes on a beamed stem, placed inside the beams
+fn do_beam_tremolo(data: &RItemBeamData, stem_x: f32, tip_y: f32, tremolo_strokes: Option<&u8>, engraving: &EngravingDefaults) -> GraphicItems {
+    let strokes = *tremolo_strokes.unwrap_or(&0);
+    if strokes == 0 {
+        return GraphicItems::new();
+    }
+    let beams = match duration_to_beamtype(&data.duration) {
+        BeamType::B16 => 2.0,
+        _ => 1.0,
+    };
+    let direction_sign = data.direction.sign();
+    do_tremolo_strokes(stem_x, tip_y - direction_sign * (beams * BEAM_SUB_DISTANCE + SPACE_HALF), direction_sign, strokes, engraving)
+}
+
+pub fn do_sub_beams(beam_width: f32, beam_height: f32, tip_coords: &Vec<(f32, f32, f32)>, direction: DirUD, durations: &Vec<Duration>, beam_thickness: f32) -> GraphicItems {
     let mut graphic_items = GraphicItems::new();
     let lastidx = tip_coords.len() - 1;
     let beamtypes: Vec<BeamType> = durations.iter().map(|d| duration_to_beamtype(d)).collect::<Vec<BeamType>>();
@@ -124,8 +145,8 @@ pub fn do_sub_beams(beam_width: f32, beam_height: f32, tip_coords: &Vec<(f32, f3
     // graphic_items.push(Line(x, y, x2, y2, Strokestyle(DEV_LINE_THICKNESS, Blue)));
 
     let beamheight = match direction {
-        DirUD::Down => -BEAM_HEIGHT,
-        DirUD::Up => BEAM_HEIGHT,
+        DirUD::Down => -beam_thickness,
+        DirUD::Up => beam_thickness,
     };
 
     graphic_items.push(Path(
@@ -142,8 +163,8 @@ pub fn do_sub_beams(beam_width: f32, beam_height: f32, tip_coords: &Vec<(f32, f3
             // graphic_items.push(Line(x, y, x2, y2, Strokestyle(DEV_LINE_THICKNESS, Blue)));
 
             let beamheight = match direction {
-                DirUD::Down => -BEAM_HEIGHT,
-                DirUD::Up => BEAM_HEIGHT,
+                DirUD::Down => -beam_thickness,
+                DirUD::Up => beam_thickness,
             };
 
             graphic_items.push(Path(
@@ -153,15 +174,15 @@ pub fn do_sub_beams(beam_width: f32, beam_height: f32, tip_coords: &Vec<(f32, f3
                 PathCacheInfo::NoCache,
             ));
         }
-        [B8, B16] | [B8, B16, B8] => graphic_items.extend(do_sub_sixteen_rightside(sixteenths[0], sixteenths[1], direction)),
-        [B16, B8] => graphic_items.extend(do_sub_sixteen_leftside(sixteenths[0], sixteenths[1], direction)),
-        [B16, B16, B8] | [B16, B16, B8, B8] => graphic_items.extend(do_sub_sixteen(sixteenths[0], sixteenths[1], direction)),
+        [B8, B16] | [B8, B16, B8] => graphic_items.extend(do_sub_sixteen_rightside(sixteenths[0], sixteenths[1], direction, beam_thickness)),
+        [B16, B8] => graphic_items.extend(do_sub_sixteen_leftside(sixteenths[0], sixteenths[1], direction, beam_thickness)),
+        [B16, B16, B8] | [B16, B16, B8, B8] => graphic_items.extend(do_sub_sixteen(sixteenths[0], sixteenths[1], direction, beam_thickness)),
         [B16, B8, B16] => {
-            graphic_items.extend(do_sub_sixteen_leftside(sixteenths[0], sixteenths[1], direction));
-            graphic_items.extend(do_sub_sixteen_rightside(sixteenths[1], sixteenths[2], direction))
+            graphic_items.extend(do_sub_sixteen_leftside(sixteenths[0], sixteenths[1], direction, beam_thickness));
+            graphic_items.extend(do_sub_sixteen_rightside(sixteenths[1], sixteenths[2], direction, beam_thickness))
         }
         [B8, B16, B16] | [B8, B16, B16, B8] => {
-            graphic_items.extend(do_sub_sixteen(sixteenths[1], sixteenths[2], direction));
+            graphic_items.extend(do_sub_sixteen(sixteenths[1], sixteenths[2], direction, beam_thickness));
         }
 
         _ => println!("Unhandled durastions for sub_beaming"),
@@ -170,7 +191,7 @@ pub fn do_sub_beams(beam_width: f32, beam_height: f32, tip_coords: &Vec<(f32, f3
     graphic_items
 }
 
-pub fn do_sub_sixteen_rightside(left: (f32, f32), right: (f32, f32), direction: DirUD) -> GraphicItems {
+pub fn do_sub_sixteen_rightside(left: (f32, f32), right: (f32, f32), direction: DirUD, beam_thickness: f32) -> GraphicItems {
     let mut graphic_items = GraphicItems::new();
     let width = right.0 - left.0;
     let height = right.1 - left.1;
@@ -182,8 +203,8 @@ pub fn do_sub_sixteen_rightside(left: (f32, f32), right: (f32, f32), direction:
     // graphic_items.push(Line(x, y, x2, y2, Strokestyle(DEV_LINE_THICKNESS, Red)));
 
     let beamheight = match direction {
-        DirUD::Down => -BEAM_HEIGHT,
-        DirUD::Up => BEAM_HEIGHT,
+        DirUD::Down => -beam_thickness,
+        DirUD::Up => beam_thickness,
     };
 
     graphic_items.push(Path(
@@ -196,14 +217,14 @@ pub fn do_sub_sixteen_rightside(left: (f32, f32), right: (f32, f32), direction:
     graphic_items
 }
 
-pub fn do_sub_sixteen(left: (f32, f32), right: (f32, f32), direction: DirUD) -> GraphicItems {
+pub fn do_sub_sixteen(left: (f32, f32), right: (f32, f32), direction: DirUD, beam_thickness: f32) -> GraphicItems {
     let mut graphic_items = GraphicItems::new();
     let (x, y, x2, y2) = (left.0 - STEM_WIDTH_HALF, left.1, right.0 + STEM_WIDTH_HALF, right.1);
     // graphic_items.push(Line(x, y, x2, y2, Strokestyle(DEV_LINE_THICKNESS, Red)));
 
     let beamheight = match direction {
-        DirUD::Down => -BEAM_HEIGHT,
-        DirUD::Up => BEAM_HEIGHT,
+        DirUD::Down => -beam_thickness,
+        DirUD::Up => beam_thickness,
     };
 
     graphic_items.push(Path(
@@ -216,19 +237,18 @@ pub fn do_sub_sixteen(left: (f32, f32), right: (f32, f32), direction: DirUD) ->
     graphic_items
 }
 
-pub fn do_sub_sixteen_leftside(left: (f32, f32), right: (f32, f32), direction: DirUD) -> GraphicItems {
+pub fn do_sub_sixteen_leftside(left: (f32, f32), right: (f32, f32), direction: DirUD, beam_thickness: f32) -> GraphicItems {
     let mut graphic_items = GraphicItems::new();
     let width = right.0 - left.0;
     let height = right.1 - left.1;
     let fraction = (width - HEAD_WIDTH_BLACK) / width;
-    dbg!(fraction);
     let tip_left_x = left.0 + HEAD_WIDTH_BLACK;
     let tip_left_y = left.1 + (fraction * height);
     let (x, y, x2, y2) = (left.0 - STEM_WIDTH_HALF, left.1, tip_left_x + STEM_WIDTH_HALF, tip_left_y);
 
     let beamheight = match direction {
-        DirUD::Down => -BEAM_HEIGHT,
-        DirUD::Up => BEAM_HEIGHT,
+        DirUD::Down => -beam_thickness,
+        DirUD::Up => beam_thickness,
     };
 
     graphic_items.push(Path(
@@ -241,3 +261,14 @@ pub fn do_sub_sixteen_leftside(left: (f32, f32), right: (f32, f32), direction: D
     graphic_items.push(Line(x, y, x2, y2, Strokestyle(DEV_LINE_THICKNESS, Red)));
     graphic_items
 }
+
+pub fn get_beam_data(beam: &RItemBeam) -> Option<&RItemBeamData> {
+    match beam {
+        RItemBeam::Single(data) | RItemBeam::Start(data) | RItemBeam::Middle(data) | RItemBeam::End(data) => Some(data),
+        _ => None,
+    }
+}
+
+pub fn get_stem_tip_y(data: &RItemBeamData, coords: NPoint) -> f32 {
+    coords.1 + (data.tip_level * SPACE_HALF) + (STEM_LENGTH * SPACE_HALF) * data.direction.sign()
+}
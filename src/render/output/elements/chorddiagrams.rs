@@ -0,0 +1,284 @@
+use crate::prelude::*;
+use graphics::prelude::*;
+use notation_rs::prelude::*;
+use std::sync::OnceLock;
+
+const DIAGRAM_STRING_DISTANCE: f32 = SPACE * 0.8;
+const DIAGRAM_FRET_DISTANCE: f32 = SPACE;
+const DIAGRAM_MIN_FRETS: u8 = 4;
+// Distance from the diagram to the chord symbol below it
+const DIAGRAM_DISTANCE: f32 = SPACE * 0.8;
+const DIAGRAM_LINE_WIDTH: f32 = 2.0;
+const DIAGRAM_NUT_WIDTH: f32 = SPACE * 0.3;
+const DIAGRAM_DOT_RADIUS: f32 = SPACE * 0.3;
+const DIAGRAM_MARKER_SIZE: f32 = SPACE * 0.25;
+const DIAGRAM_MARKER_DISTANCE: f32 = SPACE * 0.5;
+const DIAGRAM_BARRE_WIDTH: f32 = 5.0;
+const DIAGRAM_LABEL_SCALE: f32 = 0.8;
+// Height of digits in em units
+const DIAGRAM_LABEL_HEIGHT: f32 = 700.0;
+
+// Frets from the lowest string, None for muted strings
+const DEFAULT_VOICINGS: [(&str, &str); 36] = [
+    ("C", "x32010"),
+    ("D", "xx0232"),
+    ("E", "022100"),
+    ("F", "133211"),
+    ("G", "320003"),
+    ("A", "x02220"),
+    ("B", "x24442"),
+    ("Cm", "x35543"),
+    ("Dm", "xx0231"),
+    ("Em", "022000"),
+    ("Fm", "133111"),
+    ("Gm", "355333"),
+    ("Am", "x02210"),
+    ("Bm", "x24432"),
+    ("C7", "x32310"),
+    ("D7", "xx0212"),
+    ("E7", "020100"),
+    ("G7", "320001"),
+    ("A7", "x02020"),
+    ("B7", "x21202"),
+    ("Cmaj7", "x32000"),
+    ("Dmaj7", "xx0222"),
+    ("Fmaj7", "xx3210"),
+    ("Gmaj7", "320002"),
+    ("Amaj7", "x02120"),
+    ("Dm7", "xx0211"),
+    ("Em7", "022030"),
+    ("Am7", "x02010"),
+    ("Bm7", "x20202"),
+    ("Dsus4", "xx0233"),
+    ("Asus4", "x02230"),
+    ("Esus4", "022200"),
+    ("Dsus2", "xx0230"),
+    ("Asus2", "x02200"),
+    ("E5", "022xxx"),
+    ("A5", "x022xx"),
+];
+
+// DEFAULT_VOICINGS parsed on first use
+static PARSED_VOICINGS: OnceLock<Vec<ChordVoicing>> = OnceLock::new();
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Barre {
+    pub fret: u8,
+    // Strings counted from the lowest, 0 based
+    pub from_string: usize,
+    pub to_string: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChordVoicing {
+    pub chord: ChordSymbol,
+    // Frets from the lowest string, 0 for open and None for muted strings
+    pub frets: Vec<Option<u8>>,
+    pub barre: Option<Barre>,
+}
+
+impl ChordVoicing {
+    pub fn new(chord: ChordSymbol, frets: Vec<Option<u8>>) -> Self {
+        let barre = find_barre(&frets);
+        Self { chord, frets, barre }
+    }
+
+    // Chord symbol and frets such as ("C", "x32010") or ("Eb", "x-6-8-8-8-6"), barres are found automatically
+    pub fn parse(symbol: &str, frets: &str) -> Option<Self> {
+        let chord = ChordSymbol::parse(symbol)?;
+        let parse_fret = |fret: &str| match fret {
+            "x" | "X" => Some(None),
+            _ => fret.parse().ok().map(Some),
+        };
+        let frets = match frets.contains('-') {
+            true => frets.split('-').map(parse_fret).collect::<Option<Vec<_>>>()?,
+            false => frets.chars().map(|c| parse_fret(&c.to_string())).collect::<Option<Vec<_>>>()?,
+        };
+        Some(Self::new(chord, frets))
+    }
+
+    pub fn with_barre(mut self, barre: Barre) -> Self {
+        self.barre = Some(barre);
+        self
+    }
+
+    // First fret shown, 1 when the voicing and its barre fit below the nut
+    pub fn get_start_fret(&self) -> u8 {
+        let barre = self.barre.map(|barre| barre.fret);
+        let fretted = self.frets.iter().flatten().copied().chain(barre).filter(|fret| *fret > 0);
+        match fretted.clone().max() {
+            Some(max) if max > DIAGRAM_MIN_FRETS => fretted.min().unwrap(),
+            _ => 1,
+        }
+    }
+
+    fn matches(&self, chord: &ChordSymbol) -> bool {
+        self.chord.root.get_pitch_class().rem_euclid(12) == chord.root.get_pitch_class().rem_euclid(12) && self.chord.quality == chord.quality && self.chord.extension == chord.extension
+    }
+}
+
+// Barre over the lowest fret when every sounding string is fretted at or above it
+fn find_barre(frets: &[Option<u8>]) -> Option<Barre> {
+    let sounding: Vec<(usize, u8)> = frets.iter().enumerate().filter_map(|(string, fret)| fret.map(|fret| (string, fret))).collect();
+    let lowest = sounding.iter().map(|(_, fret)| *fret).min()?;
+    let on_lowest: Vec<usize> = sounding.iter().filter(|(_, fret)| *fret == lowest).map(|(string, _)| *string).collect();
+    match lowest > 0 && on_lowest.len() >= 2 {
+        true => Some(Barre {
+            fret: lowest,
+            from_string: on_lowest[0],
+            to_string: on_lowest[on_lowest.len() - 1],
+        }),
+        false => None,
+    }
+}
+
+// Movable barre shape with the root on the low E or the A string, for chords missing from the tables
+fn get_movable_voicing(chord: &ChordSymbol) -> Option<ChordVoicing> {
+    let (e_shape, a_shape) = match (chord.quality, chord.extension) {
+        (ChordQuality::Major, ChordExtension::None) => ("022100", "x02220"),
+        (ChordQuality::Minor, ChordExtension::None) => ("022000", "x02210"),
+        (ChordQuality::Major, ChordExtension::Seven) => ("020100", "x02020"),
+        (ChordQuality::Minor, ChordExtension::Seven) => ("020000", "x02010"),
+        (ChordQuality::Major, ChordExtension::MajSeven) => ("0x110x", "x02120"),
+        (ChordQuality::Power, ChordExtension::None) => ("022xxx", "x022xx"),
+        _ => return None,
+    };
+    let pitch_class = chord.root.get_pitch_class();
+    let e_fret = (pitch_class - 4).rem_euclid(12) as u8;
+    let a_fret = (pitch_class - 9).rem_euclid(12) as u8;
+    let (shape, offset) = if e_fret <= a_fret { (e_shape, e_fret) } else { (a_shape, a_fret) };
+    let frets = shape.chars().map(|c| c.to_digit(10).map(|fret| fret as u8 + offset)).collect();
+    Some(ChordVoicing::new(chord.clone(), frets))
+}
+
+// User voicings first, then the default table, then a movable shape
+pub fn get_voicing(chord: &ChordSymbol, voicings: &[ChordVoicing]) -> Option<ChordVoicing> {
+    if let Some(voicing) = voicings.iter().find(|voicing| voicing.matches(chord)) {
+        return Some(voicing.clone());
+    }
+    let defaults = PARSED_VOICINGS.get_or_init(|| DEFAULT_VOICINGS.iter().filter_map(|(symbol, frets)| ChordVoicing::parse(symbol, frets)).collect());
+    defaults.iter().find(|voicing| voicing.matches(chord)).cloned().or_else(|| get_movable_voicing(chord))
+}
+
+// Fretboard diagram with its lower left corner at x, y
+pub fn diagram2graphic(voicing: &ChordVoicing, x: f32, y: f32, render_options: &RenderOptions) -> Vec<GraphicItem> {
+    let mut v = Vec::new();
+    let strings = voicing.frets.len().max(1);
+    let start_fret = voicing.get_start_fret();
+    let highest = voicing.frets.iter().flatten().copied().chain(voicing.barre.map(|barre| barre.fret)).max().unwrap_or(0);
+    let fret_count = DIAGRAM_MIN_FRETS.max(highest.saturating_sub(start_fret) + 1);
+
+    let width = (strings - 1) as f32 * DIAGRAM_STRING_DISTANCE;
+    let height = fret_count as f32 * DIAGRAM_FRET_DISTANCE;
+    let top = y - height;
+    let string_x = |string: usize| x + string as f32 * DIAGRAM_STRING_DISTANCE;
+    let fret_y = |fret: u8| top + fret.saturating_sub(start_fret) as f32 * DIAGRAM_FRET_DISTANCE + DIAGRAM_FRET_DISTANCE * 0.5;
+
+    let stroke = || Strokestyle(DIAGRAM_LINE_WIDTH, Black);
+    for string in 0..strings {
+        v.push(Line(string_x(string), top, string_x(string), y, stroke()));
+    }
+    for fret in 0..=fret_count {
+        let line_y = top + fret as f32 * DIAGRAM_FRET_DISTANCE;
+        v.push(Line(x, line_y, x + width, line_y, stroke()));
+    }
+
+    // the nut, or the number of the first fret beside it
+    if start_fret == 1 {
+        v.push(Rect(x - DIAGRAM_LINE_WIDTH * 0.5, top - DIAGRAM_NUT_WIDTH, width + DIAGRAM_LINE_WIDTH, DIAGRAM_NUT_WIDTH, NoStroke, Fillstyle(Black)));
+    } else {
+        let size = render_options.get_text_size(TextRole::Tab) * DIAGRAM_LABEL_SCALE;
+        let label = format!("{}fr", start_fret);
+        v.push(Path(
+            render_options
+                .get_text_path(TextRole::Tab, &label)
+                .scale_path(size, size)
+                .move_path(x + width + DIAGRAM_MARKER_SIZE * 2.0, fret_y(start_fret) + DIAGRAM_LABEL_HEIGHT * size * 0.5),
+            render_options.fonts.get_stroke(TextRole::Tab),
+            Fillstyle(Black),
+            PathCacheInfo::NoCache,
+        ));
+    }
+
+    let marker_y = top - DIAGRAM_NUT_WIDTH - DIAGRAM_MARKER_DISTANCE;
+    for (string, fret) in voicing.frets.iter().enumerate() {
+        let sx = string_x(string);
+        match fret {
+            None => {
+                let d = DIAGRAM_MARKER_SIZE;
+                v.push(Line(sx - d, marker_y - d, sx + d, marker_y + d, stroke()));
+                v.push(Line(sx - d, marker_y + d, sx + d, marker_y - d, stroke()));
+            }
+            Some(0) => v.push(Path(PathSegments(get_circle_segments(sx, marker_y, DIAGRAM_MARKER_SIZE)), stroke(), NoFill, PathCacheInfo::NoCache)),
+            Some(fret) => {
+                // strings under the barre are covered by the arc
+                let barred = voicing.barre.is_some_and(|barre| barre.fret == *fret && (barre.from_string..=barre.to_string).contains(&string));
+                if !barred {
+                    v.push(Path(PathSegments(get_circle_segments(sx, fret_y(*fret), DIAGRAM_DOT_RADIUS)), NoStroke, Fillstyle(Black), PathCacheInfo::NoCache));
+                }
+            }
+        }
+    }
+
+    if let Some(barre) = voicing.barre {
+        let (x1, x2) = (string_x(barre.from_string), string_x(barre.to_string));
+        let by = fret_y(barre.fret);
+        v.push(Path(
+            PathSegments(vec![M(x1, by), Q((x1 + x2) * 0.5, by - DIAGRAM_FRET_DISTANCE * 0.8, x2, by)]),
+            Strokestyle(DIAGRAM_BARRE_WIDTH, Black),
+            NoFill,
+            PathCacheInfo::NoCache,
+        ));
+        for sx in [x1, x2] {
+            v.push(Path(PathSegments(get_circle_segments(sx, by, DIAGRAM_DOT_RADIUS)), NoStroke, Fillstyle(Black), PathCacheInfo::NoCache));
+        }
+    }
+    v
+}
+
+// Diagram above a chord symbol whose text top is at y
+pub fn do_chord_diagram(chord: &ChordSymbol, x: f32, y: f32, render_options: &RenderOptions) -> Vec<GraphicItem> {
+    match get_voicing(chord, &render_options.chord_voicings) {
+        Some(voicing) => diagram2graphic(&voicing, x, y - DIAGRAM_DISTANCE, render_options),
+        None => vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn voicings_and_barres() {
+        let voicing = ChordVoicing::parse("F", "133211").unwrap();
+        assert_eq!(voicing.barre, Some(Barre { fret: 1, from_string: 0, to_string: 5 }));
+        assert_eq!(ChordVoicing::parse("C", "x32010").unwrap().barre, None);
+        assert_eq!(ChordVoicing::parse("Eb", "x-6-8-8-8-6").unwrap().get_start_fret(), 6);
+        let barre = Barre { fret: 3, from_string: 1, to_string: 5 };
+        assert_eq!(ChordVoicing::parse("Eb", "x-6-8-8-8-6").unwrap().with_barre(barre).get_start_fret(), 3);
+
+        let voicing = get_voicing(&ChordSymbol::parse("Am").unwrap(), &[]).unwrap();
+        assert_eq!(voicing.frets, vec![None, Some(0), Some(2), Some(2), Some(1), Some(0)]);
+        let voicing = get_voicing(&ChordSymbol::parse("Abm").unwrap(), &[]).unwrap();
+        assert_eq!(voicing.frets[0], Some(4));
+    }
+
+    #[test]
+    fn diagrams_above_chord_symbols() {
+        use crate::render::output::elements::testutils::*;
+        let matrix = get_test_matrix("|clef G | 0");
+        let colidx = get_test_note_cols(&matrix, 0)[0];
+        let mut render_options = RenderOptions::default();
+        render_options.chords.push(ChordMark::new(0, colidx, ChordSymbol::parse("Am").unwrap()));
+        let (_, symbol_top, _, _) = get_items_extent(&output_chords(&matrix, &render_options)).unwrap();
+        render_options.chord_diagrams = true;
+        let (_, diagram_top, _, _) = get_items_extent(&output_chords(&matrix, &render_options)).unwrap();
+        assert!(diagram_top < symbol_top - DIAGRAM_FRET_DISTANCE * DIAGRAM_MIN_FRETS as f32);
+
+        // chords without a voicing get no diagram
+        render_options.chords[0].chord = ChordSymbol::parse("C13(b9,#11)").unwrap();
+        let with_diagrams = output_chords(&matrix, &render_options).0.len();
+        render_options.chord_diagrams = false;
+        assert_eq!(output_chords(&matrix, &render_options).0.len(), with_diagrams);
+    }
+}
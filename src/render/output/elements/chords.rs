@@ -0,0 +1,854 @@
+use crate::prelude::*;
+use crate::render::output::elements::elements::take;
+use crate::render::fonts::metrics::get_path_advance;
+use graphics::path::PathSegment;
+use graphics::prelude::*;
+use notation_rs::prelude::*;
+
+pub const CHORD_BASS_SLASH_WIDTH: f32 = SPACE * 1.4;
+const CHORD_BASS_SLASH_STROKE: f32 = 5.0;
+// Raise of figures and their accidentals above the text baseline
+const CHORD_FIGURE_RAISE: f32 = SPACE * 0.4;
+const CHORD_FIGURE_ACCIDENTAL_RAISE: f32 = SPACE * 0.7;
+// Line distance of stacked alterations
+const CHORD_STACK_DISTANCE: f32 = SPACE * 1.1;
+const CHORD_FIGURE_GAP: f32 = SPACE * 0.1;
+// Height of capitals in em units, the reference for drawn chord signs
+const CHORD_CAP_HEIGHT: f32 = 560.0;
+const CHORD_SIGN_STROKE: f32 = 60.0;
+
+const MAJOR_SCALE_SEMITONES: [i8; 7] = [0, 2, 4, 5, 7, 9, 11];
+pub const ROMAN_NUMERALS: [&str; 7] = ["I", "II", "III", "IV", "V", "VI", "VII"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ChordStyle {
+    // Letter names with m, maj, dim and aug
+    #[default]
+    Standard,
+    // Letter names with the signs −, Δ, ø, ° and +
+    Jazz,
+    // Scandinavian and German letter names, B is written H and Bb is written B
+    Nordic,
+    // Scale degree numbers relative to the key, with the jazz signs
+    Nashville,
+    // Roman numerals relative to the key, lower case for minor and diminished chords
+    Roman,
+}
+
+impl ChordStyle {
+    fn uses_signs(&self) -> bool {
+        matches!(self, ChordStyle::Jazz | ChordStyle::Nashville | ChordStyle::Roman)
+    }
+}
+
+// Mode of the key signatures, giving the tonic for Nashville numbers and Roman numerals
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum KeyMode {
+    #[default]
+    Major,
+    Minor,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ChordAccidental {
+    Natural,
+    Flat,
+    Sharp,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChordPitch {
+    pub letter: char,
+    pub accidental: ChordAccidental,
+}
+
+impl ChordPitch {
+    pub fn new(letter: char, accidental: ChordAccidental) -> Self {
+        Self { letter, accidental }
+    }
+
+    pub fn from_root(root: &ChordRoot) -> Option<Self> {
+        let accidental = match root {
+            ChordRoot::None => return None,
+            ChordRoot::CFlat | ChordRoot::DFlat | ChordRoot::EFlat | ChordRoot::FFlat | ChordRoot::GFlat | ChordRoot::AFlat | ChordRoot::BFlat => ChordAccidental::Flat,
+            ChordRoot::CSharp | ChordRoot::DSharp | ChordRoot::ESharp | ChordRoot::FSharp | ChordRoot::GSharp | ChordRoot::ASharp | ChordRoot::BSharp => ChordAccidental::Sharp,
+            _ => ChordAccidental::Natural,
+        };
+        Some(Self::new(root.get_char(), accidental))
+    }
+
+    // Tonic of the major or minor key with the given signature
+    pub fn from_key(key: &Key, mode: KeyMode) -> Self {
+        let fifths = match key {
+            Key::Sharps(n, _) => (*n as i8).min(7),
+            Key::Flats(n, _) => -(*n as i8).min(7),
+            _ => 0,
+        };
+        match mode {
+            KeyMode::Major => Self::from_fifths(fifths),
+            // the relative minor lies three fifths up
+            KeyMode::Minor => Self::from_fifths(fifths + 3),
+        }
+    }
+
+    // Pitch on the line of fifths, C at 0, G at 1 and F at -1
+    fn from_fifths(fifths: i8) -> Self {
+        let idx = fifths + 1;
+        let letter = "FCGDAEB".chars().nth(idx.rem_euclid(7) as usize).unwrap_or('C');
+        let accidental = match idx {
+            idx if idx < 0 => ChordAccidental::Flat,
+            idx if idx >= 7 => ChordAccidental::Sharp,
+            _ => ChordAccidental::Natural,
+        };
+        Self::new(letter, accidental)
+    }
+
+    fn get_step(&self) -> i8 {
+        "CDEFGAB".find(self.letter).unwrap_or(0) as i8
+    }
+
+    pub fn get_pitch_class(&self) -> i8 {
+        let alter = match self.accidental {
+            ChordAccidental::Natural => 0,
+            ChordAccidental::Flat => -1,
+            ChordAccidental::Sharp => 1,
+        };
+        MAJOR_SCALE_SEMITONES[self.get_step() as usize] + alter
+    }
+
+    // Scale degree 1 to 7 in the major key of the tonic, with the alteration from the major scale
+    pub fn get_degree(&self, tonic: &ChordPitch) -> (u8, ChordAccidental) {
+        let step = (self.get_step() - tonic.get_step()).rem_euclid(7);
+        let semitones = (self.get_pitch_class() - tonic.get_pitch_class()).rem_euclid(12);
+        let mut alter = semitones - MAJOR_SCALE_SEMITONES[step as usize];
+        if alter > 6 {
+            alter -= 12;
+        } else if alter < -6 {
+            alter += 12;
+        }
+        let accidental = match alter {
+            0 => ChordAccidental::Natural,
+            alter if alter < 0 => ChordAccidental::Flat,
+            _ => ChordAccidental::Sharp,
+        };
+        (step as u8 + 1, accidental)
+    }
+
+    // Letter and accidental in Scandinavian and German naming: B is H, and B flat is B.
+    // Sharps are spelled from H, so B sharp is H sharp (His).
+    fn get_nordic(&self) -> Self {
+        match (self.letter, self.accidental) {
+            ('B', ChordAccidental::Flat) => Self::new('B', ChordAccidental::Natural),
+            ('B', accidental) => Self::new('H', accidental),
+            _ => *self,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ChordQuality {
+    Major,
+    Minor,
+    Diminished,
+    Augmented,
+    // Minor seventh with flat five
+    HalfDiminished,
+    Sus2,
+    Sus4,
+    // Root and fifth only
+    Power,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ChordExtension {
+    None,
+    Six,
+    SixNine,
+    Seven,
+    MajSeven,
+    Nine,
+    MajNine,
+    Eleven,
+    Thirteen,
+    MajThirteen,
+}
+
+impl ChordExtension {
+    pub fn get_text(&self) -> &'static str {
+        match self {
+            ChordExtension::None => "",
+            ChordExtension::Six => "6",
+            ChordExtension::SixNine => "6/9",
+            ChordExtension::Seven => "7",
+            ChordExtension::MajSeven => "maj7",
+            ChordExtension::Nine => "9",
+            ChordExtension::MajNine => "maj9",
+            ChordExtension::Eleven => "11",
+            ChordExtension::Thirteen => "13",
+            ChordExtension::MajThirteen => "maj13",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ChordAlteration {
+    FlatFive,
+    SharpFive,
+    FlatNine,
+    SharpNine,
+    SharpEleven,
+    FlatThirteen,
+    AddNine,
+    AddEleven,
+    AddThirteen,
+}
+
+impl ChordAlteration {
+    pub fn get_accidental(&self) -> ChordAccidental {
+        match self {
+            ChordAlteration::FlatFive | ChordAlteration::FlatNine | ChordAlteration::FlatThirteen => ChordAccidental::Flat,
+            ChordAlteration::SharpFive | ChordAlteration::SharpNine | ChordAlteration::SharpEleven => ChordAccidental::Sharp,
+            _ => ChordAccidental::Natural,
+        }
+    }
+
+    pub fn get_figure(&self) -> &'static str {
+        match self {
+            ChordAlteration::FlatFive | ChordAlteration::SharpFive => "5",
+            ChordAlteration::FlatNine | ChordAlteration::SharpNine => "9",
+            ChordAlteration::SharpEleven => "11",
+            ChordAlteration::FlatThirteen => "13",
+            ChordAlteration::AddNine => "add9",
+            ChordAlteration::AddEleven => "add11",
+            ChordAlteration::AddThirteen => "add13",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum AlterationLayout {
+    // Inline for a single alteration, stacked for several
+    #[default]
+    Auto,
+    Inline,
+    Stacked,
+    Parenthesised,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChordSymbol {
+    pub root: ChordPitch,
+    pub quality: ChordQuality,
+    pub extension: ChordExtension,
+    pub alterations: Vec<ChordAlteration>,
+    pub bass: Option<ChordPitch>,
+}
+
+impl ChordSymbol {
+    pub fn new(root: ChordPitch, quality: ChordQuality, extension: ChordExtension) -> Self {
+        Self {
+            root,
+            quality,
+            extension,
+            alterations: vec![],
+            bass: None,
+        }
+    }
+
+    pub fn with_alteration(mut self, alteration: ChordAlteration) -> Self {
+        self.alterations.push(alteration);
+        self
+    }
+
+    pub fn with_bass(mut self, bass: ChordPitch) -> Self {
+        self.bass = Some(bass);
+        self
+    }
+
+    // Chord symbol as carried by NRectType::ChordSymbol, None without a root
+    pub fn from_notation(root: &ChordRoot, flavour: &ChordFlavour, color: &ChordColor, bass: &ChordRoot) -> Option<Self> {
+        let quality = match flavour {
+            ChordFlavour::Major => ChordQuality::Major,
+            ChordFlavour::Minor => ChordQuality::Minor,
+        };
+        let mut chord = Self::new(ChordPitch::from_root(root)?, quality, ChordExtension::None);
+        match color {
+            ChordColor::None => {}
+            ChordColor::SusTwo => chord.quality = ChordQuality::Sus2,
+            ChordColor::SusFour => chord.quality = ChordQuality::Sus4,
+            ChordColor::Five => chord.quality = ChordQuality::Power,
+            ChordColor::PlusFive => chord.alterations.push(ChordAlteration::SharpFive),
+            ChordColor::Six => chord.extension = ChordExtension::Six,
+            ChordColor::Seven => chord.extension = ChordExtension::Seven,
+            ChordColor::MajSeven => chord.extension = ChordExtension::MajSeven,
+            ChordColor::Nine => chord.extension = ChordExtension::Nine,
+            ChordColor::MinusNine => chord.alterations.push(ChordAlteration::FlatNine),
+            ChordColor::PlusNine => chord.alterations.push(ChordAlteration::SharpNine),
+        }
+        chord.bass = ChordPitch::from_root(bass);
+        Some(chord)
+    }
+
+    // Lead-sheet spelling such as "Bbm7b5", "C7(b9,#11)/E", "F#dim7" or "Gsus4"
+    pub fn parse(text: &str) -> Option<Self> {
+        let (symbol, bass) = match text.split_once('/') {
+            // 6/9 is an extension, not a bass note
+            Some((symbol, rest)) if symbol.ends_with('6') && rest.starts_with('9') => match rest.split_once('/') {
+                Some((_, bass)) => (&text[..text.len() - bass.len() - 1], Some(bass)),
+                None => (text, None),
+            },
+            Some((symbol, bass)) => (symbol, Some(bass)),
+            None => (text, None),
+        };
+
+        let mut rest = symbol.trim();
+        let root = parse_pitch(&mut rest)?;
+
+        let mut quality = if take(&mut rest, "m7b5") || take(&mut rest, "ø") {
+            ChordQuality::HalfDiminished
+        } else if take(&mut rest, "dim") || take(&mut rest, "°") {
+            ChordQuality::Diminished
+        } else if take(&mut rest, "aug") || take(&mut rest, "+") {
+            ChordQuality::Augmented
+        } else if !rest.starts_with("maj") && (take(&mut rest, "min") || take(&mut rest, "m") || take(&mut rest, "-")) {
+            ChordQuality::Minor
+        } else {
+            ChordQuality::Major
+        };
+
+        let extension = if take(&mut rest, "maj13") {
+            ChordExtension::MajThirteen
+        } else if take(&mut rest, "maj9") {
+            ChordExtension::MajNine
+        } else if take(&mut rest, "maj7") || take(&mut rest, "maj") {
+            ChordExtension::MajSeven
+        } else if take(&mut rest, "6/9") || take(&mut rest, "69") {
+            ChordExtension::SixNine
+        } else if take(&mut rest, "13") {
+            ChordExtension::Thirteen
+        } else if take(&mut rest, "11") {
+            ChordExtension::Eleven
+        } else if take(&mut rest, "9") {
+            ChordExtension::Nine
+        } else if take(&mut rest, "7") {
+            ChordExtension::Seven
+        } else if take(&mut rest, "6") {
+            ChordExtension::Six
+        } else {
+            ChordExtension::None
+        };
+
+        if quality == ChordQuality::Major && extension == ChordExtension::None && take(&mut rest, "5") {
+            quality = ChordQuality::Power;
+        }
+        if take(&mut rest, "sus2") {
+            quality = ChordQuality::Sus2;
+        } else if take(&mut rest, "sus4") || take(&mut rest, "sus") {
+            quality = ChordQuality::Sus4;
+        }
+
+        let mut chord = Self::new(root, quality, extension);
+        loop {
+            rest = rest.trim_start_matches(['(', ')', ',', ' ']);
+            if rest.is_empty() {
+                break;
+            }
+            let alteration = if take(&mut rest, "b5") || take(&mut rest, "-5") {
+                ChordAlteration::FlatFive
+            } else if take(&mut rest, "#5") || take(&mut rest, "+5") {
+                ChordAlteration::SharpFive
+            } else if take(&mut rest, "b9") || take(&mut rest, "-9") {
+                ChordAlteration::FlatNine
+            } else if take(&mut rest, "#9") || take(&mut rest, "+9") {
+                ChordAlteration::SharpNine
+            } else if take(&mut rest, "#11") || take(&mut rest, "+11") {
+                ChordAlteration::SharpEleven
+            } else if take(&mut rest, "b13") || take(&mut rest, "-13") {
+                ChordAlteration::FlatThirteen
+            } else if take(&mut rest, "add9") {
+                ChordAlteration::AddNine
+            } else if take(&mut rest, "add11") {
+                ChordAlteration::AddEleven
+            } else if take(&mut rest, "add13") {
+                ChordAlteration::AddThirteen
+            } else {
+                return None;
+            };
+            chord.alterations.push(alteration);
+        }
+
+        if let Some(bass) = bass {
+            let mut bass = bass.trim();
+            chord.bass = Some(parse_pitch(&mut bass)?);
+            if !bass.is_empty() {
+                return None;
+            }
+        }
+        Some(chord)
+    }
+
+    // Alterations as drawn, with the flat five of a half-diminished chord spelled out unless the style has a sign for it
+    pub fn get_alterations(&self, style: ChordStyle) -> Vec<ChordAlteration> {
+        let mut alterations = self.alterations.clone();
+        if self.quality == ChordQuality::HalfDiminished && !style.uses_signs() && !alterations.contains(&ChordAlteration::FlatFive) {
+            alterations.insert(0, ChordAlteration::FlatFive);
+        }
+        alterations
+    }
+}
+
+fn parse_pitch(rest: &mut &str) -> Option<ChordPitch> {
+    let letter = rest.chars().next().filter(|c| ('A'..='G').contains(c))?;
+    *rest = &rest[1..];
+    let accidental = if take(rest, "b") || take(rest, "♭") {
+        ChordAccidental::Flat
+    } else if take(rest, "#") || take(rest, "♯") {
+        ChordAccidental::Sharp
+    } else {
+        ChordAccidental::Natural
+    };
+    Some(ChordPitch::new(letter, accidental))
+}
+
+// Chord symbol replacing the one of the item, for chords the notation model cannot express
+#[derive(Debug, Clone)]
+pub struct ChordMark {
+    pub rowidx: usize,
+    pub colidx: usize,
+    pub chord: ChordSymbol,
+}
+
+impl ChordMark {
+    pub fn new(rowidx: usize, colidx: usize, chord: ChordSymbol) -> Self {
+        Self { rowidx, colidx, chord }
+    }
+}
+
+pub fn get_chord_mark(chords: &[ChordMark], rowidx: usize, colidx: usize) -> Option<&ChordSymbol> {
+    chords.iter().find(|mark| mark.rowidx == rowidx && mark.colidx == colidx).map(|mark| &mark.chord)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ChordSign {
+    Minus,
+    Triangle,
+    Circle,
+    SlashedCircle,
+}
+
+// Staff a chord row belongs to: the row itself when it is a music row,
+// otherwise the nearest music row above it, or below it for chords above the top staff
+pub fn get_chord_staff_row(matrix: &RMatrix, rowidx: usize) -> Option<usize> {
+    let above = (0..=rowidx).rev().find(|rowidx| is_music_row(matrix, *rowidx));
+    above.or_else(|| (rowidx + 1..matrix.rows.len()).find(|rowidx| is_music_row(matrix, *rowidx)))
+}
+
+// Tonic for Nashville numbers and Roman numerals: the chord_key option when set,
+// otherwise the last key signature up to the column on the staff of the chord row, in the chord mode
+pub fn get_chord_tonic(matrix: &RMatrix, rowidx: usize, colidx: usize, render_options: &RenderOptions) -> ChordPitch {
+    if let Some(tonic) = render_options.chord_key {
+        return tonic;
+    }
+    let mut tonic = ChordPitch::from_key(&Key::Open, render_options.chord_mode);
+    let staff_rowidx = get_chord_staff_row(matrix, rowidx).unwrap_or(rowidx);
+    for item in (0..=colidx).filter_map(|colidx| get_row_item(matrix, staff_rowidx, colidx)) {
+        let item = item.borrow();
+        for nrect in item.nrects.iter().flatten() {
+            if let NRectType::KeySignature(key, _) = &nrect.borrow().1 {
+                tonic = ChordPitch::from_key(key, render_options.chord_mode);
+            }
+        }
+    }
+    tonic
+}
+
+// Accidental and scale degree label of a pitch for Nashville numbers and Roman numerals,
+// Roman numerals in lower case for minor and diminished chords
+pub fn get_degree_label(pitch: &ChordPitch, tonic: &ChordPitch, style: ChordStyle, lower_case: bool) -> (ChordAccidental, String) {
+    let (degree, accidental) = pitch.get_degree(tonic);
+    let label = match style {
+        ChordStyle::Roman if lower_case => ROMAN_NUMERALS[degree as usize - 1].to_lowercase(),
+        ChordStyle::Roman => ROMAN_NUMERALS[degree as usize - 1].to_string(),
+        _ => degree.to_string(),
+    };
+    (accidental, label)
+}
+
+// Glyph placement for a chord symbol, left to right from x with the text top at y
+struct ChordLayout<'a> {
+    render_options: &'a RenderOptions,
+    style: ChordStyle,
+    tonic: ChordPitch,
+    items: Vec<GraphicItem>,
+    x: f32,
+    y: f32,
+    size: f32,
+    figure_size: f32,
+    accidental_size: f32,
+}
+
+impl<'a> ChordLayout<'a> {
+    fn new(x: f32, y: f32, tonic: ChordPitch, render_options: &'a RenderOptions) -> Self {
+        let size = render_options.get_text_size(TextRole::Chord);
+        Self {
+            render_options,
+            style: render_options.chord_style,
+            tonic,
+            items: vec![],
+            x,
+            y,
+            size,
+            figure_size: size * CHORD_FONT_FIGURE_SCALE / CHORD_FONT_SCALE,
+            accidental_size: size * CHORD_FONT_ACCIDENTAL_SCALE / CHORD_FONT_SCALE,
+        }
+    }
+
+    fn get_baseline(&self) -> f32 {
+        self.y + GLYPH_HEIGHT * self.size
+    }
+
+    fn get_figure_baseline(&self) -> f32 {
+        self.y + GLYPH_HEIGHT * self.figure_size - CHORD_FIGURE_RAISE
+    }
+
+    fn get_figure_width(&self, text: &str) -> f32 {
+        self.render_options.get_text_width(TextRole::Chord, text) * self.figure_size
+    }
+
+    fn get_figure_accidental_width(&self, accidental: ChordAccidental) -> f32 {
+        match accidental {
+            ChordAccidental::Natural => 0.0,
+            ChordAccidental::Flat => get_path_advance(CADENZA_ACCIDENTAL_FLAT) * self.accidental_size,
+            ChordAccidental::Sharp => get_path_advance(CADENZA_ACCIDENTAL_SHARP) * self.accidental_size,
+        }
+    }
+
+    fn get_alteration_width(&self, alteration: &ChordAlteration) -> f32 {
+        self.get_figure_accidental_width(alteration.get_accidental()) + self.get_figure_width(alteration.get_figure())
+    }
+
+    fn push_text(&mut self, text: &str, x: f32, baseline: f32, size: f32) -> f32 {
+        let path = self.render_options.get_text_path(TextRole::Chord, text).scale_path(size, size).move_path(x, baseline);
+        self.items.push(Path(path, self.render_options.fonts.get_stroke(TextRole::Chord), Fillstyle(Black), PathCacheInfo::NoCache));
+        self.render_options.get_text_width(TextRole::Chord, text) * size
+    }
+
+    // Root or bass at full size: letter and accidental, or accidental and scale degree
+    fn do_pitch(&mut self, pitch: &ChordPitch, lower_case: bool) {
+        let baseline = self.get_baseline();
+        match self.style {
+            ChordStyle::Nashville | ChordStyle::Roman => {
+                let (accidental, label) = get_degree_label(pitch, &self.tonic, self.style, lower_case);
+                self.do_pitch_accidental(accidental);
+                self.x += self.push_text(&label, self.x, baseline, self.size);
+            }
+            _ => {
+                let pitch = match self.style {
+                    ChordStyle::Nordic => pitch.get_nordic(),
+                    _ => *pitch,
+                };
+                self.x += self.push_text(&pitch.letter.to_string(), self.x, baseline, self.size);
+                self.do_pitch_accidental(pitch.accidental);
+            }
+        }
+    }
+
+    fn do_pitch_accidental(&mut self, accidental: ChordAccidental) {
+        let (glyph, scale, raise) = match accidental {
+            ChordAccidental::Natural => return,
+            ChordAccidental::Flat => (CADENZA_ACCIDENTAL_FLAT, self.size, SPACE * 1.3),
+            ChordAccidental::Sharp => (CADENZA_ACCIDENTAL_SHARP, self.size * 0.9, SPACE * 1.6),
+        };
+        let acc = PathSegments(glyph.to_vec()).scale_path(scale, -scale).move_path(self.x, self.get_baseline() - raise);
+        self.items.push(Path(acc, NoStroke, Fillstyle(Black), PathCacheInfo::NoCache));
+        self.x += get_path_advance(glyph) * scale;
+    }
+
+    // Drawn signs, the minus at text size and the others as figures
+    fn do_sign(&mut self, sign: ChordSign) {
+        let (x, baseline) = (self.x, self.get_figure_baseline());
+        let height = CHORD_CAP_HEIGHT * self.figure_size;
+        let (segments, width) = match sign {
+            ChordSign::Minus => {
+                let width = CHORD_CAP_HEIGHT * self.size * 0.55;
+                let y = self.get_baseline() - CHORD_CAP_HEIGHT * self.size * 0.35;
+                (vec![M(x, y), L(x + width, y)], width)
+            }
+            ChordSign::Triangle => {
+                let width = height * 0.9;
+                (vec![M(x, baseline), L(x + width, baseline), L(x + width / 2.0, baseline - height), Z], width)
+            }
+            ChordSign::Circle => {
+                let radius = height * 0.22;
+                (get_circle_segments(x + radius, baseline - height + radius, radius), radius * 2.0)
+            }
+            ChordSign::SlashedCircle => {
+                let radius = height * 0.3;
+                let (cx, cy) = (x + radius * 1.2, baseline - height * 0.5);
+                let mut segments = get_circle_segments(cx, cy, radius);
+                segments.extend([M(cx - radius * 1.2, cy + radius * 1.2), L(cx + radius * 1.2, cy - radius * 1.2)]);
+                (segments, radius * 2.4)
+            }
+        };
+        let stroke_width = match sign {
+            ChordSign::Minus => CHORD_SIGN_STROKE * self.size,
+            _ => CHORD_SIGN_STROKE * self.figure_size,
+        };
+        self.items.push(Path(PathSegments(segments), Strokestyle(stroke_width, Black), NoFill, PathCacheInfo::NoCache));
+        self.x += width + CHORD_FIGURE_GAP + stroke_width;
+    }
+
+    fn do_figure_accidental(&mut self, accidental: ChordAccidental, x: f32, figure_baseline: f32) {
+        let glyph = match accidental {
+            ChordAccidental::Natural => return,
+            ChordAccidental::Flat => CADENZA_ACCIDENTAL_FLAT,
+            ChordAccidental::Sharp => CADENZA_ACCIDENTAL_SHARP,
+        };
+        let raise = CHORD_FIGURE_ACCIDENTAL_RAISE - CHORD_FIGURE_RAISE;
+        let acc = PathSegments(glyph.to_vec())
+            .scale_path(self.accidental_size, -self.accidental_size)
+            .move_path(x, figure_baseline - GLYPH_HEIGHT * (self.figure_size - self.accidental_size) - raise);
+        self.items.push(Path(acc, NoStroke, Fillstyle(Black), PathCacheInfo::NoCache));
+    }
+
+    fn do_figure(&mut self, text: &str) {
+        if text.is_empty() {
+            return;
+        }
+        let baseline = self.get_figure_baseline();
+        self.x += self.push_text(text, self.x, baseline, self.figure_size) + CHORD_FIGURE_GAP;
+    }
+
+    fn do_alteration(&mut self, alteration: &ChordAlteration, x: f32, figure_baseline: f32) {
+        let accidental = alteration.get_accidental();
+        self.do_figure_accidental(accidental, x, figure_baseline);
+        let x = x + self.get_figure_accidental_width(accidental);
+        self.push_text(alteration.get_figure(), x, figure_baseline, self.figure_size);
+    }
+
+    fn do_alterations(&mut self, alterations: &[ChordAlteration], layout: AlterationLayout) {
+        let layout = match layout {
+            AlterationLayout::Auto if alterations.len() > 1 => AlterationLayout::Stacked,
+            AlterationLayout::Auto => AlterationLayout::Inline,
+            _ => layout,
+        };
+        let baseline = self.get_figure_baseline();
+        match layout {
+            AlterationLayout::Stacked => {
+                // first alteration on top, the column centered on the figure baseline
+                let count = alterations.len() as f32;
+                for (idx, alteration) in alterations.iter().enumerate() {
+                    let line_baseline = baseline + (idx as f32 - (count - 1.0) / 2.0) * CHORD_STACK_DISTANCE;
+                    self.do_alteration(alteration, self.x, line_baseline);
+                }
+                self.x += alterations.iter().map(|alteration| self.get_alteration_width(alteration)).fold(0.0, f32::max) + CHORD_FIGURE_GAP;
+            }
+            AlterationLayout::Parenthesised => {
+                self.x += self.push_text("(", self.x, baseline, self.figure_size);
+                for (idx, alteration) in alterations.iter().enumerate() {
+                    if idx > 0 {
+                        self.x += self.push_text(",", self.x, baseline, self.figure_size) + CHORD_FIGURE_GAP;
+                    }
+                    self.do_alteration(alteration, self.x, baseline);
+                    self.x += self.get_alteration_width(alteration);
+                }
+                self.x += self.push_text(")", self.x, baseline, self.figure_size) + CHORD_FIGURE_GAP;
+            }
+            _ => {
+                for alteration in alterations {
+                    self.do_alteration(alteration, self.x, baseline);
+                    self.x += self.get_alteration_width(alteration) + CHORD_FIGURE_GAP;
+                }
+            }
+        }
+    }
+
+    fn do_bass(&mut self, bass: &ChordPitch) {
+        let slash_x = self.x;
+        let slash_y = self.y + GLYPH_HEIGHT * self.figure_size;
+        self.items.push(Line(slash_x + SPACE, slash_y - SPACE * 2.0, slash_x, slash_y + SPACE * 1.3, Strokestyle(CHORD_BASS_SLASH_STROKE, Black)));
+        self.x += CHORD_BASS_SLASH_WIDTH;
+        self.do_pitch(bass, false);
+    }
+}
+
+pub fn get_circle_segments(cx: f32, cy: f32, radius: f32) -> Vec<PathSegment> {
+    // control point distance for quarter circles
+    let k = radius * 0.5523;
+    vec![
+        M(cx + radius, cy),
+        C(cx + radius, cy + k, cx + k, cy + radius, cx, cy + radius),
+        C(cx - k, cy + radius, cx - radius, cy + k, cx - radius, cy),
+        C(cx - radius, cy - k, cx - k, cy - radius, cx, cy - radius),
+        C(cx + k, cy - radius, cx + radius, cy - k, cx + radius, cy),
+        Z,
+    ]
+}
+
+// Root, quality, extension, alterations and bass of a chord symbol, left aligned at x,
+// with degrees relative to the tonic for Nashville numbers and Roman numerals
+pub fn chord2graphic(chord: &ChordSymbol, x: f32, y: f32, tonic: ChordPitch, render_options: &RenderOptions) -> Vec<GraphicItem> {
+    let mut layout = ChordLayout::new(x, y, tonic, render_options);
+    let style = layout.style;
+    let lower_case = matches!(chord.quality, ChordQuality::Minor | ChordQuality::Diminished | ChordQuality::HalfDiminished);
+    layout.do_pitch(&chord.root, lower_case);
+
+    match (chord.quality, style) {
+        // Roman numerals show minor by case
+        (ChordQuality::Minor, ChordStyle::Roman) => {}
+        (ChordQuality::Minor, _) if style.uses_signs() => layout.do_sign(ChordSign::Minus),
+        (ChordQuality::HalfDiminished, _) if style.uses_signs() => layout.do_sign(ChordSign::SlashedCircle),
+        (ChordQuality::Minor | ChordQuality::HalfDiminished, _) => {
+            let baseline = layout.get_baseline();
+            layout.x += layout.push_text("m", layout.x, baseline, layout.size);
+        }
+        (ChordQuality::Diminished, _) if style.uses_signs() => layout.do_sign(ChordSign::Circle),
+        (ChordQuality::Diminished, _) => layout.do_figure("dim"),
+        (ChordQuality::Augmented, _) if style.uses_signs() => layout.do_figure("+"),
+        (ChordQuality::Augmented, _) => layout.do_figure("aug"),
+        _ => {}
+    }
+
+    let extension = match (chord.quality, chord.extension) {
+        (ChordQuality::HalfDiminished, ChordExtension::None) => ChordExtension::Seven,
+        (_, extension) => extension,
+    };
+    match extension {
+        ChordExtension::MajSeven | ChordExtension::MajNine | ChordExtension::MajThirteen if style.uses_signs() => {
+            layout.do_sign(ChordSign::Triangle);
+            layout.do_figure(extension.get_text().trim_start_matches("maj").trim_start_matches('7'));
+        }
+        _ => layout.do_figure(extension.get_text()),
+    }
+
+    match chord.quality {
+        ChordQuality::Sus2 => layout.do_figure("sus2"),
+        ChordQuality::Sus4 => layout.do_figure("sus4"),
+        ChordQuality::Power => layout.do_figure("5"),
+        _ => {}
+    }
+
+    let alterations = chord.get_alterations(style);
+    if !alterations.is_empty() {
+        layout.do_alterations(&alterations, render_options.chord_alterations);
+    }
+
+    if let Some(bass) = &chord.bass {
+        layout.do_bass(bass);
+    }
+    if render_options.chord_diagrams {
+        layout.items.extend(do_chord_diagram(chord, x, y, render_options));
+    }
+    layout.items
+}
+
+pub fn output_chords(matrix: &RMatrix, render_options: &RenderOptions) -> GraphicItems {
+    let mut graphic_items = GraphicItems::new();
+    for mark in render_options.chords.iter() {
+        let item = get_row_item(matrix, mark.rowidx, mark.colidx);
+        if item.is_none() {
+            continue;
+        }
+        let item = item.as_ref().unwrap().borrow();
+        // items with a chord symbol rect draw the mark in its place
+        let has_chord_rect = item.nrects.as_ref().is_some_and(|nrects| nrects.iter().any(|nrect| matches!(nrect.borrow().1, NRectType::ChordSymbol(..))));
+        if has_chord_rect {
+            continue;
+        }
+        let x = item.coord_x.unwrap() + CHORD_MARGIN * 0.5;
+        let y = item.coord_y.unwrap() - SPACE * 3.0;
+        let tonic = get_chord_tonic(matrix, mark.rowidx, mark.colidx, render_options);
+        graphic_items.extend(GraphicItems(chord2graphic(&mark.chord, x, y, tonic, render_options)));
+    }
+    graphic_items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_chord_symbols() {
+        let chord = ChordSymbol::parse("Bbm7b5/E").unwrap();
+        assert_eq!(chord.root, ChordPitch::new('B', ChordAccidental::Flat));
+        assert_eq!(chord.quality, ChordQuality::HalfDiminished);
+        assert_eq!(chord.bass, Some(ChordPitch::new('E', ChordAccidental::Natural)));
+        let chord = ChordSymbol::parse("C13(b9,#11)").unwrap();
+        assert_eq!(chord.extension, ChordExtension::Thirteen);
+        assert_eq!(chord.alterations, vec![ChordAlteration::FlatNine, ChordAlteration::SharpEleven]);
+        assert_eq!(ChordSymbol::parse("F6/9").unwrap().extension, ChordExtension::SixNine);
+        assert_eq!(ChordSymbol::parse("Gmaj7").unwrap().quality, ChordQuality::Major);
+        assert!(ChordSymbol::parse("Cxyz").is_none());
+
+        let tonic = ChordPitch::new('E', ChordAccidental::Flat);
+        assert_eq!(ChordPitch::new('A', ChordAccidental::Flat).get_degree(&tonic), (4, ChordAccidental::Natural));
+        assert_eq!(ChordPitch::new('G', ChordAccidental::Flat).get_degree(&tonic), (3, ChordAccidental::Flat));
+        assert_eq!(ChordPitch::new('B', ChordAccidental::Natural).get_nordic().letter, 'H');
+
+        // tonics from key signatures, minor keys get their own tonic and not the relative major
+        assert_eq!(ChordPitch::from_key(&Key::Open, KeyMode::Major), ChordPitch::new('C', ChordAccidental::Natural));
+        assert_eq!(ChordPitch::from_key(&Key::Open, KeyMode::Minor), ChordPitch::new('A', ChordAccidental::Natural));
+        assert_eq!(ChordPitch::from_fifths(-3), ChordPitch::new('E', ChordAccidental::Flat));
+        assert_eq!(ChordPitch::from_fifths(3 + 3), ChordPitch::new('F', ChordAccidental::Sharp));
+        assert_eq!(ChordPitch::from_fifths(-7), ChordPitch::new('C', ChordAccidental::Flat));
+
+        // the dominant of C minor and the relative major chord
+        let tonic = ChordPitch::from_fifths(-3 + 3);
+        let g = ChordPitch::new('G', ChordAccidental::Natural);
+        let e_flat = ChordPitch::new('E', ChordAccidental::Flat);
+        assert_eq!(get_degree_label(&g, &tonic, ChordStyle::Roman, false), (ChordAccidental::Natural, "V".to_string()));
+        assert_eq!(get_degree_label(&e_flat, &tonic, ChordStyle::Roman, false), (ChordAccidental::Flat, "III".to_string()));
+        assert_eq!(get_degree_label(&e_flat, &tonic, ChordStyle::Nashville, false), (ChordAccidental::Flat, "3".to_string()));
+        assert_eq!(get_degree_label(&tonic, &tonic, ChordStyle::Roman, true), (ChordAccidental::Natural, "i".to_string()));
+    }
+
+    #[test]
+    fn nordic_spelling_of_b() {
+        assert_eq!(ChordPitch::new('B', ChordAccidental::Natural).get_nordic(), ChordPitch::new('H', ChordAccidental::Natural));
+        assert_eq!(ChordPitch::new('B', ChordAccidental::Flat).get_nordic(), ChordPitch::new('B', ChordAccidental::Natural));
+        assert_eq!(ChordPitch::new('B', ChordAccidental::Sharp).get_nordic(), ChordPitch::new('H', ChordAccidental::Sharp));
+        assert_eq!(ChordPitch::new('E', ChordAccidental::Flat).get_nordic(), ChordPitch::new('E', ChordAccidental::Flat));
+    }
+
+    #[test]
+    fn chord_rows_take_the_staff_above() {
+        use crate::render::output::elements::testutils::*;
+        let matrix = get_test_matrix("|clef G - F | 0 / - / 0");
+        assert_eq!(get_chord_staff_row(&matrix, 0), Some(0));
+        assert_eq!(get_chord_staff_row(&matrix, 1), Some(0));
+        assert_eq!(get_chord_staff_row(&matrix, 2), Some(2));
+        // chords above the top staff belong to it
+        let matrix = get_test_matrix("|clef - G | - / 0");
+        assert_eq!(get_chord_staff_row(&matrix, 0), Some(1));
+
+        // without a key signature on the staff the tonic is the open key in the chord mode, chord_key overrides it
+        let colidx = get_test_note_cols(&matrix, 1)[0];
+        let mut render_options = RenderOptions::default();
+        assert_eq!(get_chord_tonic(&matrix, 0, colidx, &render_options), ChordPitch::new('C', ChordAccidental::Natural));
+        render_options.chord_mode = KeyMode::Minor;
+        assert_eq!(get_chord_tonic(&matrix, 0, colidx, &render_options), ChordPitch::new('A', ChordAccidental::Natural));
+        render_options.chord_key = Some(ChordPitch::new('E', ChordAccidental::Flat));
+        assert_eq!(get_chord_tonic(&matrix, 0, colidx, &render_options), ChordPitch::new('E', ChordAccidental::Flat));
+    }
+
+    #[test]
+    fn chord_marks_over_their_notes() {
+        use crate::render::output::elements::testutils::*;
+        let matrix = get_test_matrix("|clef G | 0 1");
+        let cols = get_test_note_cols(&matrix, 0);
+        let mut render_options = RenderOptions::default();
+        render_options.chords.push(ChordMark::new(0, cols[1], ChordSymbol::parse("C").unwrap()));
+        let triad = output_chords(&matrix, &render_options);
+        let (x1, _, triad_x2, _) = get_items_extent(&triad).unwrap();
+        assert!(x1 >= get_test_col_x(&matrix, cols[1]));
+
+        // the seventh is drawn after the root
+        render_options.chords[0].chord = ChordSymbol::parse("C7").unwrap();
+        let (_, _, seventh_x2, _) = get_items_extent(&output_chords(&matrix, &render_options)).unwrap();
+        assert!(seventh_x2 > triad_x2);
+
+        // marks outside the row items are skipped
+        render_options.chords[0].colidx = matrix.cols.len();
+        assert!(output_chords(&matrix, &render_options).0.is_empty());
+    }
+}
@@ -7,7 +7,6 @@ use std::{
 use crate::{
     prelude::{rects2graphic::ncolor_to_color, *},
     render::{
-        fonts::opensans_regular::{OPENSANS_REGULAR_189, OPENSANS_REGULAR_49},
         output::rects2graphic::nrectext2graphic,
     },
 };
@@ -15,8 +14,13 @@ use graphics::prelude::*;
 use itertools::{Itertools, TupleWindows};
 use notation_rs::prelude::*;
 
-pub fn output_lines(matrix: &RMatrix) -> GraphicItems {
+// Step labels are drawn smaller than the figure style they use
+const LINE_HALFSTEP_LABEL_SCALE: f32 = 0.7;
+const LINE_WHOLESTEP_LABEL_SCALE: f32 = 0.6;
+
+pub fn output_lines(matrix: &RMatrix, render_options: &RenderOptions) -> GraphicItems {
     let mut graphic_items = GraphicItems::new();
+    let figure_size = render_options.get_text_size(TextRole::Figure);
 
     for row in matrix.rows.iter() {
         let row = row.borrow();
@@ -58,6 +62,7 @@ pub fn output_lines(matrix: &RMatrix) -> GraphicItems {
                         HeadLineType::Halfstep => {
                             let xmid = x + (x2 - x) / 2.0;
                             let ymid = y + (y2 - y) / 2.0;
+                            let label_size = figure_size * LINE_HALFSTEP_LABEL_SCALE;
                             let p = GraphicItem::Path(
                                 PathSegments([M(x, y), L(xmid, ymid + 15.0), L(x2, y2)].to_vec()),
                                 Strokestyle(5.0, Tomato),
@@ -65,20 +70,19 @@ pub fn output_lines(matrix: &RMatrix) -> GraphicItems {
                                 PathCacheInfo::NoCache,
                             );
                             graphic_items.push(p);
-                            let p1 = GraphicItem::Path(
-                                PathSegments(OPENSANS_REGULAR_189.to_vec())
-                                    .scale_path(0.05, 0.05)
-                                    .move_path(xmid - 10.0, ymid + 56.0),
-                                NoStroke,
-                                Fillstyle(Black),
-                                PathCacheInfo::NoCache,
-                            );
-                            graphic_items.push(p1);
+                            // below the zigzag, which bends downwards
+                            graphic_items.push(do_line_label(
+                                "½",
+                                label_size,
+                                (x, ymid + 15.0),
+                                (x2, ymid + 15.0),
+                                true,
+                                render_options,
+                            ));
                         }
 
                         HeadLineType::Wholestep => {
-                            let xmid = x + (x2 - x) / 2.0;
-                            let ymid = y + (y2 - y) / 2.0;
+                            let label_size = figure_size * LINE_WHOLESTEP_LABEL_SCALE;
                             let p = GraphicItem::Path(
                                 //  L(x + 3.0, y + 12.0), L(x2 - 3.0, y2 + 12.0),
                                 PathSegments([M(x, y), L(x2, y2)].to_vec()),
@@ -87,16 +91,14 @@ pub fn output_lines(matrix: &RMatrix) -> GraphicItems {
                                 PathCacheInfo::NoCache,
                             );
                             graphic_items.push(p);
-
-                            let p1 = GraphicItem::Path(
-                                PathSegments(OPENSANS_REGULAR_49.to_vec())
-                                    .scale_path(0.04, 0.04)
-                                    .move_path(xmid - 10.0, ymid - 30.0),
-                                NoStroke,
-                                Fillstyle(Black),
-                                PathCacheInfo::NoCache,
-                            );
-                            graphic_items.push(p1);
+                            graphic_items.push(do_line_label(
+                                "1",
+                                label_size,
+                                (x, y),
+                                (x2, y2),
+                                false,
+                                render_options,
+                            ));
                         }
                         HeadLineType::LineColor(ncolor) => {
                             let graphic_item: GraphicItem = GraphicItem::Line(
@@ -138,6 +140,37 @@ pub fn output_lines(matrix: &RMatrix) -> GraphicItems {
 //     }
 // }
 
+pub fn get_row_item(
+    matrix: &RMatrix,
+    rowidx: usize,
+    colidx: usize,
+) -> Option<Rc<RefCell<RItem>>> {
+    let row = matrix.rows.get(rowidx)?.borrow();
+    row.items.get(colidx)?.clone()
+}
+
+// Strips a prefix from a symbol being parsed; returns whether it was there
+pub(crate) fn take(rest: &mut &str, prefix: &str) -> bool {
+    match rest.strip_prefix(prefix) {
+        Some(stripped) => {
+            *rest = stripped;
+            true
+        }
+        None => false,
+    }
+}
+
+// Strips a suffix and trailing whitespace from a symbol being parsed
+pub(crate) fn take_suffix(rest: &mut &str, suffix: &str) -> bool {
+    match rest.trim_end().strip_suffix(suffix) {
+        Some(stripped) => {
+            *rest = stripped.trim_end();
+            true
+        }
+        None => false,
+    }
+}
+
 pub fn output_ties(matrix: &RMatrix) -> GraphicItems {
     // drawing of ties
 
@@ -365,103 +398,38 @@ pub fn output_ties(matrix: &RMatrix) -> GraphicItems {
     graphic_items
 }
 
-pub fn output_ackolades(matrix: &RMatrix) -> GraphicItems {
+pub fn output_notelines(matrix: &RMatrix, render_options: &RenderOptions) -> GraphicItems {
     let mut graphic_items = GraphicItems::new();
-
-    let mut first_y: Option<f32> = None;
-    let mut last_y: Option<f32> = None;
-
-    if let Some(bartemplate) = &matrix.bartemplate {
-        for (rowidx, row) in matrix.rows.iter().enumerate() {
-            let template = bartemplate.0[rowidx];
-            match template {
-                PartTemplate::Music => {
-                    let row = row.borrow();
-                    if first_y.is_none() {
-                        first_y = Some(row.y);
-                    } else {
-                        last_y = Some(row.y);
-                    }
-                }
-                _ => {}
-            }
+    let staff_line_width = render_options.get_engraving().staff_line_width;
+    for (rowidx, row) in matrix.rows.iter().enumerate() {
+        if !is_staff_row(matrix, rowidx, render_options) {
+            continue;
         }
-    }
-
-    if first_y.is_some() && last_y.is_some() {
-        let y1 = first_y.unwrap() - SPACE * 2.0;
-        let y2 = last_y.unwrap() + SPACE * 2.0;
-        graphic_items.push(Line(0., y1, 0., y2, Strokestyle(NOTELINES_WIDTH, Black)));
-
-        let y1 = y1 - 5.0;
-        let y2 = y2 + 5.0;
-        graphic_items.push(Rect(
-            -SPACE,
-            y1,
-            SPACE * 0.5,
-            y2 - y1,
-            NoStroke,
-            Fillstyle(Black),
-        ));
-
-        graphic_items.push(Path(
-            PathSegments(vec![
-                M(-SPACE, y1),
-                L(0., y1 - 10.0),
-                L(0., y1 - 8.0),
-                L(-SPACE_HALF, y1),
-            ]),
-            NoStroke,
-            Fillstyle(Black),
-            PathCacheInfo::NoCache,
-        ));
-
-        graphic_items.push(Path(
-            PathSegments(vec![
-                M(-SPACE, y2),
-                L(0., y2 + 10.0),
-                L(0., y2 + 8.0),
-                L(-SPACE_HALF, y2),
-            ]),
-            NoStroke,
-            Fillstyle(Black),
-            PathCacheInfo::NoCache,
-        ));
-    }
-
-    graphic_items
-}
-
-pub fn output_notelines(matrix: &RMatrix) -> GraphicItems {
-    let mut graphic_items = GraphicItems::new();
-    if let Some(bartemplate) = &matrix.bartemplate {
-        for (rowidx, row) in matrix.rows.iter().enumerate() {
-            let template = bartemplate.0[rowidx];
-            match template {
-                PartTemplate::Music => {
-                    let row = row.borrow();
-                    for i in -2..3 {
-                        let y = row.y + (i as f32) * SPACE;
-                        graphic_items.push(Line(
-                            0.,
-                            y,
-                            matrix.width,
-                            y,
-                            Strokestyle(NOTELINES_WIDTH, Black),
-                        ));
-                    }
-                }
-                _ => {}
-            }
+        let row = row.borrow();
+        let template = get_staff_template(&render_options.staves, rowidx)
+            .cloned()
+            .unwrap_or_else(|| StaffTemplate::new(rowidx, 5));
+        for level in template.get_line_levels() {
+            let y = row.y + level as f32 * SPACE_HALF * template.scale;
+            graphic_items.push(Line(
+                0.,
+                y,
+                matrix.width,
+                y,
+                Strokestyle(staff_line_width * template.scale, Black),
+            ));
         }
     }
     graphic_items
 }
 
-pub fn output_beamgroups(matrix: &RMatrix) -> GraphicItems {
+pub fn output_beamgroups(matrix: &RMatrix, render_options: &RenderOptions) -> GraphicItems {
     let mut graphic_items = GraphicItems::new();
-    for row in matrix.rows.iter() {
+    let tremolos = &render_options.tremolos;
+    let engraving = render_options.get_engraving();
+    for (rowidx, row) in matrix.rows.iter().enumerate() {
         let row = row.borrow();
+        let row_start = graphic_items.0.len();
 
         let mut note_beam_start: (f32, f32, f32) = (0., 0., 0.);
         let mut note_beam_end: (f32, f32, f32) = (0., 0., 0.);
@@ -473,10 +441,12 @@ pub fn output_beamgroups(matrix: &RMatrix) -> GraphicItems {
 
         let mut notedata: Vec<(RItemBeamData, NPoint)> = vec![];
         let mut note2data: Vec<(RItemBeamData, NPoint)> = vec![];
+        let mut notetremolos: Vec<u8> = vec![];
 
-        for item in &row.items {
+        for (colidx, item) in row.items.iter().enumerate() {
             if let Some(item) = item {
                 let item: Ref<RItem> = item.borrow();
+                let tremolo_strokes = get_tremolo_strokes(tremolos, rowidx, colidx);
                 // upper beams
                 let item_x = item.coord_x.unwrap();
                 let item_y = item.coord_y.unwrap();
@@ -487,6 +457,15 @@ pub fn output_beamgroups(matrix: &RMatrix) -> GraphicItems {
                             graphic_items.extend(do_single(
                                 data,
                                 NPoint(item.coord_x.unwrap(), item.coord_y.unwrap()),
+                                tremolo_strokes,
+                                &engraving,
+                            ));
+                        } else if tremolo_strokes > 0 {
+                            graphic_items.extend(do_head_tremolo(
+                                data,
+                                NPoint(item.coord_x.unwrap(), item.coord_y.unwrap()),
+                                tremolo_strokes,
+                                &engraving,
                             ));
                         }
                     }
@@ -496,19 +475,22 @@ pub fn output_beamgroups(matrix: &RMatrix) -> GraphicItems {
                             data.clone(),
                             NPoint(item.coord_x.unwrap(), item.coord_y.unwrap()),
                         ));
+                        notetremolos = vec![tremolo_strokes];
                     }
                     RItemBeam::Middle(data) => {
                         notedata.push((
                             data.clone(),
                             NPoint(item.coord_x.unwrap(), item.coord_y.unwrap()),
                         ));
+                        notetremolos.push(tremolo_strokes);
                     }
                     RItemBeam::End(data) => {
                         notedata.push((
                             data.clone(),
                             NPoint(item.coord_x.unwrap(), item.coord_y.unwrap()),
                         ));
-                        graphic_items.extend(do_beam(&notedata));
+                        notetremolos.push(tremolo_strokes);
+                        graphic_items.extend(do_beam(&notedata, &notetremolos, &engraving));
                     }
                     _ => {}
                 }
@@ -518,6 +500,8 @@ pub fn output_beamgroups(matrix: &RMatrix) -> GraphicItems {
                             graphic_items.extend(do_single(
                                 data,
                                 NPoint(item.coord_x.unwrap(), item.coord_y.unwrap()),
+                                0,
+                                &engraving,
                             ));
                         }
                     }
@@ -539,30 +523,54 @@ pub fn output_beamgroups(matrix: &RMatrix) -> GraphicItems {
                             data.clone(),
                             NPoint(item.coord_x.unwrap(), item.coord_y.unwrap()),
                         ));
-                        graphic_items.extend(do_beam(&note2data));
+                        graphic_items.extend(do_beam(&note2data, &[], &engraving));
                     }
                     _ => {}
                 }
             }
         }
+
+        // stems and beams of scaled staves shrink with their heads
+        if let Some(template) = get_staff_template(&render_options.staves, rowidx) {
+            let anchors = get_row_anchors(matrix, rowidx);
+            template.scale_items(&mut graphic_items.0[row_start..], &anchors, row.y);
+        }
     }
 
+    graphic_items.extend(output_two_note_tremolos(matrix, tremolos, &engraving));
+
     graphic_items
 }
 
-pub fn output_main_elements(matrix: &RMatrix, draw_dev_frames: bool) -> GraphicItems {
+pub fn output_main_elements(
+    matrix: &RMatrix,
+    draw_dev_frames: bool,
+    render_options: &RenderOptions,
+) -> GraphicItems {
     let mut graphic_items = GraphicItems::new();
-    for col in matrix.cols.iter() {
+    let ledger_line_width = render_options.get_engraving().ledger_line_width;
+    for (colidx, col) in matrix.cols.iter().enumerate() {
         let col = col.borrow();
         let mut rowidx = 0;
         for item in &col.items {
             if let Some(item) = item {
                 let item: Ref<RItem> = item.borrow();
                 let coords = NPoint(item.coord_x.unwrap(), item.coord_y.unwrap());
+                let row_y = matrix.get_row(rowidx).unwrap().borrow().y;
+                let template = get_staff_template(&render_options.staves, rowidx);
+                let mut item_items: Vec<GraphicItem> = Vec::new();
+
+                // staves with a template draw their own ledger lines
+                if let Some(template) = template {
+                    item_items.extend(do_staff_ledger_lines(template, &item, ledger_line_width));
+                }
 
                 let nrects = item.nrects.as_ref().unwrap();
                 for nrect in nrects.iter() {
                     let nrect = nrect.borrow();
+                    if template.is_some() && matches!(nrect.1, NRectType::HelpLine) {
+                        continue;
+                    }
 
                     let color = "orange";
                     let frame_rect = nrect.0;
@@ -571,17 +579,55 @@ pub fn output_main_elements(matrix: &RMatrix, draw_dev_frames: bool) -> GraphicI
                     if col.duration == 0 || draw_dev_frames {
                         let frame_nrect =
                             NRectExt::new(frame_rect, NRectType::Dev(false, color.to_string()));
-                        let frame_items = nrectext2graphic(&frame_nrect, coords.0, coords.1);
+                        let frame_items =
+                            nrectext2graphic(&frame_nrect, coords.0, coords.1, render_options);
 
-                        graphic_items.extend(GraphicItems(frame_items));
+                        item_items.extend(frame_items);
                     }
 
-                    // glyph rect
-                    let glyph_items = nrectext2graphic(&nrect, coords.0, coords.1);
-                    graphic_items.extend(GraphicItems(glyph_items));
+                    // glyph rect, chord and function marks replace the symbol of the item
+                    let chord_mark = get_chord_mark(&render_options.chords, rowidx, colidx);
+                    let function_mark = get_function_mark(&render_options.functions, rowidx, colidx);
+                    let r = nrect.0.move_rect(coords.0, coords.1);
+                    let staff_clef = match (&nrect.1, template) {
+                        (NRectType::Clef(_), _) if get_tab_staff(&render_options.tab_staves, rowidx).is_some() => {
+                            Some(do_tab_clef(r.0, row_y, render_options))
+                        }
+                        (NRectType::Clef(_), Some(template)) => {
+                            template.get_clef_items(r.0, row_y, render_options)
+                        }
+                        _ => None,
+                    };
+                    let glyph_items = match (staff_clef, &nrect.1, chord_mark, function_mark) {
+                        (Some(clef_items), ..) => clef_items,
+                        (None, NRectType::ChordSymbol(..), Some(chord), _) => {
+                            let tonic = get_chord_tonic(matrix, rowidx, colidx, render_options);
+                            chord2graphic(chord, r.0 + CHORD_MARGIN * 0.5, r.1, tonic, render_options)
+                        }
+                        (None, NRectType::ChordSymbol(chord_root, chord_flavour, chord_color, chord_bass), None, _) => {
+                            match ChordSymbol::from_notation(chord_root, chord_flavour, chord_color, chord_bass) {
+                                Some(chord) => {
+                                    let tonic = get_chord_tonic(matrix, rowidx, colidx, render_options);
+                                    chord2graphic(&chord, r.0 + CHORD_MARGIN * 0.5, r.1, tonic, render_options)
+                                }
+                                None => vec![],
+                            }
+                        }
+                        (None, NRectType::FunctionSymbol(..), _, Some(function)) => {
+                            function2graphic(function, r.0, r.1, render_options)
+                        }
+                        _ => nrectext2graphic(&nrect, coords.0, coords.1, render_options),
+                    };
+                    item_items.extend(glyph_items);
 
                     // let graphic_item = next2graphic(&nrect, coords.0, coords.1).unwrap();
                 }
+
+                // clefs, heads and ledger lines of scaled staves shrink around the item
+                if let Some(template) = template {
+                    template.scale_items_around(&mut item_items, coords.0, row_y);
+                }
+                graphic_items.extend(GraphicItems(item_items));
             } else {
                 let y = matrix.get_row(rowidx).unwrap().borrow().y;
                 let x = col.x;
@@ -590,7 +636,7 @@ pub fn output_main_elements(matrix: &RMatrix, draw_dev_frames: bool) -> GraphicI
                     rect,
                     NRectType::Dev(false, "output_main_elements".to_string()),
                 );
-                let items = nrectext2graphic(&nrect, x, y);
+                let items = nrectext2graphic(&nrect, x, y, render_options);
                 graphic_items.extend(GraphicItems(items));
             }
             rowidx += 1;
@@ -599,13 +645,13 @@ pub fn output_main_elements(matrix: &RMatrix, draw_dev_frames: bool) -> GraphicI
     graphic_items
 }
 
-pub fn output_row_nrects(matrix: &RMatrix) -> GraphicItems {
+pub fn output_row_nrects(matrix: &RMatrix, render_options: &RenderOptions) -> GraphicItems {
     let mut graphic_items = GraphicItems::new();
     for (rowidx, row) in matrix.rows.iter().enumerate() {
         let row = row.borrow();
         for nrect in row.nrects.iter() {
             let nrect = nrect.borrow();
-            let items = nrectext2graphic(&nrect, 0.0, row.y);
+            let items = nrectext2graphic(&nrect, 0.0, row.y, render_options);
             graphic_items.extend(GraphicItems(items));
         }
     }
@@ -0,0 +1,256 @@
+use crate::prelude::*;
+use crate::render::fonts::metrics::get_path_advance;
+use graphics::path::PathSegment;
+use graphics::prelude::*;
+use notation_rs::prelude::*;
+use std::cell::Ref;
+
+// Line distance of stacked figures
+pub const FIGURE_STACK_DISTANCE: f32 = SPACE * 1.1;
+const FIGURED_BASS_DISTANCE: f32 = SPACE * 1.5;
+// Height of digits in em units
+const FIGURE_DIGIT_HEIGHT: f32 = 560.0;
+const FIGURE_STROKE: f32 = 50.0;
+const FIGURE_ACCIDENTAL_RATIO: f32 = CHORD_FONT_ACCIDENTAL_SCALE / CHORD_FONT_FIGURE_SCALE;
+const FIGURE_ACCIDENTAL_GAP: f32 = SPACE * 0.1;
+const CONTINUATION_LINE_WIDTH: f32 = 3.0;
+const CONTINUATION_LINE_GAP: f32 = SPACE_QUARTER;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FigureAccidental {
+    None,
+    Flat,
+    Sharp,
+    Natural,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BassFigure {
+    // None for an accidental alone, which applies to the third
+    pub number: Option<u8>,
+    pub accidental: FigureAccidental,
+    // Raised figure written with a stroke through it, as in the slashed 6
+    pub slashed: bool,
+    // Continuation line to the given column
+    pub extend_to: Option<usize>,
+}
+
+impl BassFigure {
+    pub fn new(number: u8) -> Self {
+        Self {
+            number: Some(number),
+            accidental: FigureAccidental::None,
+            slashed: false,
+            extend_to: None,
+        }
+    }
+
+    pub fn new_accidental(accidental: FigureAccidental) -> Self {
+        Self {
+            number: None,
+            accidental,
+            slashed: false,
+            extend_to: None,
+        }
+    }
+
+    pub fn with_accidental(mut self, accidental: FigureAccidental) -> Self {
+        self.accidental = accidental;
+        self
+    }
+
+    pub fn slashed(mut self) -> Self {
+        self.slashed = true;
+        self
+    }
+
+    pub fn extended(mut self, to_colidx: usize) -> Self {
+        self.extend_to = Some(to_colidx);
+        self
+    }
+
+    // Figure such as "6", "#4", "b", "n3" or "6\" for the slashed 6
+    pub fn parse(text: &str) -> Option<Self> {
+        let mut chars = text.chars().peekable();
+        let accidental = match chars.peek() {
+            Some('#') | Some('♯') => FigureAccidental::Sharp,
+            Some('b') | Some('♭') => FigureAccidental::Flat,
+            Some('n') | Some('♮') => FigureAccidental::Natural,
+            _ => FigureAccidental::None,
+        };
+        if accidental != FigureAccidental::None {
+            chars.next();
+        }
+        let rest: String = chars.collect();
+        let (digits, slashed) = match rest.strip_suffix('\\').or_else(|| rest.strip_suffix('+')) {
+            Some(digits) => (digits, true),
+            None => (rest.as_str(), false),
+        };
+        let number = match digits {
+            "" if accidental != FigureAccidental::None => None,
+            _ => Some(digits.parse().ok()?),
+        };
+        Some(Self {
+            number,
+            accidental,
+            slashed,
+            extend_to: None,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FiguredBass {
+    pub rowidx: usize,
+    pub colidx: usize,
+    // From the top
+    pub figures: Vec<BassFigure>,
+}
+
+impl FiguredBass {
+    pub fn new(rowidx: usize, colidx: usize, figures: Vec<BassFigure>) -> Self {
+        Self { rowidx, colidx, figures }
+    }
+
+    // Figures from the top separated by spaces, such as "#6 4" or "7 #"
+    pub fn parse(rowidx: usize, colidx: usize, text: &str) -> Option<Self> {
+        let figures = text.split_whitespace().map(BassFigure::parse).collect::<Option<Vec<_>>>()?;
+        Some(Self::new(rowidx, colidx, figures))
+    }
+}
+
+fn get_accidental_glyph(accidental: FigureAccidental) -> Option<&'static [PathSegment]> {
+    match accidental {
+        FigureAccidental::None => None,
+        FigureAccidental::Flat => Some(CADENZA_ACCIDENTAL_FLAT),
+        FigureAccidental::Sharp => Some(CADENZA_ACCIDENTAL_SHARP),
+        FigureAccidental::Natural => Some(CADENZA_ACCIDENTAL_NATURAL),
+    }
+}
+
+// Figures stacked downwards from the first baseline, each with its accidental in front.
+// Returns the items and the right edge of each figure line, for continuation lines.
+pub fn do_figure_stack(figures: &[BassFigure], x: f32, baseline: f32, role: TextRole, size: f32, render_options: &RenderOptions) -> (Vec<GraphicItem>, Vec<f32>) {
+    let mut v = Vec::new();
+    let mut ends = Vec::new();
+    let accidental_size = size * FIGURE_ACCIDENTAL_RATIO;
+    let digit_height = FIGURE_DIGIT_HEIGHT * size;
+    for (idx, figure) in figures.iter().enumerate() {
+        let line_baseline = baseline + idx as f32 * FIGURE_STACK_DISTANCE;
+        let mut line_x = x;
+
+        if let Some(glyph) = get_accidental_glyph(figure.accidental) {
+            // accidentals are centered on the digit height
+            let acc = PathSegments(glyph.to_vec())
+                .scale_path(accidental_size, -accidental_size)
+                .move_path(line_x, line_baseline - GLYPH_HEIGHT * (size - accidental_size) - SPACE * 0.3);
+            v.push(Path(acc, NoStroke, Fillstyle(Black), PathCacheInfo::NoCache));
+            line_x += get_path_advance(glyph) * accidental_size + FIGURE_ACCIDENTAL_GAP;
+        }
+
+        if let Some(number) = figure.number {
+            let text = number.to_string();
+            let width = render_options.get_text_width(role, &text) * size;
+            v.push(Path(
+                render_options.get_text_path(role, &text).scale_path(size, size).move_path(line_x, line_baseline),
+                render_options.fonts.get_stroke(role),
+                Fillstyle(Black),
+                PathCacheInfo::NoCache,
+            ));
+            if figure.slashed {
+                v.push(Line(
+                    line_x + width * 0.1,
+                    line_baseline - digit_height * 0.2,
+                    line_x + width,
+                    line_baseline - digit_height * 1.05,
+                    Strokestyle(FIGURE_STROKE * size, Black),
+                ));
+            }
+            line_x += width;
+        }
+        ends.push(line_x);
+    }
+    (v, ends)
+}
+
+pub fn output_figured_bass(matrix: &RMatrix, render_options: &RenderOptions) -> GraphicItems {
+    let mut graphic_items = GraphicItems::new();
+    let size = render_options.get_text_size(TextRole::Figure);
+    let digit_height = FIGURE_DIGIT_HEIGHT * size;
+
+    // all figures of a row hang from a common line below its lowest note
+    let mut row_bottoms: Vec<Option<f32>> = vec![None; matrix.rows.len()];
+    for figured_bass in render_options.figured_bass.iter() {
+        if let (Some(item), Some((_, staff_bottom))) = (get_row_item(matrix, figured_bass.rowidx, figured_bass.colidx), get_staff_extent(matrix, figured_bass.rowidx, render_options)) {
+            let bottom = get_note_bottom_y(&item.borrow(), staff_bottom);
+            let row_bottom = &mut row_bottoms[figured_bass.rowidx];
+            *row_bottom = Some(row_bottom.map_or(bottom, |row_bottom| row_bottom.max(bottom)));
+        }
+    }
+
+    for figured_bass in render_options.figured_bass.iter() {
+        let item = get_row_item(matrix, figured_bass.rowidx, figured_bass.colidx);
+        if item.is_none() {
+            continue;
+        }
+        let item: Ref<RItem> = item.as_ref().unwrap().borrow();
+        let x = item.coord_x.unwrap();
+        let baseline = row_bottoms[figured_bass.rowidx].unwrap() + FIGURED_BASS_DISTANCE + digit_height;
+
+        let (items, ends) = do_figure_stack(&figured_bass.figures, x, baseline, TextRole::Figure, size, render_options);
+        graphic_items.extend(GraphicItems(items));
+
+        for (idx, figure) in figured_bass.figures.iter().enumerate() {
+            if let Some(to_colidx) = figure.extend_to {
+                let x1 = ends[idx] + CONTINUATION_LINE_GAP;
+                let x2 = match matrix.cols.get(to_colidx) {
+                    Some(col) => col.borrow().x + HEAD_WIDTH_BLACK,
+                    None => matrix.width,
+                };
+                let y = baseline + idx as f32 * FIGURE_STACK_DISTANCE - digit_height * 0.4;
+                graphic_items.push(Line(x1, y, x2, y, Strokestyle(CONTINUATION_LINE_WIDTH, Black)));
+            }
+        }
+    }
+    graphic_items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_bass_figures() {
+        let figured_bass = FiguredBass::parse(1, 4, "#6\\ 4 b").unwrap();
+        assert_eq!(figured_bass.figures[0], BassFigure::new(6).with_accidental(FigureAccidental::Sharp).slashed());
+        assert_eq!(figured_bass.figures[1], BassFigure::new(4));
+        assert_eq!(figured_bass.figures[2], BassFigure::new_accidental(FigureAccidental::Flat));
+        assert!(BassFigure::parse("x").is_none());
+    }
+
+    #[test]
+    fn figures_hang_below_the_lowest_note() {
+        use crate::render::output::elements::testutils::*;
+        // the downward stem of the first note reaches below the staff
+        let matrix = get_test_matrix("|clef F | -2 2");
+        let cols = get_test_note_cols(&matrix, 0);
+        let mut render_options = RenderOptions::default();
+        render_options.figured_bass.push(FiguredBass::parse(0, cols[0], "6 4").unwrap());
+        render_options.figured_bass.push(FiguredBass::parse(0, cols[1], "5").unwrap());
+        let (_, staff_bottom) = get_staff_extent(&matrix, 0, &render_options).unwrap();
+        let first = get_row_item(&matrix, 0, cols[0]).unwrap();
+        let note_bottom = get_note_bottom_y(&first.borrow(), staff_bottom);
+        assert!(note_bottom > staff_bottom);
+
+        let graphic_items = output_figured_bass(&matrix, &render_options);
+        assert_eq!(graphic_items.0.len(), 3);
+        let (x1, y1, _, _) = get_items_extent(&graphic_items).unwrap();
+        assert!(y1 > note_bottom);
+        assert!(x1 >= get_test_col_x(&matrix, cols[0]));
+
+        // both columns share the top line, the lower figure of the stack below it
+        let tops: Vec<f32> = graphic_items.0.iter().map(|item| get_items_extent(&GraphicItems(vec![item.clone()])).unwrap().1).collect();
+        assert!((tops[2] - tops[0]).abs() < SPACE_HALF);
+        assert!(tops[1] > tops[0] + SPACE_HALF);
+    }
+}
@@ -0,0 +1,380 @@
+use crate::prelude::*;
+use crate::render::output::elements::elements::{take, take_suffix};
+use graphics::prelude::*;
+use notation_rs::prelude::*;
+
+// Offset in em units of the second D of the double dominant
+const DOUBLE_DOMINANT_OFFSET: (f32, f32) = (200.0, 120.0);
+const FUNCTION_PARENTHESIS_WIDTH: f32 = SPACE;
+// Height of capitals in em units
+const FUNCTION_CAP_HEIGHT: f32 = 560.0;
+const FUNCTION_STROKE: f32 = 50.0;
+// Distance of the bass figure below the baseline
+const FUNCTION_BASS_DISTANCE: f32 = SPACE * 1.6;
+const FUNCTION_ARROW_LENGTH: f32 = SPACE * 2.0;
+const FUNCTION_ARROW_HEAD: f32 = SPACE_HALF;
+// Indent of the function letters from the left edge of the function
+const FUNCTION_LETTERS_INDENT: f32 = 10.0;
+// Distance of the text top below the lowest note of the row
+const FUNCTION_DISTANCE: f32 = SPACE * 1.5;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RiemannBase {
+    // Figures only, no function letter
+    None,
+    T,
+    S,
+    D,
+    DD,
+    // Dominant without root, the struck-through D
+    DStruck,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RiemannRelative {
+    None,
+    // Parallel, as in Tp, Sp and Dp
+    Parallel,
+    // Gegenklang, as in Tg, Sg and Dg
+    Gegenklang,
+    // Neapolitan sixth, as in Sn
+    Neapolitan,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RiemannFunction {
+    pub base: RiemannBase,
+    pub relative: RiemannRelative,
+    // Minor functions are written in lower case
+    pub minor: bool,
+    // Figures to the right of the letters, stacked from the top
+    pub figures: Vec<u8>,
+    // Chord tone in the bass, written below the letters
+    pub bass: Option<u8>,
+    pub open_parenthesis: bool,
+    pub close_parenthesis: bool,
+    // Arrow after the closing parenthesis towards the function the secondary function refers to
+    pub arrow: bool,
+}
+
+impl RiemannFunction {
+    pub fn new(base: RiemannBase, relative: RiemannRelative) -> Self {
+        Self {
+            base,
+            relative,
+            minor: false,
+            figures: vec![],
+            bass: None,
+            open_parenthesis: false,
+            close_parenthesis: false,
+            arrow: false,
+        }
+    }
+
+    // Secondary function in parentheses, with an arrow when the function it refers to is not written
+    pub fn secondary(mut self, arrow: bool) -> Self {
+        self.open_parenthesis = true;
+        self.close_parenthesis = true;
+        self.arrow = arrow;
+        self
+    }
+
+    // Function as carried by NRectType::FunctionSymbol
+    pub fn from_notation(ftype: &FunctionType, fcolor: &FunctionColor, fbass: &FunctionBass, spar: bool, epar: bool) -> Self {
+        let (base, relative) = match ftype {
+            FunctionType::Spacer => (RiemannBase::None, RiemannRelative::None),
+            FunctionType::T => (RiemannBase::T, RiemannRelative::None),
+            FunctionType::S => (RiemannBase::S, RiemannRelative::None),
+            FunctionType::D => (RiemannBase::D, RiemannRelative::None),
+            FunctionType::DD => (RiemannBase::DD, RiemannRelative::None),
+            FunctionType::Tp => (RiemannBase::T, RiemannRelative::Parallel),
+            FunctionType::Sp => (RiemannBase::S, RiemannRelative::Parallel),
+        };
+        let mut function = Self::new(base, relative);
+        function.figures = match fcolor {
+            FunctionColor::FcNone => vec![],
+            FunctionColor::Fc3 => vec![3],
+            FunctionColor::Fc4 => vec![4],
+            FunctionColor::Fc5 => vec![5],
+            FunctionColor::Fc53 => vec![5, 3],
+            FunctionColor::Fc6 => vec![6],
+            FunctionColor::Fc64 => vec![6, 4],
+            FunctionColor::Fc7 => vec![7],
+        };
+        function.bass = match fbass {
+            FunctionBass::FbNone => None,
+            FunctionBass::Fb3 => Some(3),
+        };
+        function.open_parenthesis = spar;
+        function.close_parenthesis = epar;
+        function
+    }
+
+    // Function spelling such as "D7", "Tp", "dg", "Sn", "DD7", "Ð9", "D7_3" with bass 3, "(D7)" or "(D7)->"
+    pub fn parse(text: &str) -> Option<Self> {
+        let mut rest = text.trim();
+        let open_parenthesis = take(&mut rest, "(");
+        let arrow = take_suffix(&mut rest, "->") || take_suffix(&mut rest, "→");
+        let close_parenthesis = take_suffix(&mut rest, ")");
+
+        let (base, minor) = if take(&mut rest, "DD") {
+            (RiemannBase::DD, false)
+        } else if take(&mut rest, "Ð") || take(&mut rest, "Đ") || take(&mut rest, "D/") {
+            (RiemannBase::DStruck, false)
+        } else if take(&mut rest, "T") {
+            (RiemannBase::T, false)
+        } else if take(&mut rest, "t") {
+            (RiemannBase::T, true)
+        } else if take(&mut rest, "S") {
+            (RiemannBase::S, false)
+        } else if take(&mut rest, "s") {
+            (RiemannBase::S, true)
+        } else if take(&mut rest, "D") {
+            (RiemannBase::D, false)
+        } else if take(&mut rest, "d") {
+            (RiemannBase::D, true)
+        } else {
+            return None;
+        };
+
+        let relative = if take(&mut rest, "p") || take(&mut rest, "P") {
+            RiemannRelative::Parallel
+        } else if take(&mut rest, "g") || take(&mut rest, "G") {
+            RiemannRelative::Gegenklang
+        } else if base == RiemannBase::S && (take(&mut rest, "n") || take(&mut rest, "N")) {
+            RiemannRelative::Neapolitan
+        } else {
+            RiemannRelative::None
+        };
+
+        let mut function = Self::new(base, relative);
+        function.minor = minor;
+        function.open_parenthesis = open_parenthesis;
+        function.close_parenthesis = close_parenthesis;
+        function.arrow = arrow;
+
+        let (figures, bass) = match rest.split_once('_') {
+            Some((figures, bass)) => (figures, Some(bass)),
+            None => (rest, None),
+        };
+        for c in figures.chars() {
+            function.figures.push(c.to_digit(10)? as u8);
+        }
+        if let Some(bass) = bass {
+            function.bass = Some(bass.parse().ok()?);
+        }
+        Some(function)
+    }
+
+    // Letters as written, without the second D of the double dominant
+    pub fn get_letters(&self) -> String {
+        let letter = match (self.base, self.minor) {
+            (RiemannBase::None, _) => return String::new(),
+            (RiemannBase::T, false) => 'T',
+            (RiemannBase::T, true) => 't',
+            (RiemannBase::S, false) => 'S',
+            (RiemannBase::S, true) => 's',
+            (RiemannBase::D | RiemannBase::DD | RiemannBase::DStruck, false) => 'D',
+            (RiemannBase::D | RiemannBase::DD | RiemannBase::DStruck, true) => 'd',
+        };
+        // the relative is written in the opposite case of a minor function
+        let relative = match (self.relative, self.minor) {
+            (RiemannRelative::None, _) => None,
+            (RiemannRelative::Parallel, false) => Some('p'),
+            (RiemannRelative::Parallel, true) => Some('P'),
+            (RiemannRelative::Gegenklang, false) => Some('g'),
+            (RiemannRelative::Gegenklang, true) => Some('G'),
+            (RiemannRelative::Neapolitan, _) => Some('n'),
+        };
+        letter.to_string() + &relative.map(String::from).unwrap_or_default()
+    }
+}
+
+// Function replacing the one of the item, for functions the notation model cannot express
+#[derive(Debug, Clone)]
+pub struct FunctionMark {
+    pub rowidx: usize,
+    pub colidx: usize,
+    pub function: RiemannFunction,
+}
+
+impl FunctionMark {
+    pub fn new(rowidx: usize, colidx: usize, function: RiemannFunction) -> Self {
+        Self { rowidx, colidx, function }
+    }
+}
+
+pub fn get_function_mark(functions: &[FunctionMark], rowidx: usize, colidx: usize) -> Option<&RiemannFunction> {
+    functions.iter().find(|mark| mark.rowidx == rowidx && mark.colidx == colidx).map(|mark| &mark.function)
+}
+
+fn do_function_text(text: &str, x: f32, baseline: f32, size: f32, render_options: &RenderOptions) -> GraphicItem {
+    Path(
+        render_options.get_text_path(TextRole::Function, text).scale_path(size, size).move_path(x, baseline),
+        render_options.fonts.get_stroke(TextRole::Function),
+        Fillstyle(Black),
+        PathCacheInfo::NoCache,
+    )
+}
+
+// Letters, figures, bass, parentheses and arrow of a function, left aligned at x with the text top at y
+pub fn function2graphic(function: &RiemannFunction, x: f32, y: f32, render_options: &RenderOptions) -> Vec<GraphicItem> {
+    let mut v = Vec::new();
+    let size = render_options.get_text_size(TextRole::Function);
+    let figure_size = size * FUNCTION_FONT_FIGURE_SCALE / FUNCTION_FONT_SCALE;
+    let baseline = y + GLYPH_HEIGHT * size;
+
+    let mut letters_x = FUNCTION_LETTERS_INDENT;
+    if function.open_parenthesis {
+        letters_x += FUNCTION_PARENTHESIS_WIDTH;
+        v.push(do_function_text("(", x, baseline, size, render_options));
+    }
+
+    let letters = function.get_letters();
+    let mut letters_width = render_options.get_text_width(TextRole::Function, &letters) * size;
+    if !letters.is_empty() {
+        v.push(do_function_text(&letters, x + letters_x, baseline, size, render_options));
+    }
+    match function.base {
+        RiemannBase::DD => {
+            let (dx, dy) = DOUBLE_DOMINANT_OFFSET;
+            v.push(do_function_text(&letters[..1], x + letters_x + dx * size, baseline + dy * size, size, render_options));
+            letters_width += dx * size;
+        }
+        RiemannBase::DStruck => {
+            // stroke through the D from lower left to upper right
+            let width = render_options.get_text_advance(TextRole::Function, 'D') * size;
+            let height = FUNCTION_CAP_HEIGHT * size;
+            v.push(Line(
+                x + letters_x - width * 0.1,
+                baseline + height * 0.1,
+                x + letters_x + width * 1.1,
+                baseline - height * 1.1,
+                Strokestyle(FUNCTION_STROKE * size, Black),
+            ));
+        }
+        _ => {}
+    }
+
+    // figures follow the letters
+    let figure_x = letters_x + letters_width + SPACE_QUARTER;
+    let figures: Vec<BassFigure> = function.figures.iter().map(|figure| BassFigure::new(*figure)).collect();
+    let figure_baseline = y + GLYPH_HEIGHT * figure_size - SPACE * 0.2;
+    let (figure_items, figure_ends) = do_figure_stack(&figures, x + figure_x, figure_baseline, TextRole::Function, figure_size, render_options);
+    v.extend(figure_items);
+    let figure_width = figure_ends.iter().map(|end| end - x - figure_x + SPACE_QUARTER).fold(0.0, f32::max);
+
+    if let Some(bass) = function.bass {
+        v.push(do_function_text(&bass.to_string(), x + letters_x + SPACE * 0.8, baseline + FUNCTION_BASS_DISTANCE, figure_size, render_options));
+    }
+
+    let mut end_x = x + figure_x + figure_width;
+    if function.close_parenthesis {
+        v.push(do_function_text(")", end_x, baseline, size, render_options));
+        end_x += render_options.get_text_advance(TextRole::Function, ')') * size;
+    }
+
+    if function.arrow {
+        let arrow_x = end_x + SPACE_QUARTER;
+        let arrow_y = baseline - FUNCTION_CAP_HEIGHT * size * 0.5;
+        let tip_x = arrow_x + FUNCTION_ARROW_LENGTH;
+        v.push(Path(
+            PathSegments(vec![
+                M(arrow_x, arrow_y),
+                L(tip_x, arrow_y),
+                M(tip_x - FUNCTION_ARROW_HEAD, arrow_y - FUNCTION_ARROW_HEAD * 0.6),
+                L(tip_x, arrow_y),
+                L(tip_x - FUNCTION_ARROW_HEAD, arrow_y + FUNCTION_ARROW_HEAD * 0.6),
+            ]),
+            Strokestyle(FUNCTION_STROKE * size, Black),
+            NoFill,
+            PathCacheInfo::NoCache,
+        ));
+    }
+
+    // orange rect for functions
+    // v.push(Rect(r.0, r.1, r.2, r.3, Strokestyle(3.0, Orange), NoFill));
+
+    v
+}
+
+pub fn output_functions(matrix: &RMatrix, render_options: &RenderOptions) -> GraphicItems {
+    let mut graphic_items = GraphicItems::new();
+
+    // all functions of a row share a line below its lowest note
+    let mut row_bottoms: Vec<Option<f32>> = vec![None; matrix.rows.len()];
+    for mark in render_options.functions.iter() {
+        if let (Some(item), Some((_, staff_bottom))) = (get_row_item(matrix, mark.rowidx, mark.colidx), get_staff_extent(matrix, mark.rowidx, render_options)) {
+            let bottom = get_note_bottom_y(&item.borrow(), staff_bottom);
+            let row_bottom = &mut row_bottoms[mark.rowidx];
+            *row_bottom = Some(row_bottom.map_or(bottom, |row_bottom| row_bottom.max(bottom)));
+        }
+    }
+
+    for mark in render_options.functions.iter() {
+        let item = get_row_item(matrix, mark.rowidx, mark.colidx);
+        if item.is_none() {
+            continue;
+        }
+        let item = item.as_ref().unwrap().borrow();
+        // items with a function symbol rect draw the mark in its place
+        let has_function_rect = item.nrects.as_ref().is_some_and(|nrects| nrects.iter().any(|nrect| matches!(nrect.borrow().1, NRectType::FunctionSymbol(..))));
+        if has_function_rect {
+            continue;
+        }
+        let x = item.coord_x.unwrap();
+        let y = row_bottoms[mark.rowidx].unwrap() + FUNCTION_DISTANCE;
+        graphic_items.extend(GraphicItems(function2graphic(&mark.function, x, y, render_options)));
+    }
+    graphic_items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_riemann_functions() {
+        let function = RiemannFunction::parse("(DD7)->").unwrap();
+        assert_eq!(function.base, RiemannBase::DD);
+        assert_eq!(function.figures, vec![7]);
+        assert!(function.open_parenthesis && function.close_parenthesis && function.arrow);
+        let function = RiemannFunction::parse("Ð9_5").unwrap();
+        assert_eq!((function.base, function.bass), (RiemannBase::DStruck, Some(5)));
+        assert_eq!(RiemannFunction::parse("tG").unwrap().get_letters(), "tG");
+        assert_eq!(RiemannFunction::parse("Sn").unwrap().relative, RiemannRelative::Neapolitan);
+        assert_eq!(RiemannFunction::parse("Dg64").unwrap().figures, vec![6, 4]);
+        assert!(RiemannFunction::parse("X").is_none());
+
+        let function = RiemannFunction::from_notation(&FunctionType::Tp, &FunctionColor::Fc64, &FunctionBass::Fb3, true, false);
+        assert_eq!((function.base, function.relative), (RiemannBase::T, RiemannRelative::Parallel));
+        assert_eq!((function.figures, function.bass), (vec![6, 4], Some(3)));
+        assert!(function.open_parenthesis && !function.close_parenthesis);
+    }
+
+    #[test]
+    fn functions_below_the_lowest_note() {
+        use crate::render::output::elements::testutils::*;
+        // the downward stem of the first note reaches below the staff
+        let matrix = get_test_matrix("|clef G | 3 -2");
+        let cols = get_test_note_cols(&matrix, 0);
+        let mut render_options = RenderOptions::default();
+        render_options.functions.push(FunctionMark::new(0, cols[0], RiemannFunction::parse("T").unwrap()));
+        render_options.functions.push(FunctionMark::new(0, cols[1], RiemannFunction::parse("D7").unwrap()));
+        let (_, staff_bottom) = get_staff_extent(&matrix, 0, &render_options).unwrap();
+        let second = get_row_item(&matrix, 0, cols[1]).unwrap();
+        let note_bottom = get_note_bottom_y(&second.borrow(), staff_bottom);
+        assert!(note_bottom > staff_bottom);
+
+        // the tonic letter first, then the dominant letter and its figure
+        let graphic_items = output_functions(&matrix, &render_options);
+        assert_eq!(graphic_items.0.len(), 3);
+        let (tx1, ty1, _, _) = get_items_extent(&GraphicItems(graphic_items.0[..1].to_vec())).unwrap();
+        let (dx1, dy1, _, _) = get_items_extent(&GraphicItems(graphic_items.0[1..2].to_vec())).unwrap();
+        // both letters hang from the common line below the stem of the second note
+        assert!(ty1 > note_bottom && dy1 > note_bottom);
+        assert!((ty1 - dy1).abs() < SPACE_QUARTER);
+        assert!(tx1 >= get_test_col_x(&matrix, cols[0]));
+        assert!(dx1 >= get_test_col_x(&matrix, cols[1]));
+    }
+}
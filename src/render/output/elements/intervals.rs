@@ -0,0 +1,316 @@
+use crate::prelude::rects2graphic::ncolor_to_color;
+use crate::prelude::*;
+use graphics::path::PathSegment;
+use graphics::prelude::*;
+use notation_rs::prelude::*;
+use std::cell::Ref;
+
+const INTERVAL_LINE_GAP: f32 = SPACE * 0.4;
+const INTERVAL_LINE_WIDTH: f32 = 5.0;
+const GLISSANDO_LINE_WIDTH: f32 = 3.0;
+const GLISSANDO_WAVE_PERIOD: f32 = SPACE * 0.8;
+const GLISSANDO_WAVE_AMPLITUDE: f32 = SPACE * 0.15;
+const PORTAMENTO_DASH: f32 = SPACE * 0.5;
+// Height of digits and capitals in em units
+const INTERVAL_LABEL_HEIGHT: f32 = 700.0;
+const INTERVAL_LABEL_GAP: f32 = SPACE * 0.3;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IntervalQuality {
+    Perfect,
+    Major,
+    Minor,
+    Augmented,
+    Diminished,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum IntervalNaming {
+    // Short names such as m3, P5 and A4
+    #[default]
+    English,
+    // Names such as liten ters, ren kvint and överstigande kvart
+    Swedish,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Interval {
+    pub quality: IntervalQuality,
+    // 1 for the unison up to 15 for the double octave
+    pub number: u8,
+}
+
+const SWEDISH_INTERVALS: [&str; 15] = [
+    "prim", "sekund", "ters", "kvart", "kvint", "sext", "septim", "oktav", "nona", "decima", "undecima", "duodecima", "tredecima", "kvartdecima", "kvintdecima",
+];
+
+impl Interval {
+    pub fn new(quality: IntervalQuality, number: u8) -> Self {
+        Self { quality, number }
+    }
+
+    // Short name such as "m3", "M6", "P5", "A4" or "d7"
+    pub fn parse(text: &str) -> Option<Self> {
+        let mut chars = text.trim().chars();
+        let quality = match chars.next()? {
+            'P' => IntervalQuality::Perfect,
+            'M' => IntervalQuality::Major,
+            'm' => IntervalQuality::Minor,
+            'A' => IntervalQuality::Augmented,
+            'd' => IntervalQuality::Diminished,
+            _ => return None,
+        };
+        let number: u8 = chars.as_str().parse().ok()?;
+        if !(1..=15).contains(&number) {
+            return None;
+        }
+        // unisons, fourths, fifths and octaves are perfect, the others major or minor
+        let perfect = matches!((number - 1) % 7, 0 | 3 | 4);
+        match (quality, perfect) {
+            (IntervalQuality::Perfect, false) | (IntervalQuality::Major, true) | (IntervalQuality::Minor, true) => None,
+            _ => Some(Self::new(quality, number)),
+        }
+    }
+
+    pub fn get_name(&self, naming: IntervalNaming) -> String {
+        match naming {
+            IntervalNaming::English => {
+                let quality = match self.quality {
+                    IntervalQuality::Perfect => "P",
+                    IntervalQuality::Major => "M",
+                    IntervalQuality::Minor => "m",
+                    IntervalQuality::Augmented => "A",
+                    IntervalQuality::Diminished => "d",
+                };
+                format!("{}{}", quality, self.number)
+            }
+            IntervalNaming::Swedish => {
+                let quality = match self.quality {
+                    IntervalQuality::Perfect => "ren",
+                    IntervalQuality::Major => "stor",
+                    IntervalQuality::Minor => "liten",
+                    IntervalQuality::Augmented => "överstigande",
+                    IntervalQuality::Diminished => "förminskad",
+                };
+                let number = SWEDISH_INTERVALS[(self.number.clamp(1, 15) - 1) as usize];
+                format!("{} {}", quality, number)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IntervalLineStyle {
+    Line,
+    Glissando,
+    WavyGlissando,
+    Portamento,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum IntervalLabel {
+    Interval(Interval),
+    Text(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct IntervalLine {
+    pub rowidx: usize,
+    pub from_colidx: usize,
+    pub to_colidx: usize,
+    pub style: IntervalLineStyle,
+    pub label: Option<IntervalLabel>,
+    pub color: NColor,
+}
+
+impl IntervalLine {
+    pub fn new(rowidx: usize, from_colidx: usize, to_colidx: usize, style: IntervalLineStyle) -> Self {
+        Self {
+            rowidx,
+            from_colidx,
+            to_colidx,
+            style,
+            label: None,
+            color: NColor::Black,
+        }
+    }
+
+    pub fn with_label(mut self, label: IntervalLabel) -> Self {
+        self.label = Some(label);
+        self
+    }
+
+    pub fn with_color(mut self, color: NColor) -> Self {
+        self.color = color;
+        self
+    }
+}
+
+// Wave along the line from x1, y1 to x2, y2
+fn slanted_wave_segments(x1: f32, y1: f32, x2: f32, y2: f32) -> Vec<PathSegment> {
+    let length = ((x2 - x1).powi(2) + (y2 - y1).powi(2)).sqrt();
+    let (cos, sin) = ((x2 - x1) / length, (y2 - y1) / length);
+    let rotate = |u: f32, v: f32| (x1 + u * cos - v * sin, y1 + u * sin + v * cos);
+    wavy_line_segments(0.0, 0.0, length, GLISSANDO_WAVE_PERIOD, GLISSANDO_WAVE_AMPLITUDE)
+        .into_iter()
+        .map(|segment| match segment {
+            M(u, v) => {
+                let (x, y) = rotate(u, v);
+                M(x, y)
+            }
+            Q(u1, v1, u, v) => {
+                let (cx, cy) = rotate(u1, v1);
+                let (x, y) = rotate(u, v);
+                Q(cx, cy, x, y)
+            }
+            segment => segment,
+        })
+        .collect()
+}
+
+// Label beside the line between the from and to points, pushed off the line far enough that its box clears it.
+// Labels go above the line, or below it when below is set; labels wider than the line go above or below both notes.
+pub fn do_line_label(text: &str, size: f32, from: (f32, f32), to: (f32, f32), below: bool, render_options: &RenderOptions) -> GraphicItem {
+    let ((x1, y1), (x2, y2)) = (from, to);
+    let width = render_options.get_text_width(TextRole::Figure, text) * size;
+    let height = INTERVAL_LABEL_HEIGHT * size;
+    let (center_x, center_y) = if width + INTERVAL_LABEL_GAP * 2.0 > (x2 - x1).abs() {
+        let y = match below {
+            true => y1.max(y2) + SPACE + height * 0.5,
+            false => y1.min(y2) - SPACE - height * 0.5,
+        };
+        ((x1 + x2) * 0.5, y)
+    } else {
+        let length = ((x2 - x1).powi(2) + (y2 - y1).powi(2)).sqrt().max(1.0);
+        // unit normal pointing up, or down for labels below
+        let (mut nx, mut ny) = ((y2 - y1) / length, -(x2 - x1).abs() / length);
+        if x2 < x1 {
+            nx = -nx;
+        }
+        if below {
+            nx = -nx;
+            ny = -ny;
+        }
+        let distance = width * 0.5 * nx.abs() + height * 0.5 * ny.abs() + INTERVAL_LABEL_GAP;
+        ((x1 + x2) * 0.5 + nx * distance, (y1 + y2) * 0.5 + ny * distance)
+    };
+    Path(
+        render_options
+            .get_text_path(TextRole::Figure, text)
+            .scale_path(size, size)
+            .move_path(center_x - width * 0.5, center_y + height * 0.5),
+        render_options.fonts.get_stroke(TextRole::Figure),
+        Fillstyle(Black),
+        PathCacheInfo::NoCache,
+    )
+}
+
+// Head level nearest to the other end of the line
+fn get_head_level(item: &RItem, upwards: bool) -> f32 {
+    match get_beam_data(&item.notedata.beamdata1) {
+        Some(data) if upwards => data.top_level as f32,
+        Some(data) => data.bottom_level as f32,
+        None => 0.0,
+    }
+}
+
+pub fn output_interval_lines(matrix: &RMatrix, render_options: &RenderOptions) -> GraphicItems {
+    let mut graphic_items = GraphicItems::new();
+    let size = render_options.get_text_size(TextRole::Figure);
+
+    for line in render_options.interval_lines.iter() {
+        let (from, to) = match (get_row_item(matrix, line.rowidx, line.from_colidx), get_row_item(matrix, line.rowidx, line.to_colidx)) {
+            (Some(from), Some(to)) => (from, to),
+            _ => continue,
+        };
+        let from: Ref<RItem> = from.borrow();
+        let to: Ref<RItem> = to.borrow();
+
+        let from_top = from.coord_y.unwrap() + get_head_level(&from, true) * SPACE_HALF;
+        let to_top = to.coord_y.unwrap() + get_head_level(&to, true) * SPACE_HALF;
+        let upwards = to_top < from_top;
+        let x1 = from.coord_x.unwrap() + HEAD_WIDTH_BLACK + INTERVAL_LINE_GAP;
+        let x2 = to.coord_x.unwrap() - INTERVAL_LINE_GAP;
+        let y1 = from.coord_y.unwrap() + get_head_level(&from, upwards) * SPACE_HALF;
+        let y2 = to.coord_y.unwrap() + get_head_level(&to, !upwards) * SPACE_HALF;
+        let color = ncolor_to_color(&line.color);
+
+        match line.style {
+            IntervalLineStyle::Line => graphic_items.push(Line(x1, y1, x2, y2, Strokestyle(INTERVAL_LINE_WIDTH, color))),
+            IntervalLineStyle::Glissando => graphic_items.push(Line(x1, y1, x2, y2, Strokestyle(GLISSANDO_LINE_WIDTH, color))),
+            IntervalLineStyle::WavyGlissando => graphic_items.push(Path(
+                PathSegments(slanted_wave_segments(x1, y1, x2, y2)),
+                Strokestyle(GLISSANDO_LINE_WIDTH, color),
+                NoFill,
+                PathCacheInfo::NoCache,
+            )),
+            IntervalLineStyle::Portamento => {
+                let length = ((x2 - x1).powi(2) + (y2 - y1).powi(2)).sqrt();
+                let dashes = (length / (PORTAMENTO_DASH * 2.0)).floor().max(1.0) as usize;
+                for dash in 0..dashes {
+                    let t1 = dash as f32 / dashes as f32;
+                    let t2 = t1 + 0.5 / dashes as f32;
+                    graphic_items.push(Line(
+                        x1 + (x2 - x1) * t1,
+                        y1 + (y2 - y1) * t1,
+                        x1 + (x2 - x1) * t2,
+                        y1 + (y2 - y1) * t2,
+                        Strokestyle(GLISSANDO_LINE_WIDTH, ncolor_to_color(&line.color)),
+                    ));
+                }
+            }
+        }
+
+        let text = match &line.label {
+            Some(IntervalLabel::Interval(interval)) => interval.get_name(render_options.interval_naming),
+            Some(IntervalLabel::Text(text)) => text.clone(),
+            None => continue,
+        };
+        // the label goes on the side away from the stems
+        let stems_down = matches!(get_beam_data(&from.notedata.beamdata1).map(|data| &data.direction), Some(DirUD::Down));
+        graphic_items.push(do_line_label(&text, size, (x1, y1), (x2, y2), !stems_down, render_options));
+    }
+    graphic_items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_and_name_intervals() {
+        assert_eq!(Interval::parse("m3"), Some(Interval::new(IntervalQuality::Minor, 3)));
+        assert_eq!(Interval::parse("P5").unwrap().get_name(IntervalNaming::Swedish), "ren kvint");
+        assert_eq!(Interval::parse("A4").unwrap().get_name(IntervalNaming::English), "A4");
+        assert!(Interval::parse("P3").is_none());
+        assert!(Interval::parse("M5").is_none());
+    }
+
+    #[test]
+    fn lines_between_the_heads() {
+        use crate::render::output::elements::testutils::*;
+        let matrix = get_test_matrix("|clef G | 2 -2");
+        let cols = get_test_note_cols(&matrix, 0);
+        let mut render_options = RenderOptions::default();
+        render_options.interval_lines.push(IntervalLine::new(0, cols[0], cols[1], IntervalLineStyle::Glissando));
+        let graphic_items = output_interval_lines(&matrix, &render_options);
+        assert_eq!(graphic_items.0.len(), 1);
+        // a rising line from the right of the first head to the left of the second
+        match graphic_items.0[0] {
+            Line(x1, y1, x2, y2, _) => {
+                assert!(x1 > get_test_col_x(&matrix, cols[0]) + HEAD_WIDTH_BLACK);
+                assert!(x2 < get_test_col_x(&matrix, cols[1]));
+                assert!(y2 < y1);
+            }
+            _ => panic!("glissando is a straight line"),
+        }
+
+        // portamento dashes stay on the same span, the label is added after them
+        render_options.interval_lines[0].style = IntervalLineStyle::Portamento;
+        let (x1, _, x2, _) = get_items_extent(&output_interval_lines(&matrix, &render_options)).unwrap();
+        assert!(x1 > get_test_col_x(&matrix, cols[0]) + HEAD_WIDTH_BLACK && x2 < get_test_col_x(&matrix, cols[1]));
+        let dashes = output_interval_lines(&matrix, &render_options).0.len();
+        render_options.interval_lines[0].label = Some(IntervalLabel::Interval(Interval::parse("P5").unwrap()));
+        assert_eq!(output_interval_lines(&matrix, &render_options).0.len(), dashes + 1);
+    }
+}
@@ -0,0 +1,406 @@
+use crate::prelude::rects2graphic::{get_accidental_anchor_y, ncolor_to_color};
+use crate::prelude::*;
+use graphics::prelude::*;
+use notation_rs::prelude::*;
+use std::collections::BTreeMap;
+
+const KEYBOARD_WHITE_WIDTH: f32 = SPACE * 1.2;
+const KEYBOARD_WHITE_HEIGHT: f32 = SPACE * 5.0;
+const KEYBOARD_BLACK_WIDTH: f32 = KEYBOARD_WHITE_WIDTH * 0.6;
+const KEYBOARD_BLACK_HEIGHT: f32 = KEYBOARD_WHITE_HEIGHT * 0.62;
+const KEYBOARD_LINE_WIDTH: f32 = 2.0;
+// Distance from the lowest staff line, or from the end of the system
+const KEYBOARD_DISTANCE: f32 = SPACE * 2.0;
+const KEYBOARD_LABEL_DISTANCE: f32 = SPACE * 0.5;
+// Height of capitals in em units
+const KEYBOARD_LABEL_HEIGHT: f32 = 700.0;
+
+const MIDDLE_C: u8 = 60;
+const WHITE_SEMITONES: [u8; 7] = [0, 2, 4, 5, 7, 9, 11];
+const SHARP_NAMES: [&str; 12] = ["C", "C#", "D", "D#", "E", "F", "F#", "G", "G#", "A", "A#", "B"];
+const FLAT_NAMES: [&str; 12] = ["C", "Db", "D", "Eb", "E", "F", "Gb", "G", "Ab", "A", "Bb", "B"];
+// Sharps and flats of key signatures by step from C, in signature order
+const KEY_SHARP_STEPS: [i8; 7] = [3, 0, 4, 1, 5, 2, 6];
+const KEY_FLAT_STEPS: [i8; 7] = [6, 2, 5, 1, 4, 0, 3];
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum KeyboardKeys {
+    // Midi note numbers, 60 for middle C
+    Notes(Vec<u8>),
+    Chord(ChordSymbol),
+    // Heads of all rows in the column of the diagram
+    Column,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum KeyboardPlacement {
+    // Below the row at the column
+    Below,
+    // Right of the system, level with the row
+    After,
+}
+
+#[derive(Debug, Clone)]
+pub struct KeyboardDiagram {
+    pub rowidx: usize,
+    pub colidx: usize,
+    pub keys: KeyboardKeys,
+    // Midi note numbers of the lowest and highest keys
+    pub lowest: u8,
+    pub highest: u8,
+    pub color: NColor,
+    // Note names below the highlighted keys
+    pub labels: bool,
+    pub flats: bool,
+    pub placement: KeyboardPlacement,
+}
+
+impl KeyboardDiagram {
+    pub fn new(rowidx: usize, colidx: usize, keys: KeyboardKeys) -> Self {
+        // flat chords are labelled with flats
+        let flats = matches!(&keys, KeyboardKeys::Chord(chord) if chord.root.accidental == ChordAccidental::Flat);
+        Self {
+            rowidx,
+            colidx,
+            keys,
+            lowest: MIDDLE_C - 12,
+            highest: MIDDLE_C + 23,
+            color: NColor::Dodgerblue,
+            labels: false,
+            flats,
+            placement: KeyboardPlacement::Below,
+        }
+    }
+
+    pub fn with_range(mut self, lowest: u8, highest: u8) -> Self {
+        self.lowest = lowest.min(highest);
+        self.highest = lowest.max(highest);
+        self
+    }
+
+    pub fn with_color(mut self, color: NColor) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn with_labels(mut self, flats: bool) -> Self {
+        self.labels = true;
+        self.flats = flats;
+        self
+    }
+
+    pub fn after(mut self) -> Self {
+        self.placement = KeyboardPlacement::After;
+        self
+    }
+
+    // Range widened to start and end on white keys
+    pub fn get_range(&self) -> (u8, u8) {
+        let lowest = if is_black_key(self.lowest) { self.lowest - 1 } else { self.lowest };
+        let highest = if is_black_key(self.highest) { self.highest + 1 } else { self.highest };
+        (lowest, highest)
+    }
+}
+
+pub fn is_black_key(note: u8) -> bool {
+    !WHITE_SEMITONES.contains(&(note % 12))
+}
+
+pub fn get_key_name(note: u8, flats: bool) -> &'static str {
+    match flats {
+        true => FLAT_NAMES[(note % 12) as usize],
+        false => SHARP_NAMES[(note % 12) as usize],
+    }
+}
+
+// Semitones above the root of the chord tones, with the bass below the root
+pub fn get_chord_tones(chord: &ChordSymbol) -> Vec<i8> {
+    let mut tones: Vec<i8> = match chord.quality {
+        ChordQuality::Major => vec![0, 4, 7],
+        ChordQuality::Minor => vec![0, 3, 7],
+        ChordQuality::Diminished => vec![0, 3, 6],
+        ChordQuality::Augmented => vec![0, 4, 8],
+        ChordQuality::HalfDiminished => vec![0, 3, 6, 10],
+        ChordQuality::Sus2 => vec![0, 2, 7],
+        ChordQuality::Sus4 => vec![0, 5, 7],
+        ChordQuality::Power => vec![0, 7],
+    };
+    let seventh = match chord.quality {
+        ChordQuality::Diminished => 9,
+        _ => 10,
+    };
+    tones.extend(match chord.extension {
+        ChordExtension::None => vec![],
+        ChordExtension::Six => vec![9],
+        ChordExtension::SixNine => vec![9, 14],
+        ChordExtension::Seven => vec![seventh],
+        ChordExtension::MajSeven => vec![11],
+        ChordExtension::Nine => vec![seventh, 14],
+        ChordExtension::MajNine => vec![11, 14],
+        ChordExtension::Eleven => vec![seventh, 14, 17],
+        ChordExtension::Thirteen => vec![seventh, 14, 21],
+        ChordExtension::MajThirteen => vec![11, 14, 21],
+    });
+    for alteration in chord.alterations.iter() {
+        match alteration {
+            ChordAlteration::FlatFive => tones.iter_mut().filter(|tone| **tone == 7).for_each(|tone| *tone = 6),
+            ChordAlteration::SharpFive => tones.iter_mut().filter(|tone| **tone == 7).for_each(|tone| *tone = 8),
+            ChordAlteration::FlatNine => tones.push(13),
+            ChordAlteration::SharpNine => tones.push(15),
+            ChordAlteration::SharpEleven => tones.push(18),
+            ChordAlteration::FlatThirteen => tones.push(20),
+            ChordAlteration::AddNine => tones.push(14),
+            ChordAlteration::AddEleven => tones.push(17),
+            ChordAlteration::AddThirteen => tones.push(21),
+        }
+    }
+    if let Some(bass) = &chord.bass {
+        let below = (bass.get_pitch_class() - chord.root.get_pitch_class()).rem_euclid(12) - 12;
+        tones.push(below);
+    }
+    tones.sort();
+    tones.dedup();
+    tones
+}
+
+// Chord with its root in the octave above middle C
+pub fn get_chord_notes(chord: &ChordSymbol) -> Vec<u8> {
+    let root = MIDDLE_C as i8 + chord.root.get_pitch_class().rem_euclid(12);
+    get_chord_tones(chord).into_iter().map(|tone| (root + tone) as u8).collect()
+}
+
+// Steps from C0 of the middle staff line
+fn get_clef_middle(clef: &Clef) -> i8 {
+    match clef {
+        Clef::G => 34,
+        Clef::F => 22,
+        Clef::C => 28,
+    }
+}
+
+fn get_accidental_alter(accidental: &Accidental) -> i8 {
+    match accidental {
+        Accidental::Sharp => 1,
+        Accidental::Flat => -1,
+        Accidental::Natural => 0,
+        Accidental::DblSharp => 2,
+        Accidental::DblFlat => -2,
+    }
+}
+
+// Midi note of a head level below a middle line step, where steps of the key signature are raised or lowered
+// unless the head has an accidental
+pub fn get_level_note(level: i8, middle: i8, key_alters: &[i8; 7], alter: Option<i8>) -> u8 {
+    let step = middle as i32 - level as i32;
+    let alter = alter.unwrap_or(key_alters[step.rem_euclid(7) as usize]) as i32;
+    (12 * (step.div_euclid(7) + 1) + WHITE_SEMITONES[step.rem_euclid(7) as usize] as i32 + alter).clamp(0, 127) as u8
+}
+
+// Middle line step of the clef and key signature in effect at the column, G clef without a key when the row has none
+fn get_row_signature(matrix: &RMatrix, rowidx: usize, colidx: usize) -> (i8, [i8; 7]) {
+    let mut middle = get_clef_middle(&Clef::G);
+    let mut key_alters = [0; 7];
+    for item in (0..=colidx).filter_map(|colidx| get_row_item(matrix, rowidx, colidx)) {
+        let item = item.borrow();
+        for nrect in item.nrects.iter().flatten() {
+            match &nrect.borrow().1 {
+                NRectType::Clef(clef) => middle = get_clef_middle(clef),
+                NRectType::KeySignature(key, _) => {
+                    key_alters = [0; 7];
+                    let (steps, alter, count) = match key {
+                        Key::Sharps(n, _) => (KEY_SHARP_STEPS, 1, *n as usize),
+                        Key::Flats(n, _) => (KEY_FLAT_STEPS, -1, *n as usize),
+                        _ => (KEY_SHARP_STEPS, 0, 0),
+                    };
+                    steps.iter().take(count).for_each(|step| key_alters[*step as usize] = alter);
+                }
+                _ => {}
+            }
+        }
+    }
+    (middle, key_alters)
+}
+
+// Accidentals of an item with the head level their glyphs are drawn at
+fn get_item_alters(item: &RItem) -> Vec<(i8, i8)> {
+    let mut alters = Vec::new();
+    for nrect in item.nrects.iter().flatten() {
+        let nrect = nrect.borrow();
+        if let NRectType::Accidental(accidental) = &nrect.1 {
+            let level = ((nrect.0 .1 + get_accidental_anchor_y(accidental)) / SPACE_HALF).round() as i8;
+            alters.push((level, get_accidental_alter(accidental)));
+        }
+    }
+    alters
+}
+
+// Accidentals still in effect at the column: the last one at each level since the last barline of the row
+fn get_measure_alters(matrix: &RMatrix, rowidx: usize, colidx: usize) -> BTreeMap<i8, i8> {
+    let mut alters = BTreeMap::new();
+    for item in (0..colidx).filter_map(|colidx| get_row_item(matrix, rowidx, colidx)) {
+        let item = item.borrow();
+        if item.nrects.iter().flatten().any(|nrect| matches!(nrect.borrow().1, NRectType::Barline(_))) {
+            alters.clear();
+        }
+        alters.extend(get_item_alters(&item));
+    }
+    alters
+}
+
+// Sounding notes of the heads in a column. Accidentals apply to the head at their level,
+// and to later heads at that level up to the next barline.
+pub fn get_column_notes(matrix: &RMatrix, colidx: usize) -> Vec<u8> {
+    let mut notes = Vec::new();
+    for rowidx in 0..matrix.rows.len() {
+        let item = match get_row_item(matrix, rowidx, colidx) {
+            Some(item) => item,
+            None => continue,
+        };
+        let (middle, key_alters) = get_row_signature(matrix, rowidx, colidx);
+        let mut alters = get_measure_alters(matrix, rowidx, colidx);
+        let item = item.borrow();
+        // outer heads from the note data, inner chord heads and accidentals from the level their glyphs are drawn at
+        let mut heads: Vec<i8> = match get_beam_data(&item.notedata.beamdata1) {
+            Some(data) => vec![data.top_level as i8, data.bottom_level as i8],
+            None => vec![],
+        };
+        for nrect in item.nrects.iter().flatten() {
+            let nrect = nrect.borrow();
+            if let NRectType::Head(..) = &nrect.1 {
+                heads.push(((nrect.0 .1 + SPACE_HALF) / SPACE_HALF).round() as i8);
+            }
+        }
+        alters.extend(get_item_alters(&item));
+        heads.sort();
+        heads.dedup();
+        for level in heads {
+            notes.push(get_level_note(level, middle, &key_alters, alters.get(&level).copied()));
+        }
+    }
+    notes.sort();
+    notes.dedup();
+    notes
+}
+
+// Keyboard with its upper left corner at x, y and the notes highlighted
+pub fn keyboard2graphic(diagram: &KeyboardDiagram, notes: &[u8], x: f32, y: f32, render_options: &RenderOptions) -> Vec<GraphicItem> {
+    let mut v = Vec::new();
+    let mut labels = Vec::new();
+    let (lowest, highest) = diagram.get_range();
+    let color = ncolor_to_color(&diagram.color);
+    let fill = |note: u8, unlit: Color| match notes.contains(&note) {
+        true => Fillstyle(color.clone()),
+        false => Fillstyle(unlit),
+    };
+
+    // white keys first, the black keys are drawn over their edges
+    let mut white_x = x;
+    let mut black_keys = Vec::new();
+    for note in lowest..=highest {
+        if is_black_key(note) {
+            black_keys.push((note, white_x - KEYBOARD_BLACK_WIDTH * 0.5));
+            continue;
+        }
+        v.push(Rect(white_x, y, KEYBOARD_WHITE_WIDTH, KEYBOARD_WHITE_HEIGHT, Strokestyle(KEYBOARD_LINE_WIDTH, Black), fill(note, White)));
+        labels.push((note, white_x + KEYBOARD_WHITE_WIDTH * 0.5));
+        white_x += KEYBOARD_WHITE_WIDTH;
+    }
+    for (note, black_x) in black_keys.iter() {
+        v.push(Rect(*black_x, y, KEYBOARD_BLACK_WIDTH, KEYBOARD_BLACK_HEIGHT, Strokestyle(KEYBOARD_LINE_WIDTH, Black), fill(*note, Black)));
+        labels.push((*note, black_x + KEYBOARD_BLACK_WIDTH * 0.5));
+    }
+
+    if diagram.labels {
+        let size = render_options.get_text_size(TextRole::Tab);
+        let baseline = y + KEYBOARD_WHITE_HEIGHT + KEYBOARD_LABEL_DISTANCE + KEYBOARD_LABEL_HEIGHT * size;
+        for (note, center_x) in labels.into_iter().filter(|(note, _)| notes.contains(note)) {
+            let name = get_key_name(note, diagram.flats);
+            let width = render_options.get_text_width(TextRole::Tab, name) * size;
+            v.push(render_options.get_text_item(TextRole::Tab, name, center_x - width * 0.5, baseline));
+        }
+    }
+    v
+}
+
+pub fn output_keyboards(matrix: &RMatrix, render_options: &RenderOptions) -> GraphicItems {
+    let mut graphic_items = GraphicItems::new();
+    for diagram in render_options.keyboards.iter() {
+        let ((staff_top, staff_bottom), col_x) = match (get_staff_extent(matrix, diagram.rowidx, render_options), matrix.cols.get(diagram.colidx)) {
+            (Some(extent), Some(col)) => (extent, col.borrow().x),
+            _ => continue,
+        };
+        let notes = match &diagram.keys {
+            KeyboardKeys::Notes(notes) => notes.clone(),
+            KeyboardKeys::Chord(chord) => get_chord_notes(chord),
+            KeyboardKeys::Column => get_column_notes(matrix, diagram.colidx),
+        };
+        let (x, y) = match diagram.placement {
+            KeyboardPlacement::Below => (col_x, staff_bottom + KEYBOARD_DISTANCE),
+            KeyboardPlacement::After => (matrix.width + KEYBOARD_DISTANCE, (staff_top + staff_bottom - KEYBOARD_WHITE_HEIGHT) * 0.5),
+        };
+        graphic_items.extend(GraphicItems(keyboard2graphic(diagram, &notes, x, y, render_options)));
+    }
+    graphic_items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keyboard_notes_and_names() {
+        assert_eq!(get_chord_notes(&ChordSymbol::parse("C").unwrap()), vec![60, 64, 67]);
+        assert_eq!(get_chord_notes(&ChordSymbol::parse("Am7").unwrap()), vec![69, 72, 76, 79]);
+        assert_eq!(get_chord_notes(&ChordSymbol::parse("C/G").unwrap()), vec![55, 60, 64, 67]);
+        assert_eq!(get_key_name(70, true), "Bb");
+        // F sharp on the top line of the G clef in G major, E flat below the F clef
+        assert_eq!(get_level_note(-4, 34, &[0, 0, 0, 1, 0, 0, 0], None), 78);
+        assert_eq!(get_level_note(6, 22, &[0; 7], Some(-1)), 39);
+        assert_eq!(KeyboardDiagram::new(0, 0, KeyboardKeys::Column).with_range(61, 70).get_range(), (60, 71));
+    }
+
+    #[test]
+    fn accidentals_carry_to_the_barline() {
+        use crate::render::output::elements::testutils::*;
+        // A sharp, A, A natural, A | A on the second space of the G clef
+        let matrix = get_test_matrix("|clef G | #1 1 n1 1 | 1");
+        let cols = get_test_note_cols(&matrix, 0);
+        let notes: Vec<Vec<u8>> = cols.iter().map(|colidx| get_column_notes(&matrix, *colidx)).collect();
+        assert_eq!(notes, vec![vec![70], vec![70], vec![69], vec![69], vec![69]]);
+
+        // only the level of the accidental is affected
+        let matrix = get_test_matrix("|clef G | #1 2 -6");
+        let notes: Vec<Vec<u8>> = get_test_note_cols(&matrix, 0).iter().map(|colidx| get_column_notes(&matrix, *colidx)).collect();
+        assert_eq!(notes, vec![vec![70], vec![67], vec![81]]);
+    }
+
+    #[test]
+    fn keyboard_below_the_column() {
+        use crate::render::output::elements::testutils::*;
+        let matrix = get_test_matrix("|clef G | #1 1");
+        let cols = get_test_note_cols(&matrix, 0);
+        let mut render_options = RenderOptions::default();
+        render_options.keyboards.push(KeyboardDiagram::new(0, cols[1], KeyboardKeys::Column).with_labels(false));
+        let (_, staff_bottom) = get_staff_extent(&matrix, 0, &render_options).unwrap();
+        let graphic_items = output_keyboards(&matrix, &render_options);
+        let (x1, y1, _, _) = get_items_extent(&graphic_items).unwrap();
+        assert!((x1 - get_test_col_x(&matrix, cols[1])).abs() < 0.01);
+        assert!(y1 > staff_bottom);
+
+        // one label, for the carried A sharp
+        let (lowest, highest) = render_options.keyboards[0].get_range();
+        let keys = (highest - lowest + 1) as usize;
+        assert_eq!(graphic_items.0.len(), keys + 1);
+        let (label_x1, _, label_x2, _) = get_items_extent(&GraphicItems(vec![graphic_items.0[keys].clone()])).unwrap();
+        let black_keys: Vec<(f32, f32)> = graphic_items.0[..keys]
+            .iter()
+            .filter_map(|item| match item {
+                Rect(x, _, w, h, _, _) if *h == KEYBOARD_BLACK_HEIGHT => Some((*x, x + w)),
+                _ => None,
+            })
+            .collect();
+        let (key_x1, key_x2) = black_keys[(lowest..70).filter(|note| is_black_key(*note)).count()];
+        let label_center = (label_x1 + label_x2) * 0.5;
+        assert!(label_center > key_x1 && label_center < key_x2);
+    }
+}
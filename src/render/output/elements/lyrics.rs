@@ -0,0 +1,253 @@
+use crate::prelude::*;
+use graphics::prelude::*;
+use notation_rs::prelude::*;
+use std::cell::Ref;
+
+// Distance from the lowest note of a row to the top of the first verse
+const LYRICS_DISTANCE: f32 = SPACE * 1.5;
+const LYRICS_VERSE_DISTANCE: f32 = SPACE * 2.6;
+// Height of capitals and of the hyphen above the baseline in em units
+const LYRICS_CAP_HEIGHT: f32 = 700.0;
+const LYRICS_HYPHEN_HEIGHT: f32 = 260.0;
+const LYRICS_HYPHEN_LENGTH: f32 = SPACE * 0.7;
+// Longer gaps between syllables get more than one hyphen
+const LYRICS_HYPHEN_SPACING: f32 = SPACE * 6.0;
+const LYRICS_GAP: f32 = SPACE * 0.3;
+const LYRICS_LINE_WIDTH: f32 = 3.0;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LyricSyllable {
+    pub colidx: usize,
+    pub text: String,
+    // Hyphen to the next syllable of the word
+    pub hyphen: bool,
+    // Melisma extender line to the given column
+    pub extend_to: Option<usize>,
+}
+
+impl LyricSyllable {
+    pub fn new(colidx: usize, text: &str) -> Self {
+        Self {
+            colidx,
+            text: text.to_string(),
+            hyphen: false,
+            extend_to: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LyricVerse {
+    // Row of the notes the verse is sung to
+    pub rowidx: usize,
+    // Verse number such as "1." in front of the first syllable
+    pub number: Option<String>,
+    // Refrains are set in italics
+    pub italic: bool,
+    pub syllables: Vec<LyricSyllable>,
+}
+
+impl LyricVerse {
+    pub fn new(rowidx: usize, syllables: Vec<LyricSyllable>) -> Self {
+        Self {
+            rowidx,
+            number: None,
+            italic: false,
+            syllables,
+        }
+    }
+
+    pub fn with_number(mut self, number: &str) -> Self {
+        self.number = Some(number.to_string());
+        self
+    }
+
+    pub fn italic(mut self) -> Self {
+        self.italic = true;
+        self
+    }
+
+    // Syllables sung to successive note columns, such as "A -- ma -- zing grace __ _ how sweet".
+    // "--" joins syllables with a hyphen, "__" extends the previous syllable over the notes skipped with "_".
+    pub fn parse(rowidx: usize, note_cols: &[usize], text: &str) -> Option<Self> {
+        let mut syllables: Vec<LyricSyllable> = Vec::new();
+        let mut extending = false;
+        let mut cols = note_cols.iter();
+        for token in text.split_whitespace() {
+            match token {
+                "--" => syllables.last_mut()?.hyphen = true,
+                "__" => extending = true,
+                "_" => {
+                    let colidx = *cols.next()?;
+                    if extending {
+                        syllables.last_mut()?.extend_to = Some(colidx);
+                    }
+                }
+                _ => {
+                    extending = false;
+                    let (text, hyphen) = match token.strip_suffix("--") {
+                        Some(text) => (text, true),
+                        None => (token, false),
+                    };
+                    let mut syllable = LyricSyllable::new(*cols.next()?, text);
+                    syllable.hyphen = hyphen;
+                    syllables.push(syllable);
+                }
+            }
+        }
+        Some(Self::new(rowidx, syllables))
+    }
+
+    fn get_role(&self) -> TextRole {
+        match self.italic {
+            true => TextRole::Refrain,
+            false => TextRole::Lyrics,
+        }
+    }
+}
+
+// Columns of the row that hold notes, skipping pauses and empty columns
+pub fn get_note_columns(matrix: &RMatrix, rowidx: usize) -> Vec<usize> {
+    (0..matrix.cols.len())
+        .filter(|colidx| match get_row_item(matrix, rowidx, *colidx) {
+            Some(item) => get_beam_data(&item.borrow().notedata.beamdata1).is_some(),
+            None => false,
+        })
+        .collect()
+}
+
+pub fn output_lyrics(matrix: &RMatrix, render_options: &RenderOptions) -> GraphicItems {
+    let mut graphic_items = GraphicItems::new();
+
+    // all verses of a row hang from a common line below its lowest note
+    let mut row_bottoms: Vec<Option<f32>> = vec![None; matrix.rows.len()];
+    for verse in render_options.lyrics.iter() {
+        let staff_bottom = match get_staff_extent(matrix, verse.rowidx, render_options) {
+            Some((_, staff_bottom)) => staff_bottom,
+            None => continue,
+        };
+        for syllable in verse.syllables.iter() {
+            if let Some(item) = get_row_item(matrix, verse.rowidx, syllable.colidx) {
+                let bottom = get_note_bottom_y(&item.borrow(), staff_bottom);
+                let row_bottom = &mut row_bottoms[verse.rowidx];
+                *row_bottom = Some(row_bottom.map_or(bottom, |row_bottom| row_bottom.max(bottom)));
+            }
+        }
+    }
+
+    let mut verse_counts: Vec<usize> = vec![0; matrix.rows.len()];
+    for verse in render_options.lyrics.iter() {
+        let row_bottom = match row_bottoms.get(verse.rowidx) {
+            Some(Some(row_bottom)) => *row_bottom,
+            _ => continue,
+        };
+        let role = verse.get_role();
+        let size = render_options.get_text_size(role);
+        let baseline = row_bottom + LYRICS_DISTANCE + LYRICS_CAP_HEIGHT * size + verse_counts[verse.rowidx] as f32 * LYRICS_VERSE_DISTANCE;
+        verse_counts[verse.rowidx] += 1;
+
+        // syllables are centered under the notehead, melisma syllables start at it
+        let mut spans: Vec<(f32, f32)> = Vec::new();
+        for syllable in verse.syllables.iter() {
+            let item = get_row_item(matrix, verse.rowidx, syllable.colidx);
+            if item.is_none() {
+                spans.push((0.0, 0.0));
+                continue;
+            }
+            let item: Ref<RItem> = item.as_ref().unwrap().borrow();
+            let width = render_options.get_text_width(role, &syllable.text) * size;
+            let x = match syllable.extend_to {
+                Some(_) => item.coord_x.unwrap(),
+                None => item.coord_x.unwrap() + (HEAD_WIDTH_BLACK - width) * 0.5,
+            };
+            graphic_items.push(render_options.get_text_item(role, &syllable.text, x, baseline));
+            spans.push((x, x + width));
+        }
+
+        if let (Some(number), Some((first_x, _))) = (&verse.number, spans.first()) {
+            let width = render_options.get_text_width(role, number) * size;
+            graphic_items.push(render_options.get_text_item(role, number, first_x - LYRICS_GAP * 2.0 - width, baseline));
+        }
+
+        for (idx, syllable) in verse.syllables.iter().enumerate() {
+            let (_, end_x) = spans[idx];
+            if syllable.hyphen {
+                let next_x = match spans.get(idx + 1) {
+                    Some((next_x, _)) => *next_x,
+                    None => end_x + LYRICS_HYPHEN_LENGTH + LYRICS_GAP * 2.0,
+                };
+                let gap = next_x - end_x;
+                // hyphens are spread evenly over the gap and shortened when it is narrow
+                let count = (gap / LYRICS_HYPHEN_SPACING).ceil().max(1.0);
+                let length = LYRICS_HYPHEN_LENGTH.min(gap * 0.6);
+                let y = baseline - LYRICS_HYPHEN_HEIGHT * size;
+                if length > 0.0 {
+                    for n in 0..count as usize {
+                        let center_x = end_x + gap * (n as f32 + 0.5) / count;
+                        graphic_items.push(Line(center_x - length * 0.5, y, center_x + length * 0.5, y, Strokestyle(LYRICS_LINE_WIDTH, Black)));
+                    }
+                }
+            }
+            if let Some(to_colidx) = syllable.extend_to {
+                let x2 = match matrix.cols.get(to_colidx) {
+                    Some(col) => col.borrow().x + HEAD_WIDTH_BLACK,
+                    None => matrix.width,
+                };
+                let x1 = end_x + LYRICS_GAP;
+                if x2 > x1 {
+                    graphic_items.push(Line(x1, baseline, x2, baseline, Strokestyle(LYRICS_LINE_WIDTH, Black)));
+                }
+            }
+        }
+    }
+    graphic_items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_verse_syllables() {
+        let verse = LyricVerse::parse(0, &[1, 2, 4, 5, 6, 8], "A -- ma-- zing grace __ _ how").unwrap();
+        assert_eq!(verse.syllables.len(), 5);
+        assert!(verse.syllables[0].hyphen && verse.syllables[1].hyphen && !verse.syllables[2].hyphen);
+        assert_eq!(verse.syllables[3], LyricSyllable { colidx: 5, text: "grace".to_string(), hyphen: false, extend_to: Some(6) });
+        assert_eq!(verse.syllables[4].colidx, 8);
+        assert!(LyricVerse::parse(0, &[1], "too many").is_none());
+    }
+
+    #[test]
+    fn verses_below_the_notes() {
+        use crate::render::output::elements::testutils::*;
+        // whole notes leave room for the hyphen between the syllables
+        let matrix = get_test_matrix("|clef G | nv1 0 1 2");
+        let cols = get_note_columns(&matrix, 0);
+        assert_eq!(cols.len(), 3);
+        let mut render_options = RenderOptions::default();
+        render_options.lyrics.push(LyricVerse::parse(0, &cols, "Hå -- kan är").unwrap());
+        let (_, staff_bottom) = get_staff_extent(&matrix, 0, &render_options).unwrap();
+        let first_verse = output_lyrics(&matrix, &render_options);
+        let (_, y1, _, first_bottom) = get_items_extent(&first_verse).unwrap();
+        assert!(y1 > staff_bottom);
+
+        // the hyphen sits between the syllables of the word
+        let hyphens: Vec<(f32, f32)> = first_verse
+            .0
+            .iter()
+            .filter_map(|item| match item {
+                Line(x1, _, x2, _, _) => Some((*x1, *x2)),
+                _ => None,
+            })
+            .collect();
+        assert!(!hyphens.is_empty());
+        let first_x2 = get_items_extent(&GraphicItems(vec![first_verse.0[0].clone()])).unwrap().2;
+        let second_x1 = get_items_extent(&GraphicItems(vec![first_verse.0[1].clone()])).unwrap().0;
+        assert!(hyphens.iter().all(|(x1, x2)| *x1 > first_x2 && *x2 < second_x1));
+
+        // a second verse goes below the first
+        render_options.lyrics.push(LyricVerse::parse(0, &cols, "Ö -- ja").unwrap());
+        let (_, _, _, both_bottom) = get_items_extent(&output_lyrics(&matrix, &render_options)).unwrap();
+        assert!(both_bottom > first_bottom + SPACE);
+    }
+}
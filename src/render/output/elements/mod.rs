@@ -1,2 +1,22 @@
+pub mod ackolades;
+pub mod analysis;
 pub mod beams;
+pub mod chorddiagrams;
+pub mod chords;
 pub mod elements;
+pub mod figuredbass;
+pub mod functions;
+pub mod intervals;
+pub mod keyboards;
+pub mod lyrics;
+pub mod ornaments;
+pub mod romannumerals;
+pub mod scaledegrees;
+pub mod smallnotes;
+pub mod spanners;
+pub mod staves;
+pub mod tablature;
+#[cfg(test)]
+pub mod testutils;
+pub mod tremolos;
+pub mod tuplets;
@@ -0,0 +1,171 @@
+use crate::prelude::*;
+use graphics::prelude::*;
+use notation_rs::prelude::*;
+use std::cell::Ref;
+
+const ORNAMENT_STROKE_WIDTH: f32 = 4.0;
+const ORNAMENT_DISTANCE: f32 = SPACE;
+const WAVY_LINE_PERIOD: f32 = SPACE;
+const WAVY_LINE_AMPLITUDE: f32 = SPACE_QUARTER;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OrnamentType {
+    Trill,
+    // Trill followed by a wavy line to the given column, or to the end of the system if None
+    TrillLine(Option<usize>),
+    UpperMordent,
+    LowerMordent,
+    Turn,
+    InvertedTurn,
+}
+
+#[derive(Debug, Clone)]
+pub struct OrnamentMark {
+    pub rowidx: usize,
+    pub colidx: usize,
+    pub ornament: OrnamentType,
+}
+
+impl OrnamentMark {
+    pub fn new(rowidx: usize, colidx: usize, ornament: OrnamentType) -> Self {
+        Self { rowidx, colidx, ornament }
+    }
+}
+
+pub fn output_ornaments(matrix: &RMatrix, render_options: &RenderOptions) -> GraphicItems {
+    let mut graphic_items = GraphicItems::new();
+
+    let trill_width = render_options.get_text_width(TextRole::Marking, "tr") * render_options.get_text_size(TextRole::Marking);
+    for ornament in &render_options.ornaments {
+        let item = get_row_item(matrix, ornament.rowidx, ornament.colidx);
+        let (item, (staff_top, _)) = match (item, get_staff_extent(matrix, ornament.rowidx, render_options)) {
+            (Some(item), Some(extent)) => (item, extent),
+            _ => continue,
+        };
+        let item: Ref<RItem> = item.borrow();
+
+        // ornaments are centered over the notehead
+        let x = item.coord_x.unwrap() + HEAD_WIDTH_BLACK / 2.0;
+        let y = get_note_top_y(&item, staff_top) - ORNAMENT_DISTANCE;
+
+        match ornament.ornament {
+            OrnamentType::Trill => graphic_items.push(render_options.get_text_item(TextRole::Marking, "tr", x - trill_width / 2.0, y)),
+            OrnamentType::TrillLine(to_colidx) => {
+                let x_tr = x - trill_width / 2.0;
+                graphic_items.push(render_options.get_text_item(TextRole::Marking, "tr", x_tr, y));
+                let x2 = match to_colidx {
+                    Some(to_colidx) => match matrix.cols.get(to_colidx) {
+                        Some(col) => col.borrow().x + HEAD_WIDTH_BLACK,
+                        None => matrix.width,
+                    },
+                    None => matrix.width,
+                };
+                graphic_items.push(Path(
+                    PathSegments(wavy_line_segments(x_tr + trill_width + SPACE_QUARTER, y - SPACE_HALF, x2, WAVY_LINE_PERIOD, WAVY_LINE_AMPLITUDE)),
+                    Strokestyle(ORNAMENT_STROKE_WIDTH, Black),
+                    NoFill,
+                    PathCacheInfo::NoCache,
+                ));
+            }
+            OrnamentType::UpperMordent | OrnamentType::LowerMordent => {
+                let mut p = vec![
+                    M(x - SPACE, y - SPACE * 0.2),
+                    L(x - SPACE_HALF, y - SPACE * 0.8),
+                    L(x, y - SPACE * 0.2),
+                    L(x + SPACE_HALF, y - SPACE * 0.8),
+                    L(x + SPACE, y - SPACE * 0.2),
+                ];
+                if ornament.ornament == OrnamentType::LowerMordent {
+                    p.extend(vec![M(x, y + SPACE * 0.1), L(x, y - SPACE * 1.1)]);
+                }
+                graphic_items.push(Path(PathSegments(p), Strokestyle(ORNAMENT_STROKE_WIDTH, Black), NoFill, PathCacheInfo::NoCache));
+            }
+            OrnamentType::Turn | OrnamentType::InvertedTurn => {
+                let y_sign = if ornament.ornament == OrnamentType::Turn { 1.0 } else { -1.0 };
+                let p = PathSegments(vec![
+                    M(-SPACE * 0.6, SPACE * 0.1),
+                    C(-SPACE * 1.1, SPACE * 0.1, -SPACE * 1.1, -SPACE * 0.5, -SPACE * 0.6, -SPACE * 0.5),
+                    C(-SPACE * 0.2, -SPACE * 0.5, SPACE * 0.2, SPACE * 0.5, SPACE * 0.6, SPACE * 0.5),
+                    C(SPACE * 1.1, SPACE * 0.5, SPACE * 1.1, -SPACE * 0.1, SPACE * 0.6, -SPACE * 0.1),
+                ])
+                .scale_path(1.0, y_sign)
+                .move_path(x, y - SPACE_HALF);
+                graphic_items.push(Path(p, Strokestyle(ORNAMENT_STROKE_WIDTH, Black), NoFill, PathCacheInfo::NoCache));
+            }
+        }
+    }
+
+    graphic_items
+}
+
+// Topmost y of a note including an upwards stem, never lower than the top staff line
+pub fn get_note_top_y(item: &RItem, staff_top: f32) -> f32 {
+    let coords = NPoint(item.coord_x.unwrap(), item.coord_y.unwrap());
+    let note_top = match get_beam_data(&item.notedata.beamdata1) {
+        Some(data) => match data.direction {
+            DirUD::Up => get_stem_tip_y(data, coords),
+            DirUD::Down => coords.1 + data.top_level as f32 * SPACE_HALF - SPACE_HALF,
+        },
+        None => staff_top,
+    };
+    note_top.min(staff_top)
+}
+
+// Lowest y of a note including a downwards stem, never higher than the bottom staff line
+pub fn get_note_bottom_y(item: &RItem, staff_bottom: f32) -> f32 {
+    let coords = NPoint(item.coord_x.unwrap(), item.coord_y.unwrap());
+    let note_bottom = match get_beam_data(&item.notedata.beamdata1) {
+        Some(data) => match data.direction {
+            DirUD::Up => coords.1 + data.bottom_level as f32 * SPACE_HALF + SPACE_HALF,
+            DirUD::Down => get_stem_tip_y(data, coords),
+        },
+        None => staff_bottom,
+    };
+    note_bottom.max(staff_bottom)
+}
+
+pub fn wavy_line_segments(x1: f32, y: f32, x2: f32, period: f32, amplitude: f32) -> Vec<PathSegment> {
+    let mut segments = vec![M(x1, y)];
+    let mut x = x1;
+    while x + period <= x2 {
+        segments.push(Q(x + period * 0.25, y - amplitude * 2.0, x + period * 0.5, y));
+        segments.push(Q(x + period * 0.75, y + amplitude * 2.0, x + period, y));
+        x += period;
+    }
+    segments
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wavy_line_periods() {
+        // one crest and one trough per period, stopping before an incomplete period
+        let segments = wavy_line_segments(0.0, 50.0, 110.0, 20.0, 5.0);
+        assert_eq!(segments.len(), 1 + 5 * 2);
+        assert!(matches!(segments[0], M(x, y) if x == 0.0 && y == 50.0));
+        assert!(matches!(segments[1], Q(_, cy, x, y) if cy == 40.0 && x == 10.0 && y == 50.0));
+        assert!(matches!(segments[10], Q(_, cy, x, _) if cy == 60.0 && x == 100.0));
+    }
+
+    #[test]
+    fn ornaments_above_the_staff() {
+        use crate::render::output::elements::testutils::*;
+        let matrix = get_test_matrix("|clef G | 2 3 4 5");
+        let cols = get_test_note_cols(&matrix, 0);
+        let mut render_options = RenderOptions::default();
+        render_options.ornaments.push(OrnamentMark::new(0, cols[0], OrnamentType::UpperMordent));
+        let (staff_top, _) = get_staff_extent(&matrix, 0, &render_options).unwrap();
+        let (x1, _, x2, y2) = get_items_extent(&output_ornaments(&matrix, &render_options)).unwrap();
+        let center_x = get_test_col_x(&matrix, cols[0]) + HEAD_WIDTH_BLACK / 2.0;
+        assert!(((x1 + x2) / 2.0 - center_x).abs() < 0.01);
+        assert!(y2 < staff_top);
+
+        // the wavy line of a trill runs to the head of its last column
+        render_options.ornaments = vec![OrnamentMark::new(0, cols[0], OrnamentType::TrillLine(Some(cols[3])))];
+        let (_, _, x2, _) = get_items_extent(&output_ornaments(&matrix, &render_options)).unwrap();
+        assert!(x2 <= get_test_col_x(&matrix, cols[3]) + HEAD_WIDTH_BLACK);
+        assert!(x2 > get_test_col_x(&matrix, cols[2]));
+    }
+}
@@ -0,0 +1,325 @@
+use crate::prelude::*;
+use crate::render::output::elements::elements::take;
+use crate::render::fonts::metrics::get_path_advance;
+use graphics::prelude::*;
+use notation_rs::prelude::*;
+
+// Height of capitals in em units
+const ROMAN_CAP_HEIGHT: f32 = 560.0;
+const ROMAN_GAP: f32 = SPACE * 0.2;
+// Distance of the new key reading below a pivot chord
+const ROMAN_PIVOT_DISTANCE: f32 = SPACE * 3.5;
+const ROMAN_BRACKET_WIDTH: f32 = 3.0;
+const ROMAN_BRACKET_HOOK: f32 = SPACE_HALF;
+const ROMAN_BRACKET_PADDING: f32 = SPACE_HALF;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RomanQuality {
+    Major,
+    Minor,
+    Diminished,
+    HalfDiminished,
+    Augmented,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum RomanStyle {
+    // Case shows the quality, applied chords as V/V
+    #[default]
+    AngloAmerican,
+    // Stufentheorie: upper case numerals, applied chords in parentheses followed by the target in brackets
+    German,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RomanNumeral {
+    // Scale degree 1 to 7
+    pub degree: u8,
+    // Chromatic root, as in bII and bVI
+    pub accidental: ChordAccidental,
+    pub quality: RomanQuality,
+    // Inversion figures from the top, such as 6 4 or 4 3
+    pub figures: Vec<u8>,
+    // Chord the numeral is applied to, as in V/V
+    pub applied_to: Option<Box<RomanNumeral>>,
+}
+
+impl RomanNumeral {
+    pub fn new(degree: u8, quality: RomanQuality) -> Self {
+        Self {
+            degree,
+            accidental: ChordAccidental::Natural,
+            quality,
+            figures: vec![],
+            applied_to: None,
+        }
+    }
+
+    pub fn with_figures(mut self, figures: &[u8]) -> Self {
+        self.figures = figures.to_vec();
+        self
+    }
+
+    pub fn applied_to(mut self, target: RomanNumeral) -> Self {
+        self.applied_to = Some(Box::new(target));
+        self
+    }
+
+    // Numeral such as "I", "ii65", "vii°7", "viiø43", "bVI", "III+" or "V7/V"
+    pub fn parse(text: &str) -> Option<Self> {
+        let (numeral, target) = match text.split_once('/') {
+            Some((numeral, target)) => (numeral, Some(target)),
+            None => (text, None),
+        };
+        let mut rest = numeral.trim();
+
+        let accidental = if take(&mut rest, "b") || take(&mut rest, "♭") {
+            ChordAccidental::Flat
+        } else if take(&mut rest, "#") || take(&mut rest, "♯") {
+            ChordAccidental::Sharp
+        } else {
+            ChordAccidental::Natural
+        };
+
+        // longer numerals first, so that VII is not read as V
+        const NUMERALS: [(&str, u8); 7] = [("VII", 7), ("III", 3), ("IV", 4), ("VI", 6), ("II", 2), ("V", 5), ("I", 1)];
+        let (degree, lower_case) = NUMERALS.iter().find_map(|(numeral, degree)| {
+            if take(&mut rest, numeral) {
+                Some((*degree, false))
+            } else if take(&mut rest, &numeral.to_lowercase()) {
+                Some((*degree, true))
+            } else {
+                None
+            }
+        })?;
+
+        let quality = if take(&mut rest, "°") || take(&mut rest, "o") {
+            RomanQuality::Diminished
+        } else if take(&mut rest, "ø") || take(&mut rest, "%") {
+            RomanQuality::HalfDiminished
+        } else if take(&mut rest, "+") {
+            RomanQuality::Augmented
+        } else if lower_case {
+            RomanQuality::Minor
+        } else {
+            RomanQuality::Major
+        };
+
+        let mut numeral = Self::new(degree, quality);
+        numeral.accidental = accidental;
+        for c in rest.chars() {
+            numeral.figures.push(c.to_digit(10)? as u8);
+        }
+        if let Some(target) = target {
+            numeral.applied_to = Some(Box::new(Self::parse(target)?));
+        }
+        Some(numeral)
+    }
+
+    fn is_lower_case(&self) -> bool {
+        matches!(self.quality, RomanQuality::Minor | RomanQuality::Diminished | RomanQuality::HalfDiminished)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RomanAnalysis {
+    // Analysis row, usually an otherwise empty row below the staff
+    pub rowidx: usize,
+    pub colidx: usize,
+    pub numeral: RomanNumeral,
+    // Key written in front of the numeral, such as "C:" or "f#:"
+    pub key: Option<String>,
+    // Reading of a pivot chord in the new key, drawn below the numeral with both in a bracket
+    pub pivot: Option<RomanNumeral>,
+    pub pivot_key: Option<String>,
+}
+
+impl RomanAnalysis {
+    pub fn new(rowidx: usize, colidx: usize, numeral: RomanNumeral) -> Self {
+        Self {
+            rowidx,
+            colidx,
+            numeral,
+            key: None,
+            pivot: None,
+            pivot_key: None,
+        }
+    }
+
+    pub fn with_key(mut self, key: &str) -> Self {
+        self.key = Some(key.to_string());
+        self
+    }
+
+    pub fn with_pivot(mut self, pivot_key: &str, pivot: RomanNumeral) -> Self {
+        self.pivot_key = Some(pivot_key.to_string());
+        self.pivot = Some(pivot);
+        self
+    }
+}
+
+fn do_roman_text(text: &str, x: f32, baseline: f32, size: f32, v: &mut Vec<GraphicItem>, render_options: &RenderOptions) -> f32 {
+    v.push(Path(
+        render_options.get_text_path(TextRole::Function, text).scale_path(size, size).move_path(x, baseline),
+        render_options.fonts.get_stroke(TextRole::Function),
+        Fillstyle(Black),
+        PathCacheInfo::NoCache,
+    ));
+    render_options.get_text_width(TextRole::Function, text) * size
+}
+
+// Numeral with accidental, quality sign, inversion figures and applied target from x; returns the end x
+fn do_numeral(numeral: &RomanNumeral, x: f32, baseline: f32, style: RomanStyle, v: &mut Vec<GraphicItem>, render_options: &RenderOptions) -> f32 {
+    let size = render_options.get_text_size(TextRole::Function);
+    let figure_size = size * FUNCTION_FONT_FIGURE_SCALE / FUNCTION_FONT_SCALE;
+    let mut x = x;
+
+    let applied_german = style == RomanStyle::German && numeral.applied_to.is_some();
+    if applied_german {
+        x += do_roman_text("(", x, baseline, size, v, render_options);
+    }
+
+    let (glyph, scale, raise) = match numeral.accidental {
+        ChordAccidental::Flat => (Some(CADENZA_ACCIDENTAL_FLAT), size, SPACE * 1.3),
+        ChordAccidental::Sharp => (Some(CADENZA_ACCIDENTAL_SHARP), size * 0.9, SPACE * 1.6),
+        ChordAccidental::Natural => (None, size, 0.0),
+    };
+    if let Some(glyph) = glyph {
+        let acc = PathSegments(glyph.to_vec()).scale_path(scale, -scale).move_path(x, baseline - raise);
+        v.push(Path(acc, NoStroke, Fillstyle(Black), PathCacheInfo::NoCache));
+        x += get_path_advance(glyph) * scale;
+    }
+
+    let label = ROMAN_NUMERALS[(numeral.degree.clamp(1, 7) - 1) as usize];
+    let label = match style {
+        RomanStyle::AngloAmerican if numeral.is_lower_case() => label.to_lowercase(),
+        _ => label.to_string(),
+    };
+    x += do_roman_text(&label, x, baseline, size, v, render_options);
+
+    // quality sign and the first figure are superscripts, a second figure is a subscript
+    let superscript = baseline - ROMAN_CAP_HEIGHT * size * 0.45;
+    let sign = match numeral.quality {
+        RomanQuality::Diminished => Some("°"),
+        RomanQuality::HalfDiminished => Some("ø"),
+        RomanQuality::Augmented => Some("+"),
+        _ => None,
+    };
+    let mut sign_width = 0.0;
+    if let Some(sign) = sign {
+        sign_width = do_roman_text(sign, x, superscript, figure_size, v, render_options);
+    }
+    if !numeral.figures.is_empty() {
+        let figures: Vec<BassFigure> = numeral.figures.iter().map(|figure| BassFigure::new(*figure)).collect();
+        let (items, ends) = do_figure_stack(&figures, x + sign_width, superscript, TextRole::Function, figure_size, render_options);
+        v.extend(items);
+        x = ends.iter().fold(x, |x, end| x.max(*end));
+    } else {
+        x += sign_width;
+    }
+
+    if let Some(target) = &numeral.applied_to {
+        match style {
+            RomanStyle::German => {
+                x += do_roman_text(")", x + ROMAN_GAP, baseline, size, v, render_options) + ROMAN_GAP;
+                // the target follows in brackets
+                x += do_roman_text("[", x, baseline, size, v, render_options);
+                x = do_numeral(target, x, baseline, style, v, render_options);
+                x += do_roman_text("]", x, baseline, size, v, render_options);
+            }
+            RomanStyle::AngloAmerican => {
+                x += do_roman_text("/", x + ROMAN_GAP, baseline, size, v, render_options) + ROMAN_GAP * 2.0;
+                x = do_numeral(target, x, baseline, style, v, render_options);
+            }
+        }
+    }
+    x
+}
+
+// Key label and numeral from x; returns the end x
+fn do_reading(key: &Option<String>, numeral: &RomanNumeral, x: f32, baseline: f32, style: RomanStyle, v: &mut Vec<GraphicItem>, render_options: &RenderOptions) -> f32 {
+    let size = render_options.get_text_size(TextRole::Function);
+    let mut x = x;
+    if let Some(key) = key {
+        x += do_roman_text(key, x, baseline, size, v, render_options) + ROMAN_GAP * 2.0;
+    }
+    do_numeral(numeral, x, baseline, style, v, render_options)
+}
+
+pub fn output_roman_numerals(matrix: &RMatrix, render_options: &RenderOptions) -> GraphicItems {
+    let mut graphic_items = GraphicItems::new();
+    let size = render_options.get_text_size(TextRole::Function);
+    let cap_height = ROMAN_CAP_HEIGHT * size;
+    let style = render_options.roman_style;
+
+    for analysis in render_options.roman_numerals.iter() {
+        let (row, col) = match (matrix.rows.get(analysis.rowidx), matrix.cols.get(analysis.colidx)) {
+            (Some(row), Some(col)) => (row.borrow(), col.borrow()),
+            _ => continue,
+        };
+        // numerals are centered on the row
+        let x = col.x;
+        let baseline = row.y + cap_height / 2.0;
+
+        let mut v = Vec::new();
+        let end_x = do_reading(&analysis.key, &analysis.numeral, x, baseline, style, &mut v, render_options);
+
+        if let Some(pivot) = &analysis.pivot {
+            let pivot_baseline = baseline + ROMAN_PIVOT_DISTANCE;
+            let pivot_end_x = do_reading(&analysis.pivot_key, pivot, x, pivot_baseline, style, &mut v, render_options);
+
+            // bracket around both readings of the pivot chord
+            let x1 = x - ROMAN_BRACKET_PADDING;
+            let x2 = end_x.max(pivot_end_x) + ROMAN_BRACKET_PADDING;
+            let y1 = baseline - cap_height - ROMAN_BRACKET_PADDING;
+            let y2 = pivot_baseline + ROMAN_BRACKET_PADDING;
+            for (x, hook) in [(x1, ROMAN_BRACKET_HOOK), (x2, -ROMAN_BRACKET_HOOK)] {
+                v.push(Path(
+                    PathSegments(vec![M(x + hook, y1), L(x, y1), L(x, y2), L(x + hook, y2)]),
+                    Strokestyle(ROMAN_BRACKET_WIDTH, Black),
+                    NoFill,
+                    PathCacheInfo::NoCache,
+                ));
+            }
+        }
+        graphic_items.extend(GraphicItems(v));
+    }
+    graphic_items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_roman_numerals() {
+        let numeral = RomanNumeral::parse("viiø43").unwrap();
+        assert_eq!((numeral.degree, numeral.quality), (7, RomanQuality::HalfDiminished));
+        assert_eq!(numeral.figures, vec![4, 3]);
+        let numeral = RomanNumeral::parse("V7/V").unwrap();
+        assert_eq!(numeral.applied_to, Some(Box::new(RomanNumeral::new(5, RomanQuality::Major))));
+        assert_eq!(RomanNumeral::parse("bVI").unwrap().accidental, ChordAccidental::Flat);
+        assert_eq!(RomanNumeral::parse("iv64").unwrap(), RomanNumeral::new(4, RomanQuality::Minor).with_figures(&[6, 4]));
+        assert!(RomanNumeral::parse("X").is_none());
+    }
+
+    #[test]
+    fn numerals_on_the_analysis_row() {
+        use crate::render::output::elements::testutils::*;
+        let matrix = get_test_matrix("|clef G - | 0 1 / -");
+        let cols = get_test_note_cols(&matrix, 0);
+        let mut render_options = RenderOptions::default();
+        render_options.roman_numerals.push(RomanAnalysis::new(1, cols[1], RomanNumeral::parse("V7").unwrap()));
+        let (x1, y1, _, y2) = get_items_extent(&output_roman_numerals(&matrix, &render_options)).unwrap();
+        let row_y = matrix.rows[1].borrow().y;
+        assert!(y1 < row_y && y2 > row_y);
+        // the numeral starts at the column, give or take the side bearing of its first glyph
+        assert!((x1 - get_test_col_x(&matrix, cols[1])).abs() < SPACE_QUARTER);
+
+        // a pivot chord adds the second reading below and a bracket around both
+        render_options.roman_numerals[0].pivot = RomanNumeral::parse("ii");
+        let (bracket_x1, _, _, pivot_y2) = get_items_extent(&output_roman_numerals(&matrix, &render_options)).unwrap();
+        assert!(bracket_x1 < x1);
+        assert!(pivot_y2 > y2);
+    }
+}
@@ -0,0 +1,195 @@
+use crate::prelude::*;
+use crate::render::fonts::metrics::get_path_bbox;
+use crate::render::fonts::svg_symbols::SVG_SYMBOL_TPL_CIRCLE;
+use graphics::path::PathSegment;
+use graphics::prelude::*;
+use notation_rs::prelude::*;
+
+const DEGREE_CIRCLE_SCALE: f32 = 0.14;
+// Digit height relative to the circle height
+const DEGREE_DIGIT_RATIO: f32 = 0.55;
+const DEGREE_ACCIDENTAL_SCALE: f32 = 0.6;
+const DEGREE_ACCIDENTAL_GAP: f32 = SPACE * 0.15;
+const DEGREE_OCTAVE_DOT_RADIUS: f32 = SPACE * 0.16;
+const DEGREE_OCTAVE_GAP: f32 = SPACE * 0.3;
+const DEGREE_PRIME_LENGTH: f32 = SPACE * 0.6;
+const DEGREE_CARET_HEIGHT: f32 = SPACE * 0.5;
+const DEGREE_STROKE: f32 = 3.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum DegreeStyle {
+    // Digit in a circle
+    #[default]
+    Circle,
+    // Digit under a caret, as in ^1 ^2
+    Caret,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum DegreeOctaveMark {
+    // Dots above for higher octaves, below for lower ones
+    #[default]
+    Dots,
+    // Primes to the upper right for higher octaves, to the lower right for lower ones
+    Primes,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DegreeAccidental {
+    None,
+    Raised,
+    Lowered,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScaleDegree {
+    // 1 to 7, 0 and 8 and 9 are drawn as they are
+    pub figure: u8,
+    // Octaves above (positive) or below (negative) the middle octave
+    pub octave: i8,
+    pub accidental: DegreeAccidental,
+}
+
+impl ScaleDegree {
+    pub fn new(figure: u8, octave: i8, accidental: DegreeAccidental) -> Self {
+        Self { figure, octave, accidental }
+    }
+
+    // Degree of a notation TplSymbol
+    pub fn from_notation(figure_nr: &char, octave: &TplOctave, accidental: &TplAccidental) -> Option<Self> {
+        let figure = figure_nr.to_digit(10)? as u8;
+        let octave = match octave {
+            TplOctave::Lower => -1,
+            TplOctave::Mid => 0,
+            TplOctave::Upper => 1,
+        };
+        let accidental = match accidental {
+            TplAccidental::TAFlat => DegreeAccidental::Lowered,
+            TplAccidental::TANone => DegreeAccidental::None,
+            TplAccidental::TASharp => DegreeAccidental::Raised,
+        };
+        Some(Self::new(figure, octave, accidental))
+    }
+
+    // Degree such as "1", "#4", "b7", "^3", "5'" an octave up or "5," an octave down
+    pub fn parse(text: &str) -> Option<Self> {
+        let mut rest = text.trim().trim_start_matches('^');
+        let accidental = if let Some(stripped) = rest.strip_prefix(['#', '♯', '+']) {
+            rest = stripped;
+            DegreeAccidental::Raised
+        } else if let Some(stripped) = rest.strip_prefix(['b', '♭', '-']) {
+            rest = stripped;
+            DegreeAccidental::Lowered
+        } else {
+            DegreeAccidental::None
+        };
+        let mut chars = rest.chars();
+        let figure = chars.next()?.to_digit(10)? as u8;
+        let mut octave = 0;
+        for c in chars {
+            octave += match c {
+                '\'' | '′' => 1,
+                ',' => -1,
+                _ => return None,
+            };
+        }
+        Some(Self::new(figure, octave, accidental))
+    }
+}
+
+// Glyph scaled and placed with its bbox center at cx, cy; a negative scale_y flips y-up music glyphs
+fn centered_glyph(glyph: &[PathSegment], scale: f32, scale_y: f32, cx: f32, cy: f32) -> PathSegments {
+    match get_path_bbox(glyph) {
+        Some(bbox) => {
+            let x = cx - (bbox.x_min + bbox.x_max) * 0.5 * scale;
+            let y = cy - (bbox.y_min + bbox.y_max) * 0.5 * scale_y;
+            PathSegments(glyph.to_vec()).scale_path(scale, scale_y).move_path(x, y)
+        }
+        None => PathSegments(vec![]),
+    }
+}
+
+// Scale degree centered on cx, cy
+pub fn degree2graphic(degree: &ScaleDegree, cx: f32, cy: f32, render_options: &RenderOptions) -> Vec<GraphicItem> {
+    let mut v = Vec::new();
+    let circle_bbox = get_path_bbox(SVG_SYMBOL_TPL_CIRCLE).expect("circle symbol has outlines");
+    let half_width = circle_bbox.width() * DEGREE_CIRCLE_SCALE * 0.5;
+    let half_height = circle_bbox.height() * DEGREE_CIRCLE_SCALE * 0.5;
+
+    // the digit glyph is scaled to a fixed share of the circle, whatever the font
+    let digit = render_options.get_text_glyph(TextRole::Figure, (b'0' + degree.figure.min(9)) as char);
+    let digit_bbox = get_path_bbox(&digit);
+    let digit_height = half_height * 2.0 * DEGREE_DIGIT_RATIO;
+    let digit_scale = digit_bbox.as_ref().map_or(0.0, |bbox| digit_height / bbox.height());
+    let digit_half_width = digit_bbox.as_ref().map_or(0.0, |bbox| bbox.width() * digit_scale * 0.5);
+    let digit = centered_glyph(&digit, digit_scale, digit_scale, cx, cy);
+
+    // symbol extent around the center, octave marks and accidentals go outside it
+    let (extent_x, extent_y) = match render_options.degree_style {
+        DegreeStyle::Circle => {
+            let circle = PathSegments(SVG_SYMBOL_TPL_CIRCLE.to_vec()).scale_path(DEGREE_CIRCLE_SCALE, DEGREE_CIRCLE_SCALE).move_path(
+                cx - (circle_bbox.x_min + circle_bbox.x_max) * 0.5 * DEGREE_CIRCLE_SCALE,
+                cy - (circle_bbox.y_min + circle_bbox.y_max) * 0.5 * DEGREE_CIRCLE_SCALE,
+            );
+            v.push(Path(circle, NoStroke, Fillstyle(Black), PathCacheInfo::NoCache));
+            (half_width, half_height)
+        }
+        DegreeStyle::Caret => {
+            let caret_y = cy - digit_height * 0.5 - DEGREE_OCTAVE_GAP;
+            v.push(Path(
+                PathSegments(vec![
+                    M(cx - digit_half_width, caret_y),
+                    L(cx, caret_y - DEGREE_CARET_HEIGHT),
+                    L(cx + digit_half_width, caret_y),
+                ]),
+                Strokestyle(DEGREE_STROKE, Black),
+                NoFill,
+                PathCacheInfo::NoCache,
+            ));
+            (digit_half_width, digit_height * 0.5 + DEGREE_OCTAVE_GAP + DEGREE_CARET_HEIGHT)
+        }
+    };
+    v.push(Path(digit, NoStroke, Fillstyle(Black), PathCacheInfo::NoCache));
+
+    let glyph = match degree.accidental {
+        DegreeAccidental::Raised => Some(CADENZA_ACCIDENTAL_SHARP),
+        DegreeAccidental::Lowered => Some(CADENZA_ACCIDENTAL_FLAT),
+        DegreeAccidental::None => None,
+    };
+    if let Some(glyph) = glyph {
+        let width = get_path_bbox(glyph).map_or(0.0, |bbox| bbox.width()) * DEGREE_ACCIDENTAL_SCALE;
+        let acc = centered_glyph(glyph, DEGREE_ACCIDENTAL_SCALE, -DEGREE_ACCIDENTAL_SCALE, cx - extent_x - DEGREE_ACCIDENTAL_GAP - width * 0.5, cy);
+        v.push(Path(acc, NoStroke, Fillstyle(Black), PathCacheInfo::NoCache));
+    }
+
+    let direction = degree.octave.signum() as f32;
+    for idx in 0..degree.octave.unsigned_abs() {
+        let step = idx as f32;
+        match render_options.degree_octaves {
+            DegreeOctaveMark::Dots => {
+                let y = cy + direction * (extent_y + DEGREE_OCTAVE_GAP + DEGREE_OCTAVE_DOT_RADIUS * (1.0 + 2.5 * step));
+                v.push(Path(PathSegments(get_circle_segments(cx, y, DEGREE_OCTAVE_DOT_RADIUS)), NoStroke, Fillstyle(Black), PathCacheInfo::NoCache));
+            }
+            DegreeOctaveMark::Primes => {
+                let x = cx + extent_x + DEGREE_OCTAVE_GAP * (1.0 + step);
+                let y = cy + direction * extent_y;
+                v.push(Line(x + DEGREE_PRIME_LENGTH * 0.3, y - DEGREE_PRIME_LENGTH * 0.5, x, y + DEGREE_PRIME_LENGTH * 0.5, Strokestyle(DEGREE_STROKE, Black)));
+            }
+        }
+    }
+    v
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_scale_degrees() {
+        assert_eq!(ScaleDegree::parse("#4'").unwrap(), ScaleDegree::new(4, 1, DegreeAccidental::Raised));
+        assert_eq!(ScaleDegree::parse("^b7,,").unwrap(), ScaleDegree::new(7, -2, DegreeAccidental::Lowered));
+        assert!(ScaleDegree::parse("x").is_none());
+        assert_eq!(ScaleDegree::from_notation(&'5', &TplOctave::Lower, &TplAccidental::TASharp).unwrap(), ScaleDegree::new(5, -1, DegreeAccidental::Raised));
+        assert!(ScaleDegree::from_notation(&'x', &TplOctave::Mid, &TplAccidental::TANone).is_none());
+    }
+}
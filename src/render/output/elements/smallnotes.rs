@@ -0,0 +1,293 @@
+use crate::prelude::*;
+use crate::render::fonts::smufl::MusicGlyph;
+use graphics::prelude::*;
+use notation_rs::prelude::*;
+use std::cell::Ref;
+
+const GRACE_SCALE: f32 = 0.6;
+const CUE_SCALE: f32 = 0.75;
+const GRACE_DISTANCE: f32 = SPACE_HALF;
+const SMALLNOTE_LEDGER_OVERLAP: f32 = SPACE_QUARTER;
+const SMALLNOTE_ACCIDENTAL_GAP: f32 = SPACE_QUARTER;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SmallNoteType {
+    // Slashed grace note
+    Acciaccatura,
+    Appoggiatura,
+    Cue,
+}
+
+impl SmallNoteType {
+    pub fn get_scale(&self) -> f32 {
+        match self {
+            SmallNoteType::Acciaccatura | SmallNoteType::Appoggiatura => GRACE_SCALE,
+            SmallNoteType::Cue => CUE_SCALE,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SmallNote {
+    // Head level in half spaces from the middle line, as for regular heads
+    pub level: i8,
+    pub head_shape: HeadShape,
+    // 0 for quarters and longer, 1 for eighths, 2 for sixteenths
+    pub flags: u8,
+    pub accidental: Option<Accidental>,
+    pub dots: u8,
+}
+
+impl SmallNote {
+    pub fn new(level: i8, head_shape: HeadShape, flags: u8) -> Self {
+        Self { level, head_shape, flags, accidental: None, dots: 0 }
+    }
+
+    pub fn with_accidental(mut self, accidental: Accidental) -> Self {
+        self.accidental = Some(accidental);
+        self
+    }
+
+    pub fn with_dots(mut self, dots: u8) -> Self {
+        self.dots = dots;
+        self
+    }
+
+    // Room taken by the accidental in front of the head, before scaling
+    fn get_accidental_width(&self) -> f32 {
+        match self.accidental {
+            Some(Accidental::Flat) => ACCIDENTAL_WIDTH_FLAT + SMALLNOTE_ACCIDENTAL_GAP,
+            Some(_) => ACCIDENTAL_WIDTH_SHARP + SMALLNOTE_ACCIDENTAL_GAP,
+            None => 0.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SmallNoteGroup {
+    pub rowidx: usize,
+    // Principal note that grace notes attach to, or the column of the cue notes
+    pub colidx: usize,
+    pub note_type: SmallNoteType,
+    pub notes: Vec<SmallNote>,
+}
+
+impl SmallNoteGroup {
+    pub fn new(rowidx: usize, colidx: usize, note_type: SmallNoteType, notes: Vec<SmallNote>) -> Self {
+        Self { rowidx, colidx, note_type, notes }
+    }
+}
+
+pub fn output_smallnotes(matrix: &RMatrix, render_options: &RenderOptions) -> GraphicItems {
+    let mut graphic_items = GraphicItems::new();
+    let engraving = render_options.get_engraving();
+
+    for group in render_options.smallnotes.iter() {
+        if group.notes.is_empty() {
+            continue;
+        }
+        let (row_y, col_x) = match (matrix.rows.get(group.rowidx), matrix.cols.get(group.colidx)) {
+            (Some(row), Some(col)) => (row.borrow().y, col.borrow().x),
+            _ => continue,
+        };
+        let template = get_staff_template(&render_options.staves, group.rowidx).cloned().unwrap_or_else(|| StaffTemplate::new(group.rowidx, 5));
+        let scale = group.note_type.get_scale();
+        let stem_width = engraving.stem_width * scale;
+        // cue notes follow the columns of the notes on the other staves
+        let note_col_xs: Vec<f32> = matrix.cols[group.colidx..].iter().map(|col| col.borrow()).filter(|col| col.duration > 0).map(|col| col.x).collect();
+        let accidental_widths: Vec<f32> = group.notes.iter().map(|note| note.get_accidental_width()).collect();
+        let xs = get_smallnote_xs(group.note_type, &accidental_widths, col_x, &note_col_xs);
+
+        let stem_length = STEM_LENGTH * SPACE_HALF * scale;
+        let top_level = group.notes.iter().map(|note| note.level).min().unwrap();
+        let beamed = group.notes.len() > 1 && group.notes.iter().all(|note| note.flags > 0);
+        let beam_y = row_y + top_level as f32 * SPACE_HALF - stem_length;
+
+        let mut stem_xs: Vec<f32> = vec![];
+        for (note, x) in group.notes.iter().zip(xs) {
+            let y = row_y + note.level as f32 * SPACE_HALF;
+            graphic_items.extend(do_smallnote_ledger_lines(&template, x, row_y, note.level, scale, engraving.ledger_line_width));
+
+            let (glyph, fallback) = match note.head_shape {
+                HeadShape::BlackHead => (MusicGlyph::NoteheadBlack, CADENZA_HEAD_BLACK),
+                HeadShape::WhiteHead => (MusicGlyph::NoteheadHalf, CADENZA_HEAD_WHITE),
+                HeadShape::WholeHead => (MusicGlyph::NoteheadWhole, CADENZA_HEAD_WHOLE),
+            };
+            graphic_items.push(do_smallnote_glyph(glyph, fallback, x, y, scale, render_options));
+
+            if let Some(accidental) = &note.accidental {
+                let (glyph, fallback) = match accidental {
+                    Accidental::Sharp => (MusicGlyph::AccidentalSharp, CADENZA_ACCIDENTAL_SHARP),
+                    Accidental::Flat => (MusicGlyph::AccidentalFlat, CADENZA_ACCIDENTAL_FLAT),
+                    Accidental::Natural => (MusicGlyph::AccidentalNatural, CADENZA_ACCIDENTAL_NATURAL),
+                    Accidental::DblSharp => (MusicGlyph::AccidentalDoubleSharp, CADENZA_ACCIDENTAL_DOUBLESHARP),
+                    Accidental::DblFlat => (MusicGlyph::AccidentalDoubleFlat, CADENZA_ACCIDENTAL_DOUBLEFLAT),
+                };
+                graphic_items.push(do_smallnote_glyph(glyph, fallback, x - note.get_accidental_width() * scale, y, scale, render_options));
+            }
+
+            // dots sit in the space above heads on lines
+            let on_line = (note.level - template.get_line_levels()[0]).rem_euclid(2) == 0;
+            let dot_y = row_y + (if on_line { note.level - 1 } else { note.level }) as f32 * SPACE_HALF;
+            for dot in 0..note.dots {
+                let dot_x = x + (HEAD_WIDTH_BLACK + SPACE_QUARTER + dot as f32 * SPACE_HALF) * scale;
+                graphic_items.push(do_smallnote_glyph(MusicGlyph::AugmentationDot, CADENZA_DOT, dot_x, dot_y, scale, render_options));
+            }
+
+            // stems always up
+            if !matches!(note.head_shape, HeadShape::WholeHead) {
+                let (stem_x, stem_y) = match engraving.stem_up_se {
+                    Some((anchor_x, anchor_y)) => (x + anchor_x * scale - stem_width * 0.5, y + anchor_y * scale),
+                    None => (x + HEAD_WIDTH_BLACK * scale - stem_width * 0.5, y - STEM_HEAD_CORRECTION * scale),
+                };
+                let tip_y = if beamed { beam_y } else { y - stem_length };
+                graphic_items.push(Line(stem_x, stem_y, stem_x, tip_y, Strokestyle(stem_width, Black)));
+                stem_xs.push(stem_x);
+
+                if let (false, Some(flag)) = (beamed, do_smallnote_flag(note.flags, stem_x, stem_width, tip_y, scale, render_options)) {
+                    graphic_items.push(flag);
+                }
+            }
+        }
+
+        if beamed && stem_xs.len() > 1 {
+            let beam_x1 = stem_xs[0] - stem_width * 0.5;
+            let beam_x2 = stem_xs[stem_xs.len() - 1] + stem_width * 0.5;
+            let beams = group.notes.iter().map(|note| note.flags).min().unwrap();
+            for beamidx in 0..beams {
+                let y = beam_y + beamidx as f32 * BEAM_SUB_DISTANCE * scale;
+                graphic_items.push(Rect(beam_x1, y, beam_x2 - beam_x1, engraving.beam_height * scale, NoStroke, Fillstyle(Black)));
+            }
+        }
+
+        // slash through the stem of the first note
+        if group.note_type == SmallNoteType::Acciaccatura && !stem_xs.is_empty() {
+            let stem_x = stem_xs[0];
+            let first_y = row_y + group.notes[0].level as f32 * SPACE_HALF;
+            let slash_y = if beamed { beam_y + stem_length * 0.4 } else { first_y - stem_length * 0.6 };
+            graphic_items.push(Line(stem_x - SPACE * 0.6 * scale, slash_y + SPACE * 0.6 * scale, stem_x + SPACE * 0.9 * scale, slash_y - SPACE * 0.6 * scale, Strokestyle(stem_width, Black)));
+        }
+    }
+
+    graphic_items
+}
+
+// Music font glyph with its origin at x, y, the Cadenza glyph without a music font
+fn do_smallnote_glyph(glyph: MusicGlyph, fallback: &[PathSegment], x: f32, y: f32, scale: f32, render_options: &RenderOptions) -> GraphicItem {
+    let p = match render_options.get_music_glyph(glyph) {
+        Some(p) => p,
+        None => PathSegments(fallback.to_vec()).inv01(),
+    };
+    Path(p.scale_path(scale, scale).move_path(x, y), NoStroke, Fillstyle(Black), PathCacheInfo::NoCache)
+}
+
+// Flag on the up stem at stem_x with its tip at tip_y
+fn do_smallnote_flag(flags: u8, stem_x: f32, stem_width: f32, tip_y: f32, scale: f32, render_options: &RenderOptions) -> Option<GraphicItem> {
+    let (glyph, fallback) = match flags {
+        0 => return None,
+        1 => (MusicGlyph::Flag8thUp, CADENZA_FLAG_EIGTH_UP),
+        _ => (MusicGlyph::Flag16thUp, CADENZA_FLAG_SIXTEENTH_UP),
+    };
+    let anchor = render_options.music_font.as_ref().and_then(|font| font.get_anchor(glyph, "stemUpNW"));
+    let (x, y) = match anchor {
+        Some((anchor_x, anchor_y)) => (stem_x - stem_width * 0.5 - anchor_x * scale, tip_y - anchor_y * scale),
+        None => (stem_x - FLAG_X_ADJUST * scale, tip_y),
+    };
+    Some(do_smallnote_glyph(glyph, fallback, x, y, scale, render_options))
+}
+
+// Head positions of the notes of a group: grace notes in front of the principal note at col_x,
+// cue notes in the note columns from col_x on and spaced as grace notes past the last column.
+// Grace notes leave room for the accidental widths in front of their heads.
+fn get_smallnote_xs(note_type: SmallNoteType, accidental_widths: &[f32], col_x: f32, note_col_xs: &[f32]) -> Vec<f32> {
+    let scale = note_type.get_scale();
+    let note_spacing = HEAD_WIDTH_BLACK * scale + SPACE_HALF;
+    match note_type {
+        SmallNoteType::Cue => {
+            let mut xs: Vec<f32> = Vec::new();
+            for (idx, width) in accidental_widths.iter().enumerate() {
+                let x = match (note_col_xs.get(idx), xs.last()) {
+                    (Some(x), _) => *x,
+                    (None, Some(last)) => last + note_spacing + width * scale,
+                    (None, None) => col_x,
+                };
+                xs.push(x);
+            }
+            xs
+        }
+        _ => {
+            let mut x = col_x - GRACE_DISTANCE - note_spacing * accidental_widths.len() as f32 - accidental_widths.iter().sum::<f32>() * scale;
+            let mut xs: Vec<f32> = Vec::new();
+            for width in accidental_widths.iter() {
+                x += width * scale;
+                xs.push(x);
+                x += note_spacing;
+            }
+            xs
+        }
+    }
+}
+
+fn do_smallnote_ledger_lines(template: &StaffTemplate, x: f32, row_y: f32, level: i8, scale: f32, ledger_line_width: f32) -> GraphicItems {
+    let x1 = x - SMALLNOTE_LEDGER_OVERLAP * scale;
+    let x2 = x + HEAD_WIDTH_BLACK * scale + SMALLNOTE_LEDGER_OVERLAP * scale;
+    let lines = template.get_ledger_levels(level).into_iter().map(|ledger_level| {
+        let y = row_y + ledger_level as f32 * SPACE_HALF;
+        Line(x1, y, x2, y, Strokestyle(ledger_line_width, Black))
+    });
+    GraphicItems(lines.collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn smallnote_positions() {
+        let spacing = HEAD_WIDTH_BLACK * GRACE_SCALE + SPACE_HALF;
+        let xs = get_smallnote_xs(SmallNoteType::Appoggiatura, &[0.0, 0.0], 100.0, &[]);
+        let x1 = 100.0 - GRACE_DISTANCE - spacing * 2.0;
+        assert_eq!(xs, vec![x1, x1 + spacing]);
+
+        // cue notes line up with the columns, and continue past the last one
+        let spacing = HEAD_WIDTH_BLACK * CUE_SCALE + SPACE_HALF;
+        let xs = get_smallnote_xs(SmallNoteType::Cue, &[0.0, 0.0, 0.0], 100.0, &[100.0, 180.0]);
+        assert_eq!(xs, vec![100.0, 180.0, 180.0 + spacing]);
+
+        // an accidental pushes its grace note and the notes before it to the left
+        let spacing = HEAD_WIDTH_BLACK * GRACE_SCALE + SPACE_HALF;
+        let width = SmallNote::new(0, HeadShape::BlackHead, 1).with_accidental(Accidental::Sharp).get_accidental_width();
+        let xs = get_smallnote_xs(SmallNoteType::Acciaccatura, &[0.0, width], 100.0, &[]);
+        let x2 = 100.0 - GRACE_DISTANCE - spacing;
+        assert!((xs[0] - (x2 - spacing - width * GRACE_SCALE)).abs() < 0.01);
+        assert!((xs[1] - x2).abs() < 0.01);
+    }
+
+    #[test]
+    fn grace_notes_before_their_column() {
+        use crate::render::output::elements::testutils::*;
+        let matrix = get_test_matrix("|clef G | 0 1");
+        let cols = get_test_note_cols(&matrix, 0);
+        let mut render_options = RenderOptions::default();
+        let notes = vec![SmallNote::new(-8, HeadShape::BlackHead, 1), SmallNote::new(-2, HeadShape::BlackHead, 1)];
+        render_options.smallnotes.push(SmallNoteGroup::new(0, cols[1], SmallNoteType::Appoggiatura, notes));
+        let graphic_items = output_smallnotes(&matrix, &render_options);
+        let (_, _, x2, _) = get_items_extent(&graphic_items).unwrap();
+        assert!(x2 < get_test_col_x(&matrix, cols[1]));
+
+        // the note above the staff gets ledger lines at its own levels, not at the full sized head width
+        let row_y = matrix.rows[0].borrow().y;
+        let ledger_ys: Vec<f32> = graphic_items
+            .0
+            .iter()
+            .filter_map(|item| match item {
+                Line(lx1, y1, lx2, y2, _) if y1 == y2 => {
+                    assert!(lx2 - lx1 < HEAD_WIDTH_BLACK + SPACE_HALF);
+                    Some(*y1)
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(ledger_ys, vec![row_y - 6.0 * SPACE_HALF, row_y - 8.0 * SPACE_HALF]);
+    }
+}
@@ -0,0 +1,225 @@
+use crate::prelude::*;
+use graphics::prelude::*;
+use notation_rs::prelude::*;
+use std::cell::Ref;
+
+// Height of the labels in em units
+const SPANNER_TEXT_HEIGHT: f32 = 500.0;
+const SPANNER_LINE_WIDTH: f32 = 3.0;
+const SPANNER_DASH_LENGTH: f32 = SPACE_HALF;
+const SPANNER_DASH_GAP: f32 = SPACE_QUARTER;
+const SPANNER_HOOK_LENGTH: f32 = SPACE;
+const OTTAVA_DISTANCE: f32 = SPACE * 1.5;
+const PEDAL_DISTANCE: f32 = SPACE * 1.5;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OttavaType {
+    OctaveUp,
+    OctaveDown,
+    TwoOctavesUp,
+    TwoOctavesDown,
+}
+
+impl OttavaType {
+    pub fn get_label(&self) -> &'static str {
+        match self {
+            OttavaType::OctaveUp => "8va",
+            OttavaType::OctaveDown => "8vb",
+            OttavaType::TwoOctavesUp => "15ma",
+            OttavaType::TwoOctavesDown => "15mb",
+        }
+    }
+
+    // Label used when the line is continued from the previous system
+    pub fn get_short_label(&self) -> &'static str {
+        match self {
+            OttavaType::OctaveUp | OttavaType::OctaveDown => "(8)",
+            OttavaType::TwoOctavesUp | OttavaType::TwoOctavesDown => "(15)",
+        }
+    }
+
+    pub fn is_above(&self) -> bool {
+        matches!(self, OttavaType::OctaveUp | OttavaType::TwoOctavesUp)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PedalStyle {
+    // Ped. ... *
+    Text,
+    // |______|
+    Bracket,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SpannerType {
+    Ottava(OttavaType),
+    Pedal(PedalStyle),
+}
+
+#[derive(Debug, Clone)]
+pub struct Spanner {
+    pub rowidx: usize,
+    // None when continued from the previous system
+    pub from_colidx: Option<usize>,
+    // None when continued on the next system
+    pub to_colidx: Option<usize>,
+    pub spanner_type: SpannerType,
+}
+
+impl Spanner {
+    pub fn new(rowidx: usize, from_colidx: Option<usize>, to_colidx: Option<usize>, spanner_type: SpannerType) -> Self {
+        Self {
+            rowidx,
+            from_colidx,
+            to_colidx,
+            spanner_type,
+        }
+    }
+}
+
+pub fn output_spanners(matrix: &RMatrix, render_options: &RenderOptions) -> GraphicItems {
+    let mut graphic_items = GraphicItems::new();
+    if matrix.cols.is_empty() {
+        return graphic_items;
+    }
+    let size = render_options.get_text_size(TextRole::Marking);
+    let text_height = SPANNER_TEXT_HEIGHT * size;
+
+    for spanner in &render_options.spanners {
+        let (staff_top, staff_bottom) = match get_staff_extent(matrix, spanner.rowidx, render_options) {
+            Some(extent) => extent,
+            None => continue,
+        };
+        let x1 = match spanner.from_colidx.map(|colidx| matrix.cols.get(colidx)) {
+            Some(Some(col)) => col.borrow().x,
+            Some(None) => continue,
+            None => get_first_note_col_x(matrix),
+        };
+        // spanners ending past the last column run to the end of the system
+        let x2 = match spanner.to_colidx.and_then(|colidx| matrix.cols.get(colidx)) {
+            Some(col) => col.borrow().x + HEAD_WIDTH_BLACK,
+            None => matrix.width,
+        };
+        let from_colidx = spanner.from_colidx.unwrap_or(0);
+        let to_colidx = spanner.to_colidx.unwrap_or(matrix.cols.len() - 1).min(matrix.cols.len() - 1);
+
+        // outermost note extent in the spanned columns
+        let mut top_y = staff_top;
+        let mut bottom_y = staff_bottom;
+        for colidx in from_colidx..=to_colidx {
+            if let Some(item) = get_row_item(matrix, spanner.rowidx, colidx) {
+                let item: Ref<RItem> = item.borrow();
+                top_y = top_y.min(get_note_top_y(&item, staff_top));
+                bottom_y = bottom_y.max(get_note_bottom_y(&item, staff_bottom));
+            }
+        }
+
+        match spanner.spanner_type {
+            SpannerType::Ottava(ottava_type) => {
+                let above = ottava_type.is_above();
+                let y = if above { top_y - OTTAVA_DISTANCE } else { bottom_y + OTTAVA_DISTANCE + text_height };
+                let label = match spanner.from_colidx {
+                    Some(_) => ottava_type.get_label(),
+                    None => ottava_type.get_short_label(),
+                };
+                graphic_items.push(render_options.get_text_item(TextRole::Marking, label, x1, y));
+
+                let line_x = x1 + render_options.get_text_width(TextRole::Marking, label) * size + SPACE_QUARTER;
+                let line_y = y - text_height / 2.0;
+                let mut p = dashed_line_segments(line_x, line_y, x2, SPANNER_DASH_LENGTH, SPANNER_DASH_GAP);
+                if spanner.to_colidx.is_some() {
+                    let hook_y = if above { line_y + SPANNER_HOOK_LENGTH } else { line_y - SPANNER_HOOK_LENGTH };
+                    p.extend(vec![M(x2, line_y), L(x2, hook_y)]);
+                }
+                graphic_items.push(Path(PathSegments(p), Strokestyle(SPANNER_LINE_WIDTH, Black), NoFill, PathCacheInfo::NoCache));
+            }
+
+            SpannerType::Pedal(PedalStyle::Text) => {
+                let y = bottom_y + PEDAL_DISTANCE + text_height;
+                if spanner.from_colidx.is_some() {
+                    graphic_items.push(render_options.get_text_item(TextRole::Marking, "Ped.", x1, y));
+                }
+                if spanner.to_colidx.is_some() {
+                    let width = render_options.get_text_width(TextRole::Marking, "*") * size;
+                    graphic_items.push(render_options.get_text_item(TextRole::Marking, "*", x2 - width, y));
+                }
+            }
+
+            SpannerType::Pedal(PedalStyle::Bracket) => {
+                let y = bottom_y + PEDAL_DISTANCE + SPANNER_HOOK_LENGTH;
+                let mut p = match spanner.from_colidx {
+                    Some(_) => vec![M(x1, y - SPANNER_HOOK_LENGTH), L(x1, y)],
+                    None => vec![M(x1, y)],
+                };
+                p.push(L(x2, y));
+                if spanner.to_colidx.is_some() {
+                    p.push(L(x2, y - SPANNER_HOOK_LENGTH));
+                }
+                graphic_items.push(Path(PathSegments(p), Strokestyle(SPANNER_LINE_WIDTH, Black), NoFill, PathCacheInfo::NoCache));
+            }
+        }
+    }
+
+    graphic_items
+}
+
+pub fn dashed_line_segments(x1: f32, y: f32, x2: f32, dash: f32, gap: f32) -> Vec<PathSegment> {
+    let mut segments = vec![];
+    let mut x = x1;
+    while x < x2 {
+        segments.push(M(x, y));
+        segments.push(L((x + dash).min(x2), y));
+        x += dash + gap;
+    }
+    segments
+}
+
+// x of the first column holding durational content, i.e. after clefs, keys and time signatures
+fn get_first_note_col_x(matrix: &RMatrix) -> f32 {
+    for col in matrix.cols.iter() {
+        let col = col.borrow();
+        if col.duration > 0 {
+            return col.x;
+        }
+    }
+    0.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dashes_and_ottava_labels() {
+        // dashes of 10 with gaps of 5, the last one cut at the end
+        let segments = dashed_line_segments(0.0, 0.0, 40.0, 10.0, 5.0);
+        assert_eq!(segments.len(), 3 * 2);
+        assert!(matches!(segments[4], M(x, _) if x == 30.0));
+        assert!(matches!(segments[5], L(x, _) if x == 40.0));
+        assert_eq!(OttavaType::TwoOctavesDown.get_label(), "15mb");
+        assert_eq!(OttavaType::OctaveDown.get_short_label(), "(8)");
+        assert!(!OttavaType::OctaveDown.is_above());
+    }
+
+    #[test]
+    fn ottava_and_pedal_around_the_staff() {
+        use crate::render::output::elements::testutils::*;
+        let matrix = get_test_matrix("|clef G | 0 1 2 3");
+        let cols = get_test_note_cols(&matrix, 0);
+        let mut render_options = RenderOptions::default();
+        let (staff_top, staff_bottom) = get_staff_extent(&matrix, 0, &render_options).unwrap();
+        render_options.spanners.push(Spanner::new(0, Some(cols[1]), Some(cols[2]), SpannerType::Ottava(OttavaType::OctaveUp)));
+        let (x1, _, x2, y2) = get_items_extent(&output_spanners(&matrix, &render_options)).unwrap();
+        // the label starts at the column, its first glyph may have a side bearing
+        assert!(x1 >= get_test_col_x(&matrix, cols[1]) && x1 < get_test_col_x(&matrix, cols[1]) + SPACE_HALF);
+        assert!((x2 - (get_test_col_x(&matrix, cols[2]) + HEAD_WIDTH_BLACK)).abs() < 0.01);
+        assert!(y2 < staff_top);
+
+        // a pedal continued from the previous system starts at the first note, after the clef
+        render_options.spanners = vec![Spanner::new(0, None, Some(cols[3]), SpannerType::Pedal(PedalStyle::Bracket))];
+        let (x1, y1, _, _) = get_items_extent(&output_spanners(&matrix, &render_options)).unwrap();
+        assert!((x1 - get_test_col_x(&matrix, cols[0])).abs() < 0.01);
+        assert!(y1 > staff_bottom);
+    }
+}
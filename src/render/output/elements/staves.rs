@@ -0,0 +1,252 @@
+use crate::prelude::*;
+use graphics::path::PathSegment;
+use graphics::prelude::*;
+use notation_rs::prelude::*;
+
+const STAFF_LEDGER_OVERLAP: f32 = SPACE * 0.3;
+const PERCUSSION_CLEF_BAR_WIDTH: f32 = SPACE * 0.4;
+const PERCUSSION_CLEF_BAR_GAP: f32 = SPACE * 0.5;
+const TAB_CLEF_SCALE: f32 = 1.6;
+// Height of capitals in em units
+const TAB_CLEF_CAP_HEIGHT: f32 = 700.0;
+
+#[derive(Debug, Clone)]
+pub struct StaffTemplate {
+    pub rowidx: usize,
+    // 1 for percussion, 5 for the common staff, 6 for guitar tablature
+    pub lines: u8,
+    // Size relative to a normal staff, below 1.0 for ossia and cue staves
+    pub scale: f32,
+}
+
+impl StaffTemplate {
+    pub fn new(rowidx: usize, lines: u8) -> Self {
+        Self { rowidx, lines: lines.max(1), scale: 1.0 }
+    }
+
+    pub fn scaled(mut self, scale: f32) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    // Levels of the staff lines in half spaces from the middle of the row, top line first
+    pub fn get_line_levels(&self) -> Vec<i8> {
+        let lines = self.lines as i8;
+        (0..lines).map(|line| line * 2 - (lines - 1)).collect()
+    }
+
+    // Levels of the ledger lines needed for a head at the level
+    pub fn get_ledger_levels(&self, level: i8) -> Vec<i8> {
+        let top = 1 - self.lines as i8;
+        let bottom = self.lines as i8 - 1;
+        match level {
+            _ if level <= top - 2 => (level..=top - 2).rev().filter(|ledger| (ledger - top) % 2 == 0).collect(),
+            _ if level >= bottom + 2 => (bottom + 2..=level).filter(|ledger| (ledger - bottom) % 2 == 0).collect(),
+            _ => vec![],
+        }
+    }
+
+    // Clef drawn instead of the notation clef: percussion bars on staves of up to three lines, TAB on six
+    pub fn get_clef_items(&self, x: f32, row_y: f32, render_options: &RenderOptions) -> Option<Vec<GraphicItem>> {
+        match self.lines {
+            1..=3 => {
+                let half_height = SPACE.max((self.lines as f32 - 1.0) * SPACE_HALF);
+                let y = row_y - half_height;
+                Some(vec![
+                    Rect(x, y, PERCUSSION_CLEF_BAR_WIDTH, half_height * 2.0, NoStroke, Fillstyle(Black)),
+                    Rect(x + PERCUSSION_CLEF_BAR_WIDTH + PERCUSSION_CLEF_BAR_GAP, y, PERCUSSION_CLEF_BAR_WIDTH, half_height * 2.0, NoStroke, Fillstyle(Black)),
+                ])
+            }
+            6 => Some(do_tab_clef(x, row_y, render_options)),
+            _ => None,
+        }
+    }
+
+    // Scales the items of one row item towards its position and the middle of the row
+    pub fn scale_items_around(&self, items: &mut [GraphicItem], x: f32, row_y: f32) {
+        let scale = self.scale;
+        self.scale_with(items, |px: f32, py: f32| (x + (px - x) * scale, row_y + (py - row_y) * scale));
+    }
+
+    // Scales items of the row towards the middle of the row, and horizontally towards the nearest anchor on their left,
+    // so that stems and beams stay attached to the heads scaled around their own position
+    pub fn scale_items(&self, items: &mut [GraphicItem], anchors: &[f32], row_y: f32) {
+        let scale = self.scale;
+        let reach = HEAD_WIDTH_BLACK * 1.5;
+        self.scale_with(items, |x: f32, y: f32| {
+            let anchor = anchors.iter().filter(|anchor| **anchor <= x + 0.01 && x - **anchor <= reach).fold(None, |nearest: Option<f32>, anchor| Some(nearest.map_or(*anchor, |nearest| nearest.max(*anchor))));
+            let x = match anchor {
+                Some(anchor) => anchor + (x - anchor) * scale,
+                None => x,
+            };
+            (x, row_y + (y - row_y) * scale)
+        });
+    }
+
+    fn scale_with(&self, items: &mut [GraphicItem], map: impl Fn(f32, f32) -> (f32, f32) + Copy) {
+        if self.scale == 1.0 {
+            return;
+        }
+        let scale = self.scale;
+        let stroke = |stroke: &Stroke| match stroke {
+            Strokestyle(width, color) => Strokestyle(width * scale, color.clone()),
+            _ => NoStroke,
+        };
+        for item in items.iter_mut() {
+            *item = match &*item {
+                Path(p, s, f, _) => Path(map_path(p, map), stroke(s), f.clone(), PathCacheInfo::NoCache),
+                Line(x1, y1, x2, y2, s) => {
+                    let (x1, y1) = map(*x1, *y1);
+                    let (x2, y2) = map(*x2, *y2);
+                    Line(x1, y1, x2, y2, stroke(s))
+                }
+                Rect(x, y, w, h, s, f) => {
+                    let (x, y) = map(*x, *y);
+                    Rect(x, y, w * scale, h * scale, stroke(s), f.clone())
+                }
+                other => other.clone(),
+            };
+        }
+    }
+}
+
+fn map_path(p: &PathSegments, map: impl Fn(f32, f32) -> (f32, f32)) -> PathSegments {
+    PathSegments(
+        p.0.iter()
+            .map(|segment| match *segment {
+                PathSegment::M(x, y) => {
+                    let (x, y) = map(x, y);
+                    PathSegment::M(x, y)
+                }
+                PathSegment::L(x, y) => {
+                    let (x, y) = map(x, y);
+                    PathSegment::L(x, y)
+                }
+                PathSegment::Q(x1, y1, x, y) => {
+                    let (x1, y1) = map(x1, y1);
+                    let (x, y) = map(x, y);
+                    PathSegment::Q(x1, y1, x, y)
+                }
+                PathSegment::C(x1, y1, x2, y2, x, y) => {
+                    let (x1, y1) = map(x1, y1);
+                    let (x2, y2) = map(x2, y2);
+                    let (x, y) = map(x, y);
+                    PathSegment::C(x1, y1, x2, y2, x, y)
+                }
+                _ => segment.clone(),
+            })
+            .collect(),
+    )
+}
+
+// TAB written downwards, centered on the row
+pub fn do_tab_clef(x: f32, row_y: f32, render_options: &RenderOptions) -> Vec<GraphicItem> {
+    let size = render_options.get_text_size(TextRole::Tab) * TAB_CLEF_SCALE;
+    let cap_height = TAB_CLEF_CAP_HEIGHT * size;
+    ["T", "A", "B"]
+        .iter()
+        .enumerate()
+        .map(|(idx, letter)| {
+            let baseline = row_y + (idx as f32 - 1.0) * cap_height * 1.15 + cap_height * 0.5;
+            Path(
+                render_options.get_text_path(TextRole::Tab, letter).scale_path(size, size).move_path(x, baseline),
+                render_options.fonts.get_stroke(TextRole::Tab),
+                Fillstyle(Black),
+                PathCacheInfo::NoCache,
+            )
+        })
+        .collect()
+}
+
+pub fn get_staff_template(staves: &[StaffTemplate], rowidx: usize) -> Option<&StaffTemplate> {
+    staves.iter().find(|staff| staff.rowidx == rowidx)
+}
+
+pub fn is_music_row(matrix: &RMatrix, rowidx: usize) -> bool {
+    matches!(matrix.bartemplate.as_ref().and_then(|bartemplate| bartemplate.0.get(rowidx)), Some(PartTemplate::Music))
+}
+
+// Rows drawn with staff lines: music rows and tablature rows
+pub fn is_staff_row(matrix: &RMatrix, rowidx: usize, render_options: &RenderOptions) -> bool {
+    is_music_row(matrix, rowidx) || get_tab_staff(&render_options.tab_staves, rowidx).is_some()
+}
+
+// Top and bottom staff lines of a row, five lines unless a template says otherwise
+pub fn get_staff_extent(matrix: &RMatrix, rowidx: usize, render_options: &RenderOptions) -> Option<(f32, f32)> {
+    let row_y = matrix.rows.get(rowidx)?.borrow().y;
+    let template = get_staff_template(&render_options.staves, rowidx).cloned().unwrap_or_else(|| StaffTemplate::new(rowidx, 5));
+    let levels = template.get_line_levels();
+    let half_height = (levels[levels.len() - 1] as f32 * SPACE_HALF * template.scale).max(SPACE_HALF);
+    Some((row_y - half_height, row_y + half_height))
+}
+
+// Head positions of the row, that scaled staves shrink towards
+pub fn get_row_anchors(matrix: &RMatrix, rowidx: usize) -> Vec<f32> {
+    (0..matrix.cols.len()).filter_map(|colidx| get_row_item(matrix, rowidx, colidx)).filter_map(|item| item.borrow().coord_x).collect()
+}
+
+// Ledger lines for the heads of an item on a staff with a template
+pub fn do_staff_ledger_lines(template: &StaffTemplate, item: &RItem, ledger_line_width: f32) -> Vec<GraphicItem> {
+    let data = match get_beam_data(&item.notedata.beamdata1) {
+        Some(data) => data,
+        None => return vec![],
+    };
+    let x = item.coord_x.unwrap();
+    let y = item.coord_y.unwrap();
+    let x1 = x - STAFF_LEDGER_OVERLAP;
+    let x2 = x + HEAD_WIDTH_BLACK + STAFF_LEDGER_OVERLAP;
+    let mut levels = template.get_ledger_levels(data.top_level as i8);
+    levels.extend(template.get_ledger_levels(data.bottom_level as i8));
+    levels.sort();
+    levels.dedup();
+    levels.into_iter().map(|level| Line(x1, y + level as f32 * SPACE_HALF, x2, y + level as f32 * SPACE_HALF, Strokestyle(ledger_line_width, Black))).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_and_ledger_levels() {
+        assert_eq!(StaffTemplate::new(0, 5).get_line_levels(), vec![-4, -2, 0, 2, 4]);
+        assert_eq!(StaffTemplate::new(0, 6).get_line_levels(), vec![-5, -3, -1, 1, 3, 5]);
+        assert_eq!(StaffTemplate::new(0, 5).get_ledger_levels(-9), vec![-6, -8]);
+        assert_eq!(StaffTemplate::new(0, 3).get_ledger_levels(5), vec![4]);
+        assert_eq!(StaffTemplate::new(0, 1).get_ledger_levels(1), Vec::<i8>::new());
+    }
+
+    #[test]
+    fn staff_lines_from_templates() {
+        use crate::render::output::elements::testutils::*;
+        let matrix = get_test_matrix("|clef G - G | 5 / - / 0");
+        let get_line_ys = |render_options: &RenderOptions| -> Vec<f32> {
+            output_notelines(&matrix, render_options)
+                .0
+                .iter()
+                .filter_map(|item| match item {
+                    Line(_, y, _, _, _) => Some(*y),
+                    _ => None,
+                })
+                .collect()
+        };
+        // five lines on each music row, none on the nonmusic row
+        let mut render_options = RenderOptions::default();
+        assert_eq!(get_line_ys(&render_options).len(), 10);
+
+        let row_y = matrix.rows[0].borrow().y;
+        render_options.staves.push(StaffTemplate::new(0, 3));
+        render_options.staves.push(StaffTemplate::new(2, 5).scaled(0.5));
+        let line_ys = get_line_ys(&render_options);
+        assert_eq!(line_ys[..3], [row_y - SPACE, row_y, row_y + SPACE]);
+        let row_y = matrix.rows[2].borrow().y;
+        assert_eq!(line_ys[3..], [row_y - SPACE, row_y - SPACE_HALF, row_y, row_y + SPACE_HALF, row_y + SPACE]);
+        assert_eq!(get_staff_extent(&matrix, 2, &render_options), Some((row_y - SPACE, row_y + SPACE)));
+
+        // ledger lines start below the lowest of the three lines
+        let colidx = get_test_note_cols(&matrix, 0)[0];
+        let item = get_row_item(&matrix, 0, colidx).unwrap();
+        let ledger_lines = do_staff_ledger_lines(&render_options.staves[0], &item.borrow(), 2.0);
+        let row_y = matrix.rows[0].borrow().y;
+        assert!(matches!(ledger_lines[..], [Line(_, y, _, _, _)] if y == row_y + 4.0 * SPACE_HALF));
+    }
+}
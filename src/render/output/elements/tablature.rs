@@ -0,0 +1,388 @@
+use crate::prelude::*;
+use graphics::prelude::*;
+use notation_rs::prelude::*;
+use std::cell::Ref;
+
+// Height of digits in em units
+const TAB_DIGIT_HEIGHT: f32 = 700.0;
+const TAB_NUMBER_PADDING: f32 = SPACE * 0.1;
+const TAB_STEM_DISTANCE: f32 = SPACE * 0.8;
+const TAB_STEM_LENGTH: f32 = SPACE * 2.0;
+const TAB_STEM_WIDTH: f32 = 3.0;
+const TAB_BEAM_HEIGHT: f32 = SPACE * 0.35;
+const TAB_BEAM_DISTANCE: f32 = SPACE * 0.6;
+const TAB_FLAG_LENGTH: f32 = SPACE * 0.8;
+const TAB_ARC_HEIGHT: f32 = SPACE * 0.8;
+const TAB_SLIDE_RISE: f32 = SPACE * 0.3;
+const TAB_BEND_WIDTH: f32 = SPACE * 1.2;
+const TAB_BEND_HEIGHT: f32 = SPACE * 1.5;
+const TAB_ARROW_HEAD: f32 = SPACE * 0.3;
+const TAB_TEXT_SCALE: f32 = 0.8;
+const TAB_DASH: f32 = SPACE * 0.4;
+// Palm mute dashes and hook
+const TAB_LINE_WIDTH: f32 = 2.0;
+
+#[derive(Debug, Clone)]
+pub struct TabStaff {
+    pub rowidx: usize,
+    pub strings: u8,
+    // Notation row whose durations give the rhythm stems below the tablature
+    pub rhythm_rowidx: Option<usize>,
+}
+
+impl TabStaff {
+    pub fn new(rowidx: usize) -> Self {
+        Self { rowidx, strings: 6, rhythm_rowidx: None }
+    }
+
+    pub fn with_strings(mut self, strings: u8) -> Self {
+        self.strings = strings.max(1);
+        self
+    }
+
+    pub fn with_rhythm(mut self, rhythm_rowidx: usize) -> Self {
+        self.rhythm_rowidx = Some(rhythm_rowidx);
+        self
+    }
+
+    // Line of the string from the middle of the row, string 1 is the top line
+    pub fn get_string_y(&self, row_y: f32, string: u8) -> f32 {
+        let string = string.clamp(1, self.strings) as f32;
+        row_y + (string * 2.0 - 1.0 - self.strings as f32) * SPACE_HALF
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TabTechnique {
+    // Bend by the number of half steps
+    Bend(u8),
+    // Slide, hammer-on and pull-off to the note on the same string at the column
+    Slide(usize),
+    HammerOn(usize),
+    PullOff(usize),
+}
+
+#[derive(Debug, Clone)]
+pub struct TabNote {
+    pub rowidx: usize,
+    pub colidx: usize,
+    pub string: u8,
+    pub fret: u8,
+    pub technique: Option<TabTechnique>,
+}
+
+impl TabNote {
+    pub fn new(rowidx: usize, colidx: usize, string: u8, fret: u8) -> Self {
+        Self {
+            rowidx,
+            colidx,
+            string,
+            fret,
+            technique: None,
+        }
+    }
+
+    pub fn with_technique(mut self, technique: TabTechnique) -> Self {
+        self.technique = Some(technique);
+        self
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PalmMute {
+    pub rowidx: usize,
+    pub from_colidx: usize,
+    pub to_colidx: usize,
+}
+
+pub fn get_tab_staff(tab_staves: &[TabStaff], rowidx: usize) -> Option<&TabStaff> {
+    tab_staves.iter().find(|staff| staff.rowidx == rowidx)
+}
+
+// Bend amount as written above the arrow
+pub fn get_bend_label(halfsteps: u8) -> String {
+    match (halfsteps / 2, halfsteps % 2) {
+        (0, _) => "½".to_string(),
+        (1, 0) => "full".to_string(),
+        (whole, 0) => whole.to_string(),
+        (whole, _) => format!("{}½", whole),
+    }
+}
+
+fn do_tab_text(text: &str, x: f32, baseline: f32, size: f32, render_options: &RenderOptions) -> GraphicItem {
+    Path(
+        render_options.get_text_path(TextRole::Tab, text).scale_path(size, size).move_path(x, baseline),
+        render_options.fonts.get_stroke(TextRole::Tab),
+        Fillstyle(Black),
+        PathCacheInfo::NoCache,
+    )
+}
+
+fn get_col_x(matrix: &RMatrix, colidx: usize) -> Option<f32> {
+    matrix.cols.get(colidx).map(|col| col.borrow().x + HEAD_WIDTH_BLACK * 0.5)
+}
+
+// Stems hanging below the tablature, beamed as in the rhythm row
+fn do_tab_rhythm(matrix: &RMatrix, rhythm_rowidx: usize, stem_y: f32) -> Vec<GraphicItem> {
+    let mut v = Vec::new();
+    let stem_end = stem_y + TAB_STEM_LENGTH;
+    let mut group: Vec<(f32, usize)> = Vec::new();
+    for colidx in 0..matrix.cols.len() {
+        let item = match get_row_item(matrix, rhythm_rowidx, colidx) {
+            Some(item) => item,
+            None => continue,
+        };
+        let item: Ref<RItem> = item.borrow();
+        let x = match get_col_x(matrix, colidx) {
+            Some(x) => x,
+            None => continue,
+        };
+        let (data, single, end) = match &item.notedata.beamdata1 {
+            RItemBeam::Single(data) => (data, true, true),
+            RItemBeam::Start(data) | RItemBeam::Middle(data) => (data, false, false),
+            RItemBeam::End(data) => (data, false, true),
+            _ => continue,
+        };
+        if !duration_has_stem(&data.duration) {
+            continue;
+        }
+        v.push(Line(x, stem_y, x, stem_end, Strokestyle(TAB_STEM_WIDTH, Black)));
+        let beams = get_beam_count(&duration_to_beamtype(&data.duration));
+
+        if single {
+            // straight flags, one per beam
+            for flag in 0..beams {
+                let y = stem_end - flag as f32 * TAB_BEAM_DISTANCE;
+                v.push(Line(x, y, x + TAB_FLAG_LENGTH, y - TAB_FLAG_LENGTH, Strokestyle(TAB_STEM_WIDTH, Black)));
+            }
+            continue;
+        }
+        group.push((x, beams));
+        if end {
+            v.extend(do_tab_beams(&group, stem_end));
+            group.clear();
+        }
+    }
+    v
+}
+
+// Beams or flags of a note value
+fn get_beam_count(beamtype: &BeamType) -> usize {
+    match beamtype {
+        BeamType::B8 => 1,
+        BeamType::B16 => 2,
+        BeamType::B32 => 3,
+        _ => 0,
+    }
+}
+
+// Beams of a group of stems, given as x and beam count, with the primary beam at the stem ends.
+// Each further beam joins the neighbouring stems that have it, a lone stem gets a broken beam
+// towards the next stem, or towards the previous one at the end of the group.
+fn do_tab_beams(group: &[(f32, usize)], stem_end: f32) -> Vec<GraphicItem> {
+    let mut v = Vec::new();
+    let levels = group.iter().map(|(_, beams)| *beams).max().unwrap_or(0);
+    for level in 1..=levels {
+        let y = stem_end - (level - 1) as f32 * TAB_BEAM_DISTANCE - TAB_BEAM_HEIGHT;
+        let mut idx = 0;
+        while idx < group.len() {
+            if group[idx].1 < level {
+                idx += 1;
+                continue;
+            }
+            let start = idx;
+            while idx + 1 < group.len() && group[idx + 1].1 >= level {
+                idx += 1;
+            }
+            let (x1, x2) = match (start == idx, group.get(idx + 1), start.checked_sub(1).and_then(|previdx| group.get(previdx))) {
+                (false, ..) => (group[start].0 - TAB_STEM_WIDTH * 0.5, group[idx].0 + TAB_STEM_WIDTH * 0.5),
+                (true, Some((next_x, _)), _) => (group[idx].0, group[idx].0 + TAB_FLAG_LENGTH.min((next_x - group[idx].0) * 0.5)),
+                (true, None, Some((prev_x, _))) => (group[idx].0 - TAB_FLAG_LENGTH.min((group[idx].0 - prev_x) * 0.5), group[idx].0),
+                (true, None, None) => (group[idx].0, group[idx].0 + TAB_FLAG_LENGTH),
+            };
+            v.push(Rect(x1, y, x2 - x1, TAB_BEAM_HEIGHT, NoStroke, Fillstyle(Black)));
+            idx += 1;
+        }
+    }
+    v
+}
+
+pub fn output_tablature(matrix: &RMatrix, render_options: &RenderOptions) -> GraphicItems {
+    let mut graphic_items = GraphicItems::new();
+    let size = render_options.get_text_size(TextRole::Tab);
+    let digit_height = TAB_DIGIT_HEIGHT * size;
+    let text_size = size * TAB_TEXT_SCALE;
+
+    for staff in render_options.tab_staves.iter() {
+        let row_y = match matrix.rows.get(staff.rowidx) {
+            Some(row) => row.borrow().y,
+            None => continue,
+        };
+        // the string lines are staff lines, music rows draw the TAB clef in place of their clef
+        if !is_music_row(matrix, staff.rowidx) {
+            graphic_items.extend(GraphicItems(do_tab_clef(SPACE_HALF, row_y, render_options)));
+        }
+
+        let top_y = staff.get_string_y(row_y, 1);
+        let bottom_y = staff.get_string_y(row_y, staff.strings);
+        if let Some(rhythm_rowidx) = staff.rhythm_rowidx {
+            graphic_items.extend(GraphicItems(do_tab_rhythm(matrix, rhythm_rowidx, bottom_y + TAB_STEM_DISTANCE)));
+        }
+
+        let notes: Vec<&TabNote> = render_options.tab_notes.iter().filter(|note| note.rowidx == staff.rowidx).collect();
+        // left and right edge of a fret number and the line of its string
+        let get_number_box = |note: &TabNote| -> Option<(f32, f32, f32)> {
+            let x = get_col_x(matrix, note.colidx)?;
+            let width = render_options.get_text_width(TextRole::Tab, &note.fret.to_string()) * size;
+            Some((x - width * 0.5, x + width * 0.5, staff.get_string_y(row_y, note.string)))
+        };
+
+        for note in notes.iter() {
+            let (x1, x2, y) = match get_number_box(note) {
+                Some(number_box) => number_box,
+                None => continue,
+            };
+            // numbers interrupt the string line
+            graphic_items.push(Rect(
+                x1 - TAB_NUMBER_PADDING,
+                y - digit_height * 0.5 - TAB_NUMBER_PADDING,
+                x2 - x1 + TAB_NUMBER_PADDING * 2.0,
+                digit_height + TAB_NUMBER_PADDING * 2.0,
+                NoStroke,
+                Fillstyle(White),
+            ));
+            graphic_items.push(do_tab_text(&note.fret.to_string(), x1, y + digit_height * 0.5, size, render_options));
+
+            let target = |to_colidx: usize| notes.iter().find(|other| other.colidx == to_colidx && other.string == note.string).and_then(|other| get_number_box(other).map(|number_box| (other.fret, number_box)));
+            match note.technique {
+                Some(TabTechnique::Bend(halfsteps)) => {
+                    let (bx, by) = (x2 + TAB_NUMBER_PADDING, y);
+                    let tip_x = bx + TAB_BEND_WIDTH;
+                    let tip_y = top_y - TAB_BEND_HEIGHT;
+                    graphic_items.push(Path(PathSegments(vec![M(bx, by), Q(tip_x, by, tip_x, tip_y)]), Strokestyle(TAB_STEM_WIDTH, Black), NoFill, PathCacheInfo::NoCache));
+                    graphic_items.push(Path(
+                        PathSegments(vec![M(tip_x - TAB_ARROW_HEAD * 0.6, tip_y + TAB_ARROW_HEAD), L(tip_x, tip_y), L(tip_x + TAB_ARROW_HEAD * 0.6, tip_y + TAB_ARROW_HEAD), Z]),
+                        NoStroke,
+                        Fillstyle(Black),
+                        PathCacheInfo::NoCache,
+                    ));
+                    let label = get_bend_label(halfsteps);
+                    let width = render_options.get_text_width(TextRole::Tab, &label) * text_size;
+                    graphic_items.push(do_tab_text(&label, tip_x - width * 0.5, tip_y - TAB_NUMBER_PADDING * 2.0, text_size, render_options));
+                }
+                Some(TabTechnique::Slide(to_colidx)) => {
+                    if let Some((to_fret, (to_x1, _, to_y))) = target(to_colidx) {
+                        let rise = if to_fret >= note.fret { TAB_SLIDE_RISE } else { -TAB_SLIDE_RISE };
+                        graphic_items.push(Line(x2 + TAB_NUMBER_PADDING, y + rise, to_x1 - TAB_NUMBER_PADDING, to_y - rise, Strokestyle(TAB_STEM_WIDTH, Black)));
+                    }
+                }
+                Some(TabTechnique::HammerOn(to_colidx)) | Some(TabTechnique::PullOff(to_colidx)) => {
+                    if let Some((_, (to_x1, to_x2, _))) = target(to_colidx) {
+                        let (ax1, ax2) = ((x1 + x2) * 0.5, (to_x1 + to_x2) * 0.5);
+                        let ay = y - digit_height * 0.5 - TAB_NUMBER_PADDING * 2.0;
+                        graphic_items.push(Path(
+                            PathSegments(vec![M(ax1, ay), Q((ax1 + ax2) * 0.5, ay - TAB_ARC_HEIGHT * 2.0, ax2, ay)]),
+                            Strokestyle(TAB_STEM_WIDTH, Black),
+                            NoFill,
+                            PathCacheInfo::NoCache,
+                        ));
+                        let label = match note.technique {
+                            Some(TabTechnique::HammerOn(_)) => "H",
+                            _ => "P",
+                        };
+                        let width = render_options.get_text_width(TextRole::Tab, label) * text_size;
+                        graphic_items.push(do_tab_text(label, (ax1 + ax2 - width) * 0.5, ay - TAB_ARC_HEIGHT - TAB_NUMBER_PADDING * 2.0, text_size, render_options));
+                    }
+                }
+                None => {}
+            }
+        }
+
+        // palm mute text with a dashed line to the last muted column
+        for mute in render_options.palm_mutes.iter().filter(|mute| mute.rowidx == staff.rowidx) {
+            let (x1, x2) = match (get_col_x(matrix, mute.from_colidx), get_col_x(matrix, mute.to_colidx)) {
+                (Some(x1), Some(x2)) => (x1 - HEAD_WIDTH_BLACK * 0.5, x2 + HEAD_WIDTH_BLACK * 0.5),
+                _ => continue,
+            };
+            let baseline = top_y - TAB_BEND_HEIGHT - SPACE;
+            graphic_items.push(do_tab_text("P.M.", x1, baseline, text_size, render_options));
+            let dash_x = x1 + render_options.get_text_width(TextRole::Tab, "P.M.") * text_size + TAB_DASH;
+            let dash_y = baseline - digit_height * TAB_TEXT_SCALE * 0.5;
+            let mut x = dash_x;
+            while x + TAB_DASH <= x2 {
+                graphic_items.push(Line(x, dash_y, x + TAB_DASH, dash_y, Strokestyle(TAB_LINE_WIDTH, Black)));
+                x += TAB_DASH * 2.0;
+            }
+            graphic_items.push(Line(x2, dash_y, x2, dash_y + SPACE_HALF, Strokestyle(TAB_LINE_WIDTH, Black)));
+        }
+    }
+    graphic_items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn string_positions_and_bend_labels() {
+        let staff = TabStaff::new(1);
+        assert_eq!(staff.get_string_y(0.0, 1), -SPACE * 2.5);
+        assert_eq!(staff.get_string_y(0.0, 6), SPACE * 2.5);
+        assert_eq!(get_bend_label(1), "½");
+        assert_eq!(get_bend_label(2), "full");
+        assert_eq!(get_bend_label(3), "1½");
+    }
+
+    #[test]
+    fn fret_numbers_on_their_strings() {
+        use crate::render::output::elements::testutils::*;
+        let matrix = get_test_matrix("|clef G - | 0 1 / -");
+        let cols = get_test_note_cols(&matrix, 0);
+        let mut render_options = RenderOptions::default();
+        render_options.add_tab_staff(TabStaff::new(1).with_rhythm(0));
+        render_options.tab_notes.push(TabNote::new(1, cols[0], 1, 3));
+        render_options.tab_notes.push(TabNote::new(1, cols[1], 6, 12));
+        let row_y = matrix.rows[1].borrow().y;
+        let staff = &render_options.tab_staves[0];
+
+        // the white boxes behind the numbers are centered under the heads and on the string line
+        let boxes: Vec<(f32, f32)> = output_tablature(&matrix, &render_options)
+            .0
+            .iter()
+            .filter_map(|item| match item {
+                Rect(x, y, w, h, _, _) => Some((x + w * 0.5, y + h * 0.5)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(boxes.len(), 2);
+        assert!((boxes[0].0 - (get_test_col_x(&matrix, cols[0]) + HEAD_WIDTH_BLACK * 0.5)).abs() < 0.01);
+        assert!((boxes[0].1 - staff.get_string_y(row_y, 1)).abs() < 0.01);
+        assert!((boxes[1].1 - staff.get_string_y(row_y, 6)).abs() < 0.01);
+
+        // the nonmusic row gets six staff lines, and the rhythm stems hang below the lowest one
+        assert_eq!(output_notelines(&matrix, &render_options).0.len(), 5 + 6);
+        let (_, _, _, y2) = get_items_extent(&output_tablature(&matrix, &render_options)).unwrap();
+        assert!(y2 > staff.get_string_y(row_y, 6) + TAB_STEM_DISTANCE);
+    }
+
+    #[test]
+    fn broken_and_thirty_second_beams() {
+        // left edge, right edge and beam level of each beam
+        let get_beams = |group: &[(f32, usize)]| -> Vec<(f32, f32, usize)> {
+            do_tab_beams(group, 0.0)
+                .iter()
+                .filter_map(|item| match item {
+                    Rect(x, y, width, ..) => Some((*x, *x + *width, ((-*y - TAB_BEAM_HEIGHT) / TAB_BEAM_DISTANCE).round() as usize + 1)),
+                    _ => None,
+                })
+                .collect()
+        };
+        let half_stem = TAB_STEM_WIDTH * 0.5;
+        // dotted eighth and sixteenth: the sixteenth beam is broken back towards the eighth
+        assert_eq!(get_beams(&[(0.0, 1), (100.0, 2)]), vec![(-half_stem, 100.0 + half_stem, 1), (100.0 - TAB_FLAG_LENGTH, 100.0, 2)]);
+        // sixteenth and dotted eighth: broken forwards
+        assert_eq!(get_beams(&[(0.0, 2), (100.0, 1)]), vec![(-half_stem, 100.0 + half_stem, 1), (0.0, TAB_FLAG_LENGTH, 2)]);
+        assert_eq!(get_beams(&[(0.0, 3), (40.0, 3)]), vec![(-half_stem, 40.0 + half_stem, 1), (-half_stem, 40.0 + half_stem, 2), (-half_stem, 40.0 + half_stem, 3)]);
+        assert_eq!(get_beam_count(&BeamType::B32), 3);
+    }
+}
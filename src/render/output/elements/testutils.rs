@@ -0,0 +1,50 @@
+use crate::render::fonts::metrics::get_path_bbox;
+use graphics::prelude::*;
+use notation_rs::prelude::*;
+
+// Spaced matrix from QCode, e.g. "|clef G - | 0 #1 2 / -" for a G clef staff over a nonmusic row
+pub fn get_test_matrix(code: &str) -> RMatrix {
+    let (bartemplate, bars) = QCode::bars(code).unwrap();
+    let mut matrix = bars.to_matrix(Some(bartemplate)).unwrap();
+    matrix.calculate_col_spacing(ALLOTMENT_RELATIVE_FN);
+    matrix.calculate_row_spacing();
+    matrix.calculate_matrix_size();
+    matrix
+}
+
+pub fn get_test_col_x(matrix: &RMatrix, colidx: usize) -> f32 {
+    matrix.cols[colidx].borrow().x
+}
+
+// Columns of the row that hold a head, in order
+pub fn get_test_note_cols(matrix: &RMatrix, rowidx: usize) -> Vec<usize> {
+    let row = matrix.rows[rowidx].borrow();
+    (0..row.items.len())
+        .filter(|colidx| {
+            row.items[*colidx]
+                .as_ref()
+                .is_some_and(|item| item.borrow().nrects.iter().flatten().any(|nrect| matches!(nrect.borrow().1, NRectType::Head(..))))
+        })
+        .collect()
+}
+
+// Left, top, right and bottom of the outline points of the items, None when nothing is drawn
+pub fn get_items_extent(items: &GraphicItems) -> Option<(f32, f32, f32, f32)> {
+    let mut extent: Option<(f32, f32, f32, f32)> = None;
+    for item in items.0.iter() {
+        let (x1, y1, x2, y2) = match item {
+            Path(segments, ..) => match get_path_bbox(&segments.0) {
+                Some(bbox) => (bbox.x_min, bbox.y_min, bbox.x_max, bbox.y_max),
+                None => continue,
+            },
+            Line(x1, y1, x2, y2, _) => (x1.min(*x2), y1.min(*y2), x1.max(*x2), y1.max(*y2)),
+            Rect(x, y, w, h, ..) => (*x, *y, x + w, y + h),
+            _ => continue,
+        };
+        extent = Some(match extent {
+            Some((ex1, ey1, ex2, ey2)) => (ex1.min(x1), ey1.min(y1), ex2.max(x2), ey2.max(y2)),
+            None => (x1, y1, x2, y2),
+        });
+    }
+    extent
+}
@@ -0,0 +1,194 @@
+use crate::prelude::*;
+use crate::render::fonts::smufl::EngravingDefaults;
+use graphics::prelude::*;
+use notation_rs::prelude::*;
+use std::cell::Ref;
+
+const TREMOLO_STROKE_WIDTH: f32 = SPACE * 1.2;
+const TREMOLO_STROKE_SLANT: f32 = SPACE_QUARTER;
+// stem strokes are slightly thinner than beams
+const TREMOLO_STROKE_HEIGHT_FACTOR: f32 = 0.8;
+const TREMOLO_MAX_STROKES: u8 = 3;
+const TREMOLO_STEM_GAP: f32 = SPACE_HALF;
+
+#[derive(Debug, Clone)]
+pub struct Tremolo {
+    pub rowidx: usize,
+    // Tremolos apply to the upper voice of the item
+    pub colidx: usize,
+    // Some for a two-note tremolo ending at this column
+    pub to_colidx: Option<usize>,
+    pub strokes: u8,
+}
+
+impl Tremolo {
+    pub fn new(rowidx: usize, colidx: usize, strokes: u8) -> Self {
+        Self {
+            rowidx,
+            colidx,
+            to_colidx: None,
+            strokes: strokes.clamp(1, TREMOLO_MAX_STROKES),
+        }
+    }
+
+    pub fn new_two_note(rowidx: usize, colidx: usize, to_colidx: usize, strokes: u8) -> Self {
+        Self {
+            rowidx,
+            colidx,
+            to_colidx: Some(to_colidx),
+            strokes: strokes.clamp(1, TREMOLO_MAX_STROKES),
+        }
+    }
+}
+
+pub fn get_tremolo_strokes(tremolos: &[Tremolo], rowidx: usize, colidx: usize) -> u8 {
+    tremolos
+        .iter()
+        .find(|tremolo| tremolo.rowidx == rowidx && tremolo.colidx == colidx && tremolo.to_colidx.is_none())
+        .map(|tremolo| tremolo.strokes)
+        .unwrap_or(0)
+}
+
+// Left and right ends of the upper edges of slanted strokes across a stem, the first one at y and the following towards the head
+fn get_stroke_edges(x: f32, y: f32, direction_sign: f32, strokes: u8) -> Vec<(f32, f32, f32, f32)> {
+    (0..strokes)
+        .map(|strokeidx| {
+            let y = y - direction_sign * strokeidx as f32 * BEAM_SUB_DISTANCE;
+            (x - TREMOLO_STROKE_WIDTH / 2.0, y + TREMOLO_STROKE_SLANT, x + TREMOLO_STROKE_WIDTH / 2.0, y - TREMOLO_STROKE_SLANT)
+        })
+        .collect()
+}
+
+// Ends of the beam edges between two stems at first_x and last_x, inset from the stems and stacked towards the heads
+fn get_two_note_edges(first_x: f32, first_tip_y: f32, last_x: f32, last_tip_y: f32, direction_sign: f32, strokes: u8) -> Vec<(f32, f32, f32, f32)> {
+    let (x, x2) = (first_x + TREMOLO_STEM_GAP, last_x - TREMOLO_STEM_GAP);
+    if x2 <= x {
+        return vec![];
+    }
+    let slope = (last_tip_y - first_tip_y) / (last_x - first_x);
+    (0..strokes)
+        .map(|strokeidx| {
+            let offset = -direction_sign * strokeidx as f32 * BEAM_SUB_DISTANCE;
+            (x, first_tip_y + slope * TREMOLO_STEM_GAP + offset, x2, last_tip_y - slope * TREMOLO_STEM_GAP + offset)
+        })
+        .collect()
+}
+
+fn do_edge_paths(edges: Vec<(f32, f32, f32, f32)>, height: f32) -> GraphicItems {
+    let mut graphic_items = GraphicItems::new();
+    for (x1, y1, x2, y2) in edges {
+        graphic_items.push(Path(
+            PathSegments(vec![M(x1, y1), L(x2, y2), L(x2, y2 + height), L(x1, y1 + height), L(x1, y1)]),
+            NoStroke,
+            Fillstyle(Black),
+            PathCacheInfo::NoCache,
+        ));
+    }
+    graphic_items
+}
+
+// Slanted strokes across a stem, the first one at y and the following towards the head
+pub fn do_tremolo_strokes(x: f32, y: f32, direction_sign: f32, strokes: u8, engraving: &EngravingDefaults) -> GraphicItems {
+    do_edge_paths(get_stroke_edges(x, y, direction_sign, strokes), engraving.beam_height * TREMOLO_STROKE_HEIGHT_FACTOR)
+}
+
+// Tremolo strokes above or below a stemless head
+pub fn do_head_tremolo(data: &RItemBeamData, coords: NPoint, strokes: u8, engraving: &EngravingDefaults) -> GraphicItems {
+    let direction_sign = data.direction.sign();
+    let x = coords.0 + HEAD_WIDTH_BLACK / 2.0;
+    let y = match data.direction {
+        DirUD::Up => coords.1 + data.top_level as f32 * SPACE_HALF - SPACE * 1.5,
+        DirUD::Down => coords.1 + data.bottom_level as f32 * SPACE_HALF + SPACE * 1.5,
+    };
+    // strokes are stacked away from the head
+    do_tremolo_strokes(x, y + direction_sign * (strokes as f32 - 1.0) * BEAM_SUB_DISTANCE, direction_sign, strokes, engraving)
+}
+
+// Beams between the stems of two non-adjacent notes, not touching the stems
+pub fn do_two_note_tremolo(first: (&RItemBeamData, NPoint), last: (&RItemBeamData, NPoint), strokes: u8, engraving: &EngravingDefaults) -> GraphicItems {
+    let direction_sign = first.0.direction.sign();
+
+    let (first_x, _) = get_stem_base(first.0, first.1, engraving);
+    let (last_x, _) = get_stem_base(last.0, last.1, engraving);
+    let first_tip_y = get_stem_tip_y(first.0, first.1) - direction_sign * SPACE;
+    let last_tip_y = get_stem_tip_y(last.0, last.1) - direction_sign * SPACE;
+    let beamheight = match first.0.direction {
+        DirUD::Down => -engraving.beam_height,
+        DirUD::Up => engraving.beam_height,
+    };
+    do_edge_paths(get_two_note_edges(first_x, first_tip_y, last_x, last_tip_y, direction_sign, strokes), beamheight)
+}
+
+pub fn output_two_note_tremolos(matrix: &RMatrix, tremolos: &[Tremolo], engraving: &EngravingDefaults) -> GraphicItems {
+    let mut graphic_items = GraphicItems::new();
+    for tremolo in tremolos {
+        let to_colidx = match tremolo.to_colidx {
+            Some(to_colidx) => to_colidx,
+            None => continue,
+        };
+        let (first, last) = match (get_row_item(matrix, tremolo.rowidx, tremolo.colidx), get_row_item(matrix, tremolo.rowidx, to_colidx)) {
+            (Some(first), Some(last)) => (first, last),
+            _ => continue,
+        };
+        let first: Ref<RItem> = first.borrow();
+        let last: Ref<RItem> = last.borrow();
+        if let (Some(first_data), Some(last_data)) = (get_beam_data(&first.notedata.beamdata1), get_beam_data(&last.notedata.beamdata1)) {
+            graphic_items.extend(do_two_note_tremolo(
+                (first_data, NPoint(first.coord_x.unwrap(), first.coord_y.unwrap())),
+                (last_data, NPoint(last.coord_x.unwrap(), last.coord_y.unwrap())),
+                tremolo.strokes,
+                engraving,
+            ));
+        }
+    }
+    graphic_items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stroke_and_two_note_edges() {
+        // stem strokes on an upward stem, stacked down towards the head
+        let edges = get_stroke_edges(100.0, 50.0, -1.0, 3);
+        assert_eq!(edges.len(), 3);
+        assert_eq!(edges[0], (100.0 - TREMOLO_STROKE_WIDTH / 2.0, 50.0 + TREMOLO_STROKE_SLANT, 100.0 + TREMOLO_STROKE_WIDTH / 2.0, 50.0 - TREMOLO_STROKE_SLANT));
+        assert_eq!(edges[2].1, 50.0 + 2.0 * BEAM_SUB_DISTANCE + TREMOLO_STROKE_SLANT);
+
+        // two-note strokes keep clear of both stems and follow the slope of the tips
+        let edges = get_two_note_edges(0.0, 0.0, 100.0, 0.0, -1.0, 2);
+        assert_eq!(edges, vec![(TREMOLO_STEM_GAP, 0.0, 100.0 - TREMOLO_STEM_GAP, 0.0), (TREMOLO_STEM_GAP, BEAM_SUB_DISTANCE, 100.0 - TREMOLO_STEM_GAP, BEAM_SUB_DISTANCE)]);
+        assert!(get_two_note_edges(0.0, 0.0, TREMOLO_STEM_GAP, 0.0, -1.0, 2).is_empty());
+    }
+
+    #[test]
+    fn strokes_are_clamped() {
+        assert_eq!(Tremolo::new(0, 0, 0).strokes, 1);
+        assert_eq!(Tremolo::new(0, 0, 2).strokes, 2);
+        assert_eq!(Tremolo::new(0, 0, 5).strokes, TREMOLO_MAX_STROKES);
+        assert_eq!(Tremolo::new_two_note(0, 0, 2, 0).strokes, 1);
+        assert_eq!(Tremolo::new_two_note(0, 0, 2, 4).strokes, TREMOLO_MAX_STROKES);
+    }
+
+    #[test]
+    fn two_note_tremolo_between_the_stems() {
+        use crate::render::output::elements::testutils::*;
+        let matrix = get_test_matrix("|clef G | nv2 1 3");
+        let cols = get_test_note_cols(&matrix, 0);
+        let engraving = RenderOptions::default().get_engraving();
+        let tremolos = vec![Tremolo::new_two_note(0, cols[0], cols[1], 2), Tremolo::new(0, cols[0], 3)];
+        let graphic_items = output_two_note_tremolos(&matrix, &tremolos, &engraving);
+        // one beam per stroke, single note tremolos are drawn with the stems
+        assert_eq!(graphic_items.0.len(), 2);
+
+        let get_stem_x = |colidx: usize| {
+            let item = get_row_item(&matrix, 0, colidx).unwrap();
+            let item = item.borrow();
+            get_stem_base(get_beam_data(&item.notedata.beamdata1).unwrap(), NPoint(item.coord_x.unwrap(), item.coord_y.unwrap()), &engraving).0
+        };
+        let (x1, _, x2, _) = get_items_extent(&graphic_items).unwrap();
+        assert!((x1 - (get_stem_x(cols[0]) + TREMOLO_STEM_GAP)).abs() < 0.01);
+        assert!((x2 - (get_stem_x(cols[1]) - TREMOLO_STEM_GAP)).abs() < 0.01);
+    }
+}
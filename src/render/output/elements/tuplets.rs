@@ -0,0 +1,204 @@
+use crate::prelude::*;
+use graphics::prelude::*;
+use notation_rs::prelude::*;
+use std::cell::Ref;
+
+// Height of digits in em units
+const TUPLET_DIGIT_HEIGHT: f32 = 500.0;
+const TUPLET_DISTANCE: f32 = SPACE;
+const TUPLET_HOOK_LENGTH: f32 = SPACE_HALF;
+const TUPLET_BRACKET_WIDTH: f32 = 3.0;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TupletBracket {
+    // Bracket unless a beam spans exactly the tuplet
+    Auto,
+    Always,
+    Never,
+}
+
+#[derive(Debug, Clone)]
+pub struct TupletSpan {
+    pub rowidx: usize,
+    pub from_colidx: usize,
+    pub to_colidx: usize,
+    pub number: u8,
+    // Shown as number:ratio, for example 3:2
+    pub ratio: Option<u8>,
+    pub bracket: TupletBracket,
+}
+
+impl TupletSpan {
+    pub fn new(rowidx: usize, from_colidx: usize, to_colidx: usize, number: u8) -> Self {
+        Self {
+            rowidx,
+            from_colidx,
+            to_colidx,
+            number,
+            ratio: None,
+            bracket: TupletBracket::Auto,
+        }
+    }
+
+    pub fn get_label(&self) -> String {
+        match self.ratio {
+            Some(ratio) => format!("{}:{}", self.number, ratio),
+            None => self.number.to_string(),
+        }
+    }
+}
+
+pub fn output_tuplets(matrix: &RMatrix, render_options: &RenderOptions) -> GraphicItems {
+    let mut graphic_items = GraphicItems::new();
+    let size = render_options.get_text_size(TextRole::Tuplet);
+
+    for tuplet in &render_options.tuplets {
+        let (first, last) = match (get_row_item(matrix, tuplet.rowidx, tuplet.from_colidx), get_row_item(matrix, tuplet.rowidx, tuplet.to_colidx)) {
+            (Some(first), Some(last)) => (first, last),
+            _ => continue,
+        };
+        let first: Ref<RItem> = first.borrow();
+        let last: Ref<RItem> = last.borrow();
+
+        let direction = match get_beam_data(&first.notedata.beamdata1) {
+            Some(data) => data.direction,
+            None => DirUD::Up,
+        };
+        let sign = direction.sign();
+
+        let beamed = beam_spans_tuplet(matrix, tuplet);
+        let draw_bracket = match tuplet.bracket {
+            TupletBracket::Auto => !beamed,
+            TupletBracket::Always => true,
+            TupletBracket::Never => false,
+        };
+
+        // Stem tips of the outer notes gives the slope, following the beam when there is one
+        let (first_stem_x, first_tip_y) = get_tip(&first, direction);
+        let (last_stem_x, last_tip_y) = get_tip(&last, direction);
+        let slope = if last_stem_x > first_stem_x { (last_tip_y - first_tip_y) / (last_stem_x - first_stem_x) } else { 0.0 };
+        let line_y = |x: f32| first_tip_y + slope * (x - first_stem_x);
+
+        // Push the line outwards so that it clears every stem tip and head inside the tuplet
+        let mut clearance: f32 = 0.0;
+        if !beamed {
+            for colidx in tuplet.from_colidx..=tuplet.to_colidx {
+                if let Some(item) = get_row_item(matrix, tuplet.rowidx, colidx) {
+                    let item: Ref<RItem> = item.borrow();
+                    let (stem_x, tip_y) = get_tip(&item, direction);
+                    clearance = clearance.max(sign * (tip_y - line_y(stem_x)));
+                }
+            }
+        }
+        let offset = sign * (clearance + TUPLET_DISTANCE);
+
+        let x1 = first.coord_x.unwrap() - SPACE_QUARTER;
+        let x2 = last.coord_x.unwrap() + HEAD_WIDTH_BLACK + SPACE_QUARTER;
+        let y1 = line_y(x1) + offset;
+        let y2 = line_y(x2) + offset;
+
+        let label = tuplet.get_label();
+        let label_width = render_options.get_text_width(TextRole::Tuplet, &label) * size;
+
+        if draw_bracket {
+            let [hook1, start, gap1, gap2, end, hook2] = get_bracket_points(x1, y1, x2, y2, label_width, sign);
+            graphic_items.push(Path(
+                PathSegments(vec![M(hook1.0, hook1.1), L(start.0, start.1), L(gap1.0, gap1.1), M(gap2.0, gap2.1), L(end.0, end.1), L(hook2.0, hook2.1)]),
+                Strokestyle(TUPLET_BRACKET_WIDTH, Black),
+                NoFill,
+                PathCacheInfo::NoCache,
+            ));
+        }
+
+        let (x, baseline) = get_label_origin(x1, y1, x2, y2, label_width, TUPLET_DIGIT_HEIGHT * size);
+        graphic_items.push(render_options.get_text_item(TextRole::Tuplet, &label, x, baseline));
+    }
+
+    graphic_items
+}
+
+// Hook, end and gap points of a bracket from x1, y1 to x2, y2, with the hooks pointing against sign
+// and a gap for a label of label_width in the middle
+fn get_bracket_points(x1: f32, y1: f32, x2: f32, y2: f32, label_width: f32, sign: f32) -> [(f32, f32); 6] {
+    let xmid = x1 + (x2 - x1) / 2.0;
+    let gap_x1 = xmid - label_width / 2.0 - SPACE_QUARTER;
+    let gap_x2 = xmid + label_width / 2.0 + SPACE_QUARTER;
+    let gap_y1 = y1 + (gap_x1 - x1) * (y2 - y1) / (x2 - x1);
+    let gap_y2 = y1 + (gap_x2 - x1) * (y2 - y1) / (x2 - x1);
+    [(x1, y1 - sign * TUPLET_HOOK_LENGTH), (x1, y1), (gap_x1, gap_y1), (gap_x2, gap_y2), (x2, y2), (x2, y2 - sign * TUPLET_HOOK_LENGTH)]
+}
+
+// Left end of the label baseline, centered on the middle of the bracket line
+fn get_label_origin(x1: f32, y1: f32, x2: f32, y2: f32, label_width: f32, digit_height: f32) -> (f32, f32) {
+    let xmid = x1 + (x2 - x1) / 2.0;
+    let ymid = y1 + (y2 - y1) / 2.0;
+    (xmid - label_width / 2.0, ymid + digit_height / 2.0)
+}
+
+fn get_tip(item: &RItem, direction: DirUD) -> (f32, f32) {
+    let coords = NPoint(item.coord_x.unwrap(), item.coord_y.unwrap());
+    match get_beam_data(&item.notedata.beamdata1) {
+        Some(data) => (coords.0 + get_head_x_adjustment(data), get_stem_tip_y(data, coords)),
+        None => (coords.0, coords.1 + (STEM_LENGTH * SPACE_HALF) * direction.sign()),
+    }
+}
+
+fn beam_spans_tuplet(matrix: &RMatrix, tuplet: &TupletSpan) -> bool {
+    for colidx in tuplet.from_colidx..=tuplet.to_colidx {
+        if let Some(item) = get_row_item(matrix, tuplet.rowidx, colidx) {
+            let item: Ref<RItem> = item.borrow();
+            let is_first = colidx == tuplet.from_colidx;
+            let is_last = colidx == tuplet.to_colidx;
+            match &item.notedata.beamdata1 {
+                RItemBeam::Start(_) if is_first => {}
+                RItemBeam::Middle(_) if !is_first && !is_last => {}
+                RItemBeam::End(_) if is_last => return true,
+                _ => return false,
+            }
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bracket_leaves_gap_for_label() {
+        // rising bracket above upward stems, hooks pointing down to the notes
+        let points = get_bracket_points(0.0, 0.0, 100.0, 20.0, 20.0, -1.0);
+        assert_eq!(points[0], (0.0, TUPLET_HOOK_LENGTH));
+        assert_eq!(points[5], (100.0, 20.0 + TUPLET_HOOK_LENGTH));
+        let gap_x1 = 40.0 - SPACE_QUARTER;
+        let gap_x2 = 60.0 + SPACE_QUARTER;
+        assert_eq!(points[2], (gap_x1, gap_x1 * 20.0 / 100.0));
+        assert_eq!(points[3], (gap_x2, gap_x2 * 20.0 / 100.0));
+        assert_eq!(get_label_origin(0.0, 0.0, 100.0, 20.0, 20.0, 30.0), (40.0, 25.0));
+        assert_eq!(TupletSpan { ratio: Some(2), ..TupletSpan::new(0, 0, 2, 3) }.get_label(), "3:2");
+    }
+
+    #[test]
+    fn bracket_over_unbeamed_notes() {
+        use crate::render::output::elements::testutils::*;
+        // upward stems, so the bracket goes above the stem tips
+        let matrix = get_test_matrix("|clef G | 1 2 3");
+        let cols = get_test_note_cols(&matrix, 0);
+        let mut render_options = RenderOptions::default();
+        render_options.tuplets.push(TupletSpan::new(0, cols[0], cols[2], 3));
+        let (x1, _, x2, y2) = get_items_extent(&output_tuplets(&matrix, &render_options)).unwrap();
+        assert!((x1 - (get_test_col_x(&matrix, cols[0]) - SPACE_QUARTER)).abs() < 0.01);
+        assert!((x2 - (get_test_col_x(&matrix, cols[2]) + HEAD_WIDTH_BLACK + SPACE_QUARTER)).abs() < 0.01);
+        // the bracket follows the tips down to the last note and stays clear of it
+        let last = get_row_item(&matrix, 0, cols[2]).unwrap();
+        let last = last.borrow();
+        let tip_y = get_stem_tip_y(get_beam_data(&last.notedata.beamdata1).unwrap(), NPoint(last.coord_x.unwrap(), last.coord_y.unwrap()));
+        assert!(y2 < tip_y);
+
+        // without the bracket only the number is drawn, centered over the notes
+        render_options.tuplets[0].bracket = TupletBracket::Never;
+        let (label_x1, _, label_x2, _) = get_items_extent(&output_tuplets(&matrix, &render_options)).unwrap();
+        assert!(label_x1 > x1 && label_x2 < x2);
+        assert!(output_tuplets(&matrix, &RenderOptions::default()).0.is_empty());
+    }
+}
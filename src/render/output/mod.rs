@@ -1,3 +1,4 @@
 pub mod elements;
+pub mod options;
 pub mod output;
 pub mod rects2graphic;
@@ -0,0 +1,122 @@
+use crate::prelude::*;
+use crate::render::fonts::metrics::GlyphBBox;
+pub use crate::render::fonts::registry::{FontRegistry, TextRole};
+use crate::render::fonts::smufl::{EngravingDefaults, MusicFont, MusicGlyph};
+use crate::render::fonts::truetype::LoadedFont;
+use graphics::path::PathSegment;
+use graphics::prelude::*;
+use std::rc::Rc;
+
+/// Render-side options and annotations that are not carried by the `RMatrix` itself.
+#[derive(Debug, Clone, Default)]
+pub struct RenderOptions {
+    pub tuplets: Vec<TupletSpan>,
+    pub ornaments: Vec<OrnamentMark>,
+    pub spanners: Vec<Spanner>,
+    pub smallnotes: Vec<SmallNoteGroup>,
+    pub tremolos: Vec<Tremolo>,
+    // Brackets and braces left of the system, one bracket over all staves when empty
+    pub ackolades: Vec<AckoladeGroup>,
+    // Rows without a template get the five line staff
+    pub staves: Vec<StaffTemplate>,
+    // Added with add_tab_staff, which gives the row a staff line per string
+    pub tab_staves: Vec<TabStaff>,
+    pub tab_notes: Vec<TabNote>,
+    pub palm_mutes: Vec<PalmMute>,
+    pub chords: Vec<ChordMark>,
+    pub chord_alterations: AlterationLayout,
+    pub chord_style: ChordStyle,
+    // Tonic for Nashville numbers and Roman numerals, from the key signature of the row in chord_mode when None
+    pub chord_key: Option<ChordPitch>,
+    pub chord_mode: KeyMode,
+    // Fretboard diagrams above chord symbols, from chord_voicings before the built-in voicings
+    pub chord_diagrams: bool,
+    pub chord_voicings: Vec<ChordVoicing>,
+    pub functions: Vec<FunctionMark>,
+    pub figured_bass: Vec<FiguredBass>,
+    pub lyrics: Vec<LyricVerse>,
+    pub interval_lines: Vec<IntervalLine>,
+    pub interval_naming: IntervalNaming,
+    pub analysis_brackets: Vec<AnalysisBracket>,
+    pub keyboards: Vec<KeyboardDiagram>,
+    pub roman_numerals: Vec<RomanAnalysis>,
+    pub roman_style: RomanStyle,
+    // Scale degree symbols for TplSymbol rects
+    pub degree_style: DegreeStyle,
+    pub degree_octaves: DegreeOctaveMark,
+    // Named text styles with their fonts and fallback chains
+    pub fonts: FontRegistry,
+    // Replaces the compiled Cadenza glyphs when set
+    pub music_font: Option<Rc<MusicFont>>,
+}
+
+impl RenderOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Tablature row, drawn as a staff with a line per string
+    pub fn add_tab_staff(&mut self, staff: TabStaff) {
+        self.staves.retain(|template| template.rowidx != staff.rowidx);
+        self.staves.push(StaffTemplate::new(staff.rowidx, staff.strings));
+        self.tab_staves.push(staff);
+    }
+
+    pub fn set_text_font(&mut self, role: TextRole, font: LoadedFont) {
+        self.fonts.set_font(role, font);
+    }
+
+    pub fn get_text_glyph(&self, role: TextRole, c: char) -> Vec<PathSegment> {
+        self.fonts.get_glyph(role, c)
+    }
+
+    // Advance in em units of the glyph used for the role
+    pub fn get_text_advance(&self, role: TextRole, c: char) -> f32 {
+        self.fonts.get_advance(role, c)
+    }
+
+    pub fn get_text_bbox(&self, role: TextRole, c: char) -> Option<GlyphBBox> {
+        self.fonts.get_bbox(role, c)
+    }
+
+    pub fn get_text_kerning(&self, role: TextRole, left: char, right: char) -> f32 {
+        self.fonts.get_kerning(role, left, right)
+    }
+
+    // Width in em units of a text run, advances plus kerning
+    pub fn get_text_width(&self, role: TextRole, text: &str) -> f32 {
+        self.fonts.get_text_width(role, text)
+    }
+
+    // Outlines of a text run laid out with advances and kerning, in em units from the origin
+    pub fn get_text_path(&self, role: TextRole, text: &str) -> PathSegments {
+        self.fonts.get_text_path(role, text)
+    }
+
+    // Scale from em units to render units for the role
+    pub fn get_text_size(&self, role: TextRole) -> f32 {
+        self.fonts.get_size(role)
+    }
+
+    // Text run as a path item with its baseline at x, y
+    pub fn get_text_item(&self, role: TextRole, text: &str, x: f32, y: f32) -> GraphicItem {
+        self.fonts.get_text_item(role, text, x, y)
+    }
+
+    pub fn set_music_font(&mut self, font: MusicFont) {
+        self.music_font = Some(Rc::new(font));
+    }
+
+    // Glyph from the music font, None when no music font is set or the font lacks the glyph
+    pub fn get_music_glyph(&self, glyph: MusicGlyph) -> Option<PathSegments> {
+        let font = self.music_font.as_ref()?;
+        font.get_path(glyph).map(PathSegments)
+    }
+
+    pub fn get_engraving(&self) -> EngravingDefaults {
+        match &self.music_font {
+            Some(font) => font.get_engraving_defaults(),
+            None => EngravingDefaults::default(),
+        }
+    }
+}
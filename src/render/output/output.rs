@@ -9,17 +9,39 @@ pub fn matrix_to_svg(
     matrix: &RMatrix,
     draw_dev_frames: bool,
     options: Option<BuilderOptions>,
+) -> String {
+    matrix_to_svg_with_options(matrix, draw_dev_frames, options, &RenderOptions::default())
+}
+
+pub fn matrix_to_svg_with_options(
+    matrix: &RMatrix,
+    draw_dev_frames: bool,
+    options: Option<BuilderOptions>,
+    render_options: &RenderOptions,
 ) -> String {
     let mut graphic_items = GraphicItems::new();
 
-    graphic_items.extend(output_ackolades(matrix));
-    graphic_items.extend(output_notelines(matrix));
-    graphic_items.extend(output_main_elements(matrix, draw_dev_frames));
-    graphic_items.extend(output_beamgroups(matrix));
+    graphic_items.extend(output_ackolades(matrix, render_options));
+    graphic_items.extend(output_notelines(matrix, render_options));
+    graphic_items.extend(output_main_elements(matrix, draw_dev_frames, render_options));
+    graphic_items.extend(output_beamgroups(matrix, render_options));
+    graphic_items.extend(output_tablature(matrix, render_options));
     graphic_items.extend(output_ties(matrix));
-    graphic_items.extend(output_lines(matrix));
+    graphic_items.extend(output_lines(matrix, render_options));
+    graphic_items.extend(output_smallnotes(matrix, render_options));
+    graphic_items.extend(output_tuplets(matrix, render_options));
+    graphic_items.extend(output_ornaments(matrix, render_options));
+    graphic_items.extend(output_spanners(matrix, render_options));
+    graphic_items.extend(output_chords(matrix, render_options));
+    graphic_items.extend(output_functions(matrix, render_options));
+    graphic_items.extend(output_figured_bass(matrix, render_options));
+    graphic_items.extend(output_roman_numerals(matrix, render_options));
+    graphic_items.extend(output_lyrics(matrix, render_options));
+    graphic_items.extend(output_interval_lines(matrix, render_options));
+    graphic_items.extend(output_analysis_brackets(matrix, render_options));
+    graphic_items.extend(output_keyboards(matrix, render_options));
 
-    graphic_items.extend(output_row_nrects(matrix));
+    graphic_items.extend(output_row_nrects(matrix, render_options));
 
     let svg = SvgBuilder::new().build(graphic_items, options).unwrap();
     // std::fs::write(svg_filename, svg).unwrap();
@@ -34,11 +56,11 @@ pub fn matrix_to_fuse(
     fuse_category: &str,
 ) -> String {
     let mut graphic_items = GraphicItems::new();
-    graphic_items.extend(output_notelines(matrix));
-    graphic_items.extend(output_main_elements(matrix, draw_dev_frames));
-    graphic_items.extend(output_beamgroups(matrix));
+    graphic_items.extend(output_notelines(matrix, &RenderOptions::default()));
+    graphic_items.extend(output_main_elements(matrix, draw_dev_frames, &RenderOptions::default()));
+    graphic_items.extend(output_beamgroups(matrix, &RenderOptions::default()));
     graphic_items.extend(output_ties(matrix));
-    graphic_items.extend(output_lines(matrix));
+    graphic_items.extend(output_lines(matrix, &RenderOptions::default()));
 
     let scale = match options {
         Some(options) => options.size_scaling,
@@ -1,9 +1,5 @@
 use crate::prelude::*;
-use crate::render::fonts::opensans_regular::{
-    OPENSANS_REGULAR_171, OPENSANS_REGULAR_189, OPENSANS_REGULAR_48, OPENSANS_REGULAR_49, OPENSANS_REGULAR_50, OPENSANS_REGULAR_51, OPENSANS_REGULAR_52, OPENSANS_REGULAR_53, OPENSANS_REGULAR_54,
-    OPENSANS_REGULAR_55, OPENSANS_REGULAR_56, OPENSANS_REGULAR_57,
-};
-use crate::render::fonts::svg_symbols::SVG_SYMBOL_TPL_CIRCLE;
+use crate::render::fonts::smufl::MusicGlyph;
 use graphics::prelude::*;
 use notation_rs::prelude::*;
 use std::cell::Ref;
@@ -31,8 +27,178 @@ pub fn ncolor_to_color(ncolor: &NColor) -> Color {
     }
 }
 
-pub fn nrectext2graphic(n: &NRectExt, move_x: f32, move_y: f32) -> Vec<GraphicItem> {
+// Key signature accidental levels in half spaces from the middle line, for the G clef
+const KEY_SHARP_LEVELS: [i8; 6] = [-4, -1, -5, -2, 1, -3];
+const KEY_FLAT_LEVELS: [i8; 6] = [0, -3, 1, -2, 2, -1];
+
+// Distance from the top of an accidental rect down to the level of its head, where the glyph origin sits
+pub fn get_accidental_anchor_y(accidental: &Accidental) -> f32 {
+    match accidental {
+        Accidental::Flat => SPACE * 2.0,
+        _ => SPACE * 1.5,
+    }
+}
+
+fn time_nominator_number(nom: &TimeNominator) -> u8 {
+    match nom {
+        TimeNominator::One => 1,
+        TimeNominator::Two => 2,
+        TimeNominator::Three => 3,
+        TimeNominator::Four => 4,
+        TimeNominator::Five => 5,
+        TimeNominator::Six => 6,
+        TimeNominator::Seven => 7,
+        TimeNominator::Eight => 8,
+        TimeNominator::Nine => 9,
+        TimeNominator::Twelve => 12,
+    }
+}
+
+fn time_denominator_number(denom: &TimeDenominator) -> u8 {
+    match denom {
+        TimeDenominator::Wholes => 1,
+        TimeDenominator::Halves => 2,
+        TimeDenominator::Quarters => 4,
+        TimeDenominator::Egigths => 8,
+    }
+}
+
+// Glyphs from the SMuFL music font, placed by their origins relative to the staff.
+// Returns None for rect types that are not drawn with music font glyphs.
+fn music_glyph2graphic(n: &NRectExt, r: &NRect, move_y: f32, render_options: &RenderOptions) -> Option<Vec<GraphicItem>> {
+    let font = render_options.music_font.as_ref()?;
+    let engraving = render_options.get_engraving();
+    let glyph_item = |p: PathSegments| Path(p, NoStroke, Fillstyle(Black), PathCacheInfo::NoCache);
+
+    match &n.1 {
+        NRectType::Head(_, head_shape, head_color) => {
+            let glyph = match head_shape {
+                HeadShape::BlackHead => MusicGlyph::NoteheadBlack,
+                HeadShape::WhiteHead => MusicGlyph::NoteheadHalf,
+                HeadShape::WholeHead => MusicGlyph::NoteheadWhole,
+            };
+            let p = render_options.get_music_glyph(glyph)?;
+            Some(vec![Path(p.move_path(r.0, r.1 + SPACE_HALF), NoStroke, Fillstyle(ncolor_to_color(head_color)), PathCacheInfo::NoCache)])
+        }
+
+        NRectType::Dotted(_) => {
+            let p = render_options.get_music_glyph(MusicGlyph::AugmentationDot)?;
+            Some(vec![glyph_item(p.move_path(r.0, r.1 + r.3 / 2.0))])
+        }
+
+        NRectType::Pause(pause_type) => {
+            let (glyph, y) = match pause_type {
+                // the whole rest hangs from the second line from the top
+                PauseShape::Whole => (MusicGlyph::RestWhole, move_y - SPACE),
+                PauseShape::Half => (MusicGlyph::RestHalf, move_y),
+                PauseShape::Quarter => (MusicGlyph::RestQuarter, move_y),
+                PauseShape::Eighth => (MusicGlyph::Rest8th, move_y),
+                PauseShape::Sixteenth => (MusicGlyph::Rest16th, move_y),
+                PauseShape::ThirtySecond => (MusicGlyph::Rest32nd, move_y),
+            };
+            let p = render_options.get_music_glyph(glyph)?;
+            Some(vec![glyph_item(p.move_path(r.0, y))])
+        }
+
+        NRectType::Flag(beamtype, direction) => {
+            let (glyph, anchor, y) = match (direction, beamtype) {
+                (DirUD::Up, BeamType::B8) => (MusicGlyph::Flag8thUp, "stemUpNW", r.1),
+                (DirUD::Up, BeamType::B16) => (MusicGlyph::Flag16thUp, "stemUpNW", r.1),
+                (DirUD::Down, BeamType::B8) => (MusicGlyph::Flag8thDown, "stemDownSW", r.1 + r.3),
+                (DirUD::Down, BeamType::B16) => (MusicGlyph::Flag16thDown, "stemDownSW", r.1 + r.3),
+                _ => return Some(vec![]),
+            };
+            let p = render_options.get_music_glyph(glyph)?;
+            // attach the flag to the left edge of the stem
+            let (anchor_x, anchor_y) = font.get_anchor(glyph, anchor).unwrap_or((0.0, 0.0));
+            Some(vec![glyph_item(p.move_path(r.0 - engraving.stem_width / 2.0 - anchor_x, y - anchor_y))])
+        }
+
+        NRectType::Clef(clef) => {
+            // clef origins sit on their reference lines
+            let (glyph, y) = match clef {
+                Clef::G => (MusicGlyph::GClef, move_y + SPACE),
+                Clef::F => (MusicGlyph::FClef, move_y - SPACE),
+                Clef::C => (MusicGlyph::CClef, move_y),
+            };
+            let p = render_options.get_music_glyph(glyph)?;
+            Some(vec![glyph_item(p.move_path(r.0, y))])
+        }
+
+        NRectType::KeySignature(key, opt_clef) => {
+            let (glyph, levels, count) = match key {
+                Key::Sharps(n, _) => (MusicGlyph::AccidentalSharp, KEY_SHARP_LEVELS, *n as usize),
+                Key::Flats(n, _) => (MusicGlyph::AccidentalFlat, KEY_FLAT_LEVELS, *n as usize),
+                _ => return None,
+            };
+            let clef_shift: i8 = match opt_clef {
+                Some(Clef::F) => 2,
+                Some(Clef::C) => 1,
+                _ => 0,
+            };
+            let accidental = render_options.get_music_glyph(glyph)?;
+            let advance = font.get_advance(glyph).unwrap_or(ACCIDENTAL_WIDTH_SHARP);
+            let mut a = PathSegments(vec![]);
+            for (idx, level) in levels.iter().take(count.min(levels.len())).enumerate() {
+                let y = move_y + (level + clef_shift) as f32 * SPACE_HALF;
+                a.extend(&accidental.clone().move_path(r.0 + advance * idx as f32, y));
+            }
+            Some(vec![glyph_item(a)])
+        }
+
+        NRectType::TimeSignature(time) => {
+            let a = match time {
+                Time::Common => render_options.get_music_glyph(MusicGlyph::TimeSigCommon)?.move_path(r.0, move_y),
+                Time::Cut => render_options.get_music_glyph(MusicGlyph::TimeSigCutCommon)?.move_path(r.0, move_y),
+                Time::Standard(nom, denom) => {
+                    let nom = time_nominator_number(nom).to_string();
+                    let denom = time_denominator_number(denom).to_string();
+                    let get_width = |digits: &str| digits.chars().map(|c| font.get_advance(MusicGlyph::TimeSig(c as u8 - b'0')).unwrap_or(SPACE * 2.0)).sum::<f32>();
+                    let width = get_width(&nom).max(get_width(&denom));
+
+                    let mut a = PathSegments(vec![]);
+                    for (digits, y) in [(nom, move_y - SPACE), (denom, move_y + SPACE)] {
+                        // each line of digits is centered
+                        let mut x = r.0 + (width - get_width(&digits)) / 2.0;
+                        for c in digits.chars() {
+                            let glyph = MusicGlyph::TimeSig(c as u8 - b'0');
+                            a.extend(&render_options.get_music_glyph(glyph)?.move_path(x, y));
+                            x += font.get_advance(glyph).unwrap_or(SPACE * 2.0);
+                        }
+                    }
+                    a
+                }
+            };
+            Some(vec![glyph_item(a)])
+        }
+
+        NRectType::Accidental(accidental) => {
+            let glyph = match accidental {
+                Accidental::Sharp => MusicGlyph::AccidentalSharp,
+                Accidental::Flat => MusicGlyph::AccidentalFlat,
+                Accidental::Natural => MusicGlyph::AccidentalNatural,
+                Accidental::DblSharp => MusicGlyph::AccidentalDoubleSharp,
+                Accidental::DblFlat => MusicGlyph::AccidentalDoubleFlat,
+            };
+            let p = render_options.get_music_glyph(glyph)?;
+            Some(vec![glyph_item(p.move_path(r.0, r.1 + r.3 / 2.0))])
+        }
+
+        NRectType::Barline(BarlineType::Single) => Some(vec![Rect(r.0 + r.2 - engraving.barline_width, r.1, engraving.barline_width, r.3, NoStroke, Fillstyle(Black))]),
+
+        NRectType::HelpLine => Some(vec![Rect(r.0, r.1 + (r.3 - engraving.ledger_line_width) / 2.0, r.2, engraving.ledger_line_width, NoStroke, Fillstyle(Black))]),
+
+        _ => None,
+    }
+}
+
+pub fn nrectext2graphic(n: &NRectExt, move_x: f32, move_y: f32, render_options: &RenderOptions) -> Vec<GraphicItem> {
     let r = n.0.move_rect(move_x, move_y);
+    if render_options.music_font.is_some() {
+        if let Some(items) = music_glyph2graphic(n, &r, move_y, render_options) {
+            return items;
+        }
+    }
     match &n.1 {
         NRectType::Head(head_type, head_shape, head_color) => {
             //
@@ -125,27 +291,10 @@ pub fn nrectext2graphic(n: &NRectExt, move_x: f32, move_y: f32) -> Vec<GraphicIt
             }
         }
 
-        NRectType::TplSymbol(figure_nr, octave, accidental) => {
-            let mut circle = PathSegments(SVG_SYMBOL_TPL_CIRCLE.to_vec()).scale_path(0.14, 0.14).move_path(r.0 - 5.9 * SPACE, r.1 - 0.0 * SPACE);
-
-            let figure = match figure_nr {
-                '0' => PathSegments(OPENSANS_REGULAR_48.to_vec()).scale_path(0.07, 0.07).move_path(r.0 + SPACE * 0.85, r.1 + SPACE * 2.2),
-                '1' => PathSegments(OPENSANS_REGULAR_49.to_vec()).scale_path(0.07, 0.07).move_path(r.0 + SPACE * 0.85, r.1 + SPACE * 2.2),
-                '2' => PathSegments(OPENSANS_REGULAR_50.to_vec()).scale_path(0.07, 0.07).move_path(r.0 + SPACE * 0.85, r.1 + SPACE * 2.2),
-                '3' => PathSegments(OPENSANS_REGULAR_51.to_vec()).scale_path(0.07, 0.07).move_path(r.0 + SPACE * 0.85, r.1 + SPACE * 2.2),
-                '4' => PathSegments(OPENSANS_REGULAR_52.to_vec()).scale_path(0.07, 0.07).move_path(r.0 + SPACE * 0.85, r.1 + SPACE * 2.2),
-                '5' => PathSegments(OPENSANS_REGULAR_53.to_vec()).scale_path(0.07, 0.07).move_path(r.0 + SPACE * 0.85, r.1 + SPACE * 2.2),
-                '6' => PathSegments(OPENSANS_REGULAR_54.to_vec()).scale_path(0.07, 0.07).move_path(r.0 + SPACE * 0.85, r.1 + SPACE * 2.2),
-                '7' => PathSegments(OPENSANS_REGULAR_55.to_vec()).scale_path(0.07, 0.07).move_path(r.0 + SPACE * 0.85, r.1 + SPACE * 2.2),
-                '8' => PathSegments(OPENSANS_REGULAR_56.to_vec()).scale_path(0.07, 0.07).move_path(r.0 + SPACE * 0.85, r.1 + SPACE * 2.2),
-                '9' => PathSegments(OPENSANS_REGULAR_57.to_vec()).scale_path(0.07, 0.07).move_path(r.0 + SPACE * 0.85, r.1 + SPACE * 2.2),
-                _ => PathSegments(OPENSANS_REGULAR_48.to_vec()).scale_path(0.07, 0.07).move_path(r.0 + SPACE * 0.85, r.1 + SPACE * 2.2),
-            };
-
-            circle.extend(&figure);
-
-            vec![Path(circle, NoStroke, Fillstyle(Black), PathCacheInfo::NoCache)]
-        }
+        NRectType::TplSymbol(figure_nr, octave, accidental) => match ScaleDegree::from_notation(figure_nr, octave, accidental) {
+            Some(degree) => degree2graphic(&degree, r.0 + r.2 * 0.5, r.1 + r.3 * 0.5, render_options),
+            None => vec![],
+        },
 
         NRectType::Clef(clef) => match clef {
             Clef::G => vec![Path(
@@ -290,10 +439,7 @@ pub fn nrectext2graphic(n: &NRectExt, move_x: f32, move_y: f32) -> Vec<GraphicIt
                 Accidental::DblFlat => CADENZA_ACCIDENTAL_DOUBLEFLAT.to_vec(),
                 // _ => CADENZA_ACCIDENTAL_FLAT.to_vec(),
             };
-            let y = match accidental {
-                Accidental::Flat => SPACE * 2.0,
-                _ => SPACE * 1.5,
-            };
+            let y = get_accidental_anchor_y(accidental);
             //
             vec![Path(PathSegments(p).inv01().move_path(r.0, r.1 + y), NoStroke, Fillstyle(Black), PathCacheInfo::NoCache)]
         }
@@ -348,12 +494,13 @@ pub fn nrectext2graphic(n: &NRectExt, move_x: f32, move_y: f32) -> Vec<GraphicIt
         NRectType::HelpLine => vec![Rect(r.0, r.1, r.2, r.3, NoStroke, Fillstyle(Black))],
 
         NRectType::LyricChar(c) => {
-            let path = crate::render::fonts::merriweather_regular::get_path(*c).to_vec();
+            let size = render_options.get_text_size(TextRole::Lyrics);
+            let path = render_options.get_text_glyph(TextRole::Lyrics, *c);
             vec![Path(
                 PathSegments(path)
-                    .scale_path(LYRICS_FONT_SCALE, LYRICS_FONT_SCALE)
-                    .move_path(r.0, r.1 + GLYPH_HEIGHT * LYRICS_FONT_SCALE),
-                NoStroke,
+                    .scale_path(size, size)
+                    .move_path(r.0, r.1 + GLYPH_HEIGHT * size),
+                render_options.fonts.get_stroke(TextRole::Lyrics),
                 Fillstyle(Black),
                 PathCacheInfo::NoCache,
             )]
@@ -372,497 +519,12 @@ pub fn nrectext2graphic(n: &NRectExt, move_x: f32, move_y: f32) -> Vec<GraphicIt
             vec![]
         }
 
-        NRectType::ChordSymbol(chord_root, chord_flavour, chord_color, chord_bass) => {
-            let mut v = Vec::new();
-
-            let mut root_x = 0.0;
-            let mut root_acc_x = 0.0;
-            let mut flavour_x = 0.0;
-            let mut color_acc_x = 0.0;
-            let mut color_x = 0.0;
-            let mut bass_slash_x = 0.0;
-            let mut bass_x = 0.0;
-            let mut bass_acc_x = 0.0;
-            let mut width = 0.0;
-            let mut x = CHORD_MARGIN * 0.5;
-
-            // Root
-            root_x = width;
-            root_acc_x = width;
-            match chord_root {
-                ChordRoot::None => {}
-                _ => {
-                    let root_char = chord_root.get_char();
-                    match root_char {
-                        'G' | 'D' => width = width + 50.0,
-                        'A' | 'B' => width = width + 45.0,
-                        _ => width = width + 40.0,
-                    }
-                    root_acc_x += width;
-
-                    // Root sign
-                    match chord_root {
-                        ChordRoot::CFlat | ChordRoot::DFlat | ChordRoot::EFlat | ChordRoot::FFlat | ChordRoot::GFlat | ChordRoot::AFlat | ChordRoot::BFlat => {
-                            width = width + 30.0;
-                        }
-                        ChordRoot::CSharp | ChordRoot::DSharp | ChordRoot::ESharp | ChordRoot::FSharp | ChordRoot::GSharp | ChordRoot::ASharp | ChordRoot::BSharp => {
-                            width = width + 30.0;
-                        }
-                        _ => {}
-                    }
-                }
-            }
-
-            // Flavour Major/Minor
-            flavour_x = width;
-            match chord_flavour {
-                ChordFlavour::Minor => {
-                    width = width + 60.0;
-                }
-                _ => {}
-            }
-
-            // Colour sign
-            color_acc_x = width;
-            color_x = width;
-
-            match chord_color {
-                ChordColor::None => {}
-                _ => {
-                    match chord_color {
-                        ChordColor::PlusFive => {
-                            width = width + 15.0;
-                        }
-                        ChordColor::MinusNine => {
-                            width = width + 15.0;
-                        }
-                        ChordColor::SusTwo | ChordColor::SusFour | ChordColor::MajSeven => {
-                            width = width + 22.0;
-                            // color_acc_x = width;
-                            width = width + 28.0;
-                            // color_acc_x = width;
-                            width = width + 25.0;
-                            // color_acc_x = width;
-                        }
-                        _ => {}
-                    }
-                    color_x = width;
-
-                    // Colour figure
-                    match chord_color {
-                        ChordColor::SusTwo
-                        | ChordColor::SusFour
-                        | ChordColor::Five
-                        | ChordColor::PlusFive
-                        | ChordColor::Six
-                        | ChordColor::Seven
-                        | ChordColor::MajSeven
-                        | ChordColor::MinusNine
-                        | ChordColor::Nine
-                        | ChordColor::PlusNine => {
-                            width = width + 25.0;
-                        }
-                        _ => {}
-                    }
-                }
-            }
-
-            // Bass slash
-            match chord_bass {
-                ChordRoot::None => {}
-                _ => {
-                    bass_slash_x = width;
-                    width = width + 28.0;
-                    // slash
-                    bass_x = width;
-                    // bass root
-                    let root_char = chord_bass.get_char();
-                    match root_char {
-                        'G' | 'D' => width = width + 60.0,
-                        'A' | 'B' => width = width + 55.0,
-                        _ => width = width + 52.0,
-                    }
-
-                    // bass sign
-                    bass_acc_x = width;
-                    width += 25.0;
-                }
-            }
-
-            //-------------------------------------------------------------------
-            // Root
-            match chord_root {
-                ChordRoot::None => {}
-                _ => {
-                    let root_char = chord_root.get_char();
-                    let path = crate::render::fonts::merriweather_regular::get_path(root_char).to_vec();
-                    v.push(Path(
-                        PathSegments(path)
-                            .scale_path(CHORD_FONT_SCALE, CHORD_FONT_SCALE)
-                            .move_path(r.0 + x + root_x, r.1 + GLYPH_HEIGHT * CHORD_FONT_SCALE),
-                        NoStroke,
-                        Fillstyle(Black),
-                        PathCacheInfo::NoCache,
-                    ));
-
-                    // Root sign
-                    match chord_root {
-                        ChordRoot::CFlat | ChordRoot::DFlat | ChordRoot::EFlat | ChordRoot::FFlat | ChordRoot::GFlat | ChordRoot::AFlat | ChordRoot::BFlat => {
-                            let path = CADENZA_ACCIDENTAL_FLAT.to_vec();
-                            let acc = PathSegments(CADENZA_ACCIDENTAL_FLAT.to_vec())
-                                .scale_path(CHORD_FONT_SCALE, -CHORD_FONT_SCALE)
-                                .move_path(r.0 + x + root_acc_x, r.1 + GLYPH_HEIGHT * CHORD_FONT_SCALE - SPACE * 1.3);
-                            v.push(Path(acc, NoStroke, Fillstyle(Black), PathCacheInfo::NoCache));
-                        }
-                        ChordRoot::CSharp | ChordRoot::DSharp | ChordRoot::ESharp | ChordRoot::FSharp | ChordRoot::GSharp | ChordRoot::ASharp | ChordRoot::BSharp => {
-                            let path = CADENZA_ACCIDENTAL_SHARP.to_vec();
-                            let acc = PathSegments(CADENZA_ACCIDENTAL_SHARP.to_vec())
-                                .scale_path(CHORD_FONT_SCALE * 0.9, -CHORD_FONT_SCALE * 0.9)
-                                .move_path(r.0 + x + root_acc_x, r.1 + GLYPH_HEIGHT * CHORD_FONT_SCALE - SPACE * 1.6);
-                            v.push(Path(acc, NoStroke, Fillstyle(Black), PathCacheInfo::NoCache));
-                        }
-                        _ => {}
-                    }
-                }
-            }
-
-            match chord_flavour {
-                ChordFlavour::Minor => {
-                    let path = crate::render::fonts::merriweather_regular::get_path('m').to_vec();
-                    v.push(Path(
-                        PathSegments(path)
-                            .scale_path(CHORD_FONT_SCALE, CHORD_FONT_SCALE)
-                            .move_path(r.0 + x + flavour_x, r.1 + GLYPH_HEIGHT * CHORD_FONT_SCALE),
-                        NoStroke,
-                        Fillstyle(Black),
-                        PathCacheInfo::NoCache,
-                    ));
-                }
-                _ => {}
-            }
-
-            match chord_color {
-                ChordColor::None => {}
-                _ => {
-                    match chord_color {
-                        ChordColor::PlusFive => {
-                            let acc = PathSegments(CADENZA_ACCIDENTAL_SHARP.to_vec())
-                                .scale_path(CHORD_FONT_ACCIDENTAL_SCALE, -CHORD_FONT_ACCIDENTAL_SCALE)
-                                .move_path(r.0 + x + color_acc_x, r.1 + GLYPH_HEIGHT * CHORD_FONT_ACCIDENTAL_SCALE - SPACE * 0.7);
-                            v.push(Path(acc, NoStroke, Fillstyle(Black), PathCacheInfo::NoCache));
-                        }
-                        ChordColor::MinusNine => {
-                            let acc = PathSegments(CADENZA_ACCIDENTAL_FLAT.to_vec())
-                                .scale_path(CHORD_FONT_ACCIDENTAL_SCALE, -CHORD_FONT_ACCIDENTAL_SCALE)
-                                .move_path(r.0 + x + color_acc_x, r.1 + GLYPH_HEIGHT * CHORD_FONT_ACCIDENTAL_SCALE - SPACE * 0.7);
-                            v.push(Path(acc, NoStroke, Fillstyle(Black), PathCacheInfo::NoCache));
-                        }
-                        ChordColor::SusTwo | ChordColor::SusFour => {
-                            let path = crate::render::fonts::merriweather_regular::get_path('s').to_vec();
-                            v.push(Path(
-                                PathSegments(path)
-                                    .scale_path(CHORD_FONT_FIGURE_SCALE, CHORD_FONT_FIGURE_SCALE)
-                                    .move_path(r.0 + x + color_acc_x, r.1 + GLYPH_HEIGHT * CHORD_FONT_FIGURE_SCALE - SPACE * 0.3),
-                                NoStroke,
-                                Fillstyle(Black),
-                                PathCacheInfo::NoCache,
-                            ));
-                            let path = crate::render::fonts::merriweather_regular::get_path('u').to_vec();
-                            v.push(Path(
-                                PathSegments(path)
-                                    .scale_path(CHORD_FONT_FIGURE_SCALE, CHORD_FONT_FIGURE_SCALE)
-                                    .move_path(r.0 + x + color_acc_x + 22.0, r.1 + GLYPH_HEIGHT * CHORD_FONT_FIGURE_SCALE - SPACE * 0.3),
-                                NoStroke,
-                                Fillstyle(Black),
-                                PathCacheInfo::NoCache,
-                            ));
-                            let path = crate::render::fonts::merriweather_regular::get_path('s').to_vec();
-                            v.push(Path(
-                                PathSegments(path)
-                                    .scale_path(CHORD_FONT_FIGURE_SCALE, CHORD_FONT_FIGURE_SCALE)
-                                    .move_path(r.0 + x + color_acc_x + 49.0, r.1 + GLYPH_HEIGHT * CHORD_FONT_FIGURE_SCALE - SPACE * 0.3),
-                                NoStroke,
-                                Fillstyle(Black),
-                                PathCacheInfo::NoCache,
-                            ));
-                        }
-                        ChordColor::MajSeven => {
-                            let path = crate::render::fonts::merriweather_regular::get_path('m').to_vec();
-                            v.push(Path(
-                                PathSegments(path)
-                                    .scale_path(CHORD_FONT_FIGURE_SCALE, CHORD_FONT_FIGURE_SCALE)
-                                    .move_path(r.0 + x + color_acc_x - 5.0, r.1 + GLYPH_HEIGHT * CHORD_FONT_FIGURE_SCALE - SPACE * 0.3),
-                                NoStroke,
-                                Fillstyle(Black),
-                                PathCacheInfo::NoCache,
-                            ));
-                            let path = crate::render::fonts::merriweather_regular::get_path('a').to_vec();
-                            v.push(Path(
-                                PathSegments(path)
-                                    .scale_path(CHORD_FONT_FIGURE_SCALE, CHORD_FONT_FIGURE_SCALE)
-                                    .move_path(r.0 + x + color_acc_x + 40.0, r.1 + GLYPH_HEIGHT * CHORD_FONT_FIGURE_SCALE - SPACE * 0.3),
-                                NoStroke,
-                                Fillstyle(Black),
-                                PathCacheInfo::NoCache,
-                            ));
-                            let path = crate::render::fonts::merriweather_regular::get_path('j').to_vec();
-                            v.push(Path(
-                                PathSegments(path)
-                                    .scale_path(CHORD_FONT_FIGURE_SCALE, CHORD_FONT_FIGURE_SCALE)
-                                    .move_path(r.0 + x + color_acc_x + 65.0, r.1 + GLYPH_HEIGHT * CHORD_FONT_FIGURE_SCALE - SPACE * 0.3),
-                                NoStroke,
-                                Fillstyle(Black),
-                                PathCacheInfo::NoCache,
-                            ));
-                        }
-                        _ => {}
-                    }
-
-                    // Colour figure
-                    match chord_color {
-                        ChordColor::SusTwo
-                        | ChordColor::SusFour
-                        | ChordColor::Five
-                        | ChordColor::PlusFive
-                        | ChordColor::Six
-                        | ChordColor::Seven
-                        | ChordColor::MajSeven
-                        | ChordColor::MinusNine
-                        | ChordColor::Nine
-                        | ChordColor::PlusNine => {
-                            let figure = match chord_color {
-                                ChordColor::SusTwo => '2',
-                                ChordColor::SusFour => '4',
-                                ChordColor::Five => '5',
-                                ChordColor::PlusFive => '5',
-                                ChordColor::Six => '6',
-                                ChordColor::MinusNine => '9',
-                                ChordColor::Nine => '9',
-                                ChordColor::PlusNine => '9',
-                                ChordColor::Seven => '7',
-                                ChordColor::MajSeven => '7',
-                                _ => ' ',
-                            };
-                            let path = crate::render::fonts::merriweather_regular::get_path(figure).to_vec();
-                            v.push(Path(
-                                PathSegments(path)
-                                    .scale_path(CHORD_FONT_FIGURE_SCALE, CHORD_FONT_FIGURE_SCALE)
-                                    .move_path(r.0 + x + color_x, r.1 + GLYPH_HEIGHT * CHORD_FONT_FIGURE_SCALE - SPACE * 0.4),
-                                NoStroke,
-                                Fillstyle(Black),
-                                PathCacheInfo::NoCache,
-                            ));
-                        }
-                        _ => {}
-                    }
-                }
-            }
-
-            // Bass slash
-            match chord_bass {
-                ChordRoot::None => {}
-                _ => {
-                    // slash
-                    v.push(Line(
-                        r.0 + x + bass_slash_x + SPACE,
-                        r.1 + GLYPH_HEIGHT * CHORD_FONT_FIGURE_SCALE - SPACE * 2.0,
-                        r.0 + x + bass_slash_x,
-                        r.1 + GLYPH_HEIGHT * CHORD_FONT_FIGURE_SCALE + SPACE * 1.3,
-                        Strokestyle(5.0, Color::Black),
-                    ));
-                    // bass root
-                    let root_char = chord_bass.get_char();
-                    let path = crate::render::fonts::merriweather_regular::get_path(root_char).to_vec();
-                    v.push(Path(
-                        PathSegments(path)
-                            .scale_path(CHORD_FONT_SCALE, CHORD_FONT_SCALE)
-                            .move_path(r.0 + x + bass_x, r.1 + GLYPH_HEIGHT * CHORD_FONT_SCALE),
-                        NoStroke,
-                        Fillstyle(Black),
-                        PathCacheInfo::NoCache,
-                    ));
-
-                    // bass sign
-                    match chord_bass {
-                        ChordRoot::CFlat | ChordRoot::DFlat | ChordRoot::EFlat | ChordRoot::FFlat | ChordRoot::GFlat | ChordRoot::AFlat | ChordRoot::BFlat => {
-                            let path = CADENZA_ACCIDENTAL_FLAT.to_vec();
-                            let acc = PathSegments(CADENZA_ACCIDENTAL_FLAT.to_vec())
-                                .scale_path(CHORD_FONT_SCALE, -CHORD_FONT_SCALE)
-                                .move_path(r.0 + x + bass_acc_x, r.1 + GLYPH_HEIGHT * CHORD_FONT_SCALE - SPACE * 1.3);
-                            v.push(Path(acc, NoStroke, Fillstyle(Black), PathCacheInfo::NoCache));
-                        }
-                        ChordRoot::CSharp | ChordRoot::DSharp | ChordRoot::ESharp | ChordRoot::FSharp | ChordRoot::GSharp | ChordRoot::ASharp | ChordRoot::BSharp => {
-                            let path = CADENZA_ACCIDENTAL_SHARP.to_vec();
-                            let acc = PathSegments(CADENZA_ACCIDENTAL_SHARP.to_vec())
-                                .scale_path(CHORD_FONT_SCALE * 0.9, -CHORD_FONT_SCALE * 0.9)
-                                .move_path(r.0 + x + bass_acc_x, r.1 + GLYPH_HEIGHT * CHORD_FONT_SCALE - SPACE * 1.6);
-                            v.push(Path(acc, NoStroke, Fillstyle(Black), PathCacheInfo::NoCache));
-                        }
-                        _ => {}
-                    }
-                }
-            }
-
-            //---------------------------------
-            // chords bounding rect
-            // v.push(Rect(r.0, r.1, r.2, r.3, Strokestyle(3.0, Orange), NoFill));
-
-            v
-        }
+        // drawn by output_main_elements, which takes the tonic from the key of the staff
+        NRectType::ChordSymbol(..) => vec![],
 
         NRectType::FunctionSymbol(ftype, fcolor, fbass, spar, epar) => {
-            dbg!(spar);
-            let mut v = Vec::new();
-
-            let mut spar_width = 10.0;
-
-            if *spar {
-                spar_width += SPACE;
-
-                let spar_path = crate::render::fonts::merriweather_regular::get_path('(').to_vec();
-                v.push(Path(
-                    PathSegments(spar_path)
-                        .scale_path(FUNCTION_FONT_SCALE, FUNCTION_FONT_SCALE)
-                        .move_path(r.0, r.1 + GLYPH_HEIGHT * FUNCTION_FONT_SCALE),
-                    NoStroke,
-                    Fillstyle(Black),
-                    PathCacheInfo::NoCache,
-                ));
-            }
-
-            let fun_path = match ftype {
-                FunctionType::T => crate::render::fonts::merriweather_regular::get_path('T').to_vec(),
-                FunctionType::D => crate::render::fonts::merriweather_regular::get_path('D').to_vec(),
-                FunctionType::S => crate::render::fonts::merriweather_regular::get_path('S').to_vec(),
-                FunctionType::Sp => {
-                    let mut p1 = PathSegments(crate::render::fonts::merriweather_regular::get_path('S').to_vec());
-                    let mut p2 = PathSegments(crate::render::fonts::merriweather_regular::get_path('p').to_vec()).move_path(430.0, 0.0);
-                    p1.extend(&p2);
-                    p1.0
-                }
-                FunctionType::Tp => {
-                    let mut p1 = PathSegments(crate::render::fonts::merriweather_regular::get_path('T').to_vec());
-                    let mut p2 = PathSegments(crate::render::fonts::merriweather_regular::get_path('p').to_vec()).move_path(540.0, 0.0);
-                    p1.extend(&p2);
-                    p1.0
-                }
-                FunctionType::DD => {
-                    let mut p1 = PathSegments(crate::render::fonts::merriweather_regular::get_path('D').to_vec());
-                    let mut p2 = PathSegments(crate::render::fonts::merriweather_regular::get_path('D').to_vec()).move_path(200.0, 120.0);
-                    p1.extend(&p2);
-                    p1.0
-                }
-
-                _ => crate::render::fonts::merriweather_regular::get_path('S').to_vec(),
-            };
-
-            match ftype {
-                FunctionType::Spacer => {}
-                _ => v.push(Path(
-                    PathSegments(fun_path)
-                        .scale_path(FUNCTION_FONT_SCALE, FUNCTION_FONT_SCALE)
-                        .move_path(r.0 + spar_width, r.1 + GLYPH_HEIGHT * FUNCTION_FONT_SCALE),
-                    NoStroke,
-                    Fillstyle(Black),
-                    PathCacheInfo::NoCache,
-                )),
-            };
-
-            let col_path_upper = match fcolor {
-                FunctionColor::Fc64 | FunctionColor::Fc6 => Some(crate::render::fonts::merriweather_regular::get_path('6').to_vec()),
-                FunctionColor::Fc7 => Some(crate::render::fonts::merriweather_regular::get_path('7').to_vec()),
-                FunctionColor::Fc5 | FunctionColor::Fc53 => Some(crate::render::fonts::merriweather_regular::get_path('5').to_vec()),
-                FunctionColor::Fc4 => Some(crate::render::fonts::merriweather_regular::get_path('4').to_vec()),
-                FunctionColor::Fc3 => Some(crate::render::fonts::merriweather_regular::get_path('3').to_vec()),
-                _ => None,
-            };
-
-            match ftype {
-                FunctionType::Spacer => spar_width -= 1.2 * SPACE,
-                FunctionType::S => spar_width -= 8.0,
-                FunctionType::DD => spar_width += 15.0,
-                FunctionType::Sp => spar_width += 23.0,
-                FunctionType::Tp => spar_width += 30.0,
-                _ => {}
-            }
-
-            match fcolor {
-                FunctionColor::FcNone => {}
-                _ => {
-                    if col_path_upper.is_some() {
-                        v.push(Path(
-                            PathSegments(col_path_upper.unwrap())
-                                .scale_path(FUNCTION_FONT_FIGURE_SCALE, FUNCTION_FONT_FIGURE_SCALE)
-                                .move_path(r.0 + spar_width + SPACE * 1.9, r.1 + GLYPH_HEIGHT * FUNCTION_FONT_FIGURE_SCALE - SPACE * 0.2),
-                            NoStroke,
-                            Fillstyle(Black),
-                            PathCacheInfo::NoCache,
-                        ))
-                    }
-                }
-            }
-
-            let col_path_lower = match fcolor {
-                FunctionColor::Fc64 => {
-                    dbg!(fcolor);
-                    Some(crate::render::fonts::merriweather_regular::get_path('4').to_vec())
-                }
-                FunctionColor::Fc53 => {
-                    dbg!(fcolor);
-                    Some(crate::render::fonts::merriweather_regular::get_path('3').to_vec())
-                }
-                _ => None,
-            };
-
-            match fcolor {
-                FunctionColor::FcNone => {}
-                _ => {
-                    if col_path_lower.is_some() {
-                        v.push(Path(
-                            PathSegments(col_path_lower.unwrap())
-                                .scale_path(FUNCTION_FONT_FIGURE_SCALE, FUNCTION_FONT_FIGURE_SCALE)
-                                .move_path(r.0 + spar_width + SPACE * 1.9, r.1 + GLYPH_HEIGHT * FUNCTION_FONT_FIGURE_SCALE + SPACE * 0.9),
-                            NoStroke,
-                            Fillstyle(Black),
-                            PathCacheInfo::NoCache,
-                        ))
-                    }
-                }
-            }
-
-            let bass_path = match fbass {
-                FunctionBass::Fb3 => crate::render::fonts::merriweather_regular::get_path('3').to_vec(),
-                _ => crate::render::fonts::merriweather_regular::get_path('x').to_vec(),
-            };
-
-            match fbass {
-                FunctionBass::FbNone => {}
-                _ => v.push(Path(
-                    PathSegments(bass_path)
-                        .scale_path(FUNCTION_FONT_FIGURE_SCALE, FUNCTION_FONT_FIGURE_SCALE)
-                        .move_path(r.0 + spar_width + SPACE * 0.8, r.1 * FUNCTION_FONT_FIGURE_SCALE + SPACE * 2.6),
-                    NoStroke,
-                    Fillstyle(Black),
-                    PathCacheInfo::NoCache,
-                )),
-            }
-
-            if *epar {
-                let end_path = crate::render::fonts::merriweather_regular::get_path(')').to_vec();
-                v.push(Path(
-                    PathSegments(end_path)
-                        .scale_path(FUNCTION_FONT_SCALE, FUNCTION_FONT_SCALE)
-                        .move_path(r.0 + spar_width + SPACE * 3.3, r.1 + GLYPH_HEIGHT * FUNCTION_FONT_SCALE),
-                    NoStroke,
-                    Fillstyle(Black),
-                    PathCacheInfo::NoCache,
-                ));
-            }
-
-            // orange rect for functions
-            // v.push(Rect(r.0, r.1, r.2, r.3, Strokestyle(3.0, Orange), NoFill));
-
-            v
+            let function = RiemannFunction::from_notation(ftype, fcolor, fbass, *spar, *epar);
+            function2graphic(&function, r.0, r.1, render_options)
         }
 
         NRectType::Symbol(size) => {